@@ -3,6 +3,27 @@ pub use sea_orm_migration::prelude::*;
 mod m20241210_000001_create_users_table;
 mod m20241220_000001_create_memos_table;
 mod m20241222_000001_add_oauth_accounts;
+mod m20250105_000001_create_refresh_tokens_table;
+mod m20250106_000001_create_personal_access_tokens_table;
+mod m20250107_000001_create_sessions_table;
+mod m20250108_000001_create_two_factor_tables;
+mod m20250109_000001_add_role_to_users;
+mod m20250110_000001_create_auth_events_table;
+mod m20250111_000001_widen_oauth_provider_column;
+mod m20250112_000001_add_memos_list_index;
+mod m20250113_000001_add_memos_search_vector;
+mod m20250114_000001_create_saved_searches_table;
+mod m20250115_000001_create_tags_tables;
+mod m20250116_000001_add_auto_tag_to_users;
+mod m20250117_000001_create_notebooks_table;
+mod m20250118_000001_create_memo_revisions_table;
+mod m20250119_000001_add_deleted_at_to_memos;
+mod m20250120_000001_add_is_archived_to_memos;
+mod m20250121_000001_bound_memo_search_vector;
+mod m20250122_000001_make_auth_events_append_only;
+mod m20250123_000001_widen_recovery_code_hash_column;
+mod m20250124_000001_add_recovery_code_prefix;
+mod m20250125_000001_add_rotated_at_to_refresh_tokens;
 
 pub struct Migrator;
 
@@ -13,6 +34,27 @@ impl MigratorTrait for Migrator {
             Box::new(m20241210_000001_create_users_table::Migration),
             Box::new(m20241220_000001_create_memos_table::Migration),
             Box::new(m20241222_000001_add_oauth_accounts::Migration),
+            Box::new(m20250105_000001_create_refresh_tokens_table::Migration),
+            Box::new(m20250106_000001_create_personal_access_tokens_table::Migration),
+            Box::new(m20250107_000001_create_sessions_table::Migration),
+            Box::new(m20250108_000001_create_two_factor_tables::Migration),
+            Box::new(m20250109_000001_add_role_to_users::Migration),
+            Box::new(m20250110_000001_create_auth_events_table::Migration),
+            Box::new(m20250111_000001_widen_oauth_provider_column::Migration),
+            Box::new(m20250112_000001_add_memos_list_index::Migration),
+            Box::new(m20250113_000001_add_memos_search_vector::Migration),
+            Box::new(m20250114_000001_create_saved_searches_table::Migration),
+            Box::new(m20250115_000001_create_tags_tables::Migration),
+            Box::new(m20250116_000001_add_auto_tag_to_users::Migration),
+            Box::new(m20250117_000001_create_notebooks_table::Migration),
+            Box::new(m20250118_000001_create_memo_revisions_table::Migration),
+            Box::new(m20250119_000001_add_deleted_at_to_memos::Migration),
+            Box::new(m20250120_000001_add_is_archived_to_memos::Migration),
+            Box::new(m20250121_000001_bound_memo_search_vector::Migration),
+            Box::new(m20250122_000001_make_auth_events_append_only::Migration),
+            Box::new(m20250123_000001_widen_recovery_code_hash_column::Migration),
+            Box::new(m20250124_000001_add_recovery_code_prefix::Migration),
+            Box::new(m20250125_000001_add_rotated_at_to_refresh_tokens::Migration),
         ]
     }
 }
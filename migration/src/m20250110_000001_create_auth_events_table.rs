@@ -0,0 +1,96 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(AuthEvents::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(AuthEvents::Id)
+                            .big_integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(AuthEvents::UserId).integer().null())
+                    .col(
+                        ColumnDef::new(AuthEvents::EventType)
+                            .string_len(32)
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(AuthEvents::Detail).string_len(255).null())
+                    .col(ColumnDef::new(AuthEvents::IpAddress).string_len(45).null())
+                    .col(ColumnDef::new(AuthEvents::UserAgent).string_len(512).null())
+                    .col(
+                        ColumnDef::new(AuthEvents::CreatedAt)
+                            .timestamp()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    // 사고 조사를 위해 탈퇴한 사용자의 기록도 남겨 둔다.
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-auth_events-user_id")
+                            .from(AuthEvents::Table, AuthEvents::UserId)
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::SetNull)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx-auth_events-user_id-created_at")
+                    .table(AuthEvents::Table)
+                    .col(AuthEvents::UserId)
+                    .col(AuthEvents::CreatedAt)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx-auth_events-created_at")
+                    .table(AuthEvents::Table)
+                    .col(AuthEvents::CreatedAt)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(AuthEvents::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum AuthEvents {
+    Table,
+    Id,
+    UserId,
+    EventType,
+    Detail,
+    IpAddress,
+    UserAgent,
+    CreatedAt,
+}
+
+#[derive(DeriveIden)]
+enum Users {
+    Table,
+    Id,
+}
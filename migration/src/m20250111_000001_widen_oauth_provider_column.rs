@@ -0,0 +1,44 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // OpenID Connect 제공자는 `oidc:<이름>` 형태로 저장되므로 기존 길이(20)로는 부족하다.
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(OAuthAccounts::Table)
+                    .modify_column(
+                        ColumnDef::new(OAuthAccounts::Provider)
+                            .string_len(64)
+                            .not_null(),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(OAuthAccounts::Table)
+                    .modify_column(
+                        ColumnDef::new(OAuthAccounts::Provider)
+                            .string_len(20)
+                            .not_null(),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum OAuthAccounts {
+    Table,
+    Provider,
+}
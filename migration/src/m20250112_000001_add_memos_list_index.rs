@@ -0,0 +1,43 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // 메모 목록의 키셋 페이지네이션(is_pinned, updated_at, id 내림차순)을 인덱스 역방향 스캔으로 처리한다.
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx-memos-user_id-is_pinned-updated_at-id")
+                    .table(Memos::Table)
+                    .col(Memos::UserId)
+                    .col(Memos::IsPinned)
+                    .col(Memos::UpdatedAt)
+                    .col(Memos::Id)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_index(
+                Index::drop()
+                    .name("idx-memos-user_id-is_pinned-updated_at-id")
+                    .table(Memos::Table)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum Memos {
+    Table,
+    Id,
+    UserId,
+    IsPinned,
+    UpdatedAt,
+}
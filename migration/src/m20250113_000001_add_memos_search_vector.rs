@@ -0,0 +1,78 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+// 기본 파서는 로캘에 따라 한글을 단어로 인식하지 못하므로(C 로캘에서는 통째로 버려진다)
+// 본문을 ASCII 공백/구두점으로만 나눈 뒤 단어마다 2글자 n-gram을 직접 만들어 tsvector로 저장한다.
+// 단어 사이에는 위치를 한 칸 띄워 `<->` 구문 검색이 단어 경계를 넘지 않게 한다.
+// 검색어 쪽 토큰화는 `utils::search`가 같은 규칙으로 수행하므로 둘을 함께 바꿔야 한다.
+pub(crate) const CREATE_SEARCH_VECTOR_FUNCTION: &str = r#"
+CREATE OR REPLACE FUNCTION memo_search_vector(body text) RETURNS tsvector
+    LANGUAGE sql IMMUTABLE STRICT PARALLEL SAFE
+AS $$
+    SELECT coalesce(
+        string_agg(format('''%s'':%s', gram, least(pos, 16383)), ' '),
+        ''
+    )::tsvector
+    FROM (
+        SELECT substr(w.word, g.i, 2) AS gram,
+               row_number() OVER (ORDER BY w.ord, g.i) + w.ord AS pos
+        FROM regexp_split_to_table(
+                 translate(body, 'ABCDEFGHIJKLMNOPQRSTUVWXYZ', 'abcdefghijklmnopqrstuvwxyz'),
+                 '[\t\n\v\f\r !-/:-@\[-`{-~]+'
+             ) WITH ORDINALITY AS w(word, ord)
+        CROSS JOIN LATERAL generate_series(1, greatest(char_length(w.word) - 1, 1)) AS g(i)
+        WHERE w.word <> ''
+    ) grams
+$$
+"#;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let db = manager.get_connection();
+
+        db.execute_unprepared(CREATE_SEARCH_VECTOR_FUNCTION).await?;
+        db.execute_unprepared(
+            "ALTER TABLE memos ADD COLUMN search_vector tsvector \
+             GENERATED ALWAYS AS (memo_search_vector(content)) STORED",
+        )
+        .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx-memos-search_vector")
+                    .table(Memos::Table)
+                    .col(Memos::SearchVector)
+                    .index_type(IndexType::Custom(SeaRc::new(Alias::new("GIN"))))
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Memos::Table)
+                    .drop_column(Memos::SearchVector)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .get_connection()
+            .execute_unprepared("DROP FUNCTION IF EXISTS memo_search_vector(text)")
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(DeriveIden)]
+enum Memos {
+    Table,
+    SearchVector,
+}
@@ -0,0 +1,103 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(SavedSearches::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(SavedSearches::Id)
+                            .integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(SavedSearches::UserId).integer().not_null())
+                    .col(
+                        ColumnDef::new(SavedSearches::Name)
+                            .string_len(100)
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(SavedSearches::Query).text().not_null())
+                    .col(ColumnDef::new(SavedSearches::Position).integer().not_null())
+                    .col(
+                        ColumnDef::new(SavedSearches::CreatedAt)
+                            .timestamp()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .col(
+                        ColumnDef::new(SavedSearches::UpdatedAt)
+                            .timestamp()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-saved_searches-user_id")
+                            .from(SavedSearches::Table, SavedSearches::UserId)
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // 같은 사용자의 저장된 검색은 이름으로 구분한다.
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx-saved_searches-user_id-name")
+                    .table(SavedSearches::Table)
+                    .col(SavedSearches::UserId)
+                    .col(SavedSearches::Name)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx-saved_searches-user_id-position")
+                    .table(SavedSearches::Table)
+                    .col(SavedSearches::UserId)
+                    .col(SavedSearches::Position)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(SavedSearches::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum SavedSearches {
+    Table,
+    Id,
+    UserId,
+    Name,
+    Query,
+    Position,
+    CreatedAt,
+    UpdatedAt,
+}
+
+#[derive(DeriveIden)]
+enum Users {
+    Table,
+    Id,
+}
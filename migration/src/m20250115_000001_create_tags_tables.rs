@@ -0,0 +1,132 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(Tags::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(Tags::Id)
+                            .integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(Tags::UserId).integer().not_null())
+                    .col(ColumnDef::new(Tags::Name).string_len(50).not_null())
+                    .col(
+                        ColumnDef::new(Tags::CreatedAt)
+                            .timestamp()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-tags-user_id")
+                            .from(Tags::Table, Tags::UserId)
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx-tags-user_id-name")
+                    .table(Tags::Table)
+                    .col(Tags::UserId)
+                    .col(Tags::Name)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(MemoTags::Table)
+                    .if_not_exists()
+                    .col(ColumnDef::new(MemoTags::MemoId).integer().not_null())
+                    .col(ColumnDef::new(MemoTags::TagId).integer().not_null())
+                    .primary_key(Index::create().col(MemoTags::MemoId).col(MemoTags::TagId))
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-memo_tags-memo_id")
+                            .from(MemoTags::Table, MemoTags::MemoId)
+                            .to(Memos::Table, Memos::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-memo_tags-tag_id")
+                            .from(MemoTags::Table, MemoTags::TagId)
+                            .to(Tags::Table, Tags::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // 기본 키는 메모 기준 조회에 쓰이고, 태그로 메모를 거를 때는 이 인덱스를 쓴다.
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx-memo_tags-tag_id-memo_id")
+                    .table(MemoTags::Table)
+                    .col(MemoTags::TagId)
+                    .col(MemoTags::MemoId)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(MemoTags::Table).to_owned())
+            .await?;
+        manager
+            .drop_table(Table::drop().table(Tags::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum Tags {
+    Table,
+    Id,
+    UserId,
+    Name,
+    CreatedAt,
+}
+
+#[derive(DeriveIden)]
+enum MemoTags {
+    Table,
+    MemoId,
+    TagId,
+}
+
+#[derive(DeriveIden)]
+enum Memos {
+    Table,
+    Id,
+}
+
+#[derive(DeriveIden)]
+enum Users {
+    Table,
+    Id,
+}
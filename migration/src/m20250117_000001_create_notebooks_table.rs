@@ -0,0 +1,136 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(Notebooks::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(Notebooks::Id)
+                            .integer()
+                            .not_null()
+                            .auto_increment()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(Notebooks::UserId).integer().not_null())
+                    .col(ColumnDef::new(Notebooks::ParentId).integer().null())
+                    .col(ColumnDef::new(Notebooks::Name).string_len(100).not_null())
+                    .col(
+                        ColumnDef::new(Notebooks::CreatedAt)
+                            .timestamp()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .col(
+                        ColumnDef::new(Notebooks::UpdatedAt)
+                            .timestamp()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-notebooks-user_id")
+                            .from(Notebooks::Table, Notebooks::UserId)
+                            .to(Users::Table, Users::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-notebooks-parent_id")
+                            .from(Notebooks::Table, Notebooks::ParentId)
+                            .to(Notebooks::Table, Notebooks::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx-notebooks-user_id-parent_id")
+                    .table(Notebooks::Table)
+                    .col(Notebooks::UserId)
+                    .col(Notebooks::ParentId)
+                    .to_owned(),
+            )
+            .await?;
+
+        // 노트북이 삭제되면 메모는 지우지 않고 노트북 밖으로 꺼낸다. 메모를 함께 지울지는 서비스에서 정한다.
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Memos::Table)
+                    .add_column(ColumnDef::new(Memos::NotebookId).integer().null())
+                    .add_foreign_key(
+                        TableForeignKey::new()
+                            .name("fk-memos-notebook_id")
+                            .from_tbl(Memos::Table)
+                            .from_col(Memos::NotebookId)
+                            .to_tbl(Notebooks::Table)
+                            .to_col(Notebooks::Id)
+                            .on_delete(ForeignKeyAction::SetNull)
+                            .on_update(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx-memos-notebook_id")
+                    .table(Memos::Table)
+                    .col(Memos::NotebookId)
+                    .to_owned(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Memos::Table)
+                    .drop_column(Memos::NotebookId)
+                    .to_owned(),
+            )
+            .await?;
+        manager
+            .drop_table(Table::drop().table(Notebooks::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum Notebooks {
+    Table,
+    Id,
+    UserId,
+    ParentId,
+    Name,
+    CreatedAt,
+    UpdatedAt,
+}
+
+#[derive(DeriveIden)]
+enum Memos {
+    Table,
+    NotebookId,
+}
+
+#[derive(DeriveIden)]
+enum Users {
+    Table,
+    Id,
+}
@@ -0,0 +1,46 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Memos::Table)
+                    .add_column(ColumnDef::new(Memos::DeletedAt).timestamp().null())
+                    .to_owned(),
+            )
+            .await?;
+
+        // 보관 기간이 지난 휴지통 메모를 주기적으로 찾는다.
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx-memos-deleted_at")
+                    .table(Memos::Table)
+                    .col(Memos::DeletedAt)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Memos::Table)
+                    .drop_column(Memos::DeletedAt)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum Memos {
+    Table,
+    DeletedAt,
+}
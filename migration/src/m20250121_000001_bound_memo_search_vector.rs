@@ -0,0 +1,60 @@
+use sea_orm_migration::prelude::*;
+
+use crate::m20250113_000001_add_memos_search_vector::CREATE_SEARCH_VECTOR_FUNCTION as UNBOUNDED_SEARCH_VECTOR_FUNCTION;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+// `substr`는 멀티바이트 문자열에서 앞에서부터 글자를 세므로 구분자 없이 긴 본문이면 n-gram 생성이
+// 글자 수의 제곱에 비례해 느려지고, 메모 INSERT/UPDATE가 그만큼 멈춘다.
+// 본문 앞 20000자만 보고, 단어를 64자 조각으로 나눠(`regexp_matches` 한 번의 선형 탐색) `substr`가
+// 한 조각 안에서만 돌게 한다. 조각 사이는 단어 사이처럼 위치를 한 칸 띄운다.
+// 위치는 어차피 16383에서 멈추므로 20000자 이후 본문은 구문 검색이 정확하지 않았다.
+// 검색어 쪽 `utils::search::query_words`도 같은 길이로 단어를 나눈다.
+const CREATE_SEARCH_VECTOR_FUNCTION: &str = r#"
+CREATE OR REPLACE FUNCTION memo_search_vector(body text) RETURNS tsvector
+    LANGUAGE sql IMMUTABLE STRICT PARALLEL SAFE
+AS $$
+    SELECT coalesce(
+        string_agg(format('''%s'':%s', gram, least(pos, 16383)), ' '),
+        ''
+    )::tsvector
+    FROM (
+        SELECT substr(w.word, g.i, 2) AS gram,
+               row_number() OVER (ORDER BY w.ord, g.i) + w.ord AS pos
+        FROM regexp_matches(
+                 translate(left(body, 20000), 'ABCDEFGHIJKLMNOPQRSTUVWXYZ', 'abcdefghijklmnopqrstuvwxyz'),
+                 '[^\t\n\v\f\r !-/:-@\[-`{-~]{1,64}',
+                 'g'
+             ) WITH ORDINALITY AS m(word, ord)
+        CROSS JOIN LATERAL (SELECT m.word[1] AS word, m.ord) AS w
+        CROSS JOIN LATERAL generate_series(1, greatest(char_length(w.word) - 1, 1)) AS g(i)
+    ) grams
+$$
+"#;
+
+// 생성 컬럼은 함수를 바꿔도 다시 계산되지 않으므로 결과가 달라질 수 있는 메모(64자 초과)를 갱신한다.
+const RECOMPUTE_SEARCH_VECTORS: &str =
+    "UPDATE memos SET content = content WHERE char_length(content) > 64";
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let db = manager.get_connection();
+
+        db.execute_unprepared(CREATE_SEARCH_VECTOR_FUNCTION).await?;
+        db.execute_unprepared(RECOMPUTE_SEARCH_VECTORS).await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let db = manager.get_connection();
+
+        db.execute_unprepared(UNBOUNDED_SEARCH_VECTOR_FUNCTION)
+            .await?;
+        db.execute_unprepared(RECOMPUTE_SEARCH_VECTORS).await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,141 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+// 기록 당시의 이메일을 함께 남긴다. 사용자 ID만으로 찾는 계정 이메일을 비워 두면 DB가 채우고,
+// 존재하지 않는 계정으로 로그인을 시도하면 서비스가 시도한 이메일을 넣는다.
+const CREATE_FILL_EMAIL_TRIGGER: &str = r#"
+CREATE OR REPLACE FUNCTION auth_events_fill_email() RETURNS trigger
+    LANGUAGE plpgsql
+AS $$
+BEGIN
+    IF NEW.email IS NULL AND NEW.user_id IS NOT NULL THEN
+        SELECT email INTO NEW.email FROM users WHERE id = NEW.user_id;
+    END IF;
+    RETURN NEW;
+END
+$$;
+
+CREATE TRIGGER auth_events_fill_email
+    BEFORE INSERT ON auth_events
+    FOR EACH ROW EXECUTE FUNCTION auth_events_fill_email();
+"#;
+
+// 감사 기록은 추가만 할 수 있다. 애플리케이션 코드가 아니라 DB가 수정과 삭제를 거부한다.
+const CREATE_APPEND_ONLY_TRIGGER: &str = r#"
+CREATE OR REPLACE FUNCTION auth_events_reject_change() RETURNS trigger
+    LANGUAGE plpgsql
+AS $$
+BEGIN
+    RAISE EXCEPTION 'auth_events is append-only';
+END
+$$;
+
+CREATE TRIGGER auth_events_append_only
+    BEFORE UPDATE OR DELETE ON auth_events
+    FOR EACH ROW EXECUTE FUNCTION auth_events_reject_change();
+
+CREATE TRIGGER auth_events_no_truncate
+    BEFORE TRUNCATE ON auth_events
+    FOR EACH STATEMENT EXECUTE FUNCTION auth_events_reject_change();
+"#;
+
+const DROP_TRIGGERS: &str = r#"
+DROP TRIGGER IF EXISTS auth_events_no_truncate ON auth_events;
+DROP TRIGGER IF EXISTS auth_events_append_only ON auth_events;
+DROP TRIGGER IF EXISTS auth_events_fill_email ON auth_events;
+DROP FUNCTION IF EXISTS auth_events_reject_change();
+DROP FUNCTION IF EXISTS auth_events_fill_email();
+"#;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // 탈퇴한 사용자의 기록도 누구의 것인지 알 수 있도록 FK(ON DELETE SET NULL)를 없애 사용자 ID를 그대로 둔다.
+        manager
+            .drop_foreign_key(
+                ForeignKey::drop()
+                    .name("fk-auth_events-user_id")
+                    .table(AuthEvents::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(AuthEvents::Table)
+                    .add_column(ColumnDef::new(AuthEvents::Email).string_len(255).null())
+                    .to_owned(),
+            )
+            .await?;
+
+        let db = manager.get_connection();
+        db.execute_unprepared(
+            "UPDATE auth_events SET email = users.email \
+             FROM users WHERE users.id = auth_events.user_id",
+        )
+        .await?;
+        db.execute_unprepared(CREATE_FILL_EMAIL_TRIGGER).await?;
+        db.execute_unprepared(CREATE_APPEND_ONLY_TRIGGER).await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx-auth_events-email-created_at")
+                    .table(AuthEvents::Table)
+                    .col(AuthEvents::Email)
+                    .col(AuthEvents::CreatedAt)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        let db = manager.get_connection();
+        db.execute_unprepared(DROP_TRIGGERS).await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(AuthEvents::Table)
+                    .drop_column(AuthEvents::Email)
+                    .to_owned(),
+            )
+            .await?;
+
+        // 탈퇴한 사용자의 기록은 FK를 되살리기 전에 예전처럼 사용자 ID를 비운다.
+        db.execute_unprepared(
+            "UPDATE auth_events SET user_id = NULL \
+             WHERE user_id IS NOT NULL AND NOT EXISTS (SELECT 1 FROM users WHERE users.id = auth_events.user_id)",
+        )
+        .await?;
+
+        manager
+            .create_foreign_key(
+                ForeignKey::create()
+                    .name("fk-auth_events-user_id")
+                    .from(AuthEvents::Table, AuthEvents::UserId)
+                    .to(Users::Table, Users::Id)
+                    .on_delete(ForeignKeyAction::SetNull)
+                    .on_update(ForeignKeyAction::Cascade)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum AuthEvents {
+    Table,
+    UserId,
+    Email,
+    CreatedAt,
+}
+
+#[derive(DeriveIden)]
+enum Users {
+    Table,
+    Id,
+}
@@ -0,0 +1,50 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // 복구 코드를 argon2 PHC 문자열로 저장하므로 SHA-256 hex 길이(64)로는 부족하다.
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(RecoveryCodes::Table)
+                    .modify_column(
+                        ColumnDef::new(RecoveryCodes::CodeHash)
+                            .string_len(255)
+                            .not_null(),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // 이전 버전은 argon2 해시를 검증하지 못하므로 해당 코드를 지운다. 사용자는 복구 코드를 다시 발급받아야 한다.
+        manager
+            .get_connection()
+            .execute_unprepared("DELETE FROM recovery_codes WHERE char_length(code_hash) > 64")
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(RecoveryCodes::Table)
+                    .modify_column(
+                        ColumnDef::new(RecoveryCodes::CodeHash)
+                            .string_len(64)
+                            .not_null(),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum RecoveryCodes {
+    Table,
+    CodeHash,
+}
@@ -0,0 +1,41 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // 입력한 코드와 맞춰 볼 후보를 하나로 줄이기 위한 비밀이 아닌 앞부분이다.
+        // 이전에 발급된 코드는 값이 없다.
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(RecoveryCodes::Table)
+                    .add_column(
+                        ColumnDef::new(RecoveryCodes::CodePrefix)
+                            .string_len(8)
+                            .null(),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(RecoveryCodes::Table)
+                    .drop_column(RecoveryCodes::CodePrefix)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum RecoveryCodes {
+    Table,
+    CodePrefix,
+}
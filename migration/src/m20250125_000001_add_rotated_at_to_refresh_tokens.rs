@@ -0,0 +1,36 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // 갱신으로 교체된 토큰과 로그아웃·세션 종료로 폐기된 토큰을 구분해, 교체된 토큰의 재사용만 탈취로 본다.
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(RefreshTokens::Table)
+                    .add_column(ColumnDef::new(RefreshTokens::RotatedAt).timestamp().null())
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(RefreshTokens::Table)
+                    .drop_column(RefreshTokens::RotatedAt)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum RefreshTokens {
+    Table,
+    RotatedAt,
+}
@@ -13,4 +13,10 @@ pub enum ClientError {
 
     #[error("Qdrant error: {0}")]
     Qdrant(String),
+
+    #[error("OAuth provider error: {0}")]
+    OAuthProvider(String),
+
+    #[error("Invalid OAuth credential: {0}")]
+    InvalidCredential(String),
 }
@@ -78,9 +78,10 @@ impl Embedder for GeminiClient {
             )));
         }
 
-        let embed_response: EmbedResponse = response.json().await.map_err(|e| {
-            ClientError::ParseError(format!("Failed to parse response: {}", e))
-        })?;
+        let embed_response: EmbedResponse = response
+            .json()
+            .await
+            .map_err(|e| ClientError::ParseError(format!("Failed to parse response: {}", e)))?;
 
         Ok(embed_response.embedding.values)
     }
@@ -91,8 +92,11 @@ impl Embedder for GeminiClient {
 }
 
 #[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
 struct GenerateRequest {
     contents: Vec<ContentItem>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    generation_config: Option<GenerationConfig>,
 }
 
 #[derive(Serialize)]
@@ -100,6 +104,14 @@ struct ContentItem {
     parts: Vec<Part>,
 }
 
+// 응답을 JSON 스키마에 맞춰 받도록 요청한다.
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct GenerationConfig {
+    response_mime_type: &'static str,
+    response_schema: serde_json::Value,
+}
+
 #[derive(Deserialize)]
 struct GenerateResponse {
     candidates: Vec<Candidate>,
@@ -120,30 +132,22 @@ struct GeneratedPart {
     text: String,
 }
 
-#[async_trait::async_trait]
-impl TextGenerator for GeminiClient {
-    async fn generate(
+#[derive(Deserialize)]
+struct TagSuggestions {
+    tags: Vec<String>,
+}
+
+impl GeminiClient {
+    async fn generate_content(
         &self,
-        prompt: &str,
-        context: Vec<String>,
+        prompt_text: String,
+        generation_config: Option<GenerationConfig>,
     ) -> Result<String, ClientError> {
-        let mut prompt_text = String::from("다음은 사용자가 과거에 작성한 메모들입니다:\n\n");
-
-        for (i, memo) in context.iter().enumerate() {
-            prompt_text.push_str(&format!("메모 {}:\n{}\n\n", i + 1, memo));
-        }
-
-        prompt_text.push_str(&format!(
-            "위 메모들을 참고하여, 다음 주제에 대한 글쓰기를 도와주세요:\n{}",
-            prompt
-        ));
-
         let request_body = GenerateRequest {
             contents: vec![ContentItem {
-                parts: vec![Part {
-                    text: prompt_text,
-                }],
+                parts: vec![Part { text: prompt_text }],
             }],
+            generation_config,
         };
 
         let response = self
@@ -166,17 +170,67 @@ impl TextGenerator for GeminiClient {
             )));
         }
 
-        let generate_response: GenerateResponse = response.json().await.map_err(|e| {
-            ClientError::ParseError(format!("Failed to parse response: {}", e))
-        })?;
+        let generate_response: GenerateResponse = response
+            .json()
+            .await
+            .map_err(|e| ClientError::ParseError(format!("Failed to parse response: {}", e)))?;
 
-        let text = generate_response
+        generate_response
             .candidates
             .first()
             .and_then(|c| c.content.parts.first())
             .map(|p| p.text.clone())
-            .ok_or_else(|| ClientError::GeminiApi("No response generated".to_string()))?;
+            .ok_or_else(|| ClientError::GeminiApi("No response generated".to_string()))
+    }
+}
+
+#[async_trait::async_trait]
+impl TextGenerator for GeminiClient {
+    async fn generate(&self, prompt: &str, context: Vec<String>) -> Result<String, ClientError> {
+        let mut prompt_text = String::from("다음은 사용자가 과거에 작성한 메모들입니다:\n\n");
+
+        for (i, memo) in context.iter().enumerate() {
+            prompt_text.push_str(&format!("메모 {}:\n{}\n\n", i + 1, memo));
+        }
 
-        Ok(text)
+        prompt_text.push_str(&format!(
+            "위 메모들을 참고하여, 다음 주제에 대한 글쓰기를 도와주세요:\n{}",
+            prompt
+        ));
+
+        self.generate_content(prompt_text, None).await
+    }
+
+    async fn suggest_tags(
+        &self,
+        content: &str,
+        existing_tags: &[String],
+    ) -> Result<Vec<String>, ClientError> {
+        let mut prompt_text = String::from(
+            "다음 메모에 붙일 태그를 최대 5개 골라 주세요. \
+             태그는 공백 없는 짧은 소문자 단어로 쓰고, \
+             아래의 기존 태그 중 맞는 것이 있으면 새 태그보다 먼저 고르세요.\n\n",
+        );
+        prompt_text.push_str(&format!("기존 태그: {}\n\n", existing_tags.join(", ")));
+        prompt_text.push_str(&format!("메모:\n{}", content));
+
+        let generation_config = GenerationConfig {
+            response_mime_type: "application/json",
+            response_schema: serde_json::json!({
+                "type": "OBJECT",
+                "properties": {
+                    "tags": { "type": "ARRAY", "items": { "type": "STRING" } }
+                },
+                "required": ["tags"]
+            }),
+        };
+        let text = self
+            .generate_content(prompt_text, Some(generation_config))
+            .await?;
+
+        let suggestions: TagSuggestions = serde_json::from_str(&text).map_err(|e| {
+            ClientError::ParseError(format!("Failed to parse tag suggestions: {}", e))
+        })?;
+        Ok(suggestions.tags)
     }
 }
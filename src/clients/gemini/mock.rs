@@ -38,11 +38,7 @@ impl Embedder for MockGeminiClient {
 
 #[async_trait::async_trait]
 impl TextGenerator for MockGeminiClient {
-    async fn generate(
-        &self,
-        prompt: &str,
-        context: Vec<String>,
-    ) -> Result<String, ClientError> {
+    async fn generate(&self, prompt: &str, context: Vec<String>) -> Result<String, ClientError> {
         let mut result = format!("AI 제안 (prompt: {})\n\n", prompt);
 
         if !context.is_empty() {
@@ -56,4 +52,27 @@ impl TextGenerator for MockGeminiClient {
 
         Ok(result)
     }
+
+    // 기존 태그 중 본문에 나오는 것과 본문의 `#해시태그`를 돌려준다.
+    async fn suggest_tags(
+        &self,
+        content: &str,
+        existing_tags: &[String],
+    ) -> Result<Vec<String>, ClientError> {
+        let lowercase = content.to_lowercase();
+        let mut tags: Vec<String> = existing_tags
+            .iter()
+            .filter(|tag| lowercase.contains(tag.as_str()))
+            .cloned()
+            .collect();
+        tags.extend(
+            content
+                .split_whitespace()
+                .filter_map(|word| word.strip_prefix('#'))
+                .filter(|tag| !tag.is_empty())
+                .map(str::to_string),
+        );
+
+        Ok(tags)
+    }
 }
@@ -34,17 +34,10 @@ async fn test_real_gemini_generation() {
     let client = GeminiClient::new(api_key);
 
     let result = client
-        .generate(
-            "사랑에 대해 쓰고 싶어",
-            vec!["사랑은 수용이다".to_string()],
-        )
+        .generate("사랑에 대해 쓰고 싶어", vec!["사랑은 수용이다".to_string()])
         .await;
 
-    assert!(
-        result.is_ok(),
-        "Text generation failed: {:?}",
-        result.err()
-    );
+    assert!(result.is_ok(), "Text generation failed: {:?}", result.err());
     let text = result.unwrap();
     assert!(!text.is_empty(), "Generated text is empty");
 
@@ -52,3 +45,26 @@ async fn test_real_gemini_generation() {
     println!("   생성된 텍스트 길이: {} bytes", text.len());
     println!("   생성된 텍스트:\n{}", text);
 }
+
+#[tokio::test]
+#[ignore]
+async fn test_real_gemini_tag_suggestions() {
+    dotenv::dotenv().ok();
+    let api_key = std::env::var("GEMINI_API_KEY").expect("GEMINI_API_KEY not set");
+
+    let client = GeminiClient::new(api_key);
+
+    let result = client
+        .suggest_tags(
+            "tokio로 비동기 웹 서버를 만들며 배운 점",
+            &["rust".to_string(), "요리".to_string()],
+        )
+        .await;
+
+    assert!(result.is_ok(), "Tag suggestion failed: {:?}", result.err());
+    let tags = result.unwrap();
+    assert!(!tags.is_empty(), "No tags suggested");
+
+    println!("✅ Gemini 태그 제안 성공!");
+    println!("   제안된 태그: {:?}", tags);
+}
@@ -8,9 +8,12 @@ pub trait Embedder: Send + Sync {
 
 #[async_trait::async_trait]
 pub trait TextGenerator: Send + Sync {
-    async fn generate(
+    async fn generate(&self, prompt: &str, context: Vec<String>) -> Result<String, ClientError>;
+
+    // 메모에 어울리는 태그 후보. `existing_tags`는 사용자가 이미 쓰는 태그로, 맞는 것이 있으면 우선 고른다.
+    async fn suggest_tags(
         &self,
-        prompt: &str,
-        context: Vec<String>,
-    ) -> Result<String, ClientError>;
+        content: &str,
+        existing_tags: &[String],
+    ) -> Result<Vec<String>, ClientError>;
 }
@@ -1,5 +1,7 @@
 pub mod errors;
 pub mod gemini;
+pub mod oauth;
 
 pub use errors::ClientError;
 pub use gemini::{Embedder, GeminiClient, TextGenerator};
+pub use oauth::{OAuthClient, OAuthConfig, OAuthVerifier, OidcProviderConfig, VerifiedIdentity};
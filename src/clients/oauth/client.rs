@@ -0,0 +1,567 @@
+use super::traits::{OAuthVerifier, VerifiedIdentity};
+use crate::clients::ClientError;
+use crate::entities::oauth_account::OAuthProvider;
+use jsonwebtoken::{decode, decode_header, jwk::JwkSet, Algorithm, DecodingKey, Validation};
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+use tokio::sync::RwLock;
+
+const GOOGLE_JWKS_URL: &str = "https://www.googleapis.com/oauth2/v3/certs";
+const GOOGLE_ISSUERS: [&str; 2] = ["accounts.google.com", "https://accounts.google.com"];
+const KAKAO_USERINFO_URL: &str = "https://kapi.kakao.com/v2/user/me";
+const KAKAO_TOKEN_INFO_URL: &str = "https://kapi.kakao.com/v1/user/access_token_info";
+const NAVER_USERINFO_URL: &str = "https://openapi.naver.com/v1/nid/me";
+const NAVER_TOKEN_INFO_URL: &str = "https://openapi.naver.com/v1/nid/verify";
+
+const JWKS_CACHE_TTL: Duration = Duration::from_secs(3600);
+// 모르는 `kid`가 올 때마다 JWKS를 다시 받지 않도록 최소 간격을 둔다.
+const JWKS_MIN_REFETCH_INTERVAL: Duration = Duration::from_secs(60);
+const OIDC_DISCOVERY_PATH: &str = "/.well-known/openid-configuration";
+
+#[derive(Debug, Clone)]
+pub struct OAuthConfig {
+    pub google_client_ids: Vec<String>,
+    pub google_jwks_url: String,
+    pub google_issuers: Vec<String>,
+    pub kakao_app_ids: Vec<String>,
+    pub kakao_userinfo_url: String,
+    pub kakao_token_info_url: String,
+    pub naver_client_ids: Vec<String>,
+    pub naver_userinfo_url: String,
+    pub naver_token_info_url: String,
+    pub oidc_providers: Vec<OidcProviderConfig>,
+}
+
+#[derive(Debug, Clone)]
+pub struct OidcProviderConfig {
+    /// `oauth_accounts.provider`에 `oidc:<name>`으로 저장되는 이름
+    pub name: String,
+    pub issuer: String,
+    pub client_ids: Vec<String>,
+}
+
+impl OAuthConfig {
+    pub fn from_env() -> Self {
+        let google_client_ids = env_list("GOOGLE_CLIENT_IDS");
+
+        // OIDC_PROVIDERS=company 이면 OIDC_COMPANY_ISSUER, OIDC_COMPANY_CLIENT_IDS를 읽는다.
+        let oidc_providers = env_list("OIDC_PROVIDERS")
+            .into_iter()
+            .filter_map(|name| {
+                if !OAuthProvider::is_valid_oidc_name(&name) {
+                    tracing::warn!("Ignoring OIDC provider with invalid name: {}", name);
+                    return None;
+                }
+                let prefix = format!("OIDC_{}", name.to_uppercase().replace('-', "_"));
+                let Ok(issuer) = std::env::var(format!("{}_ISSUER", prefix)) else {
+                    tracing::warn!(
+                        "Ignoring OIDC provider {}: {}_ISSUER is not set",
+                        name,
+                        prefix
+                    );
+                    return None;
+                };
+                Some(OidcProviderConfig {
+                    client_ids: env_list(&format!("{}_CLIENT_IDS", prefix)),
+                    name,
+                    issuer,
+                })
+            })
+            .collect();
+
+        Self {
+            google_client_ids,
+            google_jwks_url: std::env::var("GOOGLE_JWKS_URL")
+                .unwrap_or_else(|_| GOOGLE_JWKS_URL.to_string()),
+            google_issuers: GOOGLE_ISSUERS.iter().map(|s| s.to_string()).collect(),
+            kakao_app_ids: env_list("KAKAO_APP_IDS"),
+            kakao_userinfo_url: std::env::var("KAKAO_USERINFO_URL")
+                .unwrap_or_else(|_| KAKAO_USERINFO_URL.to_string()),
+            kakao_token_info_url: std::env::var("KAKAO_TOKEN_INFO_URL")
+                .unwrap_or_else(|_| KAKAO_TOKEN_INFO_URL.to_string()),
+            naver_client_ids: env_list("NAVER_CLIENT_IDS"),
+            naver_userinfo_url: std::env::var("NAVER_USERINFO_URL")
+                .unwrap_or_else(|_| NAVER_USERINFO_URL.to_string()),
+            naver_token_info_url: std::env::var("NAVER_TOKEN_INFO_URL")
+                .unwrap_or_else(|_| NAVER_TOKEN_INFO_URL.to_string()),
+            oidc_providers,
+        }
+    }
+}
+
+fn env_list(key: &str) -> Vec<String> {
+    std::env::var(key)
+        .unwrap_or_default()
+        .split(',')
+        .map(str::trim)
+        .filter(|v| !v.is_empty())
+        .map(String::from)
+        .collect()
+}
+
+struct CachedJwks {
+    keys: JwkSet,
+    fetched_at: Instant,
+}
+
+struct CachedOidcMetadata {
+    metadata: OidcMetadata,
+    fetched_at: Instant,
+}
+
+pub struct OAuthClient {
+    config: OAuthConfig,
+    client: reqwest::Client,
+    // JWKS URL별 캐시. Google과 OIDC 발급자가 함께 사용한다.
+    jwks_cache: RwLock<HashMap<String, CachedJwks>>,
+    oidc_metadata: RwLock<HashMap<String, CachedOidcMetadata>>,
+}
+
+impl OAuthClient {
+    pub fn new(config: OAuthConfig) -> Self {
+        Self {
+            config,
+            client: reqwest::Client::new(),
+            jwks_cache: RwLock::new(HashMap::new()),
+            oidc_metadata: RwLock::new(HashMap::new()),
+        }
+    }
+
+    async fn decoding_key(&self, jwks_url: &str, kid: &str) -> Result<DecodingKey, ClientError> {
+        {
+            let cache = self.jwks_cache.read().await;
+            if let Some(cached) = cache.get(jwks_url) {
+                if cached.fetched_at.elapsed() < JWKS_CACHE_TTL {
+                    if let Some(jwk) = cached.keys.find(kid) {
+                        return DecodingKey::from_jwk(jwk).map_err(|e| {
+                            ClientError::OAuthProvider(format!("Invalid JWK: {}", e))
+                        });
+                    }
+                }
+                // 키 교체 직후가 아니라면 모르는 `kid`는 위조된 토큰이므로 다시 받지 않는다.
+                if cached.fetched_at.elapsed() < JWKS_MIN_REFETCH_INTERVAL {
+                    return Err(ClientError::InvalidCredential(
+                        "Unknown signing key".to_string(),
+                    ));
+                }
+            }
+        }
+
+        let keys: JwkSet = self
+            .client
+            .get(jwks_url)
+            .send()
+            .await
+            .map_err(|e| ClientError::OAuthProvider(format!("Failed to fetch JWKS: {}", e)))?
+            .error_for_status()
+            .map_err(|e| ClientError::OAuthProvider(format!("Failed to fetch JWKS: {}", e)))?
+            .json()
+            .await
+            .map_err(|e| ClientError::OAuthProvider(format!("Failed to parse JWKS: {}", e)))?;
+
+        let key = keys
+            .find(kid)
+            .ok_or_else(|| ClientError::InvalidCredential("Unknown signing key".to_string()))
+            .and_then(|jwk| {
+                DecodingKey::from_jwk(jwk)
+                    .map_err(|e| ClientError::OAuthProvider(format!("Invalid JWK: {}", e)))
+            });
+
+        self.jwks_cache.write().await.insert(
+            jwks_url.to_string(),
+            CachedJwks {
+                keys,
+                fetched_at: Instant::now(),
+            },
+        );
+
+        key
+    }
+
+    async fn verify_google(&self, id_token: &str) -> Result<VerifiedIdentity, ClientError> {
+        if self.config.google_client_ids.is_empty() {
+            return Err(ClientError::OAuthProvider(
+                "Google client ID is not configured".to_string(),
+            ));
+        }
+
+        let header = decode_header(id_token)
+            .map_err(|e| ClientError::InvalidCredential(format!("Malformed ID token: {}", e)))?;
+        let kid = header
+            .kid
+            .ok_or_else(|| ClientError::InvalidCredential("ID token has no key ID".to_string()))?;
+
+        let key = self
+            .decoding_key(&self.config.google_jwks_url, &kid)
+            .await?;
+
+        let mut validation = Validation::new(Algorithm::RS256);
+        validation.set_audience(&self.config.google_client_ids);
+        validation.set_issuer(&self.config.google_issuers);
+
+        let claims = decode::<GoogleClaims>(id_token, &key, &validation)
+            .map_err(|e| ClientError::InvalidCredential(format!("ID token rejected: {}", e)))?
+            .claims;
+
+        let email = claims
+            .email
+            .ok_or_else(|| ClientError::InvalidCredential("Email not provided".to_string()))?;
+        if !claims.email_verified.unwrap_or(false) {
+            return Err(ClientError::InvalidCredential(
+                "Email is not verified".to_string(),
+            ));
+        }
+
+        Ok(VerifiedIdentity {
+            provider_user_id: claims.sub,
+            email,
+            name: claims.name,
+        })
+    }
+
+    async fn oidc_metadata(
+        &self,
+        provider: &OidcProviderConfig,
+    ) -> Result<OidcMetadata, ClientError> {
+        {
+            let cache = self.oidc_metadata.read().await;
+            if let Some(cached) = cache.get(&provider.name) {
+                if cached.fetched_at.elapsed() < JWKS_CACHE_TTL {
+                    return Ok(cached.metadata.clone());
+                }
+            }
+        }
+
+        let url = format!(
+            "{}{}",
+            provider.issuer.trim_end_matches('/'),
+            OIDC_DISCOVERY_PATH
+        );
+        let metadata: OidcMetadata = self
+            .client
+            .get(&url)
+            .send()
+            .await
+            .map_err(|e| {
+                ClientError::OAuthProvider(format!("Failed to fetch OIDC metadata: {}", e))
+            })?
+            .error_for_status()
+            .map_err(|e| {
+                ClientError::OAuthProvider(format!("Failed to fetch OIDC metadata: {}", e))
+            })?
+            .json()
+            .await
+            .map_err(|e| {
+                ClientError::OAuthProvider(format!("Failed to parse OIDC metadata: {}", e))
+            })?;
+
+        // 메타데이터의 issuer가 설정과 다르면 다른 발급자의 문서를 받은 것이므로 신뢰하지 않는다.
+        if metadata.issuer != provider.issuer {
+            return Err(ClientError::OAuthProvider(format!(
+                "OIDC issuer mismatch: expected {}, got {}",
+                provider.issuer, metadata.issuer
+            )));
+        }
+
+        self.oidc_metadata.write().await.insert(
+            provider.name.clone(),
+            CachedOidcMetadata {
+                metadata: metadata.clone(),
+                fetched_at: Instant::now(),
+            },
+        );
+
+        Ok(metadata)
+    }
+
+    async fn verify_oidc(
+        &self,
+        name: &str,
+        id_token: &str,
+    ) -> Result<VerifiedIdentity, ClientError> {
+        let provider = self
+            .config
+            .oidc_providers
+            .iter()
+            .find(|p| p.name == name)
+            .ok_or_else(|| {
+                ClientError::InvalidCredential(format!("Unknown OIDC provider: {}", name))
+            })?;
+        if provider.client_ids.is_empty() {
+            return Err(ClientError::OAuthProvider(format!(
+                "OIDC client ID is not configured for {}",
+                name
+            )));
+        }
+
+        let header = decode_header(id_token)
+            .map_err(|e| ClientError::InvalidCredential(format!("Malformed ID token: {}", e)))?;
+        let kid = header
+            .kid
+            .ok_or_else(|| ClientError::InvalidCredential("ID token has no key ID".to_string()))?;
+
+        let metadata = self.oidc_metadata(provider).await?;
+        // 대칭 키 알고리즘은 JWKS로 검증할 수 없으므로 발급자가 광고한 비대칭 알고리즘만 허용한다.
+        let algorithm_allowed = !matches!(
+            header.alg,
+            Algorithm::HS256 | Algorithm::HS384 | Algorithm::HS512
+        ) && metadata
+            .id_token_signing_alg_values_supported
+            .iter()
+            .any(|alg| alg.parse::<Algorithm>().ok() == Some(header.alg));
+        if !algorithm_allowed {
+            return Err(ClientError::InvalidCredential(format!(
+                "Unsupported ID token algorithm: {:?}",
+                header.alg
+            )));
+        }
+
+        let key = self.decoding_key(&metadata.jwks_uri, &kid).await?;
+
+        let mut validation = Validation::new(header.alg);
+        validation.set_audience(&provider.client_ids);
+        validation.set_issuer(&[&provider.issuer]);
+
+        let claims = decode::<OidcClaims>(id_token, &key, &validation)
+            .map_err(|e| ClientError::InvalidCredential(format!("ID token rejected: {}", e)))?
+            .claims;
+
+        let email = claims
+            .email
+            .ok_or_else(|| ClientError::InvalidCredential("Email not provided".to_string()))?;
+        if !claims.email_verified.unwrap_or(false) {
+            return Err(ClientError::InvalidCredential(
+                "Email is not verified".to_string(),
+            ));
+        }
+
+        Ok(VerifiedIdentity {
+            provider_user_id: claims.sub,
+            email,
+            name: claims.name.or(claims.preferred_username),
+        })
+    }
+
+    async fn fetch_userinfo<T: for<'de> Deserialize<'de>>(
+        &self,
+        url: &str,
+        access_token: &str,
+    ) -> Result<T, ClientError> {
+        let response = self
+            .client
+            .get(url)
+            .bearer_auth(access_token)
+            .send()
+            .await
+            .map_err(|e| ClientError::OAuthProvider(format!("Failed to send request: {}", e)))?;
+
+        let status = response.status();
+        if status == reqwest::StatusCode::UNAUTHORIZED || status == reqwest::StatusCode::FORBIDDEN {
+            return Err(ClientError::InvalidCredential(
+                "Access token rejected by provider".to_string(),
+            ));
+        }
+        if !status.is_success() {
+            return Err(ClientError::OAuthProvider(format!(
+                "Userinfo request failed with status {}",
+                status
+            )));
+        }
+
+        response
+            .json()
+            .await
+            .map_err(|e| ClientError::OAuthProvider(format!("Failed to parse userinfo: {}", e)))
+    }
+
+    // 사용자 정보 조회가 성공해도 다른 앱에 발급된 토큰일 수 있으므로 발급 대상 앱을 먼저 확인한다.
+    async fn verify_kakao(&self, access_token: &str) -> Result<VerifiedIdentity, ClientError> {
+        if self.config.kakao_app_ids.is_empty() {
+            return Err(ClientError::OAuthProvider(
+                "Kakao app ID is not configured".to_string(),
+            ));
+        }
+
+        let token_info: KakaoTokenInfo = self
+            .fetch_userinfo(&self.config.kakao_token_info_url, access_token)
+            .await?;
+        if !self
+            .config
+            .kakao_app_ids
+            .contains(&token_info.app_id.to_string())
+        {
+            return Err(ClientError::InvalidCredential(
+                "Access token was issued to another app".to_string(),
+            ));
+        }
+
+        let user: KakaoUser = self
+            .fetch_userinfo(&self.config.kakao_userinfo_url, access_token)
+            .await?;
+
+        let account = user.kakao_account.unwrap_or_default();
+        let email = account
+            .email
+            .ok_or_else(|| ClientError::InvalidCredential("Email not provided".to_string()))?;
+        if !(account.is_email_valid.unwrap_or(false) && account.is_email_verified.unwrap_or(false))
+        {
+            return Err(ClientError::InvalidCredential(
+                "Email is not verified".to_string(),
+            ));
+        }
+
+        Ok(VerifiedIdentity {
+            provider_user_id: user.id.to_string(),
+            email,
+            name: account.profile.and_then(|p| p.nickname),
+        })
+    }
+
+    async fn verify_naver(&self, access_token: &str) -> Result<VerifiedIdentity, ClientError> {
+        if self.config.naver_client_ids.is_empty() {
+            return Err(ClientError::OAuthProvider(
+                "Naver client ID is not configured".to_string(),
+            ));
+        }
+
+        let token_info: NaverTokenInfoResponse = self
+            .fetch_userinfo(&self.config.naver_token_info_url, access_token)
+            .await?;
+        let client_id = match token_info.response {
+            Some(info) if token_info.resultcode == "00" => info.client_id,
+            _ => {
+                return Err(ClientError::InvalidCredential(format!(
+                    "Naver rejected access token: {}",
+                    token_info.message
+                )))
+            }
+        };
+        if !self.config.naver_client_ids.contains(&client_id) {
+            return Err(ClientError::InvalidCredential(
+                "Access token was issued to another app".to_string(),
+            ));
+        }
+
+        let body: NaverUserResponse = self
+            .fetch_userinfo(&self.config.naver_userinfo_url, access_token)
+            .await?;
+
+        if body.resultcode != "00" {
+            return Err(ClientError::InvalidCredential(format!(
+                "Naver rejected access token: {}",
+                body.message
+            )));
+        }
+
+        let user = body
+            .response
+            .ok_or_else(|| ClientError::OAuthProvider("Empty Naver profile".to_string()))?;
+        let email = user
+            .email
+            .ok_or_else(|| ClientError::InvalidCredential("Email not provided".to_string()))?;
+
+        Ok(VerifiedIdentity {
+            provider_user_id: user.id,
+            email,
+            name: user.nickname.or(user.name),
+        })
+    }
+}
+
+#[derive(Deserialize)]
+struct GoogleClaims {
+    sub: String,
+    email: Option<String>,
+    email_verified: Option<bool>,
+    name: Option<String>,
+}
+
+#[derive(Deserialize, Clone)]
+struct OidcMetadata {
+    issuer: String,
+    jwks_uri: String,
+    #[serde(default = "default_signing_algorithms")]
+    id_token_signing_alg_values_supported: Vec<String>,
+}
+
+// OIDC Discovery 명세상 RS256은 항상 지원해야 한다.
+fn default_signing_algorithms() -> Vec<String> {
+    vec!["RS256".to_string()]
+}
+
+#[derive(Deserialize)]
+struct OidcClaims {
+    sub: String,
+    email: Option<String>,
+    email_verified: Option<bool>,
+    name: Option<String>,
+    preferred_username: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct KakaoTokenInfo {
+    app_id: i64,
+}
+
+#[derive(Deserialize)]
+struct KakaoUser {
+    id: i64,
+    kakao_account: Option<KakaoAccount>,
+}
+
+#[derive(Deserialize, Default)]
+struct KakaoAccount {
+    email: Option<String>,
+    is_email_valid: Option<bool>,
+    is_email_verified: Option<bool>,
+    profile: Option<KakaoProfile>,
+}
+
+#[derive(Deserialize)]
+struct KakaoProfile {
+    nickname: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct NaverTokenInfoResponse {
+    resultcode: String,
+    message: String,
+    response: Option<NaverTokenInfo>,
+}
+
+#[derive(Deserialize)]
+struct NaverTokenInfo {
+    client_id: String,
+}
+
+#[derive(Deserialize)]
+struct NaverUserResponse {
+    resultcode: String,
+    message: String,
+    response: Option<NaverUser>,
+}
+
+#[derive(Deserialize)]
+struct NaverUser {
+    id: String,
+    email: Option<String>,
+    nickname: Option<String>,
+    name: Option<String>,
+}
+
+#[async_trait::async_trait]
+impl OAuthVerifier for OAuthClient {
+    async fn verify(
+        &self,
+        provider: &OAuthProvider,
+        credential: &str,
+    ) -> Result<VerifiedIdentity, ClientError> {
+        match provider {
+            OAuthProvider::Google => self.verify_google(credential).await,
+            OAuthProvider::Kakao => self.verify_kakao(credential).await,
+            OAuthProvider::Naver => self.verify_naver(credential).await,
+            OAuthProvider::Oidc(name) => self.verify_oidc(name, credential).await,
+        }
+    }
+}
@@ -0,0 +1,8 @@
+mod client;
+mod traits;
+
+#[cfg(test)]
+mod tests;
+
+pub use client::{OAuthClient, OAuthConfig, OidcProviderConfig};
+pub use traits::{OAuthVerifier, VerifiedIdentity};
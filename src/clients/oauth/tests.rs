@@ -0,0 +1,269 @@
+use super::*;
+use crate::{
+    clients::ClientError,
+    entities::oauth_account::OAuthProvider,
+    test_utils::{
+        mock_oauth_server::{
+            MOCK_GOOGLE_CLIENT_ID, MOCK_GOOGLE_ISSUER, MOCK_OIDC_CLIENT_ID, MOCK_OIDC_PROVIDER,
+        },
+        MockOAuthServer,
+    },
+};
+use chrono::{Duration, Utc};
+use serde_json::json;
+
+#[tokio::test]
+async fn test_verify_google_id_token() {
+    let server = MockOAuthServer::start().await;
+    let client = OAuthClient::new(server.config());
+
+    let token = server.google_id_token("google-sub-1", "google@example.com");
+    let identity = client.verify(&OAuthProvider::Google, &token).await.unwrap();
+
+    assert_eq!(identity.provider_user_id, "google-sub-1");
+    assert_eq!(identity.email, "google@example.com");
+}
+
+#[tokio::test]
+async fn test_verify_google_rejects_wrong_audience() {
+    let server = MockOAuthServer::start().await;
+    let client = OAuthClient::new(server.config());
+
+    let now = Utc::now();
+    let token = server.sign_google_id_token(json!({
+        "iss": MOCK_GOOGLE_ISSUER,
+        "aud": "someone-elses-client-id",
+        "sub": "google-sub-2",
+        "email": "google2@example.com",
+        "email_verified": true,
+        "iat": now.timestamp(),
+        "exp": (now + Duration::hours(1)).timestamp(),
+    }));
+
+    let result = client.verify(&OAuthProvider::Google, &token).await;
+    assert!(matches!(result, Err(ClientError::InvalidCredential(_))));
+}
+
+#[tokio::test]
+async fn test_verify_google_rejects_unverified_email() {
+    let server = MockOAuthServer::start().await;
+    let client = OAuthClient::new(server.config());
+
+    let now = Utc::now();
+    let token = server.sign_google_id_token(json!({
+        "iss": MOCK_GOOGLE_ISSUER,
+        "aud": server.config().google_client_ids[0],
+        "sub": "google-sub-3",
+        "email": "google3@example.com",
+        "email_verified": false,
+        "iat": now.timestamp(),
+        "exp": (now + Duration::hours(1)).timestamp(),
+    }));
+
+    let result = client.verify(&OAuthProvider::Google, &token).await;
+    assert!(matches!(result, Err(ClientError::InvalidCredential(_))));
+}
+
+#[tokio::test]
+async fn test_verify_google_does_not_refetch_jwks_for_unknown_kid() {
+    let server = MockOAuthServer::start().await;
+    let client = OAuthClient::new(server.config());
+
+    let token = server.google_id_token("google-sub-4", "google4@example.com");
+    client.verify(&OAuthProvider::Google, &token).await.unwrap();
+    assert_eq!(server.jwks_fetch_count(), 1);
+
+    // 모르는 `kid`로 반복 요청해도 최소 간격 안에서는 JWKS를 다시 받지 않는다.
+    let now = Utc::now();
+    for i in 0..5 {
+        let forged = server.sign_id_token_with_kid(
+            json!({
+                "iss": MOCK_GOOGLE_ISSUER,
+                "aud": MOCK_GOOGLE_CLIENT_ID,
+                "sub": "google-sub-4",
+                "email": "google4@example.com",
+                "email_verified": true,
+                "iat": now.timestamp(),
+                "exp": (now + Duration::hours(1)).timestamp(),
+            }),
+            &format!("unknown-key-{}", i),
+        );
+        let result = client.verify(&OAuthProvider::Google, &forged).await;
+        assert!(matches!(result, Err(ClientError::InvalidCredential(_))));
+    }
+    assert_eq!(server.jwks_fetch_count(), 1);
+}
+
+#[tokio::test]
+async fn test_verify_kakao_and_naver_access_tokens() {
+    let server = MockOAuthServer::start().await;
+    let client = OAuthClient::new(server.config());
+
+    let kakao_token = server.kakao_access_token("1001", "kakao@example.com");
+    let kakao = client
+        .verify(&OAuthProvider::Kakao, &kakao_token)
+        .await
+        .unwrap();
+    assert_eq!(kakao.provider_user_id, "1001");
+    assert_eq!(kakao.email, "kakao@example.com");
+
+    let naver_token = server.naver_access_token("naver-1001", "naver@example.com");
+    let naver = client
+        .verify(&OAuthProvider::Naver, &naver_token)
+        .await
+        .unwrap();
+    assert_eq!(naver.provider_user_id, "naver-1001");
+    assert_eq!(naver.email, "naver@example.com");
+}
+
+#[tokio::test]
+async fn test_verify_rejects_unknown_access_tokens() {
+    let server = MockOAuthServer::start().await;
+    let client = OAuthClient::new(server.config());
+
+    let kakao = client.verify(&OAuthProvider::Kakao, "not-a-token").await;
+    assert!(matches!(kakao, Err(ClientError::InvalidCredential(_))));
+
+    let naver = client.verify(&OAuthProvider::Naver, "not-a-token").await;
+    assert!(matches!(naver, Err(ClientError::InvalidCredential(_))));
+}
+
+#[tokio::test]
+async fn test_verify_rejects_access_tokens_issued_to_other_apps() {
+    let server = MockOAuthServer::start().await;
+    let client = OAuthClient::new(server.config());
+
+    let kakao_token = server.kakao_access_token_for_app("1002", "kakao2@example.com", "999999");
+    let kakao = client.verify(&OAuthProvider::Kakao, &kakao_token).await;
+    assert!(matches!(kakao, Err(ClientError::InvalidCredential(_))));
+
+    let naver_token =
+        server.naver_access_token_for_app("naver-1002", "naver2@example.com", "other-client");
+    let naver = client.verify(&OAuthProvider::Naver, &naver_token).await;
+    assert!(matches!(naver, Err(ClientError::InvalidCredential(_))));
+}
+
+#[tokio::test]
+async fn test_verify_oidc_id_token_via_discovery() {
+    let server = MockOAuthServer::start().await;
+    let client = OAuthClient::new(server.config());
+    let provider = OAuthProvider::Oidc(MOCK_OIDC_PROVIDER.to_string());
+
+    let token = server.oidc_id_token("employee-1", "employee@company.example");
+    let identity = client.verify(&provider, &token).await.unwrap();
+
+    assert_eq!(identity.provider_user_id, "employee-1");
+    assert_eq!(identity.email, "employee@company.example");
+    assert_eq!(identity.name.as_deref(), Some("employee-1"));
+
+    // 메타데이터와 JWKS가 캐시된 뒤에도 검증된다.
+    let again = client.verify(&provider, &token).await.unwrap();
+    assert_eq!(again, identity);
+}
+
+#[tokio::test]
+async fn test_verify_oidc_rejects_token_from_other_issuer() {
+    let server = MockOAuthServer::start().await;
+    let client = OAuthClient::new(server.config());
+    let provider = OAuthProvider::Oidc(MOCK_OIDC_PROVIDER.to_string());
+
+    let now = Utc::now();
+    let claims = |iss: &str, aud: &str| {
+        json!({
+            "iss": iss,
+            "aud": aud,
+            "sub": "employee-2",
+            "email": "employee2@company.example",
+            "email_verified": true,
+            "iat": now.timestamp(),
+            "exp": (now + Duration::hours(1)).timestamp(),
+        })
+    };
+
+    let wrong_issuer = server.sign_id_token(claims(MOCK_GOOGLE_ISSUER, MOCK_OIDC_CLIENT_ID));
+    let result = client.verify(&provider, &wrong_issuer).await;
+    assert!(matches!(result, Err(ClientError::InvalidCredential(_))));
+
+    let wrong_audience = server.sign_id_token(claims(&server.oidc_issuer(), "other-client"));
+    let result = client.verify(&provider, &wrong_audience).await;
+    assert!(matches!(result, Err(ClientError::InvalidCredential(_))));
+}
+
+#[tokio::test]
+async fn test_verify_oidc_rejects_token_without_email_verified() {
+    let server = MockOAuthServer::start().await;
+    let client = OAuthClient::new(server.config());
+    let provider = OAuthProvider::Oidc(MOCK_OIDC_PROVIDER.to_string());
+
+    let now = Utc::now();
+    let token = server.sign_id_token(json!({
+        "iss": server.oidc_issuer(),
+        "aud": MOCK_OIDC_CLIENT_ID,
+        "sub": "employee-4",
+        "email": "employee4@company.example",
+        "iat": now.timestamp(),
+        "exp": (now + Duration::hours(1)).timestamp(),
+    }));
+
+    let result = client.verify(&provider, &token).await;
+    assert!(matches!(result, Err(ClientError::InvalidCredential(_))));
+}
+
+#[tokio::test]
+async fn test_verify_oidc_does_not_refetch_jwks_for_unknown_kid() {
+    let server = MockOAuthServer::start().await;
+    let client = OAuthClient::new(server.config());
+    let provider = OAuthProvider::Oidc(MOCK_OIDC_PROVIDER.to_string());
+
+    let token = server.oidc_id_token("employee-5", "employee5@company.example");
+    client.verify(&provider, &token).await.unwrap();
+    assert_eq!(server.jwks_fetch_count(), 1);
+
+    let now = Utc::now();
+    for i in 0..5 {
+        let forged = server.sign_id_token_with_kid(
+            json!({
+                "iss": server.oidc_issuer(),
+                "aud": MOCK_OIDC_CLIENT_ID,
+                "sub": "employee-5",
+                "email": "employee5@company.example",
+                "email_verified": true,
+                "iat": now.timestamp(),
+                "exp": (now + Duration::hours(1)).timestamp(),
+            }),
+            &format!("unknown-key-{}", i),
+        );
+        let result = client.verify(&provider, &forged).await;
+        assert!(matches!(result, Err(ClientError::InvalidCredential(_))));
+    }
+    assert_eq!(server.jwks_fetch_count(), 1);
+}
+
+#[tokio::test]
+async fn test_verify_rejects_unconfigured_oidc_provider() {
+    let server = MockOAuthServer::start().await;
+    let client = OAuthClient::new(server.config());
+
+    let token = server.oidc_id_token("employee-3", "employee3@company.example");
+    let result = client
+        .verify(&OAuthProvider::Oidc("partner".to_string()), &token)
+        .await;
+    assert!(matches!(result, Err(ClientError::InvalidCredential(_))));
+}
+
+#[test]
+fn test_oauth_provider_representations() {
+    let provider = OAuthProvider::Oidc(MOCK_OIDC_PROVIDER.to_string());
+    assert_eq!(provider.to_string(), "oidc:company");
+    assert_eq!(
+        "oidc:company".parse::<OAuthProvider>(),
+        Ok(provider.clone())
+    );
+    assert_eq!(json!(provider), json!("oidc:company"));
+
+    // 내장 제공자의 API 표기는 기존과 같다.
+    assert_eq!(json!(OAuthProvider::Google), json!("Google"));
+    assert_eq!(OAuthProvider::Google.to_string(), "google");
+    assert!(serde_json::from_value::<OAuthProvider>(json!("oidc:Bad Name")).is_err());
+    assert!(serde_json::from_value::<OAuthProvider>(json!("github")).is_err());
+}
@@ -0,0 +1,18 @@
+use crate::clients::ClientError;
+use crate::entities::oauth_account::OAuthProvider;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct VerifiedIdentity {
+    pub provider_user_id: String,
+    pub email: String,
+    pub name: Option<String>,
+}
+
+#[async_trait::async_trait]
+pub trait OAuthVerifier: Send + Sync {
+    async fn verify(
+        &self,
+        provider: &OAuthProvider,
+        credential: &str,
+    ) -> Result<VerifiedIdentity, ClientError>;
+}
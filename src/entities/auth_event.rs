@@ -0,0 +1,72 @@
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+// 보안 사고 조사를 위한 기록이므로 추가만 한다. DB 트리거가 수정, 삭제, TRUNCATE를 거부한다.
+// 탈퇴한 사용자의 기록도 누구의 것인지 알 수 있도록 `user_id`에는 FK를 두지 않는다.
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+#[sea_orm(table_name = "auth_events")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i64,
+
+    #[sea_orm(indexed)]
+    pub user_id: Option<i32>,
+
+    /// 기록 당시 계정의 이메일(비워 두면 DB가 채운다). 없는 계정으로 로그인을 시도했으면 시도한 이메일
+    pub email: Option<String>,
+
+    pub event_type: AuthEventType,
+
+    pub detail: Option<String>,
+
+    pub ip_address: Option<String>,
+
+    pub user_agent: Option<String>,
+
+    pub created_at: DateTime,
+}
+
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, EnumIter, DeriveActiveEnum, Serialize, Deserialize, ToSchema,
+)]
+#[sea_orm(rs_type = "String", db_type = "String(StringLen::N(32))")]
+#[serde(rename_all = "snake_case")]
+pub enum AuthEventType {
+    #[sea_orm(string_value = "login_succeeded")]
+    LoginSucceeded,
+    #[sea_orm(string_value = "login_failed")]
+    LoginFailed,
+    #[sea_orm(string_value = "oauth_account_created")]
+    #[serde(rename = "oauth_account_created")]
+    OAuthAccountCreated,
+    #[sea_orm(string_value = "oauth_account_linked")]
+    #[serde(rename = "oauth_account_linked")]
+    OAuthAccountLinked,
+    #[sea_orm(string_value = "token_refreshed")]
+    TokenRefreshed,
+    #[sea_orm(string_value = "refresh_token_reused")]
+    RefreshTokenReused,
+    #[sea_orm(string_value = "logout")]
+    Logout,
+    #[sea_orm(string_value = "password_changed")]
+    PasswordChanged,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::user::Entity",
+        from = "Column::UserId",
+        to = "super::user::Column::Id"
+    )]
+    User,
+}
+
+impl Related<super::user::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::User.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
@@ -14,9 +14,18 @@ pub struct Model {
 
     pub is_pinned: bool,
 
+    /// 보관된 메모는 기본 목록과 AI 어시스턴트의 참고 메모에서 빠진다
+    pub is_archived: bool,
+
+    #[sea_orm(indexed)]
+    pub notebook_id: Option<i32>,
+
     pub created_at: DateTime,
 
     pub updated_at: DateTime,
+
+    /// 휴지통으로 옮긴 시각. None이면 휴지통에 있지 않은 메모
+    pub deleted_at: Option<DateTime>,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
@@ -27,6 +36,16 @@ pub enum Relation {
         to = "super::user::Column::Id"
     )]
     User,
+    #[sea_orm(
+        belongs_to = "super::notebook::Entity",
+        from = "Column::NotebookId",
+        to = "super::notebook::Column::Id",
+        on_update = "Cascade",
+        on_delete = "SetNull"
+    )]
+    Notebook,
+    #[sea_orm(has_many = "super::memo_revision::Entity")]
+    MemoRevision,
 }
 
 impl Related<super::user::Entity> for Entity {
@@ -35,4 +54,16 @@ impl Related<super::user::Entity> for Entity {
     }
 }
 
+impl Related<super::notebook::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Notebook.def()
+    }
+}
+
+impl Related<super::memo_revision::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::MemoRevision.def()
+    }
+}
+
 impl ActiveModelBehavior for ActiveModel {}
@@ -0,0 +1,36 @@
+use sea_orm::entity::prelude::*;
+
+// 메모가 수정되기 전의 내용. `created_at`은 이 내용이 작성된 시각(수정 전 메모의 `updated_at`)이다.
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+#[sea_orm(table_name = "memo_revisions")]
+pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i32,
+
+    #[sea_orm(indexed)]
+    pub memo_id: i32,
+
+    pub content: String,
+
+    pub created_at: DateTime,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::memo::Entity",
+        from = "Column::MemoId",
+        to = "super::memo::Column::Id",
+        on_update = "Cascade",
+        on_delete = "Cascade"
+    )]
+    Memo,
+}
+
+impl Related<super::memo::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Memo.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
@@ -0,0 +1,45 @@
+use sea_orm::entity::prelude::*;
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+#[sea_orm(table_name = "memo_tags")]
+pub struct Model {
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub memo_id: i32,
+
+    #[sea_orm(primary_key, auto_increment = false)]
+    pub tag_id: i32,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::memo::Entity",
+        from = "Column::MemoId",
+        to = "super::memo::Column::Id",
+        on_update = "Cascade",
+        on_delete = "Cascade"
+    )]
+    Memo,
+    #[sea_orm(
+        belongs_to = "super::tag::Entity",
+        from = "Column::TagId",
+        to = "super::tag::Column::Id",
+        on_update = "Cascade",
+        on_delete = "Cascade"
+    )]
+    Tag,
+}
+
+impl Related<super::memo::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Memo.def()
+    }
+}
+
+impl Related<super::tag::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Tag.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
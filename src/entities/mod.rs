@@ -1,7 +1,29 @@
+pub mod auth_event;
 pub mod memo;
+pub mod memo_revision;
+pub mod memo_tag;
+pub mod notebook;
 pub mod oauth_account;
+pub mod personal_access_token;
+pub mod recovery_code;
+pub mod refresh_token;
+pub mod saved_search;
+pub mod session;
+pub mod tag;
+pub mod totp_credential;
 pub mod user;
 
+pub use auth_event::Entity as AuthEvent;
 pub use memo::Entity as Memo;
+pub use memo_revision::Entity as MemoRevision;
+pub use memo_tag::Entity as MemoTag;
+pub use notebook::Entity as Notebook;
 pub use oauth_account::Entity as OAuthAccount;
+pub use personal_access_token::Entity as PersonalAccessToken;
+pub use recovery_code::Entity as RecoveryCode;
+pub use refresh_token::Entity as RefreshToken;
+pub use saved_search::Entity as SavedSearch;
+pub use session::Entity as Session;
+pub use tag::Entity as Tag;
+pub use totp_credential::Entity as TotpCredential;
 pub use user::Entity as User;
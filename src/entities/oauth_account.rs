@@ -1,6 +1,10 @@
 use sea_orm::entity::prelude::*;
 use serde::{Deserialize, Serialize};
-use utoipa::ToSchema;
+use std::{fmt, str::FromStr};
+use utoipa::{
+    openapi::{ObjectBuilder, RefOr, Schema, Type},
+    PartialSchema, ToSchema,
+};
 
 #[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
 #[sea_orm(table_name = "o_auth_accounts")]
@@ -10,6 +14,7 @@ pub struct Model {
 
     pub user_id: i32,
 
+    #[sea_orm(column_type = "String(StringLen::N(64))")]
     pub provider: OAuthProvider,
 
     pub provider_user_id: String,
@@ -19,17 +24,98 @@ pub struct Model {
     pub updated_at: DateTime,
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, EnumIter, DeriveActiveEnum, Serialize, Deserialize, ToSchema)]
-#[sea_orm(rs_type = "String", db_type = "String(StringLen::N(20))")]
+const OIDC_PREFIX: &str = "oidc:";
+const MAX_OIDC_NAME_LENGTH: usize = 32;
+
+// DB에는 `google`, `kakao`, `naver`, `oidc:<이름>` 형태로 저장한다.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, DeriveValueType, Serialize, Deserialize)]
+#[sea_orm(value_type = "String")]
+#[serde(try_from = "String", into = "String")]
 pub enum OAuthProvider {
-    #[sea_orm(string_value = "google")]
     Google,
-    #[sea_orm(string_value = "kakao")]
     Kakao,
-    #[sea_orm(string_value = "naver")]
     Naver,
+    /// 설정에 등록된 OpenID Connect 발급자의 이름
+    Oidc(String),
+}
+
+impl OAuthProvider {
+    pub fn is_valid_oidc_name(name: &str) -> bool {
+        !name.is_empty()
+            && name.len() <= MAX_OIDC_NAME_LENGTH
+            && name
+                .chars()
+                .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-' || c == '_')
+    }
+}
+
+impl fmt::Display for OAuthProvider {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Google => f.write_str("google"),
+            Self::Kakao => f.write_str("kakao"),
+            Self::Naver => f.write_str("naver"),
+            Self::Oidc(name) => write!(f, "{}{}", OIDC_PREFIX, name),
+        }
+    }
+}
+
+impl FromStr for OAuthProvider {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "google" => Ok(Self::Google),
+            "kakao" => Ok(Self::Kakao),
+            "naver" => Ok(Self::Naver),
+            _ => match s.strip_prefix(OIDC_PREFIX) {
+                Some(name) if Self::is_valid_oidc_name(name) => Ok(Self::Oidc(name.to_string())),
+                _ => Err(format!("Unknown OAuth provider: {}", s)),
+            },
+        }
+    }
 }
 
+// API에서는 기존 클라이언트와의 호환을 위해 내장 제공자를 `Google`처럼 표기한다.
+impl From<OAuthProvider> for String {
+    fn from(provider: OAuthProvider) -> Self {
+        match provider {
+            OAuthProvider::Google => "Google".to_string(),
+            OAuthProvider::Kakao => "Kakao".to_string(),
+            OAuthProvider::Naver => "Naver".to_string(),
+            oidc @ OAuthProvider::Oidc(_) => oidc.to_string(),
+        }
+    }
+}
+
+impl TryFrom<String> for OAuthProvider {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        match value.as_str() {
+            "Google" => Ok(Self::Google),
+            "Kakao" => Ok(Self::Kakao),
+            "Naver" => Ok(Self::Naver),
+            _ if value.starts_with(OIDC_PREFIX) => value.parse(),
+            _ => Err(format!("Unknown OAuth provider: {}", value)),
+        }
+    }
+}
+
+impl PartialSchema for OAuthProvider {
+    fn schema() -> RefOr<Schema> {
+        ObjectBuilder::new()
+            .schema_type(Type::String)
+            .description(Some(
+                "`Google`, `Kakao`, `Naver` 또는 설정된 OpenID Connect 제공자(`oidc:<이름>`)",
+            ))
+            .examples(["Google", "oidc:company"])
+            .into()
+    }
+}
+
+impl ToSchema for OAuthProvider {}
+
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
 pub enum Relation {
     #[sea_orm(
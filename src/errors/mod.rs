@@ -10,3 +10,12 @@ pub struct ErrorResponse {
     #[schema(example = "Resource not found")]
     pub error: String,
 }
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct SearchQueryErrorResponse {
+    #[schema(example = "Expected `true` or `false`")]
+    pub error: String,
+    /// 검색어에서 문제가 된 위치 (0부터 센 문자 단위)
+    #[schema(example = 7)]
+    pub position: usize,
+}
@@ -6,7 +6,7 @@ use axum::{
 use sea_orm::DbErr;
 use thiserror::Error;
 
-use crate::clients::ClientError;
+use crate::{clients::ClientError, utils::search::query::QueryParseError};
 
 #[derive(Debug, Error)]
 pub enum ServiceError {
@@ -31,8 +31,98 @@ pub enum ServiceError {
     #[error("Invalid or expired token")]
     InvalidToken,
 
-    #[error("Missing JWT secret configuration")]
-    MissingJwtSecret,
+    #[error("Token not found")]
+    TokenNotFound,
+
+    #[error("Insufficient scope")]
+    InsufficientScope,
+
+    #[error("Session not found")]
+    SessionNotFound,
+
+    #[error("Two-factor authentication is already enabled")]
+    TwoFactorAlreadyEnabled,
+
+    #[error("Two-factor authentication is not enabled")]
+    TwoFactorNotEnabled,
+
+    #[error("Two-factor enrollment has not been started")]
+    TwoFactorEnrollmentNotStarted,
+
+    #[error("Invalid two-factor code")]
+    InvalidTwoFactorCode,
+
+    #[error("Too many failed two-factor attempts. Try again later")]
+    TooManyTwoFactorAttempts,
+
+    #[error("Invalid email or password")]
+    InvalidCredentials,
+
+    #[error("Account is suspended")]
+    AccountSuspended,
+
+    #[error("Administrators cannot suspend or demote their own account")]
+    CannotModifyOwnAccount,
+
+    #[error("Current password is incorrect")]
+    IncorrectPassword,
+
+    #[error("Email is already registered")]
+    EmailAlreadyExists,
+
+    #[error("Username is already taken")]
+    UsernameAlreadyExists,
+
+    #[error("{0}")]
+    Validation(String),
+
+    #[error("Saved search not found")]
+    SavedSearchNotFound,
+
+    #[error("A saved search with this name already exists")]
+    SavedSearchNameExists,
+
+    #[error("Memo revision not found")]
+    MemoRevisionNotFound,
+
+    #[error("Tag not found")]
+    TagNotFound,
+
+    #[error("A tag with this name already exists")]
+    TagAlreadyExists,
+
+    #[error("Notebook not found")]
+    NotebookNotFound,
+
+    #[error("A notebook with this name already exists here")]
+    NotebookNameExists,
+
+    #[error("Invalid search query: {0}")]
+    InvalidSearchQuery(#[from] QueryParseError),
+
+    #[error("Failed to hash password")]
+    PasswordHashFailed,
+
+    #[error("Invalid OAuth credential")]
+    InvalidOAuthCredential(String),
+
+    #[error("OAuth provider error: {0}")]
+    OAuthProvider(String),
+
+    #[error("An account with this email already exists. Sign in and link this provider from your account")]
+    AccountLinkRequired,
+
+    #[error("This provider account is already linked to a user")]
+    OAuthAccountAlreadyLinked,
+
+    #[error("A {0} account is already linked")]
+    ProviderAlreadyLinked(String),
+
+    #[error("Linked account not found")]
+    OAuthAccountNotFound,
+
+    #[error("Cannot remove the last login method")]
+    LastLoginMethod,
 
     #[error("Database error: {0}")]
     Database(#[from] DbErr),
@@ -40,6 +130,12 @@ pub enum ServiceError {
 
 impl IntoResponse for ServiceError {
     fn into_response(self) -> Response {
+        // 검색어 오류는 클라이언트가 입력란에서 위치를 표시할 수 있게 위치를 함께 내려준다.
+        if let Self::InvalidSearchQuery(err) = &self {
+            let body = serde_json::json!({ "error": err.message, "position": err.position });
+            return (StatusCode::BAD_REQUEST, Json(body)).into_response();
+        }
+
         let (status, message) = match self {
             Self::MemoNotFound => (StatusCode::NOT_FOUND, self.to_string()),
             Self::UserNotFound => (StatusCode::NOT_FOUND, self.to_string()),
@@ -57,10 +153,43 @@ impl IntoResponse for ServiceError {
                 "Failed to generate authentication token".to_string(),
             ),
             Self::InvalidToken => (StatusCode::UNAUTHORIZED, self.to_string()),
-            Self::MissingJwtSecret => (
+            Self::TokenNotFound => (StatusCode::NOT_FOUND, self.to_string()),
+            Self::InsufficientScope => (StatusCode::FORBIDDEN, self.to_string()),
+            Self::SessionNotFound => (StatusCode::NOT_FOUND, self.to_string()),
+            Self::TwoFactorAlreadyEnabled => (StatusCode::CONFLICT, self.to_string()),
+            Self::TwoFactorNotEnabled => (StatusCode::BAD_REQUEST, self.to_string()),
+            Self::TwoFactorEnrollmentNotStarted => (StatusCode::BAD_REQUEST, self.to_string()),
+            Self::InvalidTwoFactorCode => (StatusCode::BAD_REQUEST, self.to_string()),
+            Self::TooManyTwoFactorAttempts => (StatusCode::TOO_MANY_REQUESTS, self.to_string()),
+            Self::InvalidCredentials => (StatusCode::UNAUTHORIZED, self.to_string()),
+            Self::AccountSuspended => (StatusCode::FORBIDDEN, self.to_string()),
+            Self::CannotModifyOwnAccount => (StatusCode::BAD_REQUEST, self.to_string()),
+            Self::IncorrectPassword => (StatusCode::BAD_REQUEST, self.to_string()),
+            Self::EmailAlreadyExists => (StatusCode::CONFLICT, self.to_string()),
+            Self::UsernameAlreadyExists => (StatusCode::CONFLICT, self.to_string()),
+            Self::Validation(_) => (StatusCode::BAD_REQUEST, self.to_string()),
+            Self::SavedSearchNotFound => (StatusCode::NOT_FOUND, self.to_string()),
+            Self::SavedSearchNameExists => (StatusCode::CONFLICT, self.to_string()),
+            Self::MemoRevisionNotFound => (StatusCode::NOT_FOUND, self.to_string()),
+            Self::TagNotFound => (StatusCode::NOT_FOUND, self.to_string()),
+            Self::TagAlreadyExists => (StatusCode::CONFLICT, self.to_string()),
+            Self::NotebookNotFound => (StatusCode::NOT_FOUND, self.to_string()),
+            Self::NotebookNameExists => (StatusCode::CONFLICT, self.to_string()),
+            Self::InvalidSearchQuery(_) => (StatusCode::BAD_REQUEST, self.to_string()),
+            Self::PasswordHashFailed => (
                 StatusCode::INTERNAL_SERVER_ERROR,
-                "Server configuration error".to_string(),
+                "Internal server error".to_string(),
+            ),
+            Self::InvalidOAuthCredential(_) => (StatusCode::UNAUTHORIZED, self.to_string()),
+            Self::OAuthProvider(_) => (
+                StatusCode::BAD_GATEWAY,
+                "External authentication service error".to_string(),
             ),
+            Self::AccountLinkRequired => (StatusCode::CONFLICT, self.to_string()),
+            Self::OAuthAccountAlreadyLinked => (StatusCode::CONFLICT, self.to_string()),
+            Self::ProviderAlreadyLinked(_) => (StatusCode::CONFLICT, self.to_string()),
+            Self::OAuthAccountNotFound => (StatusCode::NOT_FOUND, self.to_string()),
+            Self::LastLoginMethod => (StatusCode::CONFLICT, self.to_string()),
             Self::Database(_) => (
                 StatusCode::INTERNAL_SERVER_ERROR,
                 "Internal server error".to_string(),
@@ -78,6 +207,8 @@ impl From<ClientError> for ServiceError {
             ClientError::Network(msg) => ServiceError::GeminiApi(msg),
             ClientError::ParseError(msg) => ServiceError::GeminiApi(msg),
             ClientError::Qdrant(msg) => ServiceError::Qdrant(msg),
+            ClientError::OAuthProvider(msg) => ServiceError::OAuthProvider(msg),
+            ClientError::InvalidCredential(msg) => ServiceError::InvalidOAuthCredential(msg),
         }
     }
 }
@@ -0,0 +1,178 @@
+use axum::{
+    extract::{Path, Query, State},
+    http::StatusCode,
+    response::IntoResponse,
+    Json,
+};
+
+use super::{auth::AdminUser, AppState};
+use crate::errors::ErrorResponse;
+use crate::models::admin_dto::{
+    AdminUserListQuery, AdminUserListResponse, AdminUserResponse, UpdateUserRoleRequest,
+};
+use crate::models::auth_event_dto::{AdminSecurityEventListQuery, AuthEventListResponse};
+
+#[utoipa::path(
+    get,
+    path = "/api/admin/users",
+    tag = "Admin",
+    params(AdminUserListQuery),
+    responses(
+        (status = 200, description = "사용자 목록 조회 성공 (메모 개수 포함)", body = AdminUserListResponse),
+        (status = 401, description = "인증 실패", body = ErrorResponse),
+        (status = 403, description = "관리자가 아니거나 토큰에 `account` 스코프가 없음", body = ErrorResponse),
+        (status = 500, description = "서버 에러", body = ErrorResponse)
+    ),
+    security(("bearer_auth" = []))
+)]
+pub async fn list_users(
+    State(state): State<AppState>,
+    _admin: AdminUser,
+    Query(query): Query<AdminUserListQuery>,
+) -> impl IntoResponse {
+    match state.admin_service.list_users(query).await {
+        Ok(users) => (StatusCode::OK, Json(users)).into_response(),
+        Err(e) => e.into_response(),
+    }
+}
+
+#[utoipa::path(
+    patch,
+    path = "/api/admin/users/{id}",
+    tag = "Admin",
+    params(
+        ("id" = i32, Path, description = "사용자 ID")
+    ),
+    request_body = UpdateUserRoleRequest,
+    responses(
+        (status = 200, description = "역할 변경 성공", body = AdminUserResponse),
+        (status = 400, description = "자기 자신의 관리자 권한은 해제할 수 없음", body = ErrorResponse),
+        (status = 401, description = "인증 실패", body = ErrorResponse),
+        (status = 403, description = "관리자가 아니거나 토큰에 `account` 스코프가 없음", body = ErrorResponse),
+        (status = 404, description = "사용자를 찾을 수 없음", body = ErrorResponse),
+        (status = 500, description = "서버 에러", body = ErrorResponse)
+    ),
+    security(("bearer_auth" = []))
+)]
+pub async fn update_role(
+    State(state): State<AppState>,
+    admin: AdminUser,
+    Path(id): Path<i32>,
+    Json(payload): Json<UpdateUserRoleRequest>,
+) -> impl IntoResponse {
+    match state
+        .admin_service
+        .update_role(admin.id, id, payload.role)
+        .await
+    {
+        Ok(user) => (StatusCode::OK, Json(user)).into_response(),
+        Err(e) => e.into_response(),
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/admin/users/{id}/suspend",
+    tag = "Admin",
+    params(
+        ("id" = i32, Path, description = "사용자 ID")
+    ),
+    responses(
+        (status = 200, description = "계정 정지 성공 (모든 세션과 개인 액세스 토큰이 폐기되고 기존 토큰도 거부됨)", body = AdminUserResponse),
+        (status = 400, description = "자기 자신은 정지할 수 없음", body = ErrorResponse),
+        (status = 401, description = "인증 실패", body = ErrorResponse),
+        (status = 403, description = "관리자가 아니거나 토큰에 `account` 스코프가 없음", body = ErrorResponse),
+        (status = 404, description = "사용자를 찾을 수 없음", body = ErrorResponse),
+        (status = 500, description = "서버 에러", body = ErrorResponse)
+    ),
+    security(("bearer_auth" = []))
+)]
+pub async fn suspend_user(
+    State(state): State<AppState>,
+    admin: AdminUser,
+    Path(id): Path<i32>,
+) -> impl IntoResponse {
+    match state.admin_service.suspend_user(admin.id, id).await {
+        Ok(user) => (StatusCode::OK, Json(user)).into_response(),
+        Err(e) => e.into_response(),
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/admin/users/{id}/reactivate",
+    tag = "Admin",
+    params(
+        ("id" = i32, Path, description = "사용자 ID")
+    ),
+    responses(
+        (status = 200, description = "계정 정지 해제 성공", body = AdminUserResponse),
+        (status = 401, description = "인증 실패", body = ErrorResponse),
+        (status = 403, description = "관리자가 아니거나 토큰에 `account` 스코프가 없음", body = ErrorResponse),
+        (status = 404, description = "사용자를 찾을 수 없음", body = ErrorResponse),
+        (status = 500, description = "서버 에러", body = ErrorResponse)
+    ),
+    security(("bearer_auth" = []))
+)]
+pub async fn reactivate_user(
+    State(state): State<AppState>,
+    _admin: AdminUser,
+    Path(id): Path<i32>,
+) -> impl IntoResponse {
+    match state.admin_service.reactivate_user(id).await {
+        Ok(user) => (StatusCode::OK, Json(user)).into_response(),
+        Err(e) => e.into_response(),
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/admin/users/{id}/logout",
+    tag = "Admin",
+    params(
+        ("id" = i32, Path, description = "사용자 ID")
+    ),
+    responses(
+        (status = 204, description = "강제 로그아웃 성공 (모든 세션, 리프레시 토큰, 개인 액세스 토큰이 폐기됨)"),
+        (status = 401, description = "인증 실패", body = ErrorResponse),
+        (status = 403, description = "관리자가 아니거나 토큰에 `account` 스코프가 없음", body = ErrorResponse),
+        (status = 404, description = "사용자를 찾을 수 없음", body = ErrorResponse),
+        (status = 500, description = "서버 에러", body = ErrorResponse)
+    ),
+    security(("bearer_auth" = []))
+)]
+pub async fn force_logout(
+    State(state): State<AppState>,
+    _admin: AdminUser,
+    Path(id): Path<i32>,
+) -> impl IntoResponse {
+    match state.admin_service.force_logout(id).await {
+        Ok(_) => (StatusCode::NO_CONTENT).into_response(),
+        Err(e) => e.into_response(),
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/admin/security-events",
+    tag = "Admin",
+    params(AdminSecurityEventListQuery),
+    responses(
+        (status = 200, description = "전체 보안 이벤트 기록 조회 성공 (최신순)", body = AuthEventListResponse),
+        (status = 400, description = "잘못된 필터 값", body = ErrorResponse),
+        (status = 401, description = "인증 실패", body = ErrorResponse),
+        (status = 403, description = "관리자가 아니거나 토큰에 `account` 스코프가 없음", body = ErrorResponse),
+        (status = 500, description = "서버 에러", body = ErrorResponse)
+    ),
+    security(("bearer_auth" = []))
+)]
+pub async fn list_security_events(
+    State(state): State<AppState>,
+    _admin: AdminUser,
+    Query(query): Query<AdminSecurityEventListQuery>,
+) -> impl IntoResponse {
+    match state.auth_event_service.list_all(query).await {
+        Ok(events) => (StatusCode::OK, Json(events)).into_response(),
+        Err(e) => e.into_response(),
+    }
+}
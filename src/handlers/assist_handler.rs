@@ -1,8 +1,11 @@
 use axum::{extract::State, http::StatusCode, response::IntoResponse, Json};
 
-use super::{auth::AuthenticatedUser, AppState};
-use crate::models::assist_dto::{AssistRequest, AssistResponse};
+use super::{
+    auth::{scope, ScopedUser},
+    AppState,
+};
 use crate::errors::ErrorResponse;
+use crate::models::assist_dto::{AssistRequest, AssistResponse};
 
 #[utoipa::path(
     post,
@@ -13,13 +16,14 @@ use crate::errors::ErrorResponse;
         (status = 200, description = "AI 어시스턴트 응답 성공", body = AssistResponse),
         (status = 400, description = "잘못된 요청", body = ErrorResponse),
         (status = 401, description = "인증 실패", body = ErrorResponse),
+        (status = 403, description = "토큰에 `assist` 스코프가 없음", body = ErrorResponse),
         (status = 500, description = "서버 에러", body = ErrorResponse)
     ),
     security(("bearer_auth" = []))
 )]
 pub async fn assist(
     State(state): State<AppState>,
-    user: AuthenticatedUser,
+    user: ScopedUser<scope::Assist>,
     Json(payload): Json<AssistRequest>,
 ) -> impl IntoResponse {
     match state.assist_service.get_assistance(user.id, payload).await {
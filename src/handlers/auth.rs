@@ -1,46 +1,281 @@
 use axum::{
     async_trait,
-    extract::FromRequestParts,
-    http::{request::Parts, StatusCode},
+    extract::{ConnectInfo, FromRequestParts},
+    http::{header, request::Parts, StatusCode},
 };
-use serde::Deserialize;
+use std::{convert::Infallible, marker::PhantomData, net::SocketAddr};
 
-use crate::utils::jwt;
+use super::{cookie_auth, AppState};
+use crate::{
+    entities::user::UserRole,
+    errors::ServiceError,
+    models::{ClientInfo, Scope},
+    services::personal_access_token_service,
+    utils::{client_ip, cookie},
+};
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug)]
 pub struct AuthenticatedUser {
     pub id: i32,
+    pub role: UserRole,
+    pub scopes: Vec<Scope>,
+    pub session_id: Option<String>,
+}
+
+impl AuthenticatedUser {
+    pub fn has_scope(&self, scope: Scope) -> bool {
+        self.scopes.contains(&scope)
+    }
+
+    pub fn is_admin(&self) -> bool {
+        self.role == UserRole::Admin
+    }
 }
 
 #[async_trait]
-impl<S> FromRequestParts<S> for AuthenticatedUser
-where
-    S: Send + Sync,
-{
+impl FromRequestParts<AppState> for AuthenticatedUser {
     type Rejection = (StatusCode, &'static str);
 
-    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+    async fn from_request_parts(
+        parts: &mut Parts,
+        state: &AppState,
+    ) -> Result<Self, Self::Rejection> {
         let auth_header = parts
             .headers
             .get("Authorization")
-            .and_then(|value| value.to_str().ok())
-            .ok_or((StatusCode::UNAUTHORIZED, "Missing Authorization header"))?;
+            .and_then(|value| value.to_str().ok());
 
-        let token = auth_header
-            .strip_prefix("Bearer ")
-            .ok_or((StatusCode::UNAUTHORIZED, "Invalid Authorization format"))?;
+        // Authorization 헤더가 없을 때만 쿠키를 보며, 쿠키로 인증된 상태 변경 요청은 CSRF 토큰을 요구한다.
+        let token = match auth_header {
+            Some(auth_header) => auth_header
+                .strip_prefix("Bearer ")
+                .ok_or((StatusCode::UNAUTHORIZED, "Invalid Authorization format"))?
+                .to_string(),
+            None => {
+                let token = cookie::get(&parts.headers, cookie_auth::ACCESS_COOKIE)
+                    .ok_or((StatusCode::UNAUTHORIZED, "Missing Authorization header"))?;
+                if !cookie_auth::is_safe_method(&parts.method) {
+                    cookie_auth::verify_csrf(&parts.headers)?;
+                }
+                token
+            }
+        };
+        let token = token.as_str();
 
-        let jwt_secret = std::env::var("JWT_SECRET")
-            .map_err(|_| (StatusCode::INTERNAL_SERVER_ERROR, "JWT secret not configured"))?;
+        let (id, scopes, session_id) =
+            if personal_access_token_service::is_personal_access_token(token) {
+                let pat = state
+                    .personal_access_token_service
+                    .authenticate(token)
+                    .await
+                    .map_err(|e| match e {
+                        ServiceError::InvalidToken => {
+                            (StatusCode::UNAUTHORIZED, "Invalid or expired token")
+                        }
+                        _ => (StatusCode::INTERNAL_SERVER_ERROR, "Internal server error"),
+                    })?;
 
-        let claims = jwt::verify_token(token, &jwt_secret)
-            .map_err(|_| (StatusCode::UNAUTHORIZED, "Invalid or expired token"))?;
+                (pat.user_id, Scope::parse_list(&pat.scopes), None)
+            } else {
+                let claims = state
+                    .jwt_keys
+                    .verify_token(token)
+                    .map_err(|_| (StatusCode::UNAUTHORIZED, "Invalid or expired token"))?;
 
-        let id = claims
-            .sub
-            .parse::<i32>()
-            .map_err(|_| (StatusCode::UNAUTHORIZED, "Invalid user ID in token"))?;
+                let id = claims
+                    .sub
+                    .parse::<i32>()
+                    .map_err(|_| (StatusCode::UNAUTHORIZED, "Invalid user ID in token"))?;
 
-        Ok(AuthenticatedUser { id })
+                if let Some(session_id) = claims.sid.as_deref() {
+                    state
+                        .auth_service
+                        .validate_session(session_id)
+                        .await
+                        .map_err(|e| match e {
+                            ServiceError::InvalidToken => {
+                                (StatusCode::UNAUTHORIZED, "Session has been revoked")
+                            }
+                            _ => (StatusCode::INTERNAL_SERVER_ERROR, "Internal server error"),
+                        })?;
+                }
+
+                (id, claims.scopes(), claims.sid)
+            };
+
+        // 역할은 토큰이 아닌 DB에서 읽어 권한 변경이 즉시 반영되도록 한다.
+        let role = state
+            .auth_service
+            .ensure_active(id)
+            .await
+            .map_err(|e| match e {
+                ServiceError::AccountSuspended => (StatusCode::FORBIDDEN, "Account is suspended"),
+                ServiceError::InvalidToken => {
+                    (StatusCode::UNAUTHORIZED, "Invalid or expired token")
+                }
+                _ => (StatusCode::INTERNAL_SERVER_ERROR, "Internal server error"),
+            })?;
+
+        Ok(AuthenticatedUser {
+            id,
+            role,
+            scopes,
+            session_id,
+        })
     }
 }
+
+pub trait RequiredScope {
+    const SCOPE: Scope;
+}
+
+pub mod scope {
+    use super::{RequiredScope, Scope};
+
+    pub struct MemosRead;
+    pub struct MemosWrite;
+    pub struct Assist;
+    pub struct Account;
+
+    impl RequiredScope for MemosRead {
+        const SCOPE: Scope = Scope::MemosRead;
+    }
+
+    impl RequiredScope for MemosWrite {
+        const SCOPE: Scope = Scope::MemosWrite;
+    }
+
+    impl RequiredScope for Assist {
+        const SCOPE: Scope = Scope::Assist;
+    }
+
+    impl RequiredScope for Account {
+        const SCOPE: Scope = Scope::Account;
+    }
+}
+
+// 인증에 더해 `S`가 가리키는 스코프가 토큰에 없으면 403으로 거부한다.
+pub struct ScopedUser<S> {
+    pub id: i32,
+    pub scopes: Vec<Scope>,
+    pub session_id: Option<String>,
+    _scope: PhantomData<S>,
+}
+
+#[async_trait]
+impl<S> FromRequestParts<AppState> for ScopedUser<S>
+where
+    S: RequiredScope + Send + Sync,
+{
+    type Rejection = (StatusCode, &'static str);
+
+    async fn from_request_parts(
+        parts: &mut Parts,
+        state: &AppState,
+    ) -> Result<Self, Self::Rejection> {
+        let user = AuthenticatedUser::from_request_parts(parts, state).await?;
+
+        if !user.has_scope(S::SCOPE) {
+            return Err((StatusCode::FORBIDDEN, "Insufficient scope"));
+        }
+
+        Ok(ScopedUser {
+            id: user.id,
+            scopes: user.scopes,
+            session_id: user.session_id,
+            _scope: PhantomData,
+        })
+    }
+}
+
+// 토큰 발급, 비밀번호 변경, OAuth 계정 연결, 2단계 인증 설정, 탈퇴처럼 로그인 수단이나 계정 자체를 바꾸는 API는
+// 개인 액세스 토큰이 아닌 로그인 세션으로만 호출할 수 있다.
+pub struct SessionUser {
+    pub id: i32,
+    pub scopes: Vec<Scope>,
+    pub session_id: String,
+}
+
+#[async_trait]
+impl FromRequestParts<AppState> for SessionUser {
+    type Rejection = (StatusCode, &'static str);
+
+    async fn from_request_parts(
+        parts: &mut Parts,
+        state: &AppState,
+    ) -> Result<Self, Self::Rejection> {
+        let user = AuthenticatedUser::from_request_parts(parts, state).await?;
+
+        if !user.has_scope(Scope::Account) {
+            return Err((StatusCode::FORBIDDEN, "Insufficient scope"));
+        }
+        let session_id = user
+            .session_id
+            .ok_or((StatusCode::FORBIDDEN, "Session login required"))?;
+
+        Ok(SessionUser {
+            id: user.id,
+            scopes: user.scopes,
+            session_id,
+        })
+    }
+}
+
+// 관리자 API는 `account` 스코프와 관리자 역할을 모두 요구한다.
+pub struct AdminUser {
+    pub id: i32,
+}
+
+#[async_trait]
+impl FromRequestParts<AppState> for AdminUser {
+    type Rejection = (StatusCode, &'static str);
+
+    async fn from_request_parts(
+        parts: &mut Parts,
+        state: &AppState,
+    ) -> Result<Self, Self::Rejection> {
+        let user = AuthenticatedUser::from_request_parts(parts, state).await?;
+
+        if !user.has_scope(Scope::Account) {
+            return Err((StatusCode::FORBIDDEN, "Insufficient scope"));
+        }
+        if !user.is_admin() {
+            return Err((StatusCode::FORBIDDEN, "Admin role required"));
+        }
+
+        Ok(AdminUser { id: user.id })
+    }
+}
+
+#[async_trait]
+impl<S: Send + Sync> FromRequestParts<S> for ClientInfo {
+    type Rejection = Infallible;
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        let user_agent = parts
+            .headers
+            .get(header::USER_AGENT)
+            .and_then(|value| value.to_str().ok())
+            .map(|ua| ua.chars().take(512).collect());
+
+        let peer = parts
+            .extensions
+            .get::<ConnectInfo<SocketAddr>>()
+            .map(|ConnectInfo(addr)| addr.ip());
+        let ip_address = client_ip::resolve(&parts.headers, peer, trusted_proxy_count());
+
+        Ok(ClientInfo {
+            user_agent,
+            ip_address,
+        })
+    }
+}
+
+// 리버스 프록시 뒤에서 실행할 때만 `TRUSTED_PROXY_COUNT`에 앞단 프록시 수를 지정한다.
+// 지정하지 않으면 `X-Forwarded-For`를 믿지 않는다.
+fn trusted_proxy_count() -> usize {
+    std::env::var("TRUSTED_PROXY_COUNT")
+        .ok()
+        .and_then(|value| value.parse().ok())
+        .unwrap_or(0)
+}
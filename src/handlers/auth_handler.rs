@@ -0,0 +1,100 @@
+use axum::{
+    extract::State,
+    http::{HeaderMap, StatusCode},
+    response::IntoResponse,
+    Json,
+};
+
+use super::{
+    cookie_auth::{self, AuthMode},
+    AppState,
+};
+use crate::errors::ErrorResponse;
+use crate::models::session_dto::ClientInfo;
+use crate::models::user_dto::{AuthResponse, RefreshTokenRequest};
+
+#[utoipa::path(
+    post,
+    path = "/api/auth/refresh",
+    tag = "Auth",
+    request_body(content = Option<RefreshTokenRequest>, description = "쿠키 인증 모드에서는 생략"),
+    params(
+        ("X-Auth-Mode" = Option<String>, Header, description = "`cookie`이면 `lekha_refresh` 쿠키를 사용하고 새 토큰도 쿠키로 설정 (응답 본문은 `CookieAuthResponse`)"),
+        ("X-CSRF-Token" = Option<String>, Header, description = "쿠키 인증 모드에서 필수. `lekha_csrf` 쿠키 값")
+    ),
+    responses(
+        (status = 200, description = "토큰 재발급 성공 (리프레시 토큰도 새로 교체됨)", body = AuthResponse),
+        (status = 401, description = "유효하지 않거나 이미 사용된 리프레시 토큰", body = ErrorResponse),
+        (status = 403, description = "쿠키 인증 모드에서 CSRF 토큰 불일치"),
+        (status = 500, description = "서버 에러", body = ErrorResponse)
+    )
+)]
+pub async fn refresh(
+    State(state): State<AppState>,
+    client: ClientInfo,
+    mode: AuthMode,
+    headers: HeaderMap,
+    payload: Option<Json<RefreshTokenRequest>>,
+) -> impl IntoResponse {
+    let refresh_token = match cookie_auth::refresh_token(mode, &headers, payload) {
+        Ok(token) => token,
+        Err(rejection) => return rejection.into_response(),
+    };
+
+    match state.auth_service.refresh(&refresh_token, client).await {
+        Ok(response) => cookie_auth::auth_response(StatusCode::OK, mode, response),
+        Err(e) => e.into_response(),
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/auth/logout",
+    tag = "Auth",
+    request_body(content = Option<RefreshTokenRequest>, description = "쿠키 인증 모드에서는 생략"),
+    params(
+        ("X-Auth-Mode" = Option<String>, Header, description = "`cookie`이면 `lekha_refresh` 쿠키를 사용하고 인증 쿠키를 삭제"),
+        ("X-CSRF-Token" = Option<String>, Header, description = "쿠키 인증 모드에서 필수. `lekha_csrf` 쿠키 값")
+    ),
+    responses(
+        (status = 204, description = "로그아웃 성공 (현재 세션 종료 및 리프레시 토큰 폐기)"),
+        (status = 401, description = "유효하지 않은 리프레시 토큰 (쿠키 인증 모드에서는 이때도 인증 쿠키를 삭제)", body = ErrorResponse),
+        (status = 403, description = "쿠키 인증 모드에서 CSRF 토큰 불일치"),
+        (status = 500, description = "서버 에러", body = ErrorResponse)
+    )
+)]
+pub async fn logout(
+    State(state): State<AppState>,
+    client: ClientInfo,
+    mode: AuthMode,
+    headers: HeaderMap,
+    payload: Option<Json<RefreshTokenRequest>>,
+) -> impl IntoResponse {
+    let refresh_token = match cookie_auth::refresh_token(mode, &headers, payload) {
+        Ok(token) => token,
+        Err(rejection) => return rejection.into_response(),
+    };
+
+    let response = match state.auth_service.logout(&refresh_token, client).await {
+        Ok(()) => (StatusCode::NO_CONTENT).into_response(),
+        Err(e) => e.into_response(),
+    };
+
+    // 이미 폐기된 토큰 등으로 서버 쪽 로그아웃이 실패해도 브라우저에 인증 쿠키를 남기지 않는다.
+    match mode {
+        AuthMode::Cookie => (cookie_auth::clear_cookies(), response).into_response(),
+        AuthMode::Bearer => response,
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/.well-known/jwks.json",
+    tag = "Auth",
+    responses(
+        (status = 200, description = "액세스 토큰 검증용 공개 키 목록 (JWK Set)")
+    )
+)]
+pub async fn jwks(State(state): State<AppState>) -> impl IntoResponse {
+    (StatusCode::OK, Json(state.jwt_keys.jwks().clone()))
+}
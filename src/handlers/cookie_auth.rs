@@ -0,0 +1,194 @@
+use axum::{
+    async_trait,
+    extract::FromRequestParts,
+    http::{header, request::Parts, HeaderMap, Method, StatusCode},
+    response::{AppendHeaders, IntoResponse, Response},
+    Json,
+};
+use rand::{distributions::Alphanumeric, Rng};
+use std::convert::Infallible;
+
+use crate::{
+    models::user_dto::{
+        AuthResponse, CookieAuthResponse, CookieLoginResponse, LoginResponse, RefreshTokenRequest,
+    },
+    services::auth_service,
+    utils::{
+        constant_time_eq,
+        cookie::{self, SetCookie},
+    },
+};
+
+pub const ACCESS_COOKIE: &str = "lekha_access";
+pub const REFRESH_COOKIE: &str = "lekha_refresh";
+pub const CSRF_COOKIE: &str = "lekha_csrf";
+pub const CSRF_HEADER: &str = "X-CSRF-Token";
+pub const AUTH_MODE_HEADER: &str = "X-Auth-Mode";
+
+const CSRF_TOKEN_LENGTH: usize = 32;
+const ACCESS_COOKIE_PATH: &str = "/api";
+// 리프레시 쿠키는 재발급/로그아웃 요청에만 실리도록 경로를 좁힌다.
+const REFRESH_COOKIE_PATH: &str = "/api/auth";
+// 프런트엔드 스크립트가 읽어 헤더로 되돌려 보내야 하므로 모든 경로에서 보이게 둔다.
+const CSRF_COOKIE_PATH: &str = "/";
+
+// 브라우저 클라이언트가 `X-Auth-Mode: cookie`로 요청하면 토큰을 쿠키로 내려준다.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AuthMode {
+    Bearer,
+    Cookie,
+}
+
+#[async_trait]
+impl<S: Send + Sync> FromRequestParts<S> for AuthMode {
+    type Rejection = Infallible;
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        let is_cookie = parts
+            .headers
+            .get(AUTH_MODE_HEADER)
+            .and_then(|value| value.to_str().ok())
+            .is_some_and(|value| value.trim().eq_ignore_ascii_case("cookie"));
+
+        Ok(if is_cookie {
+            AuthMode::Cookie
+        } else {
+            AuthMode::Bearer
+        })
+    }
+}
+
+pub fn is_safe_method(method: &Method) -> bool {
+    matches!(*method, Method::GET | Method::HEAD | Method::OPTIONS)
+}
+
+// Double-submit 방식: 다른 출처의 페이지는 CSRF 쿠키를 읽을 수 없으므로 헤더에 같은 값을 실을 수 없다.
+pub fn verify_csrf(headers: &HeaderMap) -> Result<(), (StatusCode, &'static str)> {
+    let cookie = cookie::get(headers, CSRF_COOKIE);
+    let header = headers
+        .get(CSRF_HEADER)
+        .and_then(|value| value.to_str().ok());
+
+    match (cookie, header) {
+        (Some(cookie), Some(header)) if constant_time_eq(cookie.as_bytes(), header.as_bytes()) => {
+            Ok(())
+        }
+        _ => Err((StatusCode::FORBIDDEN, "Invalid CSRF token")),
+    }
+}
+
+// 쿠키 모드에서는 본문을 무시하고 쿠키의 리프레시 토큰만 사용한다.
+pub fn refresh_token(
+    mode: AuthMode,
+    headers: &HeaderMap,
+    payload: Option<Json<RefreshTokenRequest>>,
+) -> Result<String, (StatusCode, &'static str)> {
+    match mode {
+        AuthMode::Cookie => {
+            verify_csrf(headers)?;
+            cookie::get(headers, REFRESH_COOKIE)
+                .ok_or((StatusCode::UNAUTHORIZED, "Missing refresh token cookie"))
+        }
+        AuthMode::Bearer => payload
+            .map(|Json(payload)| payload.refresh_token)
+            .ok_or((StatusCode::UNAUTHORIZED, "Missing refresh token")),
+    }
+}
+
+pub fn auth_response(status: StatusCode, mode: AuthMode, response: AuthResponse) -> Response {
+    match mode {
+        AuthMode::Bearer => (status, Json(response)).into_response(),
+        AuthMode::Cookie => {
+            let (cookies, body) = into_cookies(response);
+            (status, AppendHeaders(cookies), Json(body)).into_response()
+        }
+    }
+}
+
+pub fn login_response(mode: AuthMode, response: LoginResponse) -> Response {
+    match (mode, response) {
+        (AuthMode::Cookie, LoginResponse::Authenticated(response)) => {
+            let (cookies, body) = into_cookies(response);
+            (
+                StatusCode::OK,
+                AppendHeaders(cookies),
+                Json(CookieLoginResponse::Authenticated(body)),
+            )
+                .into_response()
+        }
+        (_, response) => (StatusCode::OK, Json(response)).into_response(),
+    }
+}
+
+pub fn clear_cookies() -> AppendHeaders<[(header::HeaderName, String); 3]> {
+    let secure = cookie_secure();
+    let clear = |name, path| {
+        let cookie = SetCookie {
+            name,
+            value: "",
+            path,
+            max_age: 0,
+            http_only: name != CSRF_COOKIE,
+            secure,
+        };
+        (header::SET_COOKIE, cookie.to_header_value())
+    };
+
+    AppendHeaders([
+        clear(ACCESS_COOKIE, ACCESS_COOKIE_PATH),
+        clear(REFRESH_COOKIE, REFRESH_COOKIE_PATH),
+        clear(CSRF_COOKIE, CSRF_COOKIE_PATH),
+    ])
+}
+
+fn into_cookies(response: AuthResponse) -> ([(header::HeaderName, String); 3], CookieAuthResponse) {
+    let secure = cookie_secure();
+    let refresh_max_age = auth_service::refresh_token_days() * 24 * 60 * 60;
+    let csrf_token: String = rand::thread_rng()
+        .sample_iter(&Alphanumeric)
+        .take(CSRF_TOKEN_LENGTH)
+        .map(char::from)
+        .collect();
+
+    let cookies = [
+        SetCookie {
+            name: ACCESS_COOKIE,
+            value: &response.access_token,
+            path: ACCESS_COOKIE_PATH,
+            max_age: response.expires_in,
+            http_only: true,
+            secure,
+        },
+        SetCookie {
+            name: REFRESH_COOKIE,
+            value: &response.refresh_token,
+            path: REFRESH_COOKIE_PATH,
+            max_age: refresh_max_age,
+            http_only: true,
+            secure,
+        },
+        SetCookie {
+            name: CSRF_COOKIE,
+            value: &csrf_token,
+            path: CSRF_COOKIE_PATH,
+            max_age: refresh_max_age,
+            http_only: false,
+            secure,
+        },
+    ]
+    .map(|cookie| (header::SET_COOKIE, cookie.to_header_value()));
+
+    let body = CookieAuthResponse {
+        user: response.user,
+        csrf_token,
+        expires_in: response.expires_in,
+    };
+    (cookies, body)
+}
+
+// 로컬 개발 서버(http)에서만 `AUTH_COOKIE_SECURE=false`로 Secure 속성을 끈다.
+fn cookie_secure() -> bool {
+    std::env::var("AUTH_COOKIE_SECURE")
+        .map(|value| value != "false")
+        .unwrap_or(true)
+}
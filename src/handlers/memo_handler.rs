@@ -1,13 +1,26 @@
 use axum::{
-    extract::{Path, State},
+    extract::{Path, Query, State},
     http::StatusCode,
     response::IntoResponse,
     Json,
 };
 
-use super::{auth::AuthenticatedUser, AppState};
-use crate::models::memo_dto::{CreateMemoRequest, MemoResponse, UpdateMemoRequest};
-use crate::errors::ErrorResponse;
+use super::{
+    auth::{scope, ScopedUser},
+    AppState,
+};
+use crate::errors::{ErrorResponse, SearchQueryErrorResponse};
+use crate::models::{
+    memo_dto::{
+        CreateMemoRequest, HybridSearchQuery, HybridSearchResponse, MemoListQuery,
+        MemoListResponse, MemoResponse, MemoSearchQuery, MemoSearchResponse, MoveMemosRequest,
+        TrashedMemoResponse, UpdateMemoRequest,
+    },
+    memo_revision_dto::{
+        MemoRevisionDiffQuery, MemoRevisionDiffResponse, MemoRevisionResponse, MemoRevisionSummary,
+    },
+    tag_dto::TagSuggestionResponse,
+};
 
 #[utoipa::path(
     post,
@@ -18,17 +31,19 @@ use crate::errors::ErrorResponse;
         (status = 201, description = "메모 생성 성공", body = MemoResponse),
         (status = 400, description = "잘못된 요청", body = ErrorResponse),
         (status = 401, description = "인증 실패", body = ErrorResponse),
+        (status = 403, description = "토큰에 `memos:write` 스코프가 없음", body = ErrorResponse),
+        (status = 404, description = "노트북을 찾을 수 없음", body = ErrorResponse),
         (status = 500, description = "서버 에러", body = ErrorResponse)
     ),
     security(("bearer_auth" = []))
 )]
 pub async fn create_memo(
     State(state): State<AppState>,
-    user: AuthenticatedUser,
+    user: ScopedUser<scope::MemosWrite>,
     Json(payload): Json<CreateMemoRequest>,
 ) -> impl IntoResponse {
     match state.memo_service.create_memo(user.id, payload).await {
-        Ok(memo) => (StatusCode::CREATED, Json(MemoResponse::from(memo))).into_response(),
+        Ok(memo) => (StatusCode::CREATED, Json(memo)).into_response(),
         Err(e) => e.into_response(),
     }
 }
@@ -37,23 +52,75 @@ pub async fn create_memo(
     get,
     path = "/api/memos",
     tag = "Memos",
+    params(MemoListQuery),
     responses(
-        (status = 200, description = "메모 목록 조회 성공", body = Vec<MemoResponse>),
+        (status = 200, description = "메모 목록 조회 성공 (고정 메모 우선, 최근 수정 순)", body = MemoListResponse),
+        (status = 400, description = "잘못된 커서", body = ErrorResponse),
         (status = 401, description = "인증 실패", body = ErrorResponse),
+        (status = 403, description = "토큰에 `memos:read` 스코프가 없음", body = ErrorResponse),
+        (status = 404, description = "노트북을 찾을 수 없음", body = ErrorResponse),
         (status = 500, description = "서버 에러", body = ErrorResponse)
     ),
     security(("bearer_auth" = []))
 )]
 pub async fn list_memos(
     State(state): State<AppState>,
-    user: AuthenticatedUser,
+    user: ScopedUser<scope::MemosRead>,
+    Query(query): Query<MemoListQuery>,
+) -> impl IntoResponse {
+    match state.memo_service.list_memos(user.id, query).await {
+        Ok(memos) => (StatusCode::OK, Json(memos)).into_response(),
+        Err(e) => e.into_response(),
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/memos/search",
+    tag = "Memos",
+    params(MemoSearchQuery),
+    responses(
+        (status = 200, description = "키워드 검색 성공 (관련도 순)", body = MemoSearchResponse),
+        (status = 400, description = "검색어 문법 오류 또는 빈 검색어. 문법 오류에는 `position`이 포함됨", body = SearchQueryErrorResponse),
+        (status = 401, description = "인증 실패", body = ErrorResponse),
+        (status = 403, description = "토큰에 `memos:read` 스코프가 없음", body = ErrorResponse),
+        (status = 500, description = "서버 에러", body = ErrorResponse)
+    ),
+    security(("bearer_auth" = []))
+)]
+pub async fn search_memos(
+    State(state): State<AppState>,
+    user: ScopedUser<scope::MemosRead>,
+    Query(query): Query<MemoSearchQuery>,
+) -> impl IntoResponse {
+    match state.memo_service.search_memos(user.id, query).await {
+        Ok(results) => (StatusCode::OK, Json(results)).into_response(),
+        Err(e) => e.into_response(),
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/memos/semantic-search",
+    tag = "Memos",
+    params(HybridSearchQuery),
+    responses(
+        (status = 200, description = "키워드 검색과 의미 기반 검색을 합친 결과 (융합 점수 순)", body = HybridSearchResponse),
+        (status = 400, description = "검색어 문법 오류 또는 빈 검색어. 문법 오류에는 `position`이 포함됨", body = SearchQueryErrorResponse),
+        (status = 401, description = "인증 실패", body = ErrorResponse),
+        (status = 403, description = "토큰에 `memos:read` 스코프가 없음", body = ErrorResponse),
+        (status = 502, description = "임베딩 생성 실패", body = ErrorResponse),
+        (status = 500, description = "서버 에러", body = ErrorResponse)
+    ),
+    security(("bearer_auth" = []))
+)]
+pub async fn semantic_search(
+    State(state): State<AppState>,
+    user: ScopedUser<scope::MemosRead>,
+    Query(query): Query<HybridSearchQuery>,
 ) -> impl IntoResponse {
-    match state.memo_service.list_memos(user.id).await {
-        Ok(memos) => {
-            let memo_responses: Vec<MemoResponse> =
-                memos.into_iter().map(MemoResponse::from).collect();
-            (StatusCode::OK, Json(memo_responses)).into_response()
-        }
+    match state.memo_service.hybrid_search(user.id, query).await {
+        Ok(results) => (StatusCode::OK, Json(results)).into_response(),
         Err(e) => e.into_response(),
     }
 }
@@ -68,6 +135,7 @@ pub async fn list_memos(
     responses(
         (status = 200, description = "메모 조회 성공", body = MemoResponse),
         (status = 401, description = "인증 실패", body = ErrorResponse),
+        (status = 403, description = "토큰에 `memos:read` 스코프가 없음", body = ErrorResponse),
         (status = 404, description = "메모를 찾을 수 없음", body = ErrorResponse),
         (status = 500, description = "서버 에러", body = ErrorResponse)
     ),
@@ -75,11 +143,11 @@ pub async fn list_memos(
 )]
 pub async fn get_memo(
     State(state): State<AppState>,
-    user: AuthenticatedUser,
+    user: ScopedUser<scope::MemosRead>,
     Path(id): Path<i32>,
 ) -> impl IntoResponse {
     match state.memo_service.get_memo(user.id, id).await {
-        Ok(memo) => (StatusCode::OK, Json(MemoResponse::from(memo))).into_response(),
+        Ok(memo) => (StatusCode::OK, Json(memo)).into_response(),
         Err(e) => e.into_response(),
     }
 }
@@ -96,6 +164,7 @@ pub async fn get_memo(
         (status = 200, description = "메모 수정 성공", body = MemoResponse),
         (status = 400, description = "잘못된 요청", body = ErrorResponse),
         (status = 401, description = "인증 실패", body = ErrorResponse),
+        (status = 403, description = "토큰에 `memos:write` 스코프가 없음", body = ErrorResponse),
         (status = 404, description = "메모를 찾을 수 없음", body = ErrorResponse),
         (status = 500, description = "서버 에러", body = ErrorResponse)
     ),
@@ -103,12 +172,12 @@ pub async fn get_memo(
 )]
 pub async fn update_memo(
     State(state): State<AppState>,
-    user: AuthenticatedUser,
+    user: ScopedUser<scope::MemosWrite>,
     Path(id): Path<i32>,
     Json(payload): Json<UpdateMemoRequest>,
 ) -> impl IntoResponse {
     match state.memo_service.update_memo(user.id, id, payload).await {
-        Ok(memo) => (StatusCode::OK, Json(MemoResponse::from(memo))).into_response(),
+        Ok(memo) => (StatusCode::OK, Json(memo)).into_response(),
         Err(e) => e.into_response(),
     }
 }
@@ -121,8 +190,9 @@ pub async fn update_memo(
         ("id" = i32, Path, description = "메모 ID")
     ),
     responses(
-        (status = 204, description = "메모 삭제 성공"),
+        (status = 204, description = "메모를 휴지통으로 옮김 (보관 기간이 지나면 영구 삭제)"),
         (status = 401, description = "인증 실패", body = ErrorResponse),
+        (status = 403, description = "토큰에 `memos:write` 스코프가 없음", body = ErrorResponse),
         (status = 404, description = "메모를 찾을 수 없음", body = ErrorResponse),
         (status = 500, description = "서버 에러", body = ErrorResponse)
     ),
@@ -130,7 +200,7 @@ pub async fn update_memo(
 )]
 pub async fn delete_memo(
     State(state): State<AppState>,
-    user: AuthenticatedUser,
+    user: ScopedUser<scope::MemosWrite>,
     Path(id): Path<i32>,
 ) -> impl IntoResponse {
     match state.memo_service.delete_memo(user.id, id).await {
@@ -139,6 +209,81 @@ pub async fn delete_memo(
     }
 }
 
+#[utoipa::path(
+    get,
+    path = "/api/memos/trash",
+    tag = "Memos",
+    responses(
+        (status = 200, description = "휴지통의 메모 목록 (최근에 지운 순)", body = Vec<TrashedMemoResponse>),
+        (status = 401, description = "인증 실패", body = ErrorResponse),
+        (status = 403, description = "토큰에 `memos:read` 스코프가 없음", body = ErrorResponse),
+        (status = 500, description = "서버 에러", body = ErrorResponse)
+    ),
+    security(("bearer_auth" = []))
+)]
+pub async fn list_trash(
+    State(state): State<AppState>,
+    user: ScopedUser<scope::MemosRead>,
+) -> impl IntoResponse {
+    match state.memo_service.list_trash(user.id).await {
+        Ok(memos) => (StatusCode::OK, Json(memos)).into_response(),
+        Err(e) => e.into_response(),
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/memos/{id}/restore",
+    tag = "Memos",
+    params(
+        ("id" = i32, Path, description = "메모 ID")
+    ),
+    responses(
+        (status = 200, description = "휴지통에서 복원 성공. 휴지통에 있는 동안 노트북이 삭제되었으면 부모 노트북(`move`) 또는 노트북 밖(`cascade`)으로 복원됨", body = MemoResponse),
+        (status = 401, description = "인증 실패", body = ErrorResponse),
+        (status = 403, description = "토큰에 `memos:write` 스코프가 없음", body = ErrorResponse),
+        (status = 404, description = "휴지통에서 메모를 찾을 수 없음", body = ErrorResponse),
+        (status = 500, description = "서버 에러", body = ErrorResponse)
+    ),
+    security(("bearer_auth" = []))
+)]
+pub async fn restore_memo(
+    State(state): State<AppState>,
+    user: ScopedUser<scope::MemosWrite>,
+    Path(id): Path<i32>,
+) -> impl IntoResponse {
+    match state.memo_service.restore_memo(user.id, id).await {
+        Ok(memo) => (StatusCode::OK, Json(memo)).into_response(),
+        Err(e) => e.into_response(),
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/memos/move",
+    tag = "Memos",
+    request_body = MoveMemosRequest,
+    responses(
+        (status = 200, description = "메모 이동 성공 (옮겨진 메모)", body = Vec<MemoResponse>),
+        (status = 400, description = "옮길 메모가 없음", body = ErrorResponse),
+        (status = 401, description = "인증 실패", body = ErrorResponse),
+        (status = 403, description = "토큰에 `memos:write` 스코프가 없음", body = ErrorResponse),
+        (status = 404, description = "메모나 노트북을 찾을 수 없음", body = ErrorResponse),
+        (status = 500, description = "서버 에러", body = ErrorResponse)
+    ),
+    security(("bearer_auth" = []))
+)]
+pub async fn move_memos(
+    State(state): State<AppState>,
+    user: ScopedUser<scope::MemosWrite>,
+    Json(payload): Json<MoveMemosRequest>,
+) -> impl IntoResponse {
+    match state.memo_service.move_memos(user.id, payload).await {
+        Ok(memos) => (StatusCode::OK, Json(memos)).into_response(),
+        Err(e) => e.into_response(),
+    }
+}
+
 #[utoipa::path(
     patch,
     path = "/api/memos/{id}/pin",
@@ -149,6 +294,7 @@ pub async fn delete_memo(
     responses(
         (status = 200, description = "메모 고정 토글 성공", body = MemoResponse),
         (status = 401, description = "인증 실패", body = ErrorResponse),
+        (status = 403, description = "토큰에 `memos:write` 스코프가 없음", body = ErrorResponse),
         (status = 404, description = "메모를 찾을 수 없음", body = ErrorResponse),
         (status = 500, description = "서버 에러", body = ErrorResponse)
     ),
@@ -156,11 +302,213 @@ pub async fn delete_memo(
 )]
 pub async fn toggle_pin(
     State(state): State<AppState>,
-    user: AuthenticatedUser,
+    user: ScopedUser<scope::MemosWrite>,
     Path(id): Path<i32>,
 ) -> impl IntoResponse {
     match state.memo_service.toggle_pin(user.id, id).await {
-        Ok(memo) => (StatusCode::OK, Json(MemoResponse::from(memo))).into_response(),
+        Ok(memo) => (StatusCode::OK, Json(memo)).into_response(),
+        Err(e) => e.into_response(),
+    }
+}
+
+#[utoipa::path(
+    patch,
+    path = "/api/memos/{id}/archive",
+    tag = "Memos",
+    params(
+        ("id" = i32, Path, description = "메모 ID")
+    ),
+    responses(
+        (status = 200, description = "메모 보관 성공. 보관된 메모는 기본 목록과 AI 어시스턴트의 참고 메모에서 빠짐", body = MemoResponse),
+        (status = 401, description = "인증 실패", body = ErrorResponse),
+        (status = 403, description = "토큰에 `memos:write` 스코프가 없음", body = ErrorResponse),
+        (status = 404, description = "메모를 찾을 수 없음", body = ErrorResponse),
+        (status = 500, description = "서버 에러", body = ErrorResponse)
+    ),
+    security(("bearer_auth" = []))
+)]
+pub async fn archive_memo(
+    State(state): State<AppState>,
+    user: ScopedUser<scope::MemosWrite>,
+    Path(id): Path<i32>,
+) -> impl IntoResponse {
+    match state.memo_service.archive_memo(user.id, id).await {
+        Ok(memo) => (StatusCode::OK, Json(memo)).into_response(),
+        Err(e) => e.into_response(),
+    }
+}
+
+#[utoipa::path(
+    patch,
+    path = "/api/memos/{id}/unarchive",
+    tag = "Memos",
+    params(
+        ("id" = i32, Path, description = "메모 ID")
+    ),
+    responses(
+        (status = 200, description = "메모 보관 해제 성공", body = MemoResponse),
+        (status = 401, description = "인증 실패", body = ErrorResponse),
+        (status = 403, description = "토큰에 `memos:write` 스코프가 없음", body = ErrorResponse),
+        (status = 404, description = "메모를 찾을 수 없음", body = ErrorResponse),
+        (status = 500, description = "서버 에러", body = ErrorResponse)
+    ),
+    security(("bearer_auth" = []))
+)]
+pub async fn unarchive_memo(
+    State(state): State<AppState>,
+    user: ScopedUser<scope::MemosWrite>,
+    Path(id): Path<i32>,
+) -> impl IntoResponse {
+    match state.memo_service.unarchive_memo(user.id, id).await {
+        Ok(memo) => (StatusCode::OK, Json(memo)).into_response(),
+        Err(e) => e.into_response(),
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/memos/{id}/tag-suggestions",
+    tag = "Memos",
+    params(
+        ("id" = i32, Path, description = "메모 ID")
+    ),
+    responses(
+        (status = 200, description = "AI 태그 제안 (기존 태그 우선, 메모에 이미 붙은 태그 제외). 메모에 붙이려면 메모 수정으로 태그를 보냄", body = TagSuggestionResponse),
+        (status = 401, description = "인증 실패", body = ErrorResponse),
+        (status = 403, description = "토큰에 `memos:read` 스코프가 없음", body = ErrorResponse),
+        (status = 404, description = "메모를 찾을 수 없음", body = ErrorResponse),
+        (status = 500, description = "서버 에러", body = ErrorResponse)
+    ),
+    security(("bearer_auth" = []))
+)]
+pub async fn suggest_tags(
+    State(state): State<AppState>,
+    user: ScopedUser<scope::MemosRead>,
+    Path(id): Path<i32>,
+) -> impl IntoResponse {
+    match state.memo_service.suggest_tags(user.id, id).await {
+        Ok(suggestions) => (StatusCode::OK, Json(suggestions)).into_response(),
+        Err(e) => e.into_response(),
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/memos/{id}/revisions",
+    tag = "Memos",
+    params(
+        ("id" = i32, Path, description = "메모 ID")
+    ),
+    responses(
+        (status = 200, description = "수정 전 버전 목록 (최신순, 현재 내용 제외)", body = Vec<MemoRevisionSummary>),
+        (status = 401, description = "인증 실패", body = ErrorResponse),
+        (status = 403, description = "토큰에 `memos:read` 스코프가 없음", body = ErrorResponse),
+        (status = 404, description = "메모를 찾을 수 없음", body = ErrorResponse),
+        (status = 500, description = "서버 에러", body = ErrorResponse)
+    ),
+    security(("bearer_auth" = []))
+)]
+pub async fn list_revisions(
+    State(state): State<AppState>,
+    user: ScopedUser<scope::MemosRead>,
+    Path(id): Path<i32>,
+) -> impl IntoResponse {
+    match state.memo_service.list_revisions(user.id, id).await {
+        Ok(revisions) => (StatusCode::OK, Json(revisions)).into_response(),
+        Err(e) => e.into_response(),
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/memos/{id}/revisions/diff",
+    tag = "Memos",
+    params(
+        ("id" = i32, Path, description = "메모 ID"),
+        MemoRevisionDiffQuery
+    ),
+    responses(
+        (status = 200, description = "두 버전의 차이", body = MemoRevisionDiffResponse),
+        (status = 400, description = "잘못된 쿼리", body = ErrorResponse),
+        (status = 401, description = "인증 실패", body = ErrorResponse),
+        (status = 403, description = "토큰에 `memos:read` 스코프가 없음", body = ErrorResponse),
+        (status = 404, description = "메모나 버전을 찾을 수 없음", body = ErrorResponse),
+        (status = 500, description = "서버 에러", body = ErrorResponse)
+    ),
+    security(("bearer_auth" = []))
+)]
+pub async fn diff_revisions(
+    State(state): State<AppState>,
+    user: ScopedUser<scope::MemosRead>,
+    Path(id): Path<i32>,
+    Query(query): Query<MemoRevisionDiffQuery>,
+) -> impl IntoResponse {
+    match state.memo_service.diff_revisions(user.id, id, query).await {
+        Ok(diff) => (StatusCode::OK, Json(diff)).into_response(),
+        Err(e) => e.into_response(),
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/memos/{id}/revisions/{revision_id}",
+    tag = "Memos",
+    params(
+        ("id" = i32, Path, description = "메모 ID"),
+        ("revision_id" = i32, Path, description = "버전 ID")
+    ),
+    responses(
+        (status = 200, description = "버전 조회 성공", body = MemoRevisionResponse),
+        (status = 401, description = "인증 실패", body = ErrorResponse),
+        (status = 403, description = "토큰에 `memos:read` 스코프가 없음", body = ErrorResponse),
+        (status = 404, description = "메모나 버전을 찾을 수 없음", body = ErrorResponse),
+        (status = 500, description = "서버 에러", body = ErrorResponse)
+    ),
+    security(("bearer_auth" = []))
+)]
+pub async fn get_revision(
+    State(state): State<AppState>,
+    user: ScopedUser<scope::MemosRead>,
+    Path((id, revision_id)): Path<(i32, i32)>,
+) -> impl IntoResponse {
+    match state
+        .memo_service
+        .get_revision(user.id, id, revision_id)
+        .await
+    {
+        Ok(revision) => (StatusCode::OK, Json(revision)).into_response(),
+        Err(e) => e.into_response(),
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/memos/{id}/revisions/{revision_id}/restore",
+    tag = "Memos",
+    params(
+        ("id" = i32, Path, description = "메모 ID"),
+        ("revision_id" = i32, Path, description = "되돌릴 버전 ID")
+    ),
+    responses(
+        (status = 200, description = "되돌리기 성공 (되돌리기 전 내용은 새 버전으로 남음)", body = MemoResponse),
+        (status = 401, description = "인증 실패", body = ErrorResponse),
+        (status = 403, description = "토큰에 `memos:write` 스코프가 없음", body = ErrorResponse),
+        (status = 404, description = "메모나 버전을 찾을 수 없음", body = ErrorResponse),
+        (status = 500, description = "서버 에러", body = ErrorResponse)
+    ),
+    security(("bearer_auth" = []))
+)]
+pub async fn restore_revision(
+    State(state): State<AppState>,
+    user: ScopedUser<scope::MemosWrite>,
+    Path((id, revision_id)): Path<(i32, i32)>,
+) -> impl IntoResponse {
+    match state
+        .memo_service
+        .restore_revision(user.id, id, revision_id)
+        .await
+    {
+        Ok(memo) => (StatusCode::OK, Json(memo)).into_response(),
         Err(e) => e.into_response(),
     }
 }
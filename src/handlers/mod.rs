@@ -1,16 +1,32 @@
+pub mod admin_handler;
 pub mod assist_handler;
 pub mod auth;
+pub mod auth_handler;
+pub mod cookie_auth;
 pub mod health_handler;
 pub mod memo_handler;
+pub mod notebook_handler;
+pub mod saved_search_handler;
+pub mod security_event_handler;
+pub mod session_handler;
+pub mod tag_handler;
+pub mod token_handler;
+pub mod two_factor_handler;
 pub mod user_handler;
 
 use crate::{
-    clients::{Embedder, TextGenerator},
+    clients::{Embedder, OAuthVerifier, TextGenerator},
     openapi::ApiDoc,
     repositories::QdrantRepo,
     services::{
-        assist_service::AssistService, memo_service::MemoService, user_service::UserService,
+        admin_service::AdminService, assist_service::AssistService,
+        auth_event_service::AuthEventService, auth_service::AuthService, memo_service::MemoService,
+        notebook_service::NotebookService,
+        personal_access_token_service::PersonalAccessTokenService,
+        saved_search_service::SavedSearchService, tag_service::TagService,
+        two_factor_service::TwoFactorService, user_service::UserService,
     },
+    utils::jwt::JwtKeys,
 };
 use axum::{
     routing::{delete, get, patch, post, put},
@@ -27,6 +43,15 @@ pub struct AppState {
     pub memo_service: Arc<MemoService>,
     pub assist_service: Arc<AssistService>,
     pub user_service: Arc<UserService>,
+    pub auth_service: Arc<AuthService>,
+    pub personal_access_token_service: Arc<PersonalAccessTokenService>,
+    pub saved_search_service: Arc<SavedSearchService>,
+    pub tag_service: Arc<TagService>,
+    pub notebook_service: Arc<NotebookService>,
+    pub two_factor_service: Arc<TwoFactorService>,
+    pub admin_service: Arc<AdminService>,
+    pub auth_event_service: Arc<AuthEventService>,
+    pub jwt_keys: Arc<JwtKeys>,
 }
 
 pub fn create_router(
@@ -34,27 +59,60 @@ pub fn create_router(
     qdrant_repo: Arc<dyn QdrantRepo>,
     embedder: Arc<dyn Embedder>,
     text_generator: Arc<dyn TextGenerator>,
+    oauth_verifier: Arc<dyn OAuthVerifier>,
+    jwt_keys: Arc<JwtKeys>,
 ) -> Router {
     let memo_service = Arc::new(MemoService::new(
         db.clone(),
         qdrant_repo.clone(),
         embedder.clone(),
+        text_generator.clone(),
     ));
 
     let assist_service = Arc::new(AssistService::new(
         db.clone(),
-        qdrant_repo,
+        qdrant_repo.clone(),
         embedder,
         text_generator,
     ));
 
-    let user_service = Arc::new(UserService::new(db.clone()));
+    let user_service = Arc::new(UserService::new(
+        db.clone(),
+        qdrant_repo,
+        oauth_verifier,
+        jwt_keys.clone(),
+    ));
+
+    let auth_service = Arc::new(AuthService::new(db.clone(), jwt_keys.clone()));
+
+    let personal_access_token_service = Arc::new(PersonalAccessTokenService::new(db.clone()));
+
+    let saved_search_service = Arc::new(SavedSearchService::new(db.clone(), memo_service.clone()));
+
+    let tag_service = Arc::new(TagService::new(db.clone(), memo_service.clone()));
+
+    let notebook_service = Arc::new(NotebookService::new(db.clone(), memo_service.clone()));
+
+    let two_factor_service = Arc::new(TwoFactorService::new(db.clone(), jwt_keys.clone()));
+
+    let admin_service = Arc::new(AdminService::new(db.clone(), jwt_keys.clone()));
+
+    let auth_event_service = Arc::new(AuthEventService::new(db.clone()));
 
     let app_state = AppState {
         db,
         memo_service,
         assist_service,
         user_service,
+        auth_service,
+        personal_access_token_service,
+        saved_search_service,
+        tag_service,
+        notebook_service,
+        two_factor_service,
+        admin_service,
+        auth_event_service,
+        jwt_keys,
     };
 
     let openapi = ApiDoc::openapi();
@@ -62,17 +120,159 @@ pub fn create_router(
     Router::new()
         .merge(SwaggerUi::new("/swagger-ui").url("/api-docs/openapi.json", openapi))
         .route("/api/health", get(health_handler::health_check))
+        .route("/.well-known/jwks.json", get(auth_handler::jwks))
         .route("/api/assist", post(assist_handler::assist))
         .route("/api/users/oauth-login", post(user_handler::oauth_login))
+        .route("/api/users/register", post(user_handler::register))
+        .route("/api/users/login", post(user_handler::login))
+        .route(
+            "/api/users/login/2fa",
+            post(two_factor_handler::complete_login),
+        )
+        .route(
+            "/api/users/me",
+            get(user_handler::get_me)
+                .patch(user_handler::update_me)
+                .delete(user_handler::delete_me),
+        )
+        .route("/api/users/me/password", put(user_handler::change_password))
+        .route(
+            "/api/users/me/oauth-accounts",
+            get(user_handler::list_oauth_accounts).post(user_handler::link_oauth_account),
+        )
+        .route(
+            "/api/users/me/oauth-accounts/:provider",
+            delete(user_handler::unlink_oauth_account),
+        )
+        .route(
+            "/api/users/me/2fa",
+            get(two_factor_handler::get_status).patch(two_factor_handler::update_policy),
+        )
+        .route(
+            "/api/users/me/2fa/totp",
+            post(two_factor_handler::start_enrollment),
+        )
+        .route(
+            "/api/users/me/2fa/totp/activate",
+            post(two_factor_handler::activate),
+        )
+        .route(
+            "/api/users/me/2fa/totp/disable",
+            post(two_factor_handler::disable),
+        )
+        .route(
+            "/api/users/me/2fa/recovery-codes",
+            post(two_factor_handler::regenerate_recovery_codes),
+        )
+        .route(
+            "/api/users/me/sessions",
+            get(session_handler::list_sessions).delete(session_handler::revoke_other_sessions),
+        )
+        .route(
+            "/api/users/me/sessions/:id",
+            delete(session_handler::revoke_session),
+        )
+        .route(
+            "/api/users/me/security-events",
+            get(security_event_handler::list_security_events),
+        )
+        .route(
+            "/api/users/me/tokens",
+            get(token_handler::list_tokens).post(token_handler::create_token),
+        )
+        .route(
+            "/api/users/me/tokens/:id",
+            delete(token_handler::revoke_token),
+        )
+        .route("/api/auth/refresh", post(auth_handler::refresh))
+        .route("/api/auth/logout", post(auth_handler::logout))
         .nest(
             "/api/memos",
             Router::new()
                 .route("/", post(memo_handler::create_memo))
                 .route("/", get(memo_handler::list_memos))
+                .route("/search", get(memo_handler::search_memos))
+                .route("/semantic-search", get(memo_handler::semantic_search))
+                .route("/move", post(memo_handler::move_memos))
+                .route("/trash", get(memo_handler::list_trash))
                 .route("/:id", get(memo_handler::get_memo))
                 .route("/:id", put(memo_handler::update_memo))
                 .route("/:id", delete(memo_handler::delete_memo))
-                .route("/:id/pin", patch(memo_handler::toggle_pin)),
+                .route("/:id/pin", patch(memo_handler::toggle_pin))
+                .route("/:id/archive", patch(memo_handler::archive_memo))
+                .route("/:id/unarchive", patch(memo_handler::unarchive_memo))
+                .route("/:id/restore", post(memo_handler::restore_memo))
+                .route("/:id/tag-suggestions", get(memo_handler::suggest_tags))
+                .route("/:id/revisions", get(memo_handler::list_revisions))
+                .route("/:id/revisions/diff", get(memo_handler::diff_revisions))
+                .route(
+                    "/:id/revisions/:revision_id",
+                    get(memo_handler::get_revision),
+                )
+                .route(
+                    "/:id/revisions/:revision_id/restore",
+                    post(memo_handler::restore_revision),
+                ),
+        )
+        .nest(
+            "/api/saved-searches",
+            Router::new()
+                .route(
+                    "/",
+                    get(saved_search_handler::list_saved_searches)
+                        .post(saved_search_handler::create_saved_search),
+                )
+                .route("/order", put(saved_search_handler::reorder_saved_searches))
+                .route(
+                    "/:id",
+                    get(saved_search_handler::get_saved_search)
+                        .patch(saved_search_handler::update_saved_search)
+                        .delete(saved_search_handler::delete_saved_search),
+                )
+                .route(
+                    "/:id/memos",
+                    get(saved_search_handler::list_saved_search_memos),
+                ),
+        )
+        .nest(
+            "/api/tags",
+            Router::new()
+                .route(
+                    "/",
+                    get(tag_handler::list_tags).post(tag_handler::create_tag),
+                )
+                .route(
+                    "/:id",
+                    patch(tag_handler::rename_tag).delete(tag_handler::delete_tag),
+                )
+                .route("/:id/merge", post(tag_handler::merge_tag)),
+        )
+        .nest(
+            "/api/notebooks",
+            Router::new()
+                .route(
+                    "/",
+                    get(notebook_handler::list_notebooks).post(notebook_handler::create_notebook),
+                )
+                .route(
+                    "/:id",
+                    patch(notebook_handler::rename_notebook)
+                        .delete(notebook_handler::delete_notebook),
+                )
+                .route("/:id/move", post(notebook_handler::move_notebook)),
+        )
+        .nest(
+            "/api/admin/users",
+            Router::new()
+                .route("/", get(admin_handler::list_users))
+                .route("/:id", patch(admin_handler::update_role))
+                .route("/:id/suspend", post(admin_handler::suspend_user))
+                .route("/:id/reactivate", post(admin_handler::reactivate_user))
+                .route("/:id/logout", post(admin_handler::force_logout)),
+        )
+        .route(
+            "/api/admin/security-events",
+            get(admin_handler::list_security_events),
         )
         .with_state(app_state)
 }
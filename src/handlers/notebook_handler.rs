@@ -0,0 +1,174 @@
+use axum::{
+    extract::{Path, Query, State},
+    http::StatusCode,
+    response::IntoResponse,
+    Json,
+};
+
+use super::{
+    auth::{scope, ScopedUser},
+    AppState,
+};
+use crate::errors::ErrorResponse;
+use crate::models::notebook_dto::{
+    CreateNotebookRequest, DeleteNotebookQuery, MoveNotebookRequest, NotebookResponse,
+    RenameNotebookRequest,
+};
+
+#[utoipa::path(
+    get,
+    path = "/api/notebooks",
+    tag = "Notebooks",
+    responses(
+        (status = 200, description = "노트북 목록 (이름순, `parent_id`로 트리 구성)", body = Vec<NotebookResponse>),
+        (status = 401, description = "인증 실패", body = ErrorResponse),
+        (status = 403, description = "토큰에 `memos:read` 스코프가 없음", body = ErrorResponse),
+        (status = 500, description = "서버 에러", body = ErrorResponse)
+    ),
+    security(("bearer_auth" = []))
+)]
+pub async fn list_notebooks(
+    State(state): State<AppState>,
+    user: ScopedUser<scope::MemosRead>,
+) -> impl IntoResponse {
+    match state.notebook_service.list_notebooks(user.id).await {
+        Ok(notebooks) => (StatusCode::OK, Json(notebooks)).into_response(),
+        Err(e) => e.into_response(),
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/notebooks",
+    tag = "Notebooks",
+    request_body = CreateNotebookRequest,
+    responses(
+        (status = 201, description = "노트북 생성 성공", body = NotebookResponse),
+        (status = 400, description = "잘못된 노트북 이름", body = ErrorResponse),
+        (status = 401, description = "인증 실패", body = ErrorResponse),
+        (status = 403, description = "토큰에 `memos:write` 스코프가 없음", body = ErrorResponse),
+        (status = 404, description = "부모 노트북을 찾을 수 없음", body = ErrorResponse),
+        (status = 409, description = "같은 부모 아래에 같은 이름의 노트북이 있음", body = ErrorResponse),
+        (status = 500, description = "서버 에러", body = ErrorResponse)
+    ),
+    security(("bearer_auth" = []))
+)]
+pub async fn create_notebook(
+    State(state): State<AppState>,
+    user: ScopedUser<scope::MemosWrite>,
+    Json(payload): Json<CreateNotebookRequest>,
+) -> impl IntoResponse {
+    match state
+        .notebook_service
+        .create_notebook(user.id, payload)
+        .await
+    {
+        Ok(notebook) => (StatusCode::CREATED, Json(notebook)).into_response(),
+        Err(e) => e.into_response(),
+    }
+}
+
+#[utoipa::path(
+    patch,
+    path = "/api/notebooks/{id}",
+    tag = "Notebooks",
+    params(
+        ("id" = i32, Path, description = "노트북 ID")
+    ),
+    request_body = RenameNotebookRequest,
+    responses(
+        (status = 200, description = "노트북 이름 변경 성공", body = NotebookResponse),
+        (status = 400, description = "잘못된 노트북 이름", body = ErrorResponse),
+        (status = 401, description = "인증 실패", body = ErrorResponse),
+        (status = 403, description = "토큰에 `memos:write` 스코프가 없음", body = ErrorResponse),
+        (status = 404, description = "노트북을 찾을 수 없음", body = ErrorResponse),
+        (status = 409, description = "같은 부모 아래에 같은 이름의 노트북이 있음", body = ErrorResponse),
+        (status = 500, description = "서버 에러", body = ErrorResponse)
+    ),
+    security(("bearer_auth" = []))
+)]
+pub async fn rename_notebook(
+    State(state): State<AppState>,
+    user: ScopedUser<scope::MemosWrite>,
+    Path(id): Path<i32>,
+    Json(payload): Json<RenameNotebookRequest>,
+) -> impl IntoResponse {
+    match state
+        .notebook_service
+        .rename_notebook(user.id, id, payload)
+        .await
+    {
+        Ok(notebook) => (StatusCode::OK, Json(notebook)).into_response(),
+        Err(e) => e.into_response(),
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/notebooks/{id}/move",
+    tag = "Notebooks",
+    params(
+        ("id" = i32, Path, description = "옮길 노트북 ID")
+    ),
+    request_body = MoveNotebookRequest,
+    responses(
+        (status = 200, description = "노트북 이동 성공", body = NotebookResponse),
+        (status = 400, description = "자기 자신이나 하위 노트북 아래로 옮기려 함", body = ErrorResponse),
+        (status = 401, description = "인증 실패", body = ErrorResponse),
+        (status = 403, description = "토큰에 `memos:write` 스코프가 없음", body = ErrorResponse),
+        (status = 404, description = "노트북을 찾을 수 없음", body = ErrorResponse),
+        (status = 409, description = "옮길 위치에 같은 이름의 노트북이 있음", body = ErrorResponse),
+        (status = 500, description = "서버 에러", body = ErrorResponse)
+    ),
+    security(("bearer_auth" = []))
+)]
+pub async fn move_notebook(
+    State(state): State<AppState>,
+    user: ScopedUser<scope::MemosWrite>,
+    Path(id): Path<i32>,
+    Json(payload): Json<MoveNotebookRequest>,
+) -> impl IntoResponse {
+    match state
+        .notebook_service
+        .move_notebook(user.id, id, payload)
+        .await
+    {
+        Ok(notebook) => (StatusCode::OK, Json(notebook)).into_response(),
+        Err(e) => e.into_response(),
+    }
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/notebooks/{id}",
+    tag = "Notebooks",
+    params(
+        ("id" = i32, Path, description = "노트북 ID"),
+        DeleteNotebookQuery
+    ),
+    responses(
+        (status = 204, description = "노트북 삭제 성공"),
+        (status = 400, description = "잘못된 `mode`", body = ErrorResponse),
+        (status = 401, description = "인증 실패", body = ErrorResponse),
+        (status = 403, description = "토큰에 `memos:write` 스코프가 없음", body = ErrorResponse),
+        (status = 404, description = "노트북을 찾을 수 없음", body = ErrorResponse),
+        (status = 409, description = "`move` 모드에서 상위 노트북 아래에 하위 노트북과 같은 이름의 노트북이 있음", body = ErrorResponse),
+        (status = 500, description = "서버 에러", body = ErrorResponse)
+    ),
+    security(("bearer_auth" = []))
+)]
+pub async fn delete_notebook(
+    State(state): State<AppState>,
+    user: ScopedUser<scope::MemosWrite>,
+    Path(id): Path<i32>,
+    Query(query): Query<DeleteNotebookQuery>,
+) -> impl IntoResponse {
+    match state
+        .notebook_service
+        .delete_notebook(user.id, id, query.mode)
+        .await
+    {
+        Ok(()) => (StatusCode::NO_CONTENT).into_response(),
+        Err(e) => e.into_response(),
+    }
+}
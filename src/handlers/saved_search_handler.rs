@@ -0,0 +1,234 @@
+use axum::{
+    extract::{Path, Query, State},
+    http::StatusCode,
+    response::IntoResponse,
+    Json,
+};
+
+use super::{
+    auth::{scope, ScopedUser},
+    AppState,
+};
+use crate::errors::{ErrorResponse, SearchQueryErrorResponse};
+use crate::models::{
+    memo_dto::MemoSearchResponse,
+    saved_search_dto::{
+        CreateSavedSearchRequest, ReorderSavedSearchesRequest, SavedSearchMemosQuery,
+        SavedSearchResponse, UpdateSavedSearchRequest,
+    },
+};
+
+#[utoipa::path(
+    post,
+    path = "/api/saved-searches",
+    tag = "Saved Searches",
+    request_body = CreateSavedSearchRequest,
+    responses(
+        (status = 201, description = "검색 저장 성공 (목록 맨 뒤에 추가됨)", body = SavedSearchResponse),
+        (status = 400, description = "잘못된 이름 또는 검색어. 검색어 문법 오류에는 `position`이 포함됨", body = SearchQueryErrorResponse),
+        (status = 401, description = "인증 실패", body = ErrorResponse),
+        (status = 403, description = "토큰에 `memos:write` 스코프가 없음", body = ErrorResponse),
+        (status = 409, description = "같은 이름의 저장된 검색이 있음", body = ErrorResponse),
+        (status = 500, description = "서버 에러", body = ErrorResponse)
+    ),
+    security(("bearer_auth" = []))
+)]
+pub async fn create_saved_search(
+    State(state): State<AppState>,
+    user: ScopedUser<scope::MemosWrite>,
+    Json(payload): Json<CreateSavedSearchRequest>,
+) -> impl IntoResponse {
+    match state
+        .saved_search_service
+        .create_saved_search(user.id, payload)
+        .await
+    {
+        Ok(saved_search) => (StatusCode::CREATED, Json(saved_search)).into_response(),
+        Err(e) => e.into_response(),
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/saved-searches",
+    tag = "Saved Searches",
+    responses(
+        (status = 200, description = "저장된 검색 목록 (사용자가 정한 순서)", body = Vec<SavedSearchResponse>),
+        (status = 401, description = "인증 실패", body = ErrorResponse),
+        (status = 403, description = "토큰에 `memos:read` 스코프가 없음", body = ErrorResponse),
+        (status = 500, description = "서버 에러", body = ErrorResponse)
+    ),
+    security(("bearer_auth" = []))
+)]
+pub async fn list_saved_searches(
+    State(state): State<AppState>,
+    user: ScopedUser<scope::MemosRead>,
+) -> impl IntoResponse {
+    match state
+        .saved_search_service
+        .list_saved_searches(user.id)
+        .await
+    {
+        Ok(saved_searches) => (StatusCode::OK, Json(saved_searches)).into_response(),
+        Err(e) => e.into_response(),
+    }
+}
+
+#[utoipa::path(
+    put,
+    path = "/api/saved-searches/order",
+    tag = "Saved Searches",
+    request_body = ReorderSavedSearchesRequest,
+    responses(
+        (status = 200, description = "순서 변경 성공 (변경된 목록)", body = Vec<SavedSearchResponse>),
+        (status = 400, description = "ID 목록이 저장된 검색 전체와 일치하지 않음", body = ErrorResponse),
+        (status = 401, description = "인증 실패", body = ErrorResponse),
+        (status = 403, description = "토큰에 `memos:write` 스코프가 없음", body = ErrorResponse),
+        (status = 500, description = "서버 에러", body = ErrorResponse)
+    ),
+    security(("bearer_auth" = []))
+)]
+pub async fn reorder_saved_searches(
+    State(state): State<AppState>,
+    user: ScopedUser<scope::MemosWrite>,
+    Json(payload): Json<ReorderSavedSearchesRequest>,
+) -> impl IntoResponse {
+    match state
+        .saved_search_service
+        .reorder_saved_searches(user.id, payload)
+        .await
+    {
+        Ok(saved_searches) => (StatusCode::OK, Json(saved_searches)).into_response(),
+        Err(e) => e.into_response(),
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/saved-searches/{id}",
+    tag = "Saved Searches",
+    params(
+        ("id" = i32, Path, description = "저장된 검색 ID")
+    ),
+    responses(
+        (status = 200, description = "저장된 검색 조회 성공", body = SavedSearchResponse),
+        (status = 401, description = "인증 실패", body = ErrorResponse),
+        (status = 403, description = "토큰에 `memos:read` 스코프가 없음", body = ErrorResponse),
+        (status = 404, description = "저장된 검색을 찾을 수 없음", body = ErrorResponse),
+        (status = 500, description = "서버 에러", body = ErrorResponse)
+    ),
+    security(("bearer_auth" = []))
+)]
+pub async fn get_saved_search(
+    State(state): State<AppState>,
+    user: ScopedUser<scope::MemosRead>,
+    Path(id): Path<i32>,
+) -> impl IntoResponse {
+    match state
+        .saved_search_service
+        .get_saved_search(user.id, id)
+        .await
+    {
+        Ok(saved_search) => (StatusCode::OK, Json(saved_search)).into_response(),
+        Err(e) => e.into_response(),
+    }
+}
+
+#[utoipa::path(
+    patch,
+    path = "/api/saved-searches/{id}",
+    tag = "Saved Searches",
+    params(
+        ("id" = i32, Path, description = "저장된 검색 ID")
+    ),
+    request_body = UpdateSavedSearchRequest,
+    responses(
+        (status = 200, description = "저장된 검색 수정 성공", body = SavedSearchResponse),
+        (status = 400, description = "잘못된 이름 또는 검색어. 검색어 문법 오류에는 `position`이 포함됨", body = SearchQueryErrorResponse),
+        (status = 401, description = "인증 실패", body = ErrorResponse),
+        (status = 403, description = "토큰에 `memos:write` 스코프가 없음", body = ErrorResponse),
+        (status = 404, description = "저장된 검색을 찾을 수 없음", body = ErrorResponse),
+        (status = 409, description = "같은 이름의 저장된 검색이 있음", body = ErrorResponse),
+        (status = 500, description = "서버 에러", body = ErrorResponse)
+    ),
+    security(("bearer_auth" = []))
+)]
+pub async fn update_saved_search(
+    State(state): State<AppState>,
+    user: ScopedUser<scope::MemosWrite>,
+    Path(id): Path<i32>,
+    Json(payload): Json<UpdateSavedSearchRequest>,
+) -> impl IntoResponse {
+    match state
+        .saved_search_service
+        .update_saved_search(user.id, id, payload)
+        .await
+    {
+        Ok(saved_search) => (StatusCode::OK, Json(saved_search)).into_response(),
+        Err(e) => e.into_response(),
+    }
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/saved-searches/{id}",
+    tag = "Saved Searches",
+    params(
+        ("id" = i32, Path, description = "저장된 검색 ID")
+    ),
+    responses(
+        (status = 204, description = "저장된 검색 삭제 성공 (메모는 삭제되지 않음)"),
+        (status = 401, description = "인증 실패", body = ErrorResponse),
+        (status = 403, description = "토큰에 `memos:write` 스코프가 없음", body = ErrorResponse),
+        (status = 404, description = "저장된 검색을 찾을 수 없음", body = ErrorResponse),
+        (status = 500, description = "서버 에러", body = ErrorResponse)
+    ),
+    security(("bearer_auth" = []))
+)]
+pub async fn delete_saved_search(
+    State(state): State<AppState>,
+    user: ScopedUser<scope::MemosWrite>,
+    Path(id): Path<i32>,
+) -> impl IntoResponse {
+    match state
+        .saved_search_service
+        .delete_saved_search(user.id, id)
+        .await
+    {
+        Ok(()) => (StatusCode::NO_CONTENT).into_response(),
+        Err(e) => e.into_response(),
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/saved-searches/{id}/memos",
+    tag = "Saved Searches",
+    params(
+        ("id" = i32, Path, description = "저장된 검색 ID"),
+        SavedSearchMemosQuery
+    ),
+    responses(
+        (status = 200, description = "저장된 검색어로 지금 다시 검색한 결과", body = MemoSearchResponse),
+        (status = 401, description = "인증 실패", body = ErrorResponse),
+        (status = 403, description = "토큰에 `memos:read` 스코프가 없음", body = ErrorResponse),
+        (status = 404, description = "저장된 검색을 찾을 수 없음", body = ErrorResponse),
+        (status = 500, description = "서버 에러", body = ErrorResponse)
+    ),
+    security(("bearer_auth" = []))
+)]
+pub async fn list_saved_search_memos(
+    State(state): State<AppState>,
+    user: ScopedUser<scope::MemosRead>,
+    Path(id): Path<i32>,
+    Query(query): Query<SavedSearchMemosQuery>,
+) -> impl IntoResponse {
+    match state
+        .saved_search_service
+        .search_memos(user.id, id, query)
+        .await
+    {
+        Ok(results) => (StatusCode::OK, Json(results)).into_response(),
+        Err(e) => e.into_response(),
+    }
+}
@@ -0,0 +1,37 @@
+use axum::{
+    extract::{Query, State},
+    http::StatusCode,
+    response::IntoResponse,
+    Json,
+};
+
+use super::{
+    auth::{scope, ScopedUser},
+    AppState,
+};
+use crate::errors::ErrorResponse;
+use crate::models::auth_event_dto::{AuthEventListResponse, SecurityEventListQuery};
+
+#[utoipa::path(
+    get,
+    path = "/api/users/me/security-events",
+    tag = "Users",
+    params(SecurityEventListQuery),
+    responses(
+        (status = 200, description = "내 계정의 보안 이벤트 기록 (최신순)", body = AuthEventListResponse),
+        (status = 401, description = "인증 실패", body = ErrorResponse),
+        (status = 403, description = "토큰에 `account` 스코프가 없음", body = ErrorResponse),
+        (status = 500, description = "서버 에러", body = ErrorResponse)
+    ),
+    security(("bearer_auth" = []))
+)]
+pub async fn list_security_events(
+    State(state): State<AppState>,
+    user: ScopedUser<scope::Account>,
+    Query(query): Query<SecurityEventListQuery>,
+) -> impl IntoResponse {
+    match state.auth_event_service.list_for_user(user.id, query).await {
+        Ok(events) => (StatusCode::OK, Json(events)).into_response(),
+        Err(e) => e.into_response(),
+    }
+}
@@ -0,0 +1,92 @@
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+    Json,
+};
+
+use super::{
+    auth::{scope, ScopedUser},
+    AppState,
+};
+use crate::errors::ErrorResponse;
+use crate::models::session_dto::SessionResponse;
+
+#[utoipa::path(
+    get,
+    path = "/api/users/me/sessions",
+    tag = "Users",
+    responses(
+        (status = 200, description = "로그인된 세션(기기) 목록", body = Vec<SessionResponse>),
+        (status = 401, description = "인증 실패", body = ErrorResponse),
+        (status = 403, description = "토큰에 `account` 스코프가 없음", body = ErrorResponse),
+        (status = 500, description = "서버 에러", body = ErrorResponse)
+    ),
+    security(("bearer_auth" = []))
+)]
+pub async fn list_sessions(
+    State(state): State<AppState>,
+    user: ScopedUser<scope::Account>,
+) -> impl IntoResponse {
+    match state
+        .auth_service
+        .list_sessions(user.id, user.session_id.as_deref())
+        .await
+    {
+        Ok(sessions) => (StatusCode::OK, Json(sessions)).into_response(),
+        Err(e) => e.into_response(),
+    }
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/users/me/sessions/{id}",
+    tag = "Users",
+    params(
+        ("id" = String, Path, description = "종료할 세션 ID")
+    ),
+    responses(
+        (status = 204, description = "세션 종료 성공 (해당 기기의 토큰이 즉시 무효화됨)"),
+        (status = 401, description = "인증 실패", body = ErrorResponse),
+        (status = 403, description = "토큰에 `account` 스코프가 없음", body = ErrorResponse),
+        (status = 404, description = "세션을 찾을 수 없음", body = ErrorResponse),
+        (status = 500, description = "서버 에러", body = ErrorResponse)
+    ),
+    security(("bearer_auth" = []))
+)]
+pub async fn revoke_session(
+    State(state): State<AppState>,
+    user: ScopedUser<scope::Account>,
+    Path(id): Path<String>,
+) -> impl IntoResponse {
+    match state.auth_service.revoke_session(user.id, &id).await {
+        Ok(()) => (StatusCode::NO_CONTENT).into_response(),
+        Err(e) => e.into_response(),
+    }
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/users/me/sessions",
+    tag = "Users",
+    responses(
+        (status = 204, description = "현재 세션을 제외한 모든 세션 종료 성공"),
+        (status = 401, description = "인증 실패", body = ErrorResponse),
+        (status = 403, description = "토큰에 `account` 스코프가 없음", body = ErrorResponse),
+        (status = 500, description = "서버 에러", body = ErrorResponse)
+    ),
+    security(("bearer_auth" = []))
+)]
+pub async fn revoke_other_sessions(
+    State(state): State<AppState>,
+    user: ScopedUser<scope::Account>,
+) -> impl IntoResponse {
+    match state
+        .auth_service
+        .revoke_other_sessions(user.id, user.session_id.as_deref())
+        .await
+    {
+        Ok(_) => (StatusCode::NO_CONTENT).into_response(),
+        Err(e) => e.into_response(),
+    }
+}
@@ -0,0 +1,149 @@
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+    Json,
+};
+
+use super::{
+    auth::{scope, ScopedUser},
+    AppState,
+};
+use crate::errors::ErrorResponse;
+use crate::models::tag_dto::{CreateTagRequest, MergeTagRequest, RenameTagRequest, TagResponse};
+
+#[utoipa::path(
+    get,
+    path = "/api/tags",
+    tag = "Tags",
+    responses(
+        (status = 200, description = "태그 목록 (이름순, 메모 수 포함)", body = Vec<TagResponse>),
+        (status = 401, description = "인증 실패", body = ErrorResponse),
+        (status = 403, description = "토큰에 `memos:read` 스코프가 없음", body = ErrorResponse),
+        (status = 500, description = "서버 에러", body = ErrorResponse)
+    ),
+    security(("bearer_auth" = []))
+)]
+pub async fn list_tags(
+    State(state): State<AppState>,
+    user: ScopedUser<scope::MemosRead>,
+) -> impl IntoResponse {
+    match state.tag_service.list_tags(user.id).await {
+        Ok(tags) => (StatusCode::OK, Json(tags)).into_response(),
+        Err(e) => e.into_response(),
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/tags",
+    tag = "Tags",
+    request_body = CreateTagRequest,
+    responses(
+        (status = 201, description = "태그 생성 성공", body = TagResponse),
+        (status = 400, description = "잘못된 태그 이름", body = ErrorResponse),
+        (status = 401, description = "인증 실패", body = ErrorResponse),
+        (status = 403, description = "토큰에 `memos:write` 스코프가 없음", body = ErrorResponse),
+        (status = 409, description = "같은 이름의 태그가 있음", body = ErrorResponse),
+        (status = 500, description = "서버 에러", body = ErrorResponse)
+    ),
+    security(("bearer_auth" = []))
+)]
+pub async fn create_tag(
+    State(state): State<AppState>,
+    user: ScopedUser<scope::MemosWrite>,
+    Json(payload): Json<CreateTagRequest>,
+) -> impl IntoResponse {
+    match state.tag_service.create_tag(user.id, payload).await {
+        Ok(tag) => (StatusCode::CREATED, Json(tag)).into_response(),
+        Err(e) => e.into_response(),
+    }
+}
+
+#[utoipa::path(
+    patch,
+    path = "/api/tags/{id}",
+    tag = "Tags",
+    params(
+        ("id" = i32, Path, description = "태그 ID")
+    ),
+    request_body = RenameTagRequest,
+    responses(
+        (status = 200, description = "태그 이름 변경 성공", body = TagResponse),
+        (status = 400, description = "잘못된 태그 이름", body = ErrorResponse),
+        (status = 401, description = "인증 실패", body = ErrorResponse),
+        (status = 403, description = "토큰에 `memos:write` 스코프가 없음", body = ErrorResponse),
+        (status = 404, description = "태그를 찾을 수 없음", body = ErrorResponse),
+        (status = 409, description = "같은 이름의 태그가 있음 (합치려면 병합 사용)", body = ErrorResponse),
+        (status = 500, description = "서버 에러", body = ErrorResponse)
+    ),
+    security(("bearer_auth" = []))
+)]
+pub async fn rename_tag(
+    State(state): State<AppState>,
+    user: ScopedUser<scope::MemosWrite>,
+    Path(id): Path<i32>,
+    Json(payload): Json<RenameTagRequest>,
+) -> impl IntoResponse {
+    match state.tag_service.rename_tag(user.id, id, payload).await {
+        Ok(tag) => (StatusCode::OK, Json(tag)).into_response(),
+        Err(e) => e.into_response(),
+    }
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/tags/{id}",
+    tag = "Tags",
+    params(
+        ("id" = i32, Path, description = "태그 ID")
+    ),
+    responses(
+        (status = 204, description = "태그 삭제 성공 (메모는 삭제되지 않고 태그만 떨어짐)"),
+        (status = 401, description = "인증 실패", body = ErrorResponse),
+        (status = 403, description = "토큰에 `memos:write` 스코프가 없음", body = ErrorResponse),
+        (status = 404, description = "태그를 찾을 수 없음", body = ErrorResponse),
+        (status = 500, description = "서버 에러", body = ErrorResponse)
+    ),
+    security(("bearer_auth" = []))
+)]
+pub async fn delete_tag(
+    State(state): State<AppState>,
+    user: ScopedUser<scope::MemosWrite>,
+    Path(id): Path<i32>,
+) -> impl IntoResponse {
+    match state.tag_service.delete_tag(user.id, id).await {
+        Ok(()) => (StatusCode::NO_CONTENT).into_response(),
+        Err(e) => e.into_response(),
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/tags/{id}/merge",
+    tag = "Tags",
+    params(
+        ("id" = i32, Path, description = "합친 뒤 삭제될 태그 ID")
+    ),
+    request_body = MergeTagRequest,
+    responses(
+        (status = 200, description = "태그 병합 성공 (합쳐진 태그)", body = TagResponse),
+        (status = 400, description = "같은 태그로 병합하려 함", body = ErrorResponse),
+        (status = 401, description = "인증 실패", body = ErrorResponse),
+        (status = 403, description = "토큰에 `memos:write` 스코프가 없음", body = ErrorResponse),
+        (status = 404, description = "태그를 찾을 수 없음", body = ErrorResponse),
+        (status = 500, description = "서버 에러", body = ErrorResponse)
+    ),
+    security(("bearer_auth" = []))
+)]
+pub async fn merge_tag(
+    State(state): State<AppState>,
+    user: ScopedUser<scope::MemosWrite>,
+    Path(id): Path<i32>,
+    Json(payload): Json<MergeTagRequest>,
+) -> impl IntoResponse {
+    match state.tag_service.merge_tag(user.id, id, payload).await {
+        Ok(tag) => (StatusCode::OK, Json(tag)).into_response(),
+        Err(e) => e.into_response(),
+    }
+}
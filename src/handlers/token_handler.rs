@@ -0,0 +1,102 @@
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+    Json,
+};
+
+use super::{
+    auth::{scope, ScopedUser, SessionUser},
+    AppState,
+};
+use crate::errors::ErrorResponse;
+use crate::models::token_dto::{
+    CreatePersonalAccessTokenRequest, CreatedPersonalAccessTokenResponse,
+    PersonalAccessTokenResponse,
+};
+
+#[utoipa::path(
+    post,
+    path = "/api/users/me/tokens",
+    tag = "Users",
+    request_body = CreatePersonalAccessTokenRequest,
+    responses(
+        (status = 201, description = "개인 액세스 토큰 발급 성공 (secret은 이번 응답에서만 확인 가능)", body = CreatedPersonalAccessTokenResponse),
+        (status = 400, description = "잘못된 요청", body = ErrorResponse),
+        (status = 401, description = "인증 실패", body = ErrorResponse),
+        (status = 403, description = "로그인 세션이 아닌 개인 액세스 토큰으로 요청했거나 요청한 스코프가 현재 토큰의 권한을 넘어섬", body = ErrorResponse),
+        (status = 500, description = "서버 에러", body = ErrorResponse)
+    ),
+    security(("bearer_auth" = []))
+)]
+pub async fn create_token(
+    State(state): State<AppState>,
+    user: SessionUser,
+    Json(payload): Json<CreatePersonalAccessTokenRequest>,
+) -> impl IntoResponse {
+    match state
+        .personal_access_token_service
+        .create_token(user.id, &user.scopes, payload)
+        .await
+    {
+        Ok(token) => (StatusCode::CREATED, Json(token)).into_response(),
+        Err(e) => e.into_response(),
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/users/me/tokens",
+    tag = "Users",
+    responses(
+        (status = 200, description = "폐기되지 않은 개인 액세스 토큰 목록", body = Vec<PersonalAccessTokenResponse>),
+        (status = 401, description = "인증 실패", body = ErrorResponse),
+        (status = 403, description = "토큰에 `account` 스코프가 없음", body = ErrorResponse),
+        (status = 500, description = "서버 에러", body = ErrorResponse)
+    ),
+    security(("bearer_auth" = []))
+)]
+pub async fn list_tokens(
+    State(state): State<AppState>,
+    user: ScopedUser<scope::Account>,
+) -> impl IntoResponse {
+    match state
+        .personal_access_token_service
+        .list_tokens(user.id)
+        .await
+    {
+        Ok(tokens) => (StatusCode::OK, Json(tokens)).into_response(),
+        Err(e) => e.into_response(),
+    }
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/users/me/tokens/{id}",
+    tag = "Users",
+    params(
+        ("id" = i32, Path, description = "폐기할 토큰 ID")
+    ),
+    responses(
+        (status = 204, description = "개인 액세스 토큰 폐기 성공"),
+        (status = 401, description = "인증 실패", body = ErrorResponse),
+        (status = 403, description = "토큰에 `account` 스코프가 없음", body = ErrorResponse),
+        (status = 404, description = "토큰을 찾을 수 없음", body = ErrorResponse),
+        (status = 500, description = "서버 에러", body = ErrorResponse)
+    ),
+    security(("bearer_auth" = []))
+)]
+pub async fn revoke_token(
+    State(state): State<AppState>,
+    user: ScopedUser<scope::Account>,
+    Path(id): Path<i32>,
+) -> impl IntoResponse {
+    match state
+        .personal_access_token_service
+        .revoke_token(user.id, id)
+        .await
+    {
+        Ok(()) => (StatusCode::NO_CONTENT).into_response(),
+        Err(e) => e.into_response(),
+    }
+}
@@ -0,0 +1,210 @@
+use axum::{extract::State, http::StatusCode, response::IntoResponse, Json};
+
+use super::{
+    auth::{scope, ScopedUser, SessionUser},
+    cookie_auth::{self, AuthMode},
+    AppState,
+};
+use crate::errors::ErrorResponse;
+use crate::models::session_dto::ClientInfo;
+use crate::models::two_factor_dto::{
+    RecoveryCodesResponse, TotpEnrollmentResponse, TwoFactorCodeRequest, TwoFactorLoginRequest,
+    TwoFactorStatusResponse, UpdateTwoFactorPolicyRequest,
+};
+use crate::models::user_dto::AuthResponse;
+
+#[utoipa::path(
+    post,
+    path = "/api/users/login/2fa",
+    tag = "Users",
+    request_body = TwoFactorLoginRequest,
+    params(
+        ("X-Auth-Mode" = Option<String>, Header, description = "`cookie`이면 토큰을 HttpOnly 쿠키로 설정하고 본문에는 CSRF 토큰만 반환 (`CookieAuthResponse`)")
+    ),
+    responses(
+        (status = 200, description = "2단계 인증 완료 및 토큰 발급", body = AuthResponse),
+        (status = 400, description = "잘못된 인증 코드", body = ErrorResponse),
+        (status = 401, description = "유효하지 않거나 만료된 챌린지 토큰", body = ErrorResponse),
+        (status = 429, description = "인증 코드 실패 횟수 초과", body = ErrorResponse),
+        (status = 500, description = "서버 에러", body = ErrorResponse)
+    )
+)]
+pub async fn complete_login(
+    State(state): State<AppState>,
+    client: ClientInfo,
+    mode: AuthMode,
+    Json(payload): Json<TwoFactorLoginRequest>,
+) -> impl IntoResponse {
+    match state
+        .user_service
+        .complete_two_factor_login(payload, client)
+        .await
+    {
+        Ok(response) => cookie_auth::auth_response(StatusCode::OK, mode, response),
+        Err(e) => e.into_response(),
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/users/me/2fa",
+    tag = "Users",
+    responses(
+        (status = 200, description = "2단계 인증 설정 조회 성공", body = TwoFactorStatusResponse),
+        (status = 401, description = "인증 실패", body = ErrorResponse),
+        (status = 403, description = "토큰에 `account` 스코프가 없음", body = ErrorResponse),
+        (status = 500, description = "서버 에러", body = ErrorResponse)
+    ),
+    security(("bearer_auth" = []))
+)]
+pub async fn get_status(
+    State(state): State<AppState>,
+    user: ScopedUser<scope::Account>,
+) -> impl IntoResponse {
+    match state.two_factor_service.status(user.id).await {
+        Ok(status) => (StatusCode::OK, Json(status)).into_response(),
+        Err(e) => e.into_response(),
+    }
+}
+
+#[utoipa::path(
+    patch,
+    path = "/api/users/me/2fa",
+    tag = "Users",
+    request_body = UpdateTwoFactorPolicyRequest,
+    responses(
+        (status = 200, description = "2단계 인증 정책 변경 성공", body = TwoFactorStatusResponse),
+        (status = 400, description = "2단계 인증이 활성화되지 않음", body = ErrorResponse),
+        (status = 401, description = "인증 실패", body = ErrorResponse),
+        (status = 403, description = "로그인 세션이 아닌 개인 액세스 토큰으로 요청함", body = ErrorResponse),
+        (status = 500, description = "서버 에러", body = ErrorResponse)
+    ),
+    security(("bearer_auth" = []))
+)]
+pub async fn update_policy(
+    State(state): State<AppState>,
+    user: SessionUser,
+    Json(payload): Json<UpdateTwoFactorPolicyRequest>,
+) -> impl IntoResponse {
+    match state
+        .two_factor_service
+        .update_policy(user.id, payload)
+        .await
+    {
+        Ok(status) => (StatusCode::OK, Json(status)).into_response(),
+        Err(e) => e.into_response(),
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/users/me/2fa/totp",
+    tag = "Users",
+    responses(
+        (status = 200, description = "TOTP 등록 시작 (활성화 전까지는 로그인에 적용되지 않음)", body = TotpEnrollmentResponse),
+        (status = 401, description = "인증 실패", body = ErrorResponse),
+        (status = 403, description = "로그인 세션이 아닌 개인 액세스 토큰으로 요청함", body = ErrorResponse),
+        (status = 409, description = "이미 2단계 인증이 활성화됨", body = ErrorResponse),
+        (status = 500, description = "서버 에러", body = ErrorResponse)
+    ),
+    security(("bearer_auth" = []))
+)]
+pub async fn start_enrollment(
+    State(state): State<AppState>,
+    user: SessionUser,
+) -> impl IntoResponse {
+    match state.two_factor_service.start_enrollment(user.id).await {
+        Ok(enrollment) => (StatusCode::OK, Json(enrollment)).into_response(),
+        Err(e) => e.into_response(),
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/users/me/2fa/totp/activate",
+    tag = "Users",
+    request_body = TwoFactorCodeRequest,
+    responses(
+        (status = 200, description = "TOTP 활성화 성공 및 복구 코드 발급", body = RecoveryCodesResponse),
+        (status = 400, description = "잘못된 인증 코드 또는 등록 전 요청", body = ErrorResponse),
+        (status = 401, description = "인증 실패", body = ErrorResponse),
+        (status = 403, description = "로그인 세션이 아닌 개인 액세스 토큰으로 요청함", body = ErrorResponse),
+        (status = 409, description = "이미 2단계 인증이 활성화됨", body = ErrorResponse),
+        (status = 500, description = "서버 에러", body = ErrorResponse)
+    ),
+    security(("bearer_auth" = []))
+)]
+pub async fn activate(
+    State(state): State<AppState>,
+    user: SessionUser,
+    Json(payload): Json<TwoFactorCodeRequest>,
+) -> impl IntoResponse {
+    match state
+        .two_factor_service
+        .activate(user.id, &payload.code)
+        .await
+    {
+        Ok(codes) => (StatusCode::OK, Json(codes)).into_response(),
+        Err(e) => e.into_response(),
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/users/me/2fa/totp/disable",
+    tag = "Users",
+    request_body = TwoFactorCodeRequest,
+    responses(
+        (status = 204, description = "2단계 인증 해제 성공"),
+        (status = 400, description = "잘못된 인증 코드 또는 2단계 인증 미사용", body = ErrorResponse),
+        (status = 401, description = "인증 실패", body = ErrorResponse),
+        (status = 403, description = "로그인 세션이 아닌 개인 액세스 토큰으로 요청함", body = ErrorResponse),
+        (status = 429, description = "인증 코드 실패 횟수 초과", body = ErrorResponse),
+        (status = 500, description = "서버 에러", body = ErrorResponse)
+    ),
+    security(("bearer_auth" = []))
+)]
+pub async fn disable(
+    State(state): State<AppState>,
+    user: SessionUser,
+    Json(payload): Json<TwoFactorCodeRequest>,
+) -> impl IntoResponse {
+    match state
+        .two_factor_service
+        .disable(user.id, &payload.code)
+        .await
+    {
+        Ok(()) => (StatusCode::NO_CONTENT).into_response(),
+        Err(e) => e.into_response(),
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/users/me/2fa/recovery-codes",
+    tag = "Users",
+    request_body = TwoFactorCodeRequest,
+    responses(
+        (status = 200, description = "복구 코드 재발급 성공 (기존 복구 코드는 모두 무효화됨)", body = RecoveryCodesResponse),
+        (status = 400, description = "잘못된 인증 코드 또는 2단계 인증 미사용", body = ErrorResponse),
+        (status = 401, description = "인증 실패", body = ErrorResponse),
+        (status = 403, description = "로그인 세션이 아닌 개인 액세스 토큰으로 요청함", body = ErrorResponse),
+        (status = 429, description = "인증 코드 실패 횟수 초과", body = ErrorResponse),
+        (status = 500, description = "서버 에러", body = ErrorResponse)
+    ),
+    security(("bearer_auth" = []))
+)]
+pub async fn regenerate_recovery_codes(
+    State(state): State<AppState>,
+    user: SessionUser,
+    Json(payload): Json<TwoFactorCodeRequest>,
+) -> impl IntoResponse {
+    match state
+        .two_factor_service
+        .regenerate_recovery_codes(user.id, &payload.code)
+        .await
+    {
+        Ok(codes) => (StatusCode::OK, Json(codes)).into_response(),
+        Err(e) => e.into_response(),
+    }
+}
@@ -1,26 +1,286 @@
-use axum::{extract::State, http::StatusCode, response::IntoResponse, Json};
+use axum::{
+    extract::{Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+    Json,
+};
 
-use super::AppState;
+use super::{
+    auth::{scope, ScopedUser, SessionUser},
+    cookie_auth::{self, AuthMode},
+    AppState,
+};
+use crate::entities::oauth_account::OAuthProvider;
 use crate::errors::ErrorResponse;
-use crate::models::user_dto::{AuthResponse, OAuthLoginRequest};
+use crate::models::session_dto::ClientInfo;
+use crate::models::user_dto::{
+    AuthResponse, ChangePasswordRequest, LinkOAuthAccountRequest, LinkedAccountResponse,
+    LoginRequest, LoginResponse, OAuthLoginRequest, RegisterRequest, UpdateUserRequest,
+    UserResponse,
+};
 
 #[utoipa::path(
     post,
     path = "/api/users/oauth-login",
     tag = "Users",
     request_body = OAuthLoginRequest,
+    params(
+        ("X-Auth-Mode" = Option<String>, Header, description = "`cookie`이면 토큰을 HttpOnly 쿠키로 설정하고 본문에는 CSRF 토큰만 반환 (`CookieLoginResponse`)")
+    ),
     responses(
-        (status = 200, description = "로그인 성공", body = AuthResponse),
+        (status = 200, description = "로그인 성공 또는 2단계 인증 필요 (`status` 필드로 구분)", body = LoginResponse),
         (status = 400, description = "잘못된 요청", body = ErrorResponse),
+        (status = 401, description = "OAuth 자격 증명 검증 실패", body = ErrorResponse),
+        (status = 409, description = "같은 이메일의 계정이 이미 존재함 (로그인 후 계정 연결 필요)", body = ErrorResponse),
+        (status = 502, description = "OAuth 제공자 통신 실패", body = ErrorResponse),
         (status = 500, description = "서버 에러", body = ErrorResponse)
     )
 )]
 pub async fn oauth_login(
     State(state): State<AppState>,
+    client: ClientInfo,
+    mode: AuthMode,
     Json(payload): Json<OAuthLoginRequest>,
 ) -> impl IntoResponse {
-    match state.user_service.oauth_login(payload).await {
+    match state.user_service.oauth_login(payload, client).await {
+        Ok(response) => cookie_auth::login_response(mode, response),
+        Err(e) => e.into_response(),
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/users/register",
+    tag = "Users",
+    request_body = RegisterRequest,
+    params(
+        ("X-Auth-Mode" = Option<String>, Header, description = "`cookie`이면 토큰을 HttpOnly 쿠키로 설정하고 본문에는 CSRF 토큰만 반환 (`CookieAuthResponse`)")
+    ),
+    responses(
+        (status = 201, description = "회원가입 성공", body = AuthResponse),
+        (status = 400, description = "잘못된 요청 또는 비밀번호 정책 위반", body = ErrorResponse),
+        (status = 409, description = "이미 사용 중인 이메일 또는 사용자 이름", body = ErrorResponse),
+        (status = 500, description = "서버 에러", body = ErrorResponse)
+    )
+)]
+pub async fn register(
+    State(state): State<AppState>,
+    client: ClientInfo,
+    mode: AuthMode,
+    Json(payload): Json<RegisterRequest>,
+) -> impl IntoResponse {
+    match state.user_service.register(payload, client).await {
+        Ok(response) => cookie_auth::auth_response(StatusCode::CREATED, mode, response),
+        Err(e) => e.into_response(),
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/users/login",
+    tag = "Users",
+    request_body = LoginRequest,
+    params(
+        ("X-Auth-Mode" = Option<String>, Header, description = "`cookie`이면 토큰을 HttpOnly 쿠키로 설정하고 본문에는 CSRF 토큰만 반환 (`CookieLoginResponse`)")
+    ),
+    responses(
+        (status = 200, description = "로그인 성공 또는 2단계 인증 필요 (`status` 필드로 구분)", body = LoginResponse),
+        (status = 401, description = "이메일 또는 비밀번호 불일치", body = ErrorResponse),
+        (status = 500, description = "서버 에러", body = ErrorResponse)
+    )
+)]
+pub async fn login(
+    State(state): State<AppState>,
+    client: ClientInfo,
+    mode: AuthMode,
+    Json(payload): Json<LoginRequest>,
+) -> impl IntoResponse {
+    match state.user_service.login(payload, client).await {
+        Ok(response) => cookie_auth::login_response(mode, response),
+        Err(e) => e.into_response(),
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/users/me",
+    tag = "Users",
+    responses(
+        (status = 200, description = "내 정보 조회 성공", body = UserResponse),
+        (status = 401, description = "인증 실패", body = ErrorResponse),
+        (status = 403, description = "토큰에 `account` 스코프가 없음", body = ErrorResponse),
+        (status = 404, description = "사용자를 찾을 수 없음", body = ErrorResponse),
+        (status = 500, description = "서버 에러", body = ErrorResponse)
+    ),
+    security(("bearer_auth" = []))
+)]
+pub async fn get_me(
+    State(state): State<AppState>,
+    user: ScopedUser<scope::Account>,
+) -> impl IntoResponse {
+    match state.user_service.get_profile(user.id).await {
+        Ok(user) => (StatusCode::OK, Json(user)).into_response(),
+        Err(e) => e.into_response(),
+    }
+}
+
+#[utoipa::path(
+    patch,
+    path = "/api/users/me",
+    tag = "Users",
+    request_body = UpdateUserRequest,
+    responses(
+        (status = 200, description = "내 정보 수정 성공", body = UserResponse),
+        (status = 400, description = "잘못된 요청", body = ErrorResponse),
+        (status = 401, description = "인증 실패", body = ErrorResponse),
+        (status = 403, description = "토큰에 `account` 스코프가 없음", body = ErrorResponse),
+        (status = 409, description = "이미 사용 중인 이메일 또는 사용자 이름", body = ErrorResponse),
+        (status = 500, description = "서버 에러", body = ErrorResponse)
+    ),
+    security(("bearer_auth" = []))
+)]
+pub async fn update_me(
+    State(state): State<AppState>,
+    user: ScopedUser<scope::Account>,
+    Json(payload): Json<UpdateUserRequest>,
+) -> impl IntoResponse {
+    match state.user_service.update_profile(user.id, payload).await {
         Ok(user) => (StatusCode::OK, Json(user)).into_response(),
         Err(e) => e.into_response(),
     }
 }
+
+#[utoipa::path(
+    delete,
+    path = "/api/users/me",
+    tag = "Users",
+    responses(
+        (status = 204, description = "회원 탈퇴 성공 (메모와 연결된 OAuth 계정도 함께 삭제)"),
+        (status = 401, description = "인증 실패", body = ErrorResponse),
+        (status = 403, description = "로그인 세션이 아닌 개인 액세스 토큰으로 요청함", body = ErrorResponse),
+        (status = 404, description = "사용자를 찾을 수 없음", body = ErrorResponse),
+        (status = 500, description = "서버 에러", body = ErrorResponse)
+    ),
+    security(("bearer_auth" = []))
+)]
+pub async fn delete_me(State(state): State<AppState>, user: SessionUser) -> impl IntoResponse {
+    match state.user_service.delete_account(user.id).await {
+        Ok(()) => (StatusCode::NO_CONTENT).into_response(),
+        Err(e) => e.into_response(),
+    }
+}
+
+#[utoipa::path(
+    put,
+    path = "/api/users/me/password",
+    tag = "Users",
+    request_body = ChangePasswordRequest,
+    responses(
+        (status = 204, description = "비밀번호 변경 성공"),
+        (status = 400, description = "현재 비밀번호 불일치 또는 비밀번호 정책 위반", body = ErrorResponse),
+        (status = 401, description = "인증 실패", body = ErrorResponse),
+        (status = 403, description = "로그인 세션이 아닌 개인 액세스 토큰으로 요청함", body = ErrorResponse),
+        (status = 500, description = "서버 에러", body = ErrorResponse)
+    ),
+    security(("bearer_auth" = []))
+)]
+pub async fn change_password(
+    State(state): State<AppState>,
+    user: SessionUser,
+    client: ClientInfo,
+    Json(payload): Json<ChangePasswordRequest>,
+) -> impl IntoResponse {
+    match state
+        .user_service
+        .change_password(user.id, payload, client)
+        .await
+    {
+        Ok(()) => (StatusCode::NO_CONTENT).into_response(),
+        Err(e) => e.into_response(),
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/users/me/oauth-accounts",
+    tag = "Users",
+    responses(
+        (status = 200, description = "연결된 OAuth 계정 목록", body = Vec<LinkedAccountResponse>),
+        (status = 401, description = "인증 실패", body = ErrorResponse),
+        (status = 403, description = "토큰에 `account` 스코프가 없음", body = ErrorResponse),
+        (status = 500, description = "서버 에러", body = ErrorResponse)
+    ),
+    security(("bearer_auth" = []))
+)]
+pub async fn list_oauth_accounts(
+    State(state): State<AppState>,
+    user: ScopedUser<scope::Account>,
+) -> impl IntoResponse {
+    match state.user_service.list_oauth_accounts(user.id).await {
+        Ok(accounts) => (StatusCode::OK, Json(accounts)).into_response(),
+        Err(e) => e.into_response(),
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/users/me/oauth-accounts",
+    tag = "Users",
+    request_body = LinkOAuthAccountRequest,
+    responses(
+        (status = 201, description = "OAuth 계정 연결 성공", body = LinkedAccountResponse),
+        (status = 401, description = "인증 실패 또는 OAuth 자격 증명 검증 실패", body = ErrorResponse),
+        (status = 403, description = "로그인 세션이 아닌 개인 액세스 토큰으로 요청함", body = ErrorResponse),
+        (status = 409, description = "다른 사용자에 연결된 계정이거나 같은 제공자가 이미 연결됨", body = ErrorResponse),
+        (status = 502, description = "OAuth 제공자 통신 실패", body = ErrorResponse),
+        (status = 500, description = "서버 에러", body = ErrorResponse)
+    ),
+    security(("bearer_auth" = []))
+)]
+pub async fn link_oauth_account(
+    State(state): State<AppState>,
+    user: SessionUser,
+    client: ClientInfo,
+    Json(payload): Json<LinkOAuthAccountRequest>,
+) -> impl IntoResponse {
+    match state
+        .user_service
+        .link_oauth_account(user.id, payload, client)
+        .await
+    {
+        Ok(account) => (StatusCode::CREATED, Json(account)).into_response(),
+        Err(e) => e.into_response(),
+    }
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/users/me/oauth-accounts/{provider}",
+    tag = "Users",
+    params(
+        ("provider" = OAuthProvider, Path, description = "연결을 해제할 OAuth 제공자")
+    ),
+    responses(
+        (status = 204, description = "OAuth 계정 연결 해제 성공"),
+        (status = 401, description = "인증 실패", body = ErrorResponse),
+        (status = 403, description = "로그인 세션이 아닌 개인 액세스 토큰으로 요청함", body = ErrorResponse),
+        (status = 404, description = "연결된 계정을 찾을 수 없음", body = ErrorResponse),
+        (status = 409, description = "마지막 로그인 수단은 해제할 수 없음", body = ErrorResponse),
+        (status = 500, description = "서버 에러", body = ErrorResponse)
+    ),
+    security(("bearer_auth" = []))
+)]
+pub async fn unlink_oauth_account(
+    State(state): State<AppState>,
+    user: SessionUser,
+    Path(provider): Path<OAuthProvider>,
+) -> impl IntoResponse {
+    match state
+        .user_service
+        .unlink_oauth_account(user.id, provider)
+        .await
+    {
+        Ok(()) => (StatusCode::NO_CONTENT).into_response(),
+        Err(e) => e.into_response(),
+    }
+}
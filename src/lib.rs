@@ -10,8 +10,8 @@ pub mod services;
 pub mod test_utils;
 pub mod utils;
 
-use anyhow::Result;
-use std::{env::var, sync::Arc};
+use anyhow::{bail, Result};
+use std::{env::var, net::SocketAddr, sync::Arc};
 use tracing::info;
 
 pub async fn run() -> Result<()> {
@@ -36,12 +36,24 @@ pub async fn run() -> Result<()> {
     );
 
     let gemini_client = Arc::new(clients::GeminiClient::new(gemini_api_key));
+    let oauth_client = Arc::new(clients::OAuthClient::new(clients::OAuthConfig::from_env()));
+    let jwt_keys = Arc::new(utils::jwt::JwtKeys::from_env()?);
+
+    // 휴지통 정리는 요청 처리와 무관하게 돌아가므로 라우터와 별도의 서비스 인스턴스를 쓴다.
+    services::memo_service::spawn_trash_purge(Arc::new(services::MemoService::new(
+        db.clone(),
+        qdrant_repo.clone(),
+        gemini_client.clone() as Arc<dyn clients::Embedder>,
+        gemini_client.clone() as Arc<dyn clients::TextGenerator>,
+    )));
 
     let app = handlers::create_router(
         db,
         qdrant_repo,
         gemini_client.clone() as Arc<dyn clients::Embedder>,
         gemini_client as Arc<dyn clients::TextGenerator>,
+        oauth_client as Arc<dyn clients::OAuthVerifier>,
+        jwt_keys,
     );
 
     let listener = tokio::net::TcpListener::bind(&addr)
@@ -52,7 +64,67 @@ pub async fn run() -> Result<()> {
         info!("Server is running on http://localhost:{}/swagger-ui", port);
     }
 
-    axum::serve(listener, app).await?;
+    axum::serve(
+        listener,
+        app.into_make_service_with_connect_info::<SocketAddr>(),
+    )
+    .await?;
+
+    Ok(())
+}
+
+// 관리자 API는 관리자만 다른 사용자의 역할을 바꿀 수 있으므로, 첫 관리자는 이 명령으로 지정한다.
+pub async fn grant_admin(email: &str) -> Result<()> {
+    dotenv::dotenv().ok();
+    tracing_subscriber::fmt::init();
+
+    let database_url = var("DATABASE_URL").expect("DATABASE_URL must be set in .env file");
+    let db = Arc::new(db::create_connection(&database_url).await?);
+    let user_repo = repositories::UserRepository::new(db);
+
+    let Some(user) = user_repo
+        .find_by_email(&email.trim().to_lowercase())
+        .await?
+    else {
+        bail!("No user with email {}", email);
+    };
+    user_repo
+        .update_role(user.id, entities::user::UserRole::Admin)
+        .await?;
+    info!("Granted admin role to user {} ({})", user.id, user.email);
+
+    Ok(())
+}
+
+// 배포 후 한 번 실행하는 작업: 필터용 키가 없는 예전 Qdrant 포인트의 페이로드를 채운다.
+// 여러 번 실행해도 결과가 같다.
+pub async fn backfill_payloads() -> Result<()> {
+    dotenv::dotenv().ok();
+    tracing_subscriber::fmt::init();
+
+    let database_url = var("DATABASE_URL").expect("DATABASE_URL must be set in .env file");
+    let qdrant_url = var("QDRANT_URL").expect("QDRANT_URL must be set in .env file");
+    let gemini_api_key = var("GEMINI_API_KEY").expect("GEMINI_API_KEY must be set in .env file");
+
+    let db = Arc::new(db::create_connection(&database_url).await?);
+    let qdrant_repo = Arc::new(
+        repositories::QdrantRepository::new(qdrant_url)
+            .await
+            .expect("Failed to initialize Qdrant repository"),
+    );
+    let gemini_client = Arc::new(clients::GeminiClient::new(gemini_api_key));
+    let memo_service = services::MemoService::new(
+        db,
+        qdrant_repo,
+        gemini_client.clone() as Arc<dyn clients::Embedder>,
+        gemini_client as Arc<dyn clients::TextGenerator>,
+    );
+
+    let (updated, failed) = memo_service.backfill_payloads().await?;
+    info!(
+        "Backfilled payloads of {} memos ({} failed)",
+        updated, failed
+    );
 
     Ok(())
 }
@@ -1,7 +1,16 @@
-use anyhow::Result;
+use anyhow::{bail, Result};
 
 #[tokio::main]
 async fn main() -> Result<()> {
-    // lib.rs에 있는 run 함수를 호출하여 서버를 실행합니다.
-    inklings_server::run().await
+    // 인자가 없으면 lib.rs에 있는 run 함수를 호출하여 서버를 실행합니다.
+    let args: Vec<String> = std::env::args().collect();
+    match args.get(1).map(String::as_str) {
+        None => inklings_server::run().await,
+        Some("backfill-payloads") => inklings_server::backfill_payloads().await,
+        Some("grant-admin") => match args.get(2) {
+            Some(email) => inklings_server::grant_admin(email).await,
+            None => bail!("Usage: inklings-server grant-admin <email>"),
+        },
+        Some(command) => bail!("Unknown command: {}", command),
+    }
 }
@@ -0,0 +1,59 @@
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+
+use crate::entities::user::{self, UserRole};
+
+#[derive(Debug, Deserialize, Clone, Default, IntoParams)]
+pub struct AdminUserListQuery {
+    /// 사용자 이름 또는 이메일 검색어
+    pub q: Option<String>,
+    /// 기본 50, 최대 100
+    pub limit: Option<u64>,
+    pub offset: Option<u64>,
+}
+
+#[derive(Debug, Serialize, Clone, PartialEq, ToSchema)]
+pub struct AdminUserResponse {
+    #[schema(example = 1)]
+    pub id: i32,
+    #[schema(example = "홍길동")]
+    pub username: String,
+    #[schema(example = "user@example.com")]
+    pub email: String,
+    pub role: UserRole,
+    #[schema(example = 42)]
+    pub memo_count: i64,
+    /// 정지되지 않은 계정은 null
+    #[schema(example = "2024-02-01T09:00:00")]
+    pub suspended_at: Option<NaiveDateTime>,
+    #[schema(example = "2024-01-15T10:30:00")]
+    pub created_at: NaiveDateTime,
+}
+
+impl AdminUserResponse {
+    pub fn from_model(user: user::Model, memo_count: i64) -> Self {
+        Self {
+            id: user.id,
+            username: user.username,
+            email: user.email,
+            role: user.role,
+            memo_count,
+            suspended_at: user.suspended_at,
+            created_at: user.created_at,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct AdminUserListResponse {
+    pub users: Vec<AdminUserResponse>,
+    /// 검색 조건에 맞는 전체 사용자 수
+    #[schema(example = 128)]
+    pub total: u64,
+}
+
+#[derive(Debug, Deserialize, Clone, ToSchema)]
+pub struct UpdateUserRoleRequest {
+    pub role: UserRole,
+}
@@ -10,6 +10,16 @@ pub struct AssistRequest {
     #[serde(default = "default_limit")]
     #[schema(example = 5)]
     pub limit: u64,
+
+    /// 지정하면 이 태그가 모두 붙은 메모에서만 참고할 메모를 찾습니다
+    #[serde(default)]
+    #[schema(example = json!(["rust"]))]
+    pub tags: Vec<String>,
+
+    /// `true`면 보관된 메모도 참고합니다
+    #[serde(default)]
+    #[schema(example = false)]
+    pub include_archived: bool,
 }
 
 fn default_limit() -> u64 {
@@ -18,7 +28,9 @@ fn default_limit() -> u64 {
 
 #[derive(Debug, Serialize, ToSchema)]
 pub struct AssistResponse {
-    #[schema(example = "Rust 비동기 프로그래밍은 tokio 런타임을 사용하여 async/await 키워드로 구현됩니다...")]
+    #[schema(
+        example = "Rust 비동기 프로그래밍은 tokio 런타임을 사용하여 async/await 키워드로 구현됩니다..."
+    )]
     pub suggestion: String,
     pub similar_memos: Vec<SimilarMemo>,
 }
@@ -0,0 +1,78 @@
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+
+use crate::entities::auth_event::{self, AuthEventType};
+
+#[derive(Debug, Deserialize, Clone, Default, IntoParams)]
+pub struct SecurityEventListQuery {
+    pub event_type: Option<AuthEventType>,
+    /// 기본 50, 최대 100
+    pub limit: Option<u64>,
+    pub offset: Option<u64>,
+}
+
+#[derive(Debug, Deserialize, Clone, Default, IntoParams)]
+pub struct AdminSecurityEventListQuery {
+    pub user_id: Option<i32>,
+    /// 탈퇴한 계정이나 존재하지 않는 계정으로 시도한 기록도 이메일로 찾을 수 있다
+    #[param(example = "user@example.com")]
+    pub email: Option<String>,
+    pub event_type: Option<AuthEventType>,
+    #[param(example = "203.0.113.7")]
+    pub ip_address: Option<String>,
+    /// 이 시각 이후(포함)의 기록만 조회
+    #[param(example = "2024-01-15T00:00:00")]
+    pub since: Option<NaiveDateTime>,
+    /// 이 시각 이전의 기록만 조회
+    #[param(example = "2024-01-16T00:00:00")]
+    pub until: Option<NaiveDateTime>,
+    /// 기본 50, 최대 100
+    pub limit: Option<u64>,
+    pub offset: Option<u64>,
+}
+
+#[derive(Debug, Serialize, Clone, PartialEq, ToSchema)]
+pub struct AuthEventResponse {
+    #[schema(example = 1)]
+    pub id: i64,
+    /// 존재하지 않는 이메일로 로그인을 시도한 경우 null. 탈퇴한 계정의 기록에도 남아 있음
+    #[schema(example = 1)]
+    pub user_id: Option<i32>,
+    /// 기록 당시 계정의 이메일. 존재하지 않는 계정으로 로그인을 시도한 경우 시도한 이메일
+    #[schema(example = "user@example.com")]
+    pub email: Option<String>,
+    pub event_type: AuthEventType,
+    /// 로그인 방식, 실패 사유, OAuth 제공자 등 부가 정보
+    #[schema(example = "password")]
+    pub detail: Option<String>,
+    #[schema(example = "203.0.113.7")]
+    pub ip_address: Option<String>,
+    #[schema(example = "Mozilla/5.0 (iPhone; CPU iPhone OS 17_0 like Mac OS X)")]
+    pub user_agent: Option<String>,
+    #[schema(example = "2024-01-15T10:30:00")]
+    pub created_at: NaiveDateTime,
+}
+
+impl From<auth_event::Model> for AuthEventResponse {
+    fn from(event: auth_event::Model) -> Self {
+        Self {
+            id: event.id,
+            user_id: event.user_id,
+            email: event.email,
+            event_type: event.event_type,
+            detail: event.detail,
+            ip_address: event.ip_address,
+            user_agent: event.user_agent,
+            created_at: event.created_at,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct AuthEventListResponse {
+    pub events: Vec<AuthEventResponse>,
+    /// 조건에 맞는 전체 기록 수
+    #[schema(example = 12)]
+    pub total: u64,
+}
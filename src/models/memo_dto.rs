@@ -1,19 +1,155 @@
 use chrono::NaiveDateTime;
 use serde::{Deserialize, Serialize};
-use utoipa::ToSchema;
+use utoipa::{IntoParams, ToSchema};
 
 use crate::entities::memo;
 
-#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, ToSchema)]
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, ToSchema)]
 pub struct CreateMemoRequest {
     #[schema(example = "오늘 배운 Rust 비동기 프로그래밍을 정리해야겠다")]
     pub content: String,
+    /// 없는 태그는 새로 만들어집니다. 비워 두면 자동 태그 설정(`auto_tag_memos`)이 켜진 경우 AI가 제안한 태그가 붙습니다
+    #[serde(default)]
+    #[schema(example = json!(["rust", "공부"]))]
+    pub tags: Vec<String>,
+    /// 생략하면 어느 노트북에도 넣지 않습니다
+    #[serde(default)]
+    #[schema(example = 7)]
+    pub notebook_id: Option<i32>,
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, ToSchema)]
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, ToSchema)]
 pub struct UpdateMemoRequest {
     #[schema(example = "Rust 비동기 프로그래밍 정리 완료. tokio와 async/await 개념 이해함")]
     pub content: String,
+    /// 생략하면 태그를 바꾸지 않고, 빈 배열이면 모든 태그를 뗍니다
+    #[schema(example = json!(["rust"]))]
+    pub tags: Option<Vec<String>>,
+}
+
+#[derive(Debug, Deserialize, Clone, Default, IntoParams)]
+pub struct MemoListQuery {
+    /// 기본 50, 최대 100
+    pub limit: Option<u64>,
+    /// 이전 응답의 `next_cursor`
+    pub cursor: Option<String>,
+    /// `true`면 고정된 메모만, `false`면 고정되지 않은 메모만 조회
+    pub pinned: Option<bool>,
+    /// 이 시각 이후(포함)에 작성된 메모만 조회
+    #[param(example = "2024-01-01T00:00:00")]
+    pub created_since: Option<NaiveDateTime>,
+    /// 이 시각 이전에 작성된 메모만 조회
+    #[param(example = "2024-02-01T00:00:00")]
+    pub created_until: Option<NaiveDateTime>,
+    /// 이 시각 이후(포함)에 수정된 메모만 조회
+    #[param(example = "2024-01-01T00:00:00")]
+    pub updated_since: Option<NaiveDateTime>,
+    /// 이 시각 이전에 수정된 메모만 조회
+    #[param(example = "2024-02-01T00:00:00")]
+    pub updated_until: Option<NaiveDateTime>,
+    /// 쉼표로 구분한 태그. 모든 태그가 붙은 메모만 조회
+    #[param(example = "rust,공부")]
+    pub tags: Option<String>,
+    /// 이 노트북에 든 메모만 조회
+    #[param(example = 7)]
+    pub notebook_id: Option<i32>,
+    /// `true`면 `notebook_id`의 하위 노트북에 든 메모까지 조회
+    #[serde(default)]
+    pub recursive: bool,
+    /// `true`면 보관된 메모만 조회. 생략하면 보관되지 않은 메모만 조회
+    pub archived: Option<bool>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, ToSchema)]
+pub struct TrashedMemoResponse {
+    #[serde(flatten)]
+    pub memo: MemoResponse,
+    /// 휴지통으로 옮긴 시각
+    #[schema(example = "2024-01-15T10:30:00")]
+    pub deleted_at: NaiveDateTime,
+    /// 이 시각이 지나면 영구 삭제됩니다
+    #[schema(example = "2024-02-14T10:30:00")]
+    pub purge_at: NaiveDateTime,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, ToSchema)]
+pub struct MoveMemosRequest {
+    #[schema(example = json!([42, 43]))]
+    pub memo_ids: Vec<i32>,
+    /// 옮길 노트북 ID. null이면 노트북 밖으로 꺼냅니다
+    #[schema(example = 7)]
+    pub notebook_id: Option<i32>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, ToSchema)]
+pub struct MemoListResponse {
+    pub memos: Vec<MemoResponse>,
+    /// 다음 페이지가 없으면 null
+    #[schema(example = "MToxNzA1MzE0NjAwMDAwMDAwOjQy")]
+    pub next_cursor: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Clone, Default, IntoParams)]
+pub struct MemoSearchQuery {
+    /// 검색어. 공백으로 나눈 단어가 모두 포함된 메모를 찾으며, 단어의 일부만 입력해도 됩니다.
+    /// `"구문"`, `-제외어`, `tag:이름`, `-tag:이름`, `pinned:true`, `after:2024-01-01`, `before:2024-06-01`,
+    /// `updated_after:`, `updated_before:` 연산자를 쓸 수 있습니다. 날짜 대신 `30d`, `2w`, `12h`를 쓰면
+    /// 검색 시각으로부터 그만큼 이전을 뜻합니다
+    #[param(example = "비동기 pinned:true before:2024-06-01 -초안")]
+    pub q: String,
+    /// 기본 20, 최대 100
+    pub limit: Option<u64>,
+    pub offset: Option<u64>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, ToSchema)]
+pub struct MemoSearchResult {
+    pub memo: MemoResponse,
+    /// 검색어와의 관련도 점수 (클수록 관련도가 높음)
+    #[schema(example = 0.2)]
+    pub rank: f32,
+    /// 일치한 부분을 `<mark>`로 감싼 본문 일부 (HTML 이스케이프됨)
+    #[schema(example = "오늘 배운 Rust <mark>비동기</mark> 프로그래밍을 정리해야겠다")]
+    pub snippet: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, ToSchema)]
+pub struct MemoSearchResponse {
+    pub results: Vec<MemoSearchResult>,
+    #[schema(example = 3)]
+    pub total: u64,
+}
+
+#[derive(Debug, Deserialize, Clone, Default, IntoParams)]
+pub struct HybridSearchQuery {
+    /// 자연어 질의. 키워드 검색과 의미 기반 검색에 모두 사용됩니다.
+    /// 키워드 검색과 같은 연산자를 쓸 수 있으며, 필드 조건은 두 검색 모두에 적용됩니다
+    #[param(example = "비동기 런타임 정리한 메모 after:2024-01-01")]
+    pub q: String,
+    /// 기본 20, 최대 100
+    pub limit: Option<u64>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, ToSchema)]
+pub struct HybridSearchResult {
+    pub memo: MemoResponse,
+    /// 두 검색 결과의 순위를 Reciprocal Rank Fusion으로 합친 점수
+    #[schema(example = 0.0325)]
+    pub score: f32,
+    /// 키워드 검색 관련도 점수. 키워드 검색에서 찾지 못했으면 null
+    #[schema(example = 0.2)]
+    pub keyword_score: Option<f32>,
+    /// 의미 기반 검색 유사도(코사인). 의미 기반 검색에서 찾지 못했으면 null
+    #[schema(example = 0.83)]
+    pub semantic_score: Option<f32>,
+    /// 키워드와 일치한 부분을 `<mark>`로 감싼 본문 일부 (HTML 이스케이프됨)
+    #[schema(example = "오늘 배운 Rust <mark>비동기</mark> 프로그래밍을 정리해야겠다")]
+    pub snippet: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, ToSchema)]
+pub struct HybridSearchResponse {
+    pub results: Vec<HybridSearchResult>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq, ToSchema)]
@@ -26,19 +162,30 @@ pub struct MemoResponse {
     pub content: String,
     #[schema(example = false)]
     pub is_pinned: bool,
+    #[schema(example = false)]
+    pub is_archived: bool,
+    /// 노트북에 들어 있지 않으면 null
+    #[schema(example = 7)]
+    pub notebook_id: Option<i32>,
+    /// 이름순
+    #[schema(example = json!(["rust", "공부"]))]
+    pub tags: Vec<String>,
     #[schema(example = "2024-01-15T10:30:00")]
     pub created_at: NaiveDateTime,
     #[schema(example = "2024-01-15T10:30:00")]
     pub updated_at: NaiveDateTime,
 }
 
-impl From<memo::Model> for MemoResponse {
-    fn from(memo: memo::Model) -> Self {
+impl From<(memo::Model, Vec<String>)> for MemoResponse {
+    fn from((memo, tags): (memo::Model, Vec<String>)) -> Self {
         Self {
             id: memo.id,
             user_id: memo.user_id,
             content: memo.content,
             is_pinned: memo.is_pinned,
+            is_archived: memo.is_archived,
+            notebook_id: memo.notebook_id,
+            tags,
             created_at: memo.created_at,
             updated_at: memo.updated_at,
         }
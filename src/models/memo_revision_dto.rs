@@ -0,0 +1,101 @@
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+
+use crate::entities::memo_revision;
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, ToSchema)]
+pub struct MemoRevisionSummary {
+    #[schema(example = 15)]
+    pub id: i32,
+    /// 이 내용이 작성된 시각
+    #[schema(example = "2024-01-15T10:30:00")]
+    pub created_at: NaiveDateTime,
+    /// 내용의 글자 수
+    #[schema(example = 120)]
+    pub char_count: usize,
+}
+
+impl From<memo_revision::Model> for MemoRevisionSummary {
+    fn from(revision: memo_revision::Model) -> Self {
+        Self {
+            id: revision.id,
+            created_at: revision.created_at,
+            char_count: revision.content.chars().count(),
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, ToSchema)]
+pub struct MemoRevisionResponse {
+    #[schema(example = 15)]
+    pub id: i32,
+    #[schema(example = 42)]
+    pub memo_id: i32,
+    #[schema(example = "수정하기 전의 메모 내용")]
+    pub content: String,
+    /// 이 내용이 작성된 시각
+    #[schema(example = "2024-01-15T10:30:00")]
+    pub created_at: NaiveDateTime,
+}
+
+impl From<memo_revision::Model> for MemoRevisionResponse {
+    fn from(revision: memo_revision::Model) -> Self {
+        Self {
+            id: revision.id,
+            memo_id: revision.memo_id,
+            content: revision.content,
+            created_at: revision.created_at,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum DiffGranularity {
+    /// 줄 단위 비교
+    #[default]
+    Line,
+    /// 글자 단위 비교
+    Char,
+}
+
+#[derive(Debug, Deserialize, Clone, IntoParams)]
+pub struct MemoRevisionDiffQuery {
+    /// 비교 기준이 되는 이전 버전 ID
+    #[param(example = 15)]
+    pub from: i32,
+    /// 비교할 버전 ID. 생략하면 현재 메모 내용과 비교
+    #[param(example = 18)]
+    pub to: Option<i32>,
+    /// 기본 `line`
+    #[serde(default)]
+    pub granularity: DiffGranularity,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum DiffOp {
+    Equal,
+    Insert,
+    Delete,
+}
+
+/// 같은 종류의 변경이 이어지는 구간. 순서대로 이으면 `delete`를 뺀 것이 새 내용, `insert`를 뺀 것이 이전 내용이 됩니다
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, ToSchema)]
+pub struct DiffChunk {
+    pub op: DiffOp,
+    #[schema(example = "바뀐 줄\n")]
+    pub text: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, ToSchema)]
+pub struct MemoRevisionDiffResponse {
+    #[schema(example = 15)]
+    pub from: i32,
+    /// 현재 메모 내용과 비교했으면 null
+    #[schema(example = 18)]
+    pub to: Option<i32>,
+    pub granularity: DiffGranularity,
+    pub chunks: Vec<DiffChunk>,
+}
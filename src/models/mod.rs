@@ -1,7 +1,54 @@
+pub mod admin_dto;
 pub mod assist_dto;
+pub mod auth_event_dto;
 pub mod memo_dto;
+pub mod memo_revision_dto;
+pub mod notebook_dto;
+pub mod saved_search_dto;
+pub mod session_dto;
+pub mod tag_dto;
+pub mod token_dto;
+pub mod two_factor_dto;
 pub mod user_dto;
 
+pub use admin_dto::{
+    AdminUserListQuery, AdminUserListResponse, AdminUserResponse, UpdateUserRoleRequest,
+};
 pub use assist_dto::{AssistRequest, AssistResponse, SimilarMemo};
-pub use memo_dto::{CreateMemoRequest, MemoResponse, UpdateMemoRequest};
-pub use user_dto::{AuthResponse, OAuthLoginRequest, UserResponse};
+pub use auth_event_dto::{
+    AdminSecurityEventListQuery, AuthEventListResponse, AuthEventResponse, SecurityEventListQuery,
+};
+pub use memo_dto::{
+    CreateMemoRequest, HybridSearchQuery, HybridSearchResponse, HybridSearchResult, MemoListQuery,
+    MemoListResponse, MemoResponse, MemoSearchQuery, MemoSearchResponse, MemoSearchResult,
+    MoveMemosRequest, TrashedMemoResponse, UpdateMemoRequest,
+};
+pub use memo_revision_dto::{
+    DiffChunk, DiffGranularity, DiffOp, MemoRevisionDiffQuery, MemoRevisionDiffResponse,
+    MemoRevisionResponse, MemoRevisionSummary,
+};
+pub use notebook_dto::{
+    CreateNotebookRequest, DeleteNotebookMode, DeleteNotebookQuery, MoveNotebookRequest,
+    NotebookResponse, RenameNotebookRequest,
+};
+pub use saved_search_dto::{
+    CreateSavedSearchRequest, ReorderSavedSearchesRequest, SavedSearchMemosQuery,
+    SavedSearchResponse, UpdateSavedSearchRequest,
+};
+pub use session_dto::{ClientInfo, SessionResponse};
+pub use tag_dto::{
+    CreateTagRequest, MergeTagRequest, RenameTagRequest, TagResponse, TagSuggestionResponse,
+};
+pub use token_dto::{
+    CreatePersonalAccessTokenRequest, CreatedPersonalAccessTokenResponse,
+    PersonalAccessTokenResponse, Scope,
+};
+pub use two_factor_dto::{
+    RecoveryCodesResponse, TotpEnrollmentResponse, TwoFactorChallenge, TwoFactorCodeRequest,
+    TwoFactorLoginRequest, TwoFactorStatusResponse, UpdateTwoFactorPolicyRequest,
+};
+pub use user_dto::{
+    AuthResponse, ChangePasswordRequest, CookieAuthResponse, CookieLoginResponse,
+    LinkOAuthAccountRequest, LinkedAccountResponse, LoginRequest, LoginResponse, OAuthLoginRequest,
+    RefreshTokenRequest, RegisterRequest, UpdateUserRequest, UserResponse,
+};
@@ -0,0 +1,78 @@
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+
+use crate::entities::notebook;
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, ToSchema)]
+pub struct CreateNotebookRequest {
+    #[schema(example = "독서 노트")]
+    pub name: String,
+    /// 생략하면 최상위에 만듭니다
+    #[serde(default)]
+    #[schema(example = 3)]
+    pub parent_id: Option<i32>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, ToSchema)]
+pub struct RenameNotebookRequest {
+    #[schema(example = "2024 독서 노트")]
+    pub name: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, ToSchema)]
+pub struct MoveNotebookRequest {
+    /// 새 부모 노트북 ID. null이면 최상위로 옮깁니다. 자기 자신이나 하위 노트북 아래로는 옮길 수 없습니다
+    #[schema(example = 5)]
+    pub parent_id: Option<i32>,
+}
+
+/// 노트북을 삭제할 때 안에 든 메모와 하위 노트북을 처리하는 방법.
+/// 이미 휴지통에 있던 메모도 같은 곳으로 옮겨지므로, 복원하면 `move`는 부모 노트북에, `cascade`는 노트북 밖에 놓입니다
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum DeleteNotebookMode {
+    /// 바로 아래의 메모와 하위 노트북을 부모 노트북(최상위면 노트북 밖)으로 옮깁니다
+    #[default]
+    Move,
+    /// 하위 노트북과 그 안의 메모까지 모두 삭제합니다
+    Cascade,
+}
+
+#[derive(Debug, Deserialize, Clone, Default, IntoParams)]
+pub struct DeleteNotebookQuery {
+    /// 기본 `move`
+    #[serde(default)]
+    pub mode: DeleteNotebookMode,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, ToSchema)]
+pub struct NotebookResponse {
+    #[schema(example = 7)]
+    pub id: i32,
+    /// 최상위 노트북이면 null
+    #[schema(example = 3)]
+    pub parent_id: Option<i32>,
+    #[schema(example = "독서 노트")]
+    pub name: String,
+    /// 이 노트북 바로 아래에 있는 메모 수 (하위 노트북 제외)
+    #[schema(example = 12)]
+    pub memo_count: i64,
+    #[schema(example = "2024-01-15T10:30:00")]
+    pub created_at: NaiveDateTime,
+    #[schema(example = "2024-01-15T10:30:00")]
+    pub updated_at: NaiveDateTime,
+}
+
+impl From<(notebook::Model, i64)> for NotebookResponse {
+    fn from((notebook, memo_count): (notebook::Model, i64)) -> Self {
+        Self {
+            id: notebook.id,
+            parent_id: notebook.parent_id,
+            name: notebook.name,
+            memo_count,
+            created_at: notebook.created_at,
+            updated_at: notebook.updated_at,
+        }
+    }
+}
@@ -0,0 +1,67 @@
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+
+use crate::entities::saved_search;
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, ToSchema)]
+pub struct CreateSavedSearchRequest {
+    #[schema(example = "최근 아이디어")]
+    pub name: String,
+    /// 메모 검색과 같은 문법의 검색어. 필터는 `pinned:false after:30d`처럼 연산자로 적습니다
+    #[schema(example = "아이디어 pinned:false after:30d")]
+    pub query: String,
+}
+
+/// 생략한 항목은 바뀌지 않습니다
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, ToSchema)]
+pub struct UpdateSavedSearchRequest {
+    #[schema(example = "이번 달 아이디어")]
+    pub name: Option<String>,
+    #[schema(example = "아이디어 after:2024-06-01")]
+    pub query: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, ToSchema)]
+pub struct ReorderSavedSearchesRequest {
+    /// 사용자의 저장된 검색 ID 전체를 원하는 순서대로 나열합니다
+    #[schema(example = json!([3, 1, 2]))]
+    pub ids: Vec<i32>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, ToSchema)]
+pub struct SavedSearchResponse {
+    #[schema(example = 1)]
+    pub id: i32,
+    #[schema(example = "최근 아이디어")]
+    pub name: String,
+    #[schema(example = "아이디어 pinned:false after:30d")]
+    pub query: String,
+    /// 목록에서의 순서 (0부터)
+    #[schema(example = 0)]
+    pub position: i32,
+    #[schema(example = "2024-01-15T10:30:00")]
+    pub created_at: NaiveDateTime,
+    #[schema(example = "2024-01-15T10:30:00")]
+    pub updated_at: NaiveDateTime,
+}
+
+impl From<saved_search::Model> for SavedSearchResponse {
+    fn from(saved_search: saved_search::Model) -> Self {
+        Self {
+            id: saved_search.id,
+            name: saved_search.name,
+            query: saved_search.query,
+            position: saved_search.position,
+            created_at: saved_search.created_at,
+            updated_at: saved_search.updated_at,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Clone, Default, IntoParams)]
+pub struct SavedSearchMemosQuery {
+    /// 기본 20, 최대 100
+    pub limit: Option<u64>,
+    pub offset: Option<u64>,
+}
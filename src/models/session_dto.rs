@@ -0,0 +1,41 @@
+use chrono::NaiveDateTime;
+use serde::Serialize;
+use utoipa::ToSchema;
+
+use crate::entities::session;
+
+#[derive(Debug, Clone, Default)]
+pub struct ClientInfo {
+    pub user_agent: Option<String>,
+    pub ip_address: Option<String>,
+}
+
+#[derive(Debug, Serialize, Clone, PartialEq, ToSchema)]
+pub struct SessionResponse {
+    #[schema(example = "6f1c2a9e-3b4d-4e5f-8a7b-9c0d1e2f3a4b")]
+    pub id: String,
+    #[schema(example = "Mozilla/5.0 (iPhone; CPU iPhone OS 17_0 like Mac OS X)")]
+    pub user_agent: Option<String>,
+    #[schema(example = "203.0.113.7")]
+    pub ip_address: Option<String>,
+    #[schema(example = "2024-01-15T10:30:00")]
+    pub created_at: NaiveDateTime,
+    #[schema(example = "2024-01-16T08:00:00")]
+    pub last_seen_at: NaiveDateTime,
+    /// 현재 요청에 사용된 세션인지 여부
+    #[schema(example = true)]
+    pub is_current: bool,
+}
+
+impl SessionResponse {
+    pub fn from_model(session: session::Model, current_session_id: Option<&str>) -> Self {
+        Self {
+            is_current: current_session_id == Some(session.id.as_str()),
+            id: session.id,
+            user_agent: session.user_agent,
+            ip_address: session.ip_address,
+            created_at: session.created_at,
+            last_seen_at: session.last_seen_at,
+        }
+    }
+}
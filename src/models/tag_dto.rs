@@ -0,0 +1,59 @@
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::entities::tag;
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, ToSchema)]
+pub struct CreateTagRequest {
+    /// 앞뒤 공백과 맨 앞의 `#`은 제거되고 소문자로 저장됩니다
+    #[schema(example = "rust")]
+    pub name: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, ToSchema)]
+pub struct RenameTagRequest {
+    #[schema(example = "rust-lang")]
+    pub name: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, ToSchema)]
+pub struct MergeTagRequest {
+    /// 합쳐질 태그 ID. 요청 경로의 태그가 붙은 메모에 이 태그가 붙고, 요청 경로의 태그는 삭제됩니다
+    #[schema(example = 3)]
+    pub target_id: i32,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, ToSchema)]
+pub struct TagResponse {
+    #[schema(example = 1)]
+    pub id: i32,
+    #[schema(example = "rust")]
+    pub name: String,
+    /// 이 태그가 붙은 메모 수
+    #[schema(example = 12)]
+    pub memo_count: i64,
+    #[schema(example = "2024-01-15T10:30:00")]
+    pub created_at: NaiveDateTime,
+}
+
+impl From<(tag::Model, i64)> for TagResponse {
+    fn from((tag, memo_count): (tag::Model, i64)) -> Self {
+        Self {
+            id: tag.id,
+            name: tag.name,
+            memo_count,
+            created_at: tag.created_at,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, ToSchema)]
+pub struct TagSuggestionResponse {
+    /// 이미 쓰고 있는 태그 중 제안된 것
+    #[schema(example = json!(["rust"]))]
+    pub existing: Vec<String>,
+    /// 새로 만들 것을 제안하는 태그
+    #[schema(example = json!(["async"]))]
+    pub new: Vec<String>,
+}
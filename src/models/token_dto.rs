@@ -0,0 +1,102 @@
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::entities::personal_access_token;
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Hash, ToSchema)]
+pub enum Scope {
+    #[serde(rename = "memos:read")]
+    MemosRead,
+    #[serde(rename = "memos:write")]
+    MemosWrite,
+    #[serde(rename = "assist")]
+    Assist,
+    #[serde(rename = "account")]
+    Account,
+}
+
+impl Scope {
+    pub const ALL: [Scope; 4] = [
+        Scope::MemosRead,
+        Scope::MemosWrite,
+        Scope::Assist,
+        Scope::Account,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Scope::MemosRead => "memos:read",
+            Scope::MemosWrite => "memos:write",
+            Scope::Assist => "assist",
+            Scope::Account => "account",
+        }
+    }
+
+    pub fn parse_list(scopes: &str) -> Vec<Scope> {
+        scopes
+            .split_whitespace()
+            .filter_map(|s| Scope::ALL.into_iter().find(|scope| scope.as_str() == s))
+            .collect()
+    }
+
+    pub fn join(scopes: &[Scope]) -> String {
+        scopes
+            .iter()
+            .map(Scope::as_str)
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+}
+
+#[derive(Debug, Deserialize, Clone, ToSchema)]
+pub struct CreatePersonalAccessTokenRequest {
+    #[schema(example = "백업 스크립트")]
+    pub name: String,
+    #[schema(example = json!(["memos:read"]))]
+    pub scopes: Vec<Scope>,
+    /// 생략하면 만료되지 않습니다
+    #[schema(example = 90)]
+    pub expires_in_days: Option<i64>,
+}
+
+#[derive(Debug, Serialize, Clone, PartialEq, ToSchema)]
+pub struct PersonalAccessTokenResponse {
+    #[schema(example = 1)]
+    pub id: i32,
+    #[schema(example = "백업 스크립트")]
+    pub name: String,
+    /// 토큰을 구분하기 위한 앞부분
+    #[schema(example = "ink_pat_a1B2")]
+    pub token_prefix: String,
+    pub scopes: Vec<Scope>,
+    #[schema(example = "2024-04-15T10:30:00")]
+    pub expires_at: Option<NaiveDateTime>,
+    #[schema(example = "2024-01-16T08:00:00")]
+    pub last_used_at: Option<NaiveDateTime>,
+    #[schema(example = "2024-01-15T10:30:00")]
+    pub created_at: NaiveDateTime,
+}
+
+impl From<personal_access_token::Model> for PersonalAccessTokenResponse {
+    fn from(token: personal_access_token::Model) -> Self {
+        Self {
+            id: token.id,
+            name: token.name,
+            token_prefix: token.token_prefix,
+            scopes: Scope::parse_list(&token.scopes),
+            expires_at: token.expires_at,
+            last_used_at: token.last_used_at,
+            created_at: token.created_at,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Clone, ToSchema)]
+pub struct CreatedPersonalAccessTokenResponse {
+    #[serde(flatten)]
+    pub token: PersonalAccessTokenResponse,
+    /// 발급 시 한 번만 표시되며 다시 조회할 수 없습니다
+    #[schema(example = "ink_pat_a1B2c3D4e5F6g7H8i9J0k1L2m3N4o5P6q7R8s9T0")]
+    pub secret: String,
+}
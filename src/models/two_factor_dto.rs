@@ -0,0 +1,64 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+#[derive(Debug, Serialize, Clone, ToSchema)]
+pub struct TotpEnrollmentResponse {
+    /// 인증 앱에 직접 입력할 수 있는 Base32 비밀 키
+    #[schema(example = "JBSWY3DPEHPK3PXPJBSWY3DPEHPK3PXP")]
+    pub secret: String,
+    /// QR 코드로 변환해 인증 앱에서 스캔합니다
+    #[schema(
+        example = "otpauth://totp/Lekha:user%40example.com?secret=JBSWY3DPEHPK3PXPJBSWY3DPEHPK3PXP&issuer=Lekha"
+    )]
+    pub otpauth_uri: String,
+}
+
+#[derive(Debug, Deserialize, Clone, ToSchema)]
+pub struct TwoFactorCodeRequest {
+    /// 인증 앱의 6자리 코드 또는 복구 코드
+    #[schema(example = "123456")]
+    pub code: String,
+}
+
+#[derive(Debug, Serialize, Clone, ToSchema)]
+pub struct RecoveryCodesResponse {
+    /// 발급 시 한 번만 표시되며 각 코드는 한 번만 사용할 수 있습니다
+    #[schema(example = json!(["k3x9p-2m7r4", "a8d4q-6w1zt"]))]
+    pub recovery_codes: Vec<String>,
+}
+
+#[derive(Debug, Serialize, Clone, PartialEq, ToSchema)]
+pub struct TwoFactorStatusResponse {
+    #[schema(example = true)]
+    pub enabled: bool,
+    /// OAuth 로그인에도 2단계 인증을 요구하는지 여부
+    #[schema(example = false)]
+    pub enforce_on_oauth: bool,
+    #[schema(example = 10)]
+    pub recovery_codes_remaining: u64,
+}
+
+#[derive(Debug, Deserialize, Clone, ToSchema)]
+pub struct UpdateTwoFactorPolicyRequest {
+    #[schema(example = true)]
+    pub enforce_on_oauth: bool,
+}
+
+#[derive(Debug, Serialize, Clone, ToSchema)]
+pub struct TwoFactorChallenge {
+    /// `/api/users/login/2fa`에 코드와 함께 전달합니다
+    #[schema(example = "eyJhbGciOiJFZERTQSIsImtpZCI6Ij...")]
+    pub challenge_token: String,
+    /// 챌린지 토큰 만료까지 남은 시간(초)
+    #[schema(example = 300)]
+    pub expires_in: i64,
+}
+
+#[derive(Debug, Deserialize, Clone, ToSchema)]
+pub struct TwoFactorLoginRequest {
+    #[schema(example = "eyJhbGciOiJFZERTQSIsImtpZCI6Ij...")]
+    pub challenge_token: String,
+    /// 인증 앱의 6자리 코드 또는 복구 코드
+    #[schema(example = "123456")]
+    pub code: String,
+}
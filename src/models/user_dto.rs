@@ -2,17 +2,80 @@ use chrono::NaiveDateTime;
 use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
 
-use crate::entities::{oauth_account::OAuthProvider, user};
+use crate::entities::{
+    oauth_account::{self, OAuthProvider},
+    user::{self, UserRole},
+};
+use crate::models::two_factor_dto::TwoFactorChallenge;
 
 #[derive(Debug, Deserialize, Clone, ToSchema)]
 pub struct OAuthLoginRequest {
     pub provider: OAuthProvider,
-    #[schema(example = "google_123456789")]
-    pub provider_user_id: String,
+    /// Google과 OIDC 제공자는 ID 토큰, Kakao/Naver는 액세스 토큰
+    #[schema(example = "eyJhbGciOiJSUzI1NiIsImtpZCI6Ij...")]
+    pub credential: String,
+}
+
+#[derive(Debug, Deserialize, Clone, ToSchema)]
+pub struct LinkOAuthAccountRequest {
+    pub provider: OAuthProvider,
+    /// Google과 OIDC 제공자는 ID 토큰, Kakao/Naver는 액세스 토큰
+    #[schema(example = "eyJhbGciOiJSUzI1NiIsImtpZCI6Ij...")]
+    pub credential: String,
+}
+
+#[derive(Debug, Serialize, Clone, PartialEq, ToSchema)]
+pub struct LinkedAccountResponse {
+    pub provider: OAuthProvider,
+    #[schema(example = "2024-01-15T10:30:00")]
+    pub created_at: NaiveDateTime,
+}
+
+impl From<oauth_account::Model> for LinkedAccountResponse {
+    fn from(account: oauth_account::Model) -> Self {
+        Self {
+            provider: account.provider,
+            created_at: account.created_at,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Clone, ToSchema)]
+pub struct RegisterRequest {
     #[schema(example = "user@example.com")]
     pub email: String,
     #[schema(example = "홍길동")]
     pub username: String,
+    #[schema(example = "correct-horse-battery-42")]
+    pub password: String,
+}
+
+#[derive(Debug, Deserialize, Clone, ToSchema)]
+pub struct LoginRequest {
+    #[schema(example = "user@example.com")]
+    pub email: String,
+    #[schema(example = "correct-horse-battery-42")]
+    pub password: String,
+}
+
+#[derive(Debug, Deserialize, Clone, ToSchema)]
+pub struct ChangePasswordRequest {
+    /// 비밀번호가 없는 OAuth 전용 계정은 생략할 수 있습니다
+    #[schema(example = "correct-horse-battery-42")]
+    pub current_password: Option<String>,
+    #[schema(example = "new-horse-battery-43")]
+    pub new_password: String,
+}
+
+#[derive(Debug, Deserialize, Clone, ToSchema)]
+pub struct UpdateUserRequest {
+    #[schema(example = "홍길동")]
+    pub username: Option<String>,
+    #[schema(example = "new@example.com")]
+    pub email: Option<String>,
+    /// 켜면 태그 없이 작성한 메모에 AI가 제안한 태그를 자동으로 붙입니다
+    #[schema(example = true)]
+    pub auto_tag_memos: Option<bool>,
 }
 
 #[derive(Debug, Serialize, Clone, PartialEq, ToSchema)]
@@ -23,6 +86,10 @@ pub struct UserResponse {
     pub username: String,
     #[schema(example = "user@example.com")]
     pub email: String,
+    pub role: UserRole,
+    /// 태그 없이 작성한 메모에 AI 태그를 자동으로 붙이는지 여부
+    #[schema(example = false)]
+    pub auto_tag_memos: bool,
     #[schema(example = "2024-01-15T10:30:00")]
     pub created_at: NaiveDateTime,
 }
@@ -33,6 +100,8 @@ impl From<user::Model> for UserResponse {
             id: user.id,
             username: user.username,
             email: user.email,
+            role: user.role,
+            auto_tag_memos: user.auto_tag_memos,
             created_at: user.created_at,
         }
     }
@@ -43,6 +112,43 @@ pub struct AuthResponse {
     pub user: UserResponse,
     #[schema(example = "eyJhbGciOiJIUzI1NiIsInR5cCI6IkpXVCJ9...")]
     pub access_token: String,
-    #[schema(example = 86400)]
+    #[schema(example = "q3Jx0bV9m2Kf8hR1sT5uW7yZ4aC6eG0iL2nP4rT6vX8zB1dF3hJ5kM7oQ9sU1wY3")]
+    pub refresh_token: String,
+    /// 액세스 토큰 만료까지 남은 시간(초)
+    #[schema(example = 900)]
+    pub expires_in: i64,
+}
+
+/// 2단계 인증을 사용하는 계정은 `two_factor_required` 상태와 챌린지 토큰을 받습니다
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(tag = "status", rename_all = "snake_case")]
+pub enum LoginResponse {
+    Authenticated(AuthResponse),
+    TwoFactorRequired(TwoFactorChallenge),
+}
+
+/// `X-Auth-Mode: cookie`로 요청하면 토큰은 HttpOnly 쿠키로만 전달되고 본문에는 CSRF 토큰이 담깁니다
+#[derive(Debug, Serialize, ToSchema)]
+pub struct CookieAuthResponse {
+    pub user: UserResponse,
+    /// 상태를 바꾸는 요청마다 `X-CSRF-Token` 헤더로 보내야 합니다 (`lekha_csrf` 쿠키와 같은 값)
+    #[schema(example = "Zk3p9QwX2mL7vR4tY8nB1cF6hJ0sD5aE")]
+    pub csrf_token: String,
+    /// 액세스 토큰 만료까지 남은 시간(초)
+    #[schema(example = 900)]
     pub expires_in: i64,
 }
+
+#[derive(Debug, Serialize, ToSchema)]
+#[serde(tag = "status", rename_all = "snake_case")]
+pub enum CookieLoginResponse {
+    Authenticated(CookieAuthResponse),
+    TwoFactorRequired(TwoFactorChallenge),
+}
+
+/// 쿠키 인증 모드에서는 본문 없이 `lekha_refresh` 쿠키를 사용합니다
+#[derive(Debug, Deserialize, Clone, ToSchema)]
+pub struct RefreshTokenRequest {
+    #[schema(example = "q3Jx0bV9m2Kf8hR1sT5uW7yZ4aC6eG0iL2nP4rT6vX8zB1dF3hJ5kM7oQ9sU1wY3")]
+    pub refresh_token: String,
+}
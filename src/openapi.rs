@@ -1,52 +1,208 @@
 use utoipa::openapi::security::{Http, HttpAuthScheme, SecurityScheme};
 use utoipa::{Modify, OpenApi};
 
-use crate::entities::oauth_account::OAuthProvider;
-use crate::errors::ErrorResponse;
+use crate::entities::{auth_event::AuthEventType, oauth_account::OAuthProvider, user::UserRole};
+use crate::errors::{ErrorResponse, SearchQueryErrorResponse};
 use crate::handlers::health_handler::HealthResponse;
+use crate::models::admin_dto::{AdminUserListResponse, AdminUserResponse, UpdateUserRoleRequest};
 use crate::models::assist_dto::{AssistRequest, AssistResponse, SimilarMemo};
-use crate::models::memo_dto::{CreateMemoRequest, MemoResponse, UpdateMemoRequest};
-use crate::models::user_dto::{AuthResponse, OAuthLoginRequest, UserResponse};
+use crate::models::auth_event_dto::{AuthEventListResponse, AuthEventResponse};
+use crate::models::memo_dto::{
+    CreateMemoRequest, HybridSearchResponse, HybridSearchResult, MemoListResponse, MemoResponse,
+    MemoSearchResponse, MemoSearchResult, MoveMemosRequest, TrashedMemoResponse, UpdateMemoRequest,
+};
+use crate::models::memo_revision_dto::{
+    DiffChunk, DiffGranularity, DiffOp, MemoRevisionDiffResponse, MemoRevisionResponse,
+    MemoRevisionSummary,
+};
+use crate::models::notebook_dto::{
+    CreateNotebookRequest, DeleteNotebookMode, MoveNotebookRequest, NotebookResponse,
+    RenameNotebookRequest,
+};
+use crate::models::saved_search_dto::{
+    CreateSavedSearchRequest, ReorderSavedSearchesRequest, SavedSearchResponse,
+    UpdateSavedSearchRequest,
+};
+use crate::models::session_dto::SessionResponse;
+use crate::models::tag_dto::{
+    CreateTagRequest, MergeTagRequest, RenameTagRequest, TagResponse, TagSuggestionResponse,
+};
+use crate::models::token_dto::{
+    CreatePersonalAccessTokenRequest, CreatedPersonalAccessTokenResponse,
+    PersonalAccessTokenResponse, Scope,
+};
+use crate::models::two_factor_dto::{
+    RecoveryCodesResponse, TotpEnrollmentResponse, TwoFactorChallenge, TwoFactorCodeRequest,
+    TwoFactorLoginRequest, TwoFactorStatusResponse, UpdateTwoFactorPolicyRequest,
+};
+use crate::models::user_dto::{
+    AuthResponse, ChangePasswordRequest, CookieAuthResponse, CookieLoginResponse,
+    LinkOAuthAccountRequest, LinkedAccountResponse, LoginRequest, LoginResponse, OAuthLoginRequest,
+    RefreshTokenRequest, RegisterRequest, UpdateUserRequest, UserResponse,
+};
 
 #[derive(OpenApi)]
 #[openapi(
     info(
         title = "Lekha Server API",
         version = "0.1.0",
-        description = "당신의 생각이 글이 되도록 돕습니다\n\n## 인증\nOAuth 소셜 로그인(Google, Kakao, Naver)을 통해 사용자 인증을 수행합니다.\n로그인 후 발급받은 JWT Access Token을 `Authorization: Bearer <token>` 헤더에 포함하여 API를 호출합니다."
+        description = "당신의 생각이 글이 되도록 돕습니다\n\n## 인증\nOAuth 소셜 로그인(Google, Kakao, Naver)을 통해 사용자 인증을 수행합니다.\nGoogle은 ID 토큰, Kakao/Naver는 액세스 토큰을 전달하면 서버가 제공자에게 직접 검증합니다.\n설정된 OpenID Connect 발급자(예: 사내 SSO)는 `oidc:<이름>` 제공자로 ID 토큰을 전달하면 발급자의 디스커버리 문서와 JWKS로 검증합니다.\n같은 이메일의 계정이 이미 있으면 자동으로 병합하지 않으며, 기존 계정으로 로그인한 뒤 `/api/users/me/oauth-accounts`에서 제공자를 연결해야 합니다.\n브라우저를 거칠 수 없는 클라이언트는 이메일/비밀번호 회원가입 및 로그인을 사용할 수 있습니다.\n로그인 후 발급받은 JWT Access Token을 `Authorization: Bearer <token>` 헤더에 포함하여 API를 호출합니다.\n액세스 토큰은 수명이 짧으므로 만료되면 리프레시 토큰으로 `/api/auth/refresh`를 호출해 새 토큰 쌍을 받습니다.\n2단계 인증(TOTP)을 켠 계정은 로그인 응답의 `status`가 `two_factor_required`이며, 함께 받은 챌린지 토큰과 인증 코드(또는 복구 코드)를 `/api/users/login/2fa`로 보내야 토큰이 발급됩니다. OAuth 로그인에는 사용자가 정책으로 선택한 경우에만 적용됩니다.\n로그인할 때마다 세션이 기록되며, `/api/users/me/sessions`에서 다른 기기의 세션을 확인하고 종료할 수 있습니다. 종료된 세션의 토큰은 즉시 거부됩니다.\n로그인 성공/실패, 토큰 재발급, 로그아웃, 비밀번호 변경 등 보안 이벤트는 IP 주소, User-Agent와 함께 기록되며 `/api/users/me/security-events`에서 확인할 수 있습니다.\n액세스 토큰은 EdDSA(Ed25519)로 서명되며, 다른 서비스는 `/.well-known/jwks.json`의 공개 키로 검증할 수 있습니다.\n스크립트나 외부 연동에는 `/api/users/me/tokens`에서 발급한 개인 액세스 토큰(`ink_pat_...`)을 같은 `Authorization: Bearer` 헤더로 사용할 수 있습니다.\n브라우저 클라이언트는 로그인, 회원가입, 토큰 재발급 요청에 `X-Auth-Mode: cookie` 헤더를 보내면 토큰을 HttpOnly, Secure, SameSite=Strict 쿠키로 받을 수 있습니다. 이때 응답 본문에는 토큰 대신 `csrf_token`이 담기며, 쿠키로 인증하는 상태 변경 요청(GET/HEAD/OPTIONS 외)은 이 값을 `X-CSRF-Token` 헤더로 함께 보내야 합니다. `Authorization` 헤더가 있으면 쿠키보다 우선합니다.\n\n## 스코프\n모든 토큰에는 스코프가 있으며, 필요한 스코프가 없는 요청은 403으로 거부됩니다.\n- `memos:read`: 메모 및 저장된 검색 조회\n- `memos:write`: 메모와 저장된 검색의 생성, 수정, 삭제\n- `assist`: AI 어시스턴트 호출\n- `account`: 계정 정보, 연결된 OAuth 계정, 개인 액세스 토큰 관리\n로그인으로 발급된 액세스 토큰은 모든 스코프를 가지며, 대시보드 등에는 `memos:read`만 가진 개인 액세스 토큰을 발급해 사용할 수 있습니다.\n\n## 관리자\n`/api/admin` 아래의 API는 `admin` 역할과 `account` 스코프가 모두 필요합니다. 정지된 계정은 유효한 토큰을 가지고 있어도 모든 요청이 403으로 거부됩니다."
     ),
     paths(
         crate::handlers::health_handler::health_check,
         crate::handlers::user_handler::oauth_login,
+        crate::handlers::user_handler::register,
+        crate::handlers::user_handler::login,
+        crate::handlers::two_factor_handler::complete_login,
+        crate::handlers::user_handler::get_me,
+        crate::handlers::user_handler::update_me,
+        crate::handlers::user_handler::delete_me,
+        crate::handlers::user_handler::change_password,
+        crate::handlers::user_handler::list_oauth_accounts,
+        crate::handlers::user_handler::link_oauth_account,
+        crate::handlers::user_handler::unlink_oauth_account,
+        crate::handlers::two_factor_handler::get_status,
+        crate::handlers::two_factor_handler::update_policy,
+        crate::handlers::two_factor_handler::start_enrollment,
+        crate::handlers::two_factor_handler::activate,
+        crate::handlers::two_factor_handler::disable,
+        crate::handlers::two_factor_handler::regenerate_recovery_codes,
+        crate::handlers::session_handler::list_sessions,
+        crate::handlers::session_handler::revoke_session,
+        crate::handlers::session_handler::revoke_other_sessions,
+        crate::handlers::security_event_handler::list_security_events,
+        crate::handlers::token_handler::create_token,
+        crate::handlers::token_handler::list_tokens,
+        crate::handlers::token_handler::revoke_token,
+        crate::handlers::auth_handler::refresh,
+        crate::handlers::auth_handler::logout,
+        crate::handlers::auth_handler::jwks,
         crate::handlers::memo_handler::create_memo,
         crate::handlers::memo_handler::list_memos,
+        crate::handlers::memo_handler::search_memos,
+        crate::handlers::memo_handler::semantic_search,
         crate::handlers::memo_handler::get_memo,
         crate::handlers::memo_handler::update_memo,
         crate::handlers::memo_handler::delete_memo,
+        crate::handlers::memo_handler::move_memos,
+        crate::handlers::memo_handler::list_trash,
+        crate::handlers::memo_handler::restore_memo,
         crate::handlers::memo_handler::toggle_pin,
+        crate::handlers::memo_handler::archive_memo,
+        crate::handlers::memo_handler::unarchive_memo,
+        crate::handlers::memo_handler::suggest_tags,
+        crate::handlers::memo_handler::list_revisions,
+        crate::handlers::memo_handler::diff_revisions,
+        crate::handlers::memo_handler::get_revision,
+        crate::handlers::memo_handler::restore_revision,
+        crate::handlers::saved_search_handler::create_saved_search,
+        crate::handlers::saved_search_handler::list_saved_searches,
+        crate::handlers::saved_search_handler::reorder_saved_searches,
+        crate::handlers::saved_search_handler::get_saved_search,
+        crate::handlers::saved_search_handler::update_saved_search,
+        crate::handlers::saved_search_handler::delete_saved_search,
+        crate::handlers::saved_search_handler::list_saved_search_memos,
+        crate::handlers::tag_handler::list_tags,
+        crate::handlers::tag_handler::create_tag,
+        crate::handlers::tag_handler::rename_tag,
+        crate::handlers::tag_handler::delete_tag,
+        crate::handlers::tag_handler::merge_tag,
+        crate::handlers::notebook_handler::list_notebooks,
+        crate::handlers::notebook_handler::create_notebook,
+        crate::handlers::notebook_handler::rename_notebook,
+        crate::handlers::notebook_handler::move_notebook,
+        crate::handlers::notebook_handler::delete_notebook,
         crate::handlers::assist_handler::assist,
+        crate::handlers::admin_handler::list_users,
+        crate::handlers::admin_handler::update_role,
+        crate::handlers::admin_handler::suspend_user,
+        crate::handlers::admin_handler::reactivate_user,
+        crate::handlers::admin_handler::force_logout,
+        crate::handlers::admin_handler::list_security_events,
     ),
     components(
         schemas(
             HealthResponse,
             OAuthLoginRequest,
+            RegisterRequest,
+            LoginRequest,
+            ChangePasswordRequest,
+            UpdateUserRequest,
+            LinkOAuthAccountRequest,
+            LinkedAccountResponse,
+            RefreshTokenRequest,
+            SessionResponse,
+            AuthEventType,
+            AuthEventResponse,
+            AuthEventListResponse,
+            CreatePersonalAccessTokenRequest,
+            CreatedPersonalAccessTokenResponse,
+            PersonalAccessTokenResponse,
+            Scope,
             UserResponse,
+            UserRole,
             AuthResponse,
+            LoginResponse,
+            CookieAuthResponse,
+            CookieLoginResponse,
+            TwoFactorChallenge,
+            TwoFactorLoginRequest,
+            TwoFactorCodeRequest,
+            TwoFactorStatusResponse,
+            UpdateTwoFactorPolicyRequest,
+            TotpEnrollmentResponse,
+            RecoveryCodesResponse,
             OAuthProvider,
             CreateMemoRequest,
             UpdateMemoRequest,
             MemoResponse,
+            MemoListResponse,
+            MoveMemosRequest,
+            TrashedMemoResponse,
+            MemoSearchResult,
+            MemoSearchResponse,
+            HybridSearchResult,
+            HybridSearchResponse,
+            MemoRevisionSummary,
+            MemoRevisionResponse,
+            DiffGranularity,
+            DiffOp,
+            DiffChunk,
+            MemoRevisionDiffResponse,
+            CreateSavedSearchRequest,
+            UpdateSavedSearchRequest,
+            ReorderSavedSearchesRequest,
+            SavedSearchResponse,
+            CreateTagRequest,
+            RenameTagRequest,
+            MergeTagRequest,
+            TagResponse,
+            TagSuggestionResponse,
+            CreateNotebookRequest,
+            RenameNotebookRequest,
+            MoveNotebookRequest,
+            DeleteNotebookMode,
+            NotebookResponse,
             AssistRequest,
             AssistResponse,
             SimilarMemo,
+            AdminUserResponse,
+            AdminUserListResponse,
+            UpdateUserRoleRequest,
             ErrorResponse,
+            SearchQueryErrorResponse,
         )
     ),
     tags(
         (name = "Health", description = "서버 상태 확인"),
         (name = "Users", description = "사용자 관리"),
+        (name = "Auth", description = "토큰 재발급, 로그아웃 및 공개 키"),
         (name = "Memos", description = "메모 관리"),
+        (name = "Saved Searches", description = "이름을 붙여 저장한 검색 (조회할 때마다 다시 검색)"),
+        (name = "Tags", description = "메모 태그 관리 (이름 변경, 병합)"),
+        (name = "Notebooks", description = "중첩 가능한 노트북 관리"),
         (name = "Assist", description = "AI 어시스턴트"),
+        (name = "Admin", description = "사용자 관리 (관리자 전용)"),
     ),
     modifiers(&SecurityAddon)
 )]
@@ -0,0 +1,88 @@
+use chrono::{NaiveDateTime, Utc};
+use sea_orm::*;
+use std::sync::Arc;
+
+use crate::entities::auth_event::{self, AuthEventType, Entity as AuthEvent};
+
+#[derive(Debug, Clone, Default)]
+pub struct AuthEventFilter {
+    pub user_id: Option<i32>,
+    pub email: Option<String>,
+    pub event_type: Option<AuthEventType>,
+    pub ip_address: Option<String>,
+    pub since: Option<NaiveDateTime>,
+    pub until: Option<NaiveDateTime>,
+}
+
+#[derive(Clone)]
+pub struct AuthEventRepository {
+    db: Arc<DatabaseConnection>,
+}
+
+impl AuthEventRepository {
+    pub fn new(db: Arc<DatabaseConnection>) -> Self {
+        Self { db }
+    }
+
+    pub async fn create(
+        &self,
+        user_id: Option<i32>,
+        email: Option<String>,
+        event_type: AuthEventType,
+        detail: Option<String>,
+        ip_address: Option<String>,
+        user_agent: Option<String>,
+    ) -> Result<auth_event::Model, DbErr> {
+        let active_model = auth_event::ActiveModel {
+            user_id: Set(user_id),
+            email: Set(email),
+            event_type: Set(event_type),
+            detail: Set(detail),
+            ip_address: Set(ip_address),
+            user_agent: Set(user_agent),
+            created_at: Set(Utc::now().naive_utc()),
+            ..Default::default()
+        };
+
+        active_model.insert(self.db.as_ref()).await
+    }
+
+    // 최신 기록부터 조회한다.
+    pub async fn search(
+        &self,
+        filter: AuthEventFilter,
+        limit: u64,
+        offset: u64,
+    ) -> Result<(Vec<auth_event::Model>, u64), DbErr> {
+        let mut select = AuthEvent::find();
+        if let Some(user_id) = filter.user_id {
+            select = select.filter(auth_event::Column::UserId.eq(user_id));
+        }
+        if let Some(email) = filter.email {
+            select = select.filter(auth_event::Column::Email.eq(email));
+        }
+        if let Some(event_type) = filter.event_type {
+            select = select.filter(auth_event::Column::EventType.eq(event_type));
+        }
+        if let Some(ip_address) = filter.ip_address {
+            select = select.filter(auth_event::Column::IpAddress.eq(ip_address));
+        }
+        if let Some(since) = filter.since {
+            select = select.filter(auth_event::Column::CreatedAt.gte(since));
+        }
+        if let Some(until) = filter.until {
+            select = select.filter(auth_event::Column::CreatedAt.lt(until));
+        }
+
+        let total = select.clone().count(self.db.as_ref()).await?;
+        let events = select
+            .order_by_desc(auth_event::Column::CreatedAt)
+            .order_by_desc(auth_event::Column::Id)
+            .limit(limit)
+            .offset(offset)
+            .all(self.db.as_ref())
+            .await?;
+
+        Ok((events, total))
+    }
+}
@@ -1,8 +1,50 @@
-use chrono::Utc;
-use sea_orm::*;
-use std::sync::Arc;
+use chrono::{NaiveDateTime, Utc};
+use sea_orm::{
+    sea_query::{Expr, Query, SelectStatement},
+    *,
+};
+use std::{collections::HashMap, future::Future, sync::Arc};
 
-use crate::entities::memo::{self, Entity as Memo};
+use crate::entities::{
+    memo::{self, Entity as Memo},
+    memo_revision,
+    memo_tag::{self, Entity as MemoTag},
+    tag::{self, Entity as Tag},
+};
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct MemoListFilter {
+    pub is_pinned: Option<bool>,
+    pub is_archived: Option<bool>,
+    pub created_since: Option<NaiveDateTime>,
+    pub created_until: Option<NaiveDateTime>,
+    pub updated_since: Option<NaiveDateTime>,
+    pub updated_until: Option<NaiveDateTime>,
+    /// 모두 붙어 있어야 한다
+    pub tags: Vec<String>,
+    /// 하나라도 붙어 있으면 제외한다
+    pub excluded_tags: Vec<String>,
+    /// 이 노트북들 중 하나에 들어 있는 메모만
+    pub notebook_ids: Option<Vec<i32>>,
+}
+
+// 이전 페이지의 마지막 메모 위치. 오프셋과 달리 앞쪽에 메모가 추가되거나 수정되어도 다음 페이지가 밀리지 않는다.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MemoCursor {
+    pub is_pinned: bool,
+    pub updated_at: NaiveDateTime,
+    pub id: i32,
+}
+
+impl From<&memo::Model> for MemoCursor {
+    fn from(memo: &memo::Model) -> Self {
+        Self {
+            is_pinned: memo.is_pinned,
+            updated_at: memo.updated_at,
+            id: memo.id,
+        }
+    }
+}
 
 #[derive(Clone)]
 pub struct MemoRepository {
@@ -14,26 +56,175 @@ impl MemoRepository {
         Self { db }
     }
 
+    // 휴지통에 있는 메모는 찾지 않는다. 휴지통의 메모는 `find_trashed_by_id`로 찾는다.
     pub async fn find_by_id(&self, id: i32) -> Result<Option<memo::Model>, DbErr> {
-        Memo::find_by_id(id).one(self.db.as_ref()).await
+        Memo::find_by_id(id)
+            .filter(memo::Column::DeletedAt.is_null())
+            .one(self.db.as_ref())
+            .await
+    }
+
+    // 다른 사용자의 메모 ID가 섞여 있어도 해당 사용자의 메모만 돌려준다. 순서는 보장하지 않는다.
+    pub async fn find_by_ids(&self, user_id: i32, ids: &[i32]) -> Result<Vec<memo::Model>, DbErr> {
+        self.find_matching_ids(user_id, ids, &MemoListFilter::default(), None)
+            .await
     }
 
-    pub async fn find_by_user_id(&self, user_id: i32) -> Result<Vec<memo::Model>, DbErr> {
+    // 페이로드를 다시 쓸 때는 휴지통의 메모도 함께 읽는다.
+    pub async fn find_by_ids_with_trashed(
+        &self,
+        user_id: i32,
+        ids: &[i32],
+    ) -> Result<Vec<memo::Model>, DbErr> {
         Memo::find()
             .filter(memo::Column::UserId.eq(user_id))
+            .filter(memo::Column::Id.is_in(ids.to_vec()))
+            .all(self.db.as_ref())
+            .await
+    }
+
+    // 페이로드를 일괄로 다시 쓸 때 모든 사용자의 메모를(휴지통 포함) ID 순으로 나눠 읽는다.
+    pub async fn find_all_after_id(
+        &self,
+        after_id: i32,
+        limit: u64,
+    ) -> Result<Vec<memo::Model>, DbErr> {
+        Memo::find()
+            .filter(memo::Column::Id.gt(after_id))
+            .order_by_asc(memo::Column::Id)
+            .limit(limit)
+            .all(self.db.as_ref())
+            .await
+    }
+
+    // 벡터 검색으로 찾은 메모에 벡터 저장소가 확인할 수 없는 본문 조건(제외어)을 다시 적용할 때 쓴다.
+    pub async fn find_matching_ids(
+        &self,
+        user_id: i32,
+        ids: &[i32],
+        filter: &MemoListFilter,
+        tsquery: Option<&str>,
+    ) -> Result<Vec<memo::Model>, DbErr> {
+        if ids.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let mut select = Memo::find()
+            .filter(user_condition(user_id, filter))
+            .filter(memo::Column::Id.is_in(ids.to_vec()));
+        if let Some(tsquery) = tsquery {
+            select = select.filter(Expr::cust_with_values(
+                "search_vector @@ $1::tsquery",
+                [tsquery],
+            ));
+        }
+        select.all(self.db.as_ref()).await
+    }
+
+    // 고정 메모가 먼저, 그 안에서는 최근 수정 순. 수정 시각이 같으면 ID로 순서를 고정한다.
+    pub async fn list_by_user(
+        &self,
+        user_id: i32,
+        filter: MemoListFilter,
+        after: Option<MemoCursor>,
+        limit: u64,
+    ) -> Result<Vec<memo::Model>, DbErr> {
+        let mut select = Memo::find().filter(user_condition(user_id, &filter));
+        if let Some(cursor) = after {
+            let same_pin = memo::Column::IsPinned.eq(cursor.is_pinned);
+            let mut condition = Condition::any()
+                .add(
+                    same_pin
+                        .clone()
+                        .and(memo::Column::UpdatedAt.lt(cursor.updated_at)),
+                )
+                .add(
+                    same_pin
+                        .and(memo::Column::UpdatedAt.eq(cursor.updated_at))
+                        .and(memo::Column::Id.lt(cursor.id)),
+                );
+            if cursor.is_pinned {
+                condition = condition.add(memo::Column::IsPinned.eq(false));
+            }
+            select = select.filter(condition);
+        }
+
+        select
             .order_by_desc(memo::Column::IsPinned)
             .order_by_desc(memo::Column::UpdatedAt)
+            .order_by_desc(memo::Column::Id)
+            .limit(limit)
             .all(self.db.as_ref())
             .await
     }
 
-    pub async fn create(&self, user_id: i32, content: String) -> Result<memo::Model, DbErr> {
+    // `search_vector`는 DB가 본문으로부터 생성하는 컬럼이라 엔티티에는 두지 않는다.
+    // 검색어 없이 필드 조건만 있으면 모든 결과의 점수가 0이고 최근 수정 순으로 정렬된다.
+    pub async fn search_keyword(
+        &self,
+        user_id: i32,
+        tsquery: Option<&str>,
+        filter: &MemoListFilter,
+        limit: u64,
+        offset: u64,
+    ) -> Result<(Vec<(memo::Model, f32)>, u64), DbErr> {
+        let mut select = Memo::find().filter(user_condition(user_id, filter));
+        if let Some(tsquery) = tsquery {
+            select = select.filter(Expr::cust_with_values(
+                "search_vector @@ $1::tsquery",
+                [tsquery],
+            ));
+        }
+        let rank = match tsquery {
+            Some(tsquery) => {
+                Expr::cust_with_values("ts_rank_cd(search_vector, $1::tsquery)", [tsquery])
+            }
+            None => Expr::cust("0::real"),
+        };
+
+        let total = select.clone().count(self.db.as_ref()).await?;
+        let ranked: Vec<(i32, f32)> = select
+            .select_only()
+            .column(memo::Column::Id)
+            .column_as(rank, "rank")
+            .order_by(Expr::cust("rank"), Order::Desc)
+            .order_by_desc(memo::Column::UpdatedAt)
+            .order_by_desc(memo::Column::Id)
+            .limit(limit)
+            .offset(offset)
+            .into_tuple()
+            .all(self.db.as_ref())
+            .await?;
+
+        let ids: Vec<i32> = ranked.iter().map(|(id, _)| *id).collect();
+        let mut memos: HashMap<i32, memo::Model> = self
+            .find_by_ids(user_id, &ids)
+            .await?
+            .into_iter()
+            .map(|memo| (memo.id, memo))
+            .collect();
+
+        let results = ranked
+            .into_iter()
+            .filter_map(|(id, rank)| memos.remove(&id).map(|memo| (memo, rank)))
+            .collect();
+        Ok((results, total))
+    }
+
+    pub async fn create(
+        &self,
+        user_id: i32,
+        content: String,
+        notebook_id: Option<i32>,
+    ) -> Result<memo::Model, DbErr> {
         let now = Utc::now().naive_utc();
 
         let active_model = memo::ActiveModel {
             user_id: Set(user_id),
             content: Set(content),
             is_pinned: Set(false),
+            is_archived: Set(false),
+            notebook_id: Set(notebook_id),
             created_at: Set(now),
             updated_at: Set(now),
             ..Default::default()
@@ -42,23 +233,144 @@ impl MemoRepository {
         active_model.insert(self.db.as_ref()).await
     }
 
+    // 내용이 바뀌면 바뀌기 전의 내용을 `memo_revisions`에 남긴 뒤 덮어쓴다.
     pub async fn update(&self, id: i32, content: String) -> Result<memo::Model, DbErr> {
-        let memo = self
-            .find_by_id(id)
+        let txn = self.db.begin().await?;
+        let memo = Memo::find_by_id(id)
+            .one(&txn)
             .await?
             .ok_or(DbErr::RecordNotFound("Memo not found".into()))?;
 
+        if memo.content != content {
+            memo_revision::ActiveModel {
+                memo_id: Set(memo.id),
+                content: Set(memo.content.clone()),
+                created_at: Set(memo.updated_at),
+                ..Default::default()
+            }
+            .insert(&txn)
+            .await?;
+        }
+
         let mut active_model: memo::ActiveModel = memo.into();
         active_model.content = Set(content);
         active_model.updated_at = Set(Utc::now().naive_utc());
+        let updated = active_model.update(&txn).await?;
 
-        active_model.update(self.db.as_ref()).await
+        txn.commit().await?;
+        Ok(updated)
     }
 
     pub async fn delete(&self, id: i32) -> Result<DeleteResult, DbErr> {
         Memo::delete_by_id(id).exec(self.db.as_ref()).await
     }
 
+    // 휴지통으로 옮긴다. 이미 휴지통에 있는 메모의 삭제 시각은 바꾸지 않는다.
+    pub async fn trash_many(&self, user_id: i32, ids: &[i32]) -> Result<UpdateResult, DbErr> {
+        Memo::update_many()
+            .col_expr(memo::Column::DeletedAt, Expr::value(Utc::now().naive_utc()))
+            .filter(memo::Column::UserId.eq(user_id))
+            .filter(memo::Column::Id.is_in(ids.to_vec()))
+            .filter(memo::Column::DeletedAt.is_null())
+            .exec(self.db.as_ref())
+            .await
+    }
+
+    pub async fn restore(&self, memo: memo::Model) -> Result<memo::Model, DbErr> {
+        let mut active_model: memo::ActiveModel = memo.into();
+        active_model.deleted_at = Set(None);
+
+        active_model.update(self.db.as_ref()).await
+    }
+
+    // 최근에 지운 메모부터
+    pub async fn find_trashed(&self, user_id: i32) -> Result<Vec<memo::Model>, DbErr> {
+        Memo::find()
+            .filter(memo::Column::UserId.eq(user_id))
+            .filter(memo::Column::DeletedAt.is_not_null())
+            .order_by_desc(memo::Column::DeletedAt)
+            .order_by_desc(memo::Column::Id)
+            .all(self.db.as_ref())
+            .await
+    }
+
+    pub async fn find_trashed_by_id(
+        &self,
+        id: i32,
+        user_id: i32,
+    ) -> Result<Option<memo::Model>, DbErr> {
+        Memo::find_by_id(id)
+            .filter(memo::Column::UserId.eq(user_id))
+            .filter(memo::Column::DeletedAt.is_not_null())
+            .one(self.db.as_ref())
+            .await
+    }
+
+    // `cutoff` 이전에 휴지통으로 옮긴 메모를 모든 사용자에 걸쳐 영구 삭제하고 삭제한 ID를 돌려준다.
+    // 휴지통에 넣은 지 `cutoff`가 지난 메모를 지우고, 실제로 지운 ID만 `delete_vectors`에 넘긴다.
+    // 지운 행은 커밋할 때까지 잠겨 있어 그사이 복원되지 않고, 벡터 삭제가 실패하면 되돌려 다음 실행에서 다시 시도한다.
+    pub async fn purge_deleted_before<F, Fut>(
+        &self,
+        cutoff: NaiveDateTime,
+        delete_vectors: F,
+    ) -> Result<Vec<i32>, DbErr>
+    where
+        F: FnOnce(Vec<i32>) -> Fut,
+        Fut: Future<Output = Result<(), DbErr>>,
+    {
+        let txn = self.db.begin().await?;
+
+        let statement = Query::delete()
+            .from_table(Memo)
+            .and_where(memo::Column::DeletedAt.lt(cutoff))
+            .returning_col(memo::Column::Id)
+            .to_owned();
+        let ids = txn
+            .query_all(txn.get_database_backend().build(&statement))
+            .await?
+            .iter()
+            .map(|row| row.try_get::<i32>("", "id"))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        if !ids.is_empty() {
+            delete_vectors(ids.clone()).await?;
+        }
+        txn.commit().await?;
+
+        Ok(ids)
+    }
+
+    // 노트북을 옮기는 것은 내용 수정이 아니므로 수정 시각(목록 순서)을 바꾸지 않는다.
+    pub async fn move_to_notebook(
+        &self,
+        user_id: i32,
+        ids: &[i32],
+        notebook_id: Option<i32>,
+    ) -> Result<UpdateResult, DbErr> {
+        Memo::update_many()
+            .col_expr(memo::Column::NotebookId, Expr::value(notebook_id))
+            .filter(memo::Column::UserId.eq(user_id))
+            .filter(memo::Column::Id.is_in(ids.to_vec()))
+            .exec(self.db.as_ref())
+            .await
+    }
+
+    // 노트북이 삭제되면 휴지통의 메모도 함께 옮겨지므로 휴지통의 메모도 포함한다.
+    pub async fn find_ids_by_notebook_ids(
+        &self,
+        user_id: i32,
+        notebook_ids: &[i32],
+    ) -> Result<Vec<i32>, DbErr> {
+        Memo::find()
+            .select_only()
+            .column(memo::Column::Id)
+            .filter(memo::Column::UserId.eq(user_id))
+            .filter(memo::Column::NotebookId.is_in(notebook_ids.to_vec()))
+            .into_tuple()
+            .all(self.db.as_ref())
+            .await
+    }
+
     pub async fn toggle_pin(&self, id: i32) -> Result<memo::Model, DbErr> {
         let memo = self
             .find_by_id(id)
@@ -71,4 +383,83 @@ impl MemoRepository {
 
         active_model.update(self.db.as_ref()).await
     }
+
+    // 보관은 내용 수정이 아니므로 수정 시각(목록 순서)을 바꾸지 않는다.
+    pub async fn set_archived(&self, id: i32, is_archived: bool) -> Result<memo::Model, DbErr> {
+        let memo = self
+            .find_by_id(id)
+            .await?
+            .ok_or(DbErr::RecordNotFound("Memo not found".into()))?;
+
+        let mut active_model: memo::ActiveModel = memo.into();
+        active_model.is_archived = Set(is_archived);
+
+        active_model.update(self.db.as_ref()).await
+    }
+
+    pub async fn count_by_user_ids(&self, user_ids: &[i32]) -> Result<HashMap<i32, i64>, DbErr> {
+        if user_ids.is_empty() {
+            return Ok(HashMap::new());
+        }
+
+        let counts: Vec<(i32, i64)> = Memo::find()
+            .select_only()
+            .column(memo::Column::UserId)
+            .column_as(memo::Column::Id.count(), "memo_count")
+            .filter(memo::Column::UserId.is_in(user_ids.to_vec()))
+            .filter(memo::Column::DeletedAt.is_null())
+            .group_by(memo::Column::UserId)
+            .into_tuple()
+            .all(self.db.as_ref())
+            .await?;
+
+        Ok(counts.into_iter().collect())
+    }
+}
+
+fn user_condition(user_id: i32, filter: &MemoListFilter) -> Condition {
+    let mut condition = Condition::all()
+        .add(memo::Column::UserId.eq(user_id))
+        .add(memo::Column::DeletedAt.is_null());
+    if let Some(is_pinned) = filter.is_pinned {
+        condition = condition.add(memo::Column::IsPinned.eq(is_pinned));
+    }
+    if let Some(is_archived) = filter.is_archived {
+        condition = condition.add(memo::Column::IsArchived.eq(is_archived));
+    }
+    if let Some(since) = filter.created_since {
+        condition = condition.add(memo::Column::CreatedAt.gte(since));
+    }
+    if let Some(until) = filter.created_until {
+        condition = condition.add(memo::Column::CreatedAt.lt(until));
+    }
+    if let Some(since) = filter.updated_since {
+        condition = condition.add(memo::Column::UpdatedAt.gte(since));
+    }
+    if let Some(until) = filter.updated_until {
+        condition = condition.add(memo::Column::UpdatedAt.lt(until));
+    }
+    for name in &filter.tags {
+        condition = condition.add(memo::Column::Id.in_subquery(tagged_memo_ids(user_id, name)));
+    }
+    for name in &filter.excluded_tags {
+        condition = condition.add(memo::Column::Id.not_in_subquery(tagged_memo_ids(user_id, name)));
+    }
+    if let Some(notebook_ids) = &filter.notebook_ids {
+        condition = condition.add(memo::Column::NotebookId.is_in(notebook_ids.clone()));
+    }
+    condition
+}
+
+fn tagged_memo_ids(user_id: i32, name: &str) -> SelectStatement {
+    Query::select()
+        .column((MemoTag, memo_tag::Column::MemoId))
+        .from(MemoTag)
+        .inner_join(
+            Tag,
+            Expr::col((Tag, tag::Column::Id)).equals((MemoTag, memo_tag::Column::TagId)),
+        )
+        .and_where(Expr::col((Tag, tag::Column::UserId)).eq(user_id))
+        .and_where(Expr::col((Tag, tag::Column::Name)).eq(name))
+        .to_owned()
 }
@@ -0,0 +1,36 @@
+use sea_orm::*;
+use std::sync::Arc;
+
+use crate::entities::memo_revision::{self, Entity as MemoRevision};
+
+// 버전은 `MemoRepository::update`가 메모를 수정할 때 함께 저장한다.
+#[derive(Clone)]
+pub struct MemoRevisionRepository {
+    db: Arc<DatabaseConnection>,
+}
+
+impl MemoRevisionRepository {
+    pub fn new(db: Arc<DatabaseConnection>) -> Self {
+        Self { db }
+    }
+
+    // 최신 버전부터
+    pub async fn find_by_memo_id(&self, memo_id: i32) -> Result<Vec<memo_revision::Model>, DbErr> {
+        MemoRevision::find()
+            .filter(memo_revision::Column::MemoId.eq(memo_id))
+            .order_by_desc(memo_revision::Column::Id)
+            .all(self.db.as_ref())
+            .await
+    }
+
+    pub async fn find_by_id(
+        &self,
+        id: i32,
+        memo_id: i32,
+    ) -> Result<Option<memo_revision::Model>, DbErr> {
+        MemoRevision::find_by_id(id)
+            .filter(memo_revision::Column::MemoId.eq(memo_id))
+            .one(self.db.as_ref())
+            .await
+    }
+}
@@ -1,9 +1,29 @@
+pub mod auth_event_repository;
 pub mod memo_repository;
-pub mod qdrant_repository;
+pub mod memo_revision_repository;
+pub mod notebook_repository;
 pub mod oauth_account_repository;
+pub mod personal_access_token_repository;
+pub mod qdrant_repository;
+pub mod recovery_code_repository;
+pub mod refresh_token_repository;
+pub mod saved_search_repository;
+pub mod session_repository;
+pub mod tag_repository;
+pub mod totp_credential_repository;
 pub mod user_repository;
 
-pub use memo_repository::MemoRepository;
-pub use qdrant_repository::{QdrantRepo, QdrantRepository};
+pub use auth_event_repository::{AuthEventFilter, AuthEventRepository};
+pub use memo_repository::{MemoCursor, MemoListFilter, MemoRepository};
+pub use memo_revision_repository::MemoRevisionRepository;
+pub use notebook_repository::NotebookRepository;
 pub use oauth_account_repository::OAuthAccountRepository;
+pub use personal_access_token_repository::PersonalAccessTokenRepository;
+pub use qdrant_repository::{MemoPayload, QdrantRepo, QdrantRepository};
+pub use recovery_code_repository::RecoveryCodeRepository;
+pub use refresh_token_repository::RefreshTokenRepository;
+pub use saved_search_repository::SavedSearchRepository;
+pub use session_repository::SessionRepository;
+pub use tag_repository::TagRepository;
+pub use totp_credential_repository::TotpCredentialRepository;
 pub use user_repository::UserRepository;
@@ -0,0 +1,195 @@
+use chrono::Utc;
+use sea_orm::{sea_query::Expr, *};
+use std::{
+    collections::{HashMap, HashSet},
+    sync::Arc,
+};
+
+use crate::entities::{
+    memo::{self, Entity as Memo},
+    notebook::{self, Entity as Notebook},
+};
+
+#[derive(Clone)]
+pub struct NotebookRepository {
+    db: Arc<DatabaseConnection>,
+}
+
+impl NotebookRepository {
+    pub fn new(db: Arc<DatabaseConnection>) -> Self {
+        Self { db }
+    }
+
+    pub async fn find_by_user_id(&self, user_id: i32) -> Result<Vec<notebook::Model>, DbErr> {
+        Notebook::find()
+            .filter(notebook::Column::UserId.eq(user_id))
+            .order_by_asc(notebook::Column::Name)
+            .order_by_asc(notebook::Column::Id)
+            .all(self.db.as_ref())
+            .await
+    }
+
+    pub async fn find_by_id(
+        &self,
+        id: i32,
+        user_id: i32,
+    ) -> Result<Option<notebook::Model>, DbErr> {
+        Notebook::find_by_id(id)
+            .filter(notebook::Column::UserId.eq(user_id))
+            .one(self.db.as_ref())
+            .await
+    }
+
+    // 같은 부모 아래에서 이름이 같은 노트북. 부모가 NULL이면 UNIQUE 인덱스로 막을 수 없어 조회로 확인한다.
+    pub async fn find_sibling_by_name(
+        &self,
+        user_id: i32,
+        parent_id: Option<i32>,
+        name: &str,
+    ) -> Result<Option<notebook::Model>, DbErr> {
+        let parent = match parent_id {
+            Some(parent_id) => notebook::Column::ParentId.eq(parent_id),
+            None => notebook::Column::ParentId.is_null(),
+        };
+        Notebook::find()
+            .filter(notebook::Column::UserId.eq(user_id))
+            .filter(parent)
+            .filter(notebook::Column::Name.eq(name))
+            .one(self.db.as_ref())
+            .await
+    }
+
+    // `id`와 그 아래 모든 노트북의 ID. 사용자의 노트북 수가 많지 않아 한 번에 읽어 메모리에서 따라간다.
+    pub async fn find_subtree_ids(&self, user_id: i32, id: i32) -> Result<Vec<i32>, DbErr> {
+        let edges: Vec<(i32, Option<i32>)> = Notebook::find()
+            .select_only()
+            .column(notebook::Column::Id)
+            .column(notebook::Column::ParentId)
+            .filter(notebook::Column::UserId.eq(user_id))
+            .into_tuple()
+            .all(self.db.as_ref())
+            .await?;
+
+        let mut children: HashMap<i32, Vec<i32>> = HashMap::new();
+        for (child, parent) in edges {
+            if let Some(parent) = parent {
+                children.entry(parent).or_default().push(child);
+            }
+        }
+
+        let mut ids = vec![id];
+        let mut seen = HashSet::from([id]);
+        let mut next = 0;
+        while let Some(&current) = ids.get(next) {
+            next += 1;
+            for &child in children.get(&current).into_iter().flatten() {
+                if seen.insert(child) {
+                    ids.push(child);
+                }
+            }
+        }
+        Ok(ids)
+    }
+
+    pub async fn create(
+        &self,
+        user_id: i32,
+        parent_id: Option<i32>,
+        name: String,
+    ) -> Result<notebook::Model, DbErr> {
+        let now = Utc::now().naive_utc();
+
+        let active_model = notebook::ActiveModel {
+            user_id: Set(user_id),
+            parent_id: Set(parent_id),
+            name: Set(name),
+            created_at: Set(now),
+            updated_at: Set(now),
+            ..Default::default()
+        };
+
+        active_model.insert(self.db.as_ref()).await
+    }
+
+    pub async fn update(
+        &self,
+        notebook: notebook::Model,
+        name: String,
+        parent_id: Option<i32>,
+    ) -> Result<notebook::Model, DbErr> {
+        let mut active_model: notebook::ActiveModel = notebook.into();
+        active_model.name = Set(name);
+        active_model.parent_id = Set(parent_id);
+        active_model.updated_at = Set(Utc::now().naive_utc());
+
+        active_model.update(self.db.as_ref()).await
+    }
+
+    // 노트북 바로 아래의 메모와 노트북을 부모(최상위 노트북이면 노트북 밖)로 옮긴 뒤 삭제한다.
+    // 옮겨질 하위 노트북과 같은 이름의 노트북이 새 부모 아래에 이미 있으면 아무것도 바꾸지 않고 false를 돌려준다.
+    pub async fn delete_into_parent(&self, notebook: &notebook::Model) -> Result<bool, DbErr> {
+        let txn = self.db.begin().await?;
+
+        let child_names: Vec<String> = Notebook::find()
+            .select_only()
+            .column(notebook::Column::Name)
+            .filter(notebook::Column::ParentId.eq(notebook.id))
+            .into_tuple()
+            .all(&txn)
+            .await?;
+        if !child_names.is_empty() {
+            let parent = match notebook.parent_id {
+                Some(parent_id) => notebook::Column::ParentId.eq(parent_id),
+                None => notebook::Column::ParentId.is_null(),
+            };
+            let conflicts = Notebook::find()
+                .filter(notebook::Column::UserId.eq(notebook.user_id))
+                .filter(parent)
+                .filter(notebook::Column::Id.ne(notebook.id))
+                .filter(notebook::Column::Name.is_in(child_names))
+                .count(&txn)
+                .await?;
+            if conflicts > 0 {
+                txn.rollback().await?;
+                return Ok(false);
+            }
+        }
+
+        Memo::update_many()
+            .col_expr(memo::Column::NotebookId, Expr::value(notebook.parent_id))
+            .filter(memo::Column::NotebookId.eq(notebook.id))
+            .exec(&txn)
+            .await?;
+        Notebook::update_many()
+            .col_expr(notebook::Column::ParentId, Expr::value(notebook.parent_id))
+            .filter(notebook::Column::ParentId.eq(notebook.id))
+            .exec(&txn)
+            .await?;
+        Notebook::delete_by_id(notebook.id).exec(&txn).await?;
+
+        txn.commit().await?;
+        Ok(true)
+    }
+
+    // 하위 노트북은 FK CASCADE로 함께 삭제된다.
+    pub async fn delete(&self, id: i32) -> Result<DeleteResult, DbErr> {
+        Notebook::delete_by_id(id).exec(self.db.as_ref()).await
+    }
+
+    // 노트북 바로 아래에 있는 메모 수 (하위 노트북과 휴지통의 메모는 세지 않는다)
+    pub async fn count_memos(&self, user_id: i32) -> Result<HashMap<i32, i64>, DbErr> {
+        let counts: Vec<(i32, i64)> = Memo::find()
+            .select_only()
+            .column(memo::Column::NotebookId)
+            .column_as(memo::Column::Id.count(), "memo_count")
+            .filter(memo::Column::UserId.eq(user_id))
+            .filter(memo::Column::NotebookId.is_not_null())
+            .filter(memo::Column::DeletedAt.is_null())
+            .group_by(memo::Column::NotebookId)
+            .into_tuple()
+            .all(self.db.as_ref())
+            .await?;
+
+        Ok(counts.into_iter().collect())
+    }
+}
@@ -26,10 +26,7 @@ impl OAuthAccountRepository {
             .await
     }
 
-    pub async fn find_by_user_id(
-        &self,
-        user_id: i32,
-    ) -> Result<Vec<oauth_account::Model>, DbErr> {
+    pub async fn find_by_user_id(&self, user_id: i32) -> Result<Vec<oauth_account::Model>, DbErr> {
         OAuthAccount::find()
             .filter(oauth_account::Column::UserId.eq(user_id))
             .all(self.db.as_ref())
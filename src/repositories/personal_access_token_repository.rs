@@ -0,0 +1,105 @@
+use chrono::{NaiveDateTime, Utc};
+use sea_orm::{sea_query::Expr, *};
+use std::sync::Arc;
+
+use crate::entities::personal_access_token::{self, Entity as PersonalAccessToken};
+
+#[derive(Clone)]
+pub struct PersonalAccessTokenRepository {
+    db: Arc<DatabaseConnection>,
+}
+
+impl PersonalAccessTokenRepository {
+    pub fn new(db: Arc<DatabaseConnection>) -> Self {
+        Self { db }
+    }
+
+    pub async fn find_by_hash(
+        &self,
+        token_hash: &str,
+    ) -> Result<Option<personal_access_token::Model>, DbErr> {
+        PersonalAccessToken::find()
+            .filter(personal_access_token::Column::TokenHash.eq(token_hash))
+            .one(self.db.as_ref())
+            .await
+    }
+
+    pub async fn find_active_by_user_id(
+        &self,
+        user_id: i32,
+    ) -> Result<Vec<personal_access_token::Model>, DbErr> {
+        PersonalAccessToken::find()
+            .filter(personal_access_token::Column::UserId.eq(user_id))
+            .filter(personal_access_token::Column::RevokedAt.is_null())
+            .order_by_desc(personal_access_token::Column::CreatedAt)
+            .all(self.db.as_ref())
+            .await
+    }
+
+    pub async fn create(
+        &self,
+        user_id: i32,
+        name: String,
+        token_prefix: String,
+        token_hash: String,
+        scopes: String,
+        expires_at: Option<NaiveDateTime>,
+    ) -> Result<personal_access_token::Model, DbErr> {
+        let active_model = personal_access_token::ActiveModel {
+            user_id: Set(user_id),
+            name: Set(name),
+            token_prefix: Set(token_prefix),
+            token_hash: Set(token_hash),
+            scopes: Set(scopes),
+            expires_at: Set(expires_at),
+            last_used_at: Set(None),
+            revoked_at: Set(None),
+            created_at: Set(Utc::now().naive_utc()),
+            ..Default::default()
+        };
+
+        active_model.insert(self.db.as_ref()).await
+    }
+
+    pub async fn revoke(&self, id: i32, user_id: i32) -> Result<bool, DbErr> {
+        let result = PersonalAccessToken::update_many()
+            .col_expr(
+                personal_access_token::Column::RevokedAt,
+                Expr::value(Utc::now().naive_utc()),
+            )
+            .filter(personal_access_token::Column::Id.eq(id))
+            .filter(personal_access_token::Column::UserId.eq(user_id))
+            .filter(personal_access_token::Column::RevokedAt.is_null())
+            .exec(self.db.as_ref())
+            .await?;
+
+        Ok(result.rows_affected == 1)
+    }
+
+    pub async fn revoke_by_user_id(&self, user_id: i32) -> Result<UpdateResult, DbErr> {
+        PersonalAccessToken::update_many()
+            .col_expr(
+                personal_access_token::Column::RevokedAt,
+                Expr::value(Utc::now().naive_utc()),
+            )
+            .filter(personal_access_token::Column::UserId.eq(user_id))
+            .filter(personal_access_token::Column::RevokedAt.is_null())
+            .exec(self.db.as_ref())
+            .await
+    }
+
+    pub async fn touch_last_used(
+        &self,
+        id: i32,
+        used_at: NaiveDateTime,
+    ) -> Result<UpdateResult, DbErr> {
+        PersonalAccessToken::update_many()
+            .col_expr(
+                personal_access_token::Column::LastUsedAt,
+                Expr::value(used_at),
+            )
+            .filter(personal_access_token::Column::Id.eq(id))
+            .exec(self.db.as_ref())
+            .await
+    }
+}
@@ -1,31 +1,88 @@
 use async_trait::async_trait;
+use chrono::NaiveDateTime;
 use qdrant_client::{
     qdrant::{
-        vectors_config::Config, CreateCollection, Distance, PointStruct, Value, VectorParams,
-        VectorsConfig,
+        value::Kind, vectors_config::Config, Condition, CreateCollection, Distance, Filter,
+        NullValue, PointStruct, Range, Value, VectorParams, VectorsConfig,
     },
     Qdrant,
 };
 use sea_orm::DbErr;
 use std::collections::HashMap;
 
+use super::MemoListFilter;
+use crate::entities::memo;
+
+// 의미 기반 검색을 검색어의 필드 조건으로 거를 수 있도록 메모 속성을 포인트 페이로드에 함께 둔다.
+// 시각은 범위 조건을 쓸 수 있게 마이크로초 단위 정수로 저장한다.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MemoPayload {
+    pub memo_id: i32,
+    pub user_id: i32,
+    pub is_pinned: bool,
+    pub is_archived: bool,
+    pub notebook_id: Option<i32>,
+    pub created_at: NaiveDateTime,
+    pub updated_at: NaiveDateTime,
+    pub tags: Vec<String>,
+    /// 휴지통에 있는 메모는 검색에서 빠진다
+    pub deleted: bool,
+}
+
+impl MemoPayload {
+    pub fn new(memo: &memo::Model, tags: Vec<String>) -> Self {
+        Self {
+            memo_id: memo.id,
+            user_id: memo.user_id,
+            is_pinned: memo.is_pinned,
+            is_archived: memo.is_archived,
+            notebook_id: memo.notebook_id,
+            created_at: memo.created_at,
+            updated_at: memo.updated_at,
+            tags,
+            deleted: memo.deleted_at.is_some(),
+        }
+    }
+
+    // 페이로드 갱신은 키 단위로 덮어쓰므로, 노트북에서 꺼낸 메모는 키를 빼지 않고 null로 둔다.
+    fn into_map(self) -> HashMap<String, Value> {
+        let notebook_id = match self.notebook_id {
+            Some(id) => (id as i64).into(),
+            None => Kind::NullValue(NullValue::NullValue.into()).into(),
+        };
+        HashMap::from([
+            ("memo_id".to_string(), (self.memo_id as i64).into()),
+            ("user_id".to_string(), (self.user_id as i64).into()),
+            ("is_pinned".to_string(), self.is_pinned.into()),
+            ("is_archived".to_string(), self.is_archived.into()),
+            ("notebook_id".to_string(), notebook_id),
+            ("created_at".to_string(), micros(self.created_at).into()),
+            ("updated_at".to_string(), micros(self.updated_at).into()),
+            ("tags".to_string(), self.tags.into()),
+            ("deleted".to_string(), self.deleted.into()),
+        ])
+    }
+}
+
 #[async_trait]
 pub trait QdrantRepo: Send + Sync {
-    async fn upsert_memo(
-        &self,
-        memo_id: i32,
-        user_id: i32,
-        vector: Vec<f32>,
-    ) -> Result<(), DbErr>;
+    async fn upsert_memo(&self, payload: MemoPayload, vector: Vec<f32>) -> Result<(), DbErr>;
 
+    // 본문이 바뀌지 않아 임베딩을 다시 만들 필요가 없을 때 페이로드만 갱신한다.
+    async fn update_payload(&self, payload: MemoPayload) -> Result<(), DbErr>;
+
+    // 유사도(코사인) 내림차순으로 (메모 ID, 점수)를 돌려준다.
     async fn search_similar(
         &self,
         user_id: i32,
         query_vector: Vec<f32>,
+        filter: &MemoListFilter,
         limit: u64,
-    ) -> Result<Vec<i32>, DbErr>;
+    ) -> Result<Vec<(i32, f32)>, DbErr>;
+
+    async fn delete_memos(&self, memo_ids: &[i32]) -> Result<(), DbErr>;
 
-    async fn delete_memo(&self, memo_id: i32) -> Result<(), DbErr>;
+    async fn delete_user_memos(&self, user_id: i32) -> Result<(), DbErr>;
 }
 
 #[derive(Clone)]
@@ -85,19 +142,10 @@ impl QdrantRepository {
 
 #[async_trait]
 impl QdrantRepo for QdrantRepository {
-    async fn upsert_memo(
-        &self,
-        memo_id: i32,
-        user_id: i32,
-        vector: Vec<f32>,
-    ) -> Result<(), DbErr> {
+    async fn upsert_memo(&self, payload: MemoPayload, vector: Vec<f32>) -> Result<(), DbErr> {
         use qdrant_client::qdrant::UpsertPoints;
 
-        let mut payload: HashMap<String, Value> = HashMap::new();
-        payload.insert("user_id".to_string(), (user_id as i64).into());
-        payload.insert("memo_id".to_string(), (memo_id as i64).into());
-
-        let point = PointStruct::new(memo_id as u64, vector, payload);
+        let point = PointStruct::new(payload.memo_id as u64, vector, payload.into_map());
 
         self.client
             .upsert_points(UpsertPoints {
@@ -111,13 +159,36 @@ impl QdrantRepo for QdrantRepository {
         Ok(())
     }
 
+    async fn update_payload(&self, payload: MemoPayload) -> Result<(), DbErr> {
+        use qdrant_client::qdrant::{
+            points_selector::PointsSelectorOneOf, PointsIdsList, PointsSelector, SetPayloadPoints,
+        };
+
+        self.client
+            .set_payload(SetPayloadPoints {
+                collection_name: self.collection_name.clone(),
+                points_selector: Some(PointsSelector {
+                    points_selector_one_of: Some(PointsSelectorOneOf::Points(PointsIdsList {
+                        ids: vec![(payload.memo_id as u64).into()],
+                    })),
+                }),
+                payload: payload.into_map(),
+                ..Default::default()
+            })
+            .await
+            .map_err(|e| DbErr::Custom(format!("Failed to update memo payload: {}", e)))?;
+
+        Ok(())
+    }
+
     async fn search_similar(
         &self,
         user_id: i32,
         query_vector: Vec<f32>,
+        filter: &MemoListFilter,
         limit: u64,
-    ) -> Result<Vec<i32>, DbErr> {
-        use qdrant_client::qdrant::{Condition, Filter, SearchPoints};
+    ) -> Result<Vec<(i32, f32)>, DbErr> {
+        use qdrant_client::qdrant::SearchPoints;
 
         let search_result = self
             .client
@@ -125,17 +196,14 @@ impl QdrantRepo for QdrantRepository {
                 collection_name: self.collection_name.clone(),
                 vector: query_vector,
                 limit,
-                filter: Some(Filter::must([Condition::matches(
-                    "user_id",
-                    user_id as i64,
-                )])),
+                filter: Some(payload_filter(user_id, filter)),
                 with_payload: Some(true.into()),
                 ..Default::default()
             })
             .await
             .map_err(|e| DbErr::Custom(format!("Failed to search similar memos: {}", e)))?;
 
-        let memo_ids = search_result
+        let memos = search_result
             .result
             .into_iter()
             .filter_map(|point| {
@@ -143,14 +211,14 @@ impl QdrantRepo for QdrantRepository {
                     .payload
                     .get("memo_id")
                     .and_then(|v| v.as_integer())
-                    .map(|id| id as i32)
+                    .map(|id| (id as i32, point.score))
             })
             .collect();
 
-        Ok(memo_ids)
+        Ok(memos)
     }
 
-    async fn delete_memo(&self, memo_id: i32) -> Result<(), DbErr> {
+    async fn delete_memos(&self, memo_ids: &[i32]) -> Result<(), DbErr> {
         use qdrant_client::qdrant::{
             points_selector::PointsSelectorOneOf, DeletePoints, PointsIdsList, PointsSelector,
         };
@@ -160,7 +228,7 @@ impl QdrantRepo for QdrantRepository {
                 collection_name: self.collection_name.clone(),
                 points: Some(PointsSelector {
                     points_selector_one_of: Some(PointsSelectorOneOf::Points(PointsIdsList {
-                        ids: vec![(memo_id as u64).into()],
+                        ids: memo_ids.iter().map(|&id| (id as u64).into()).collect(),
                     })),
                 }),
                 ..Default::default()
@@ -170,4 +238,87 @@ impl QdrantRepo for QdrantRepository {
 
         Ok(())
     }
+
+    async fn delete_user_memos(&self, user_id: i32) -> Result<(), DbErr> {
+        use qdrant_client::qdrant::{
+            points_selector::PointsSelectorOneOf, DeletePoints, PointsSelector,
+        };
+
+        self.client
+            .delete_points(DeletePoints {
+                collection_name: self.collection_name.clone(),
+                points: Some(PointsSelector {
+                    points_selector_one_of: Some(PointsSelectorOneOf::Filter(Filter::must([
+                        Condition::matches("user_id", user_id as i64),
+                    ]))),
+                }),
+                wait: Some(true),
+                ..Default::default()
+            })
+            .await
+            .map_err(|e| DbErr::Custom(format!("Failed to delete user memos: {}", e)))?;
+
+        Ok(())
+    }
+}
+
+// 메모 저장소의 목록 필터와 같은 조건(작성/수정 시각은 시작 포함, 끝 제외, 태그는 모두 포함)을 페이로드 조건으로 옮긴다.
+fn payload_filter(user_id: i32, filter: &MemoListFilter) -> Filter {
+    let mut conditions = vec![Condition::matches("user_id", user_id as i64)];
+    if let Some(is_pinned) = filter.is_pinned {
+        conditions.push(Condition::matches("is_pinned", is_pinned));
+    }
+
+    let ranges = [
+        ("created_at", filter.created_since, filter.created_until),
+        ("updated_at", filter.updated_since, filter.updated_until),
+    ];
+    for (field, since, until) in ranges {
+        if since.is_none() && until.is_none() {
+            continue;
+        }
+        conditions.push(Condition::range(
+            field,
+            Range {
+                gte: since.map(|since| micros(since) as f64),
+                lt: until.map(|until| micros(until) as f64),
+                ..Default::default()
+            },
+        ));
+    }
+
+    if let Some(notebook_ids) = &filter.notebook_ids {
+        let ids: Vec<i64> = notebook_ids.iter().map(|&id| id as i64).collect();
+        conditions.push(Condition::matches("notebook_id", ids));
+    }
+
+    // 배열 페이로드에 대한 일치 조건은 원소 중 하나라도 같으면 참이다.
+    conditions.extend(
+        filter
+            .tags
+            .iter()
+            .map(|tag| Condition::matches("tags", tag.clone())),
+    );
+    // `deleted`, `is_archived` 키가 없는 예전 포인트도 찾히도록 `false`와 일치시키는 대신 `true`를 제외한다.
+    let mut excluded = vec![Condition::matches("deleted", true)];
+    match filter.is_archived {
+        Some(true) => conditions.push(Condition::matches("is_archived", true)),
+        Some(false) => excluded.push(Condition::matches("is_archived", true)),
+        None => {}
+    }
+    excluded.extend(
+        filter
+            .excluded_tags
+            .iter()
+            .map(|tag| Condition::matches("tags", tag.clone())),
+    );
+    Filter {
+        must: conditions,
+        must_not: excluded,
+        ..Default::default()
+    }
+}
+
+fn micros(time: NaiveDateTime) -> i64 {
+    time.and_utc().timestamp_micros()
 }
@@ -0,0 +1,92 @@
+use chrono::Utc;
+use sea_orm::{sea_query::Expr, *};
+use std::sync::Arc;
+
+use crate::entities::recovery_code::{self, Entity as RecoveryCode};
+
+#[derive(Clone)]
+pub struct RecoveryCodeRepository {
+    db: Arc<DatabaseConnection>,
+}
+
+impl RecoveryCodeRepository {
+    pub fn new(db: Arc<DatabaseConnection>) -> Self {
+        Self { db }
+    }
+
+    // `codes`는 (앞부분, 해시) 쌍이다.
+    pub async fn replace_for_user(
+        &self,
+        user_id: i32,
+        codes: Vec<(String, String)>,
+    ) -> Result<(), DbErr> {
+        let txn = self.db.begin().await?;
+
+        RecoveryCode::delete_many()
+            .filter(recovery_code::Column::UserId.eq(user_id))
+            .exec(&txn)
+            .await?;
+
+        let now = Utc::now().naive_utc();
+        let models = codes
+            .into_iter()
+            .map(|(code_prefix, code_hash)| recovery_code::ActiveModel {
+                user_id: Set(user_id),
+                code_hash: Set(code_hash),
+                code_prefix: Set(Some(code_prefix)),
+                used_at: Set(None),
+                created_at: Set(now),
+                ..Default::default()
+            });
+        RecoveryCode::insert_many(models).exec(&txn).await?;
+
+        txn.commit().await
+    }
+
+    // 앞부분이 같은 코드와 앞부분이 없는 이전 형식의 코드만 후보로 돌려준다.
+    pub async fn find_unused_candidates(
+        &self,
+        user_id: i32,
+        code_prefix: &str,
+    ) -> Result<Vec<recovery_code::Model>, DbErr> {
+        RecoveryCode::find()
+            .filter(recovery_code::Column::UserId.eq(user_id))
+            .filter(recovery_code::Column::UsedAt.is_null())
+            .filter(
+                Condition::any()
+                    .add(recovery_code::Column::CodePrefix.eq(code_prefix))
+                    .add(recovery_code::Column::CodePrefix.is_null()),
+            )
+            .all(self.db.as_ref())
+            .await
+    }
+
+    pub async fn mark_used(&self, id: i32) -> Result<bool, DbErr> {
+        let result = RecoveryCode::update_many()
+            .col_expr(
+                recovery_code::Column::UsedAt,
+                Expr::value(Utc::now().naive_utc()),
+            )
+            .filter(recovery_code::Column::Id.eq(id))
+            .filter(recovery_code::Column::UsedAt.is_null())
+            .exec(self.db.as_ref())
+            .await?;
+
+        Ok(result.rows_affected >= 1)
+    }
+
+    pub async fn count_unused(&self, user_id: i32) -> Result<u64, DbErr> {
+        RecoveryCode::find()
+            .filter(recovery_code::Column::UserId.eq(user_id))
+            .filter(recovery_code::Column::UsedAt.is_null())
+            .count(self.db.as_ref())
+            .await
+    }
+
+    pub async fn delete_by_user_id(&self, user_id: i32) -> Result<DeleteResult, DbErr> {
+        RecoveryCode::delete_many()
+            .filter(recovery_code::Column::UserId.eq(user_id))
+            .exec(self.db.as_ref())
+            .await
+    }
+}
@@ -0,0 +1,85 @@
+use chrono::{NaiveDateTime, Utc};
+use sea_orm::{sea_query::Expr, *};
+use std::sync::Arc;
+
+use crate::entities::refresh_token::{self, Entity as RefreshToken};
+
+#[derive(Clone)]
+pub struct RefreshTokenRepository {
+    db: Arc<DatabaseConnection>,
+}
+
+impl RefreshTokenRepository {
+    pub fn new(db: Arc<DatabaseConnection>) -> Self {
+        Self { db }
+    }
+
+    pub async fn find_by_hash(
+        &self,
+        token_hash: &str,
+    ) -> Result<Option<refresh_token::Model>, DbErr> {
+        RefreshToken::find()
+            .filter(refresh_token::Column::TokenHash.eq(token_hash))
+            .one(self.db.as_ref())
+            .await
+    }
+
+    pub async fn create(
+        &self,
+        user_id: i32,
+        family_id: String,
+        token_hash: String,
+        expires_at: NaiveDateTime,
+    ) -> Result<refresh_token::Model, DbErr> {
+        let active_model = refresh_token::ActiveModel {
+            user_id: Set(user_id),
+            family_id: Set(family_id),
+            token_hash: Set(token_hash),
+            expires_at: Set(expires_at),
+            revoked_at: Set(None),
+            rotated_at: Set(None),
+            created_at: Set(Utc::now().naive_utc()),
+            ..Default::default()
+        };
+
+        active_model.insert(self.db.as_ref()).await
+    }
+
+    // 갱신으로 새 토큰을 발급할 때 이전 토큰을 폐기한다. 이미 폐기된 토큰이면 false를 돌려준다.
+    pub async fn rotate(&self, id: i32) -> Result<bool, DbErr> {
+        let now = Utc::now().naive_utc();
+        let result = RefreshToken::update_many()
+            .col_expr(refresh_token::Column::RevokedAt, Expr::value(now))
+            .col_expr(refresh_token::Column::RotatedAt, Expr::value(now))
+            .filter(refresh_token::Column::Id.eq(id))
+            .filter(refresh_token::Column::RevokedAt.is_null())
+            .exec(self.db.as_ref())
+            .await?;
+
+        Ok(result.rows_affected == 1)
+    }
+
+    pub async fn revoke_family(&self, family_id: &str) -> Result<UpdateResult, DbErr> {
+        RefreshToken::update_many()
+            .col_expr(
+                refresh_token::Column::RevokedAt,
+                Expr::value(Utc::now().naive_utc()),
+            )
+            .filter(refresh_token::Column::FamilyId.eq(family_id))
+            .filter(refresh_token::Column::RevokedAt.is_null())
+            .exec(self.db.as_ref())
+            .await
+    }
+
+    pub async fn revoke_by_user_id(&self, user_id: i32) -> Result<UpdateResult, DbErr> {
+        RefreshToken::update_many()
+            .col_expr(
+                refresh_token::Column::RevokedAt,
+                Expr::value(Utc::now().naive_utc()),
+            )
+            .filter(refresh_token::Column::UserId.eq(user_id))
+            .filter(refresh_token::Column::RevokedAt.is_null())
+            .exec(self.db.as_ref())
+            .await
+    }
+}
@@ -0,0 +1,122 @@
+use chrono::Utc;
+use sea_orm::{sea_query::Expr, *};
+use std::sync::Arc;
+
+use crate::entities::saved_search::{self, Entity as SavedSearch};
+
+#[derive(Clone)]
+pub struct SavedSearchRepository {
+    db: Arc<DatabaseConnection>,
+}
+
+impl SavedSearchRepository {
+    pub fn new(db: Arc<DatabaseConnection>) -> Self {
+        Self { db }
+    }
+
+    pub async fn find_by_user_id(&self, user_id: i32) -> Result<Vec<saved_search::Model>, DbErr> {
+        SavedSearch::find()
+            .filter(saved_search::Column::UserId.eq(user_id))
+            .order_by_asc(saved_search::Column::Position)
+            .order_by_asc(saved_search::Column::Id)
+            .all(self.db.as_ref())
+            .await
+    }
+
+    pub async fn find_by_id(
+        &self,
+        id: i32,
+        user_id: i32,
+    ) -> Result<Option<saved_search::Model>, DbErr> {
+        SavedSearch::find_by_id(id)
+            .filter(saved_search::Column::UserId.eq(user_id))
+            .one(self.db.as_ref())
+            .await
+    }
+
+    pub async fn find_by_name(
+        &self,
+        user_id: i32,
+        name: &str,
+    ) -> Result<Option<saved_search::Model>, DbErr> {
+        SavedSearch::find()
+            .filter(saved_search::Column::UserId.eq(user_id))
+            .filter(saved_search::Column::Name.eq(name))
+            .one(self.db.as_ref())
+            .await
+    }
+
+    // 새 검색은 목록의 맨 뒤에 붙인다.
+    pub async fn create(
+        &self,
+        user_id: i32,
+        name: String,
+        query: String,
+    ) -> Result<saved_search::Model, DbErr> {
+        let last_position: Option<i32> = SavedSearch::find()
+            .select_only()
+            .column_as(saved_search::Column::Position.max(), "last_position")
+            .filter(saved_search::Column::UserId.eq(user_id))
+            .into_tuple()
+            .one(self.db.as_ref())
+            .await?
+            .flatten();
+
+        let now = Utc::now().naive_utc();
+        let active_model = saved_search::ActiveModel {
+            user_id: Set(user_id),
+            name: Set(name),
+            query: Set(query),
+            position: Set(last_position.map_or(0, |position| position + 1)),
+            created_at: Set(now),
+            updated_at: Set(now),
+            ..Default::default()
+        };
+
+        active_model.insert(self.db.as_ref()).await
+    }
+
+    pub async fn update(
+        &self,
+        saved_search: saved_search::Model,
+        name: Option<String>,
+        query: Option<String>,
+    ) -> Result<saved_search::Model, DbErr> {
+        let mut active_model: saved_search::ActiveModel = saved_search.into();
+        if let Some(name) = name {
+            active_model.name = Set(name);
+        }
+        if let Some(query) = query {
+            active_model.query = Set(query);
+        }
+        active_model.updated_at = Set(Utc::now().naive_utc());
+
+        active_model.update(self.db.as_ref()).await
+    }
+
+    pub async fn delete(&self, id: i32, user_id: i32) -> Result<bool, DbErr> {
+        let result = SavedSearch::delete_many()
+            .filter(saved_search::Column::Id.eq(id))
+            .filter(saved_search::Column::UserId.eq(user_id))
+            .exec(self.db.as_ref())
+            .await?;
+
+        Ok(result.rows_affected == 1)
+    }
+
+    // `ids`의 순서대로 0부터 위치를 다시 매긴다.
+    pub async fn reorder(&self, user_id: i32, ids: &[i32]) -> Result<(), DbErr> {
+        let txn = self.db.begin().await?;
+
+        for (position, id) in ids.iter().enumerate() {
+            SavedSearch::update_many()
+                .col_expr(saved_search::Column::Position, Expr::value(position as i32))
+                .filter(saved_search::Column::Id.eq(*id))
+                .filter(saved_search::Column::UserId.eq(user_id))
+                .exec(&txn)
+                .await?;
+        }
+
+        txn.commit().await
+    }
+}
@@ -0,0 +1,103 @@
+use chrono::{NaiveDateTime, Utc};
+use sea_orm::{sea_query::Expr, *};
+use std::sync::Arc;
+
+use crate::entities::session::{self, Entity as Session};
+
+#[derive(Clone)]
+pub struct SessionRepository {
+    db: Arc<DatabaseConnection>,
+}
+
+impl SessionRepository {
+    pub fn new(db: Arc<DatabaseConnection>) -> Self {
+        Self { db }
+    }
+
+    pub async fn find_by_id(&self, id: &str) -> Result<Option<session::Model>, DbErr> {
+        Session::find_by_id(id.to_string())
+            .one(self.db.as_ref())
+            .await
+    }
+
+    pub async fn find_active_by_user_id(&self, user_id: i32) -> Result<Vec<session::Model>, DbErr> {
+        Session::find()
+            .filter(session::Column::UserId.eq(user_id))
+            .filter(session::Column::RevokedAt.is_null())
+            .order_by_desc(session::Column::LastSeenAt)
+            .all(self.db.as_ref())
+            .await
+    }
+
+    pub async fn create(
+        &self,
+        id: String,
+        user_id: i32,
+        user_agent: Option<String>,
+        ip_address: Option<String>,
+    ) -> Result<session::Model, DbErr> {
+        let now = Utc::now().naive_utc();
+
+        let active_model = session::ActiveModel {
+            id: Set(id),
+            user_id: Set(user_id),
+            user_agent: Set(user_agent),
+            ip_address: Set(ip_address),
+            created_at: Set(now),
+            last_seen_at: Set(now),
+            revoked_at: Set(None),
+        };
+
+        active_model.insert(self.db.as_ref()).await
+    }
+
+    pub async fn touch(&self, id: &str, seen_at: NaiveDateTime) -> Result<UpdateResult, DbErr> {
+        Session::update_many()
+            .col_expr(session::Column::LastSeenAt, Expr::value(seen_at))
+            .filter(session::Column::Id.eq(id))
+            .exec(self.db.as_ref())
+            .await
+    }
+
+    pub async fn revoke(&self, id: &str, user_id: i32) -> Result<bool, DbErr> {
+        let result = Session::update_many()
+            .col_expr(
+                session::Column::RevokedAt,
+                Expr::value(Utc::now().naive_utc()),
+            )
+            .filter(session::Column::Id.eq(id))
+            .filter(session::Column::UserId.eq(user_id))
+            .filter(session::Column::RevokedAt.is_null())
+            .exec(self.db.as_ref())
+            .await?;
+
+        Ok(result.rows_affected == 1)
+    }
+
+    pub async fn revoke_all_except(
+        &self,
+        user_id: i32,
+        keep_id: &str,
+    ) -> Result<Vec<String>, DbErr> {
+        let sessions = Session::find()
+            .filter(session::Column::UserId.eq(user_id))
+            .filter(session::Column::Id.ne(keep_id))
+            .filter(session::Column::RevokedAt.is_null())
+            .all(self.db.as_ref())
+            .await?;
+        let ids: Vec<String> = sessions.into_iter().map(|s| s.id).collect();
+
+        if !ids.is_empty() {
+            Session::update_many()
+                .col_expr(
+                    session::Column::RevokedAt,
+                    Expr::value(Utc::now().naive_utc()),
+                )
+                .filter(session::Column::Id.is_in(ids.clone()))
+                .exec(self.db.as_ref())
+                .await?;
+        }
+
+        Ok(ids)
+    }
+}
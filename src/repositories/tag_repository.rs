@@ -0,0 +1,220 @@
+use chrono::Utc;
+use sea_orm::{
+    sea_query::{Expr, OnConflict, Query},
+    *,
+};
+use std::{collections::HashMap, sync::Arc};
+
+use crate::entities::{
+    memo::{self, Entity as Memo},
+    memo_tag::{self, Entity as MemoTag},
+    tag::{self, Entity as Tag},
+};
+
+#[derive(Clone)]
+pub struct TagRepository {
+    db: Arc<DatabaseConnection>,
+}
+
+impl TagRepository {
+    pub fn new(db: Arc<DatabaseConnection>) -> Self {
+        Self { db }
+    }
+
+    pub async fn find_by_user_id(&self, user_id: i32) -> Result<Vec<tag::Model>, DbErr> {
+        Tag::find()
+            .filter(tag::Column::UserId.eq(user_id))
+            .order_by_asc(tag::Column::Name)
+            .all(self.db.as_ref())
+            .await
+    }
+
+    pub async fn find_by_id(&self, id: i32, user_id: i32) -> Result<Option<tag::Model>, DbErr> {
+        Tag::find_by_id(id)
+            .filter(tag::Column::UserId.eq(user_id))
+            .one(self.db.as_ref())
+            .await
+    }
+
+    pub async fn find_by_name(
+        &self,
+        user_id: i32,
+        name: &str,
+    ) -> Result<Option<tag::Model>, DbErr> {
+        Tag::find()
+            .filter(tag::Column::UserId.eq(user_id))
+            .filter(tag::Column::Name.eq(name))
+            .one(self.db.as_ref())
+            .await
+    }
+
+    pub async fn create(&self, user_id: i32, name: String) -> Result<tag::Model, DbErr> {
+        let active_model = tag::ActiveModel {
+            user_id: Set(user_id),
+            name: Set(name),
+            created_at: Set(Utc::now().naive_utc()),
+            ..Default::default()
+        };
+
+        active_model.insert(self.db.as_ref()).await
+    }
+
+    // 메모에 태그를 붙일 때 없는 태그는 만든다. 동시에 같은 태그를 만들어도 충돌하지 않는다.
+    pub async fn find_or_create(
+        &self,
+        user_id: i32,
+        names: &[String],
+    ) -> Result<Vec<tag::Model>, DbErr> {
+        if names.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let now = Utc::now().naive_utc();
+        let models = names.iter().map(|name| tag::ActiveModel {
+            user_id: Set(user_id),
+            name: Set(name.clone()),
+            created_at: Set(now),
+            ..Default::default()
+        });
+        Tag::insert_many(models)
+            .on_conflict(
+                OnConflict::columns([tag::Column::UserId, tag::Column::Name])
+                    .do_nothing()
+                    .to_owned(),
+            )
+            .exec_without_returning(self.db.as_ref())
+            .await?;
+
+        Tag::find()
+            .filter(tag::Column::UserId.eq(user_id))
+            .filter(tag::Column::Name.is_in(names.to_vec()))
+            .all(self.db.as_ref())
+            .await
+    }
+
+    pub async fn rename(&self, tag: tag::Model, name: String) -> Result<tag::Model, DbErr> {
+        let mut active_model: tag::ActiveModel = tag.into();
+        active_model.name = Set(name);
+        active_model.update(self.db.as_ref()).await
+    }
+
+    // 메모와의 연결은 FK CASCADE로 함께 삭제된다.
+    pub async fn delete(&self, id: i32, user_id: i32) -> Result<bool, DbErr> {
+        let result = Tag::delete_many()
+            .filter(tag::Column::Id.eq(id))
+            .filter(tag::Column::UserId.eq(user_id))
+            .exec(self.db.as_ref())
+            .await?;
+
+        Ok(result.rows_affected == 1)
+    }
+
+    // `source`가 붙은 메모에 `target`을 붙이고 `source`를 삭제한다. 이미 둘 다 붙은 메모는 그대로 둔다.
+    pub async fn merge(&self, source_id: i32, target_id: i32) -> Result<(), DbErr> {
+        let txn = self.db.begin().await?;
+
+        let insert = Query::insert()
+            .into_table(MemoTag)
+            .columns([memo_tag::Column::MemoId, memo_tag::Column::TagId])
+            .select_from(
+                Query::select()
+                    .column(memo_tag::Column::MemoId)
+                    .expr(Expr::val(target_id))
+                    .from(MemoTag)
+                    .and_where(memo_tag::Column::TagId.eq(source_id))
+                    .to_owned(),
+            )
+            .map_err(|e| DbErr::Custom(e.to_string()))?
+            .on_conflict(
+                OnConflict::columns([memo_tag::Column::MemoId, memo_tag::Column::TagId])
+                    .do_nothing()
+                    .to_owned(),
+            )
+            .to_owned();
+        txn.execute(txn.get_database_backend().build(&insert))
+            .await?;
+
+        Tag::delete_by_id(source_id).exec(&txn).await?;
+
+        txn.commit().await
+    }
+
+    pub async fn find_memo_ids(&self, tag_id: i32) -> Result<Vec<i32>, DbErr> {
+        MemoTag::find()
+            .select_only()
+            .column(memo_tag::Column::MemoId)
+            .filter(memo_tag::Column::TagId.eq(tag_id))
+            .into_tuple()
+            .all(self.db.as_ref())
+            .await
+    }
+
+    // 휴지통의 메모는 세지 않는다.
+    pub async fn count_memos(&self, tag_ids: &[i32]) -> Result<HashMap<i32, i64>, DbErr> {
+        if tag_ids.is_empty() {
+            return Ok(HashMap::new());
+        }
+
+        let counts: Vec<(i32, i64)> = MemoTag::find()
+            .select_only()
+            .column(memo_tag::Column::TagId)
+            .column_as(memo_tag::Column::MemoId.count(), "memo_count")
+            .inner_join(Memo)
+            .filter(memo_tag::Column::TagId.is_in(tag_ids.to_vec()))
+            .filter(memo::Column::DeletedAt.is_null())
+            .group_by(memo_tag::Column::TagId)
+            .into_tuple()
+            .all(self.db.as_ref())
+            .await?;
+
+        Ok(counts.into_iter().collect())
+    }
+
+    // 메모별 태그 이름 (이름순). 태그가 없는 메모는 결과에 없다.
+    pub async fn find_names_by_memo_ids(
+        &self,
+        memo_ids: &[i32],
+    ) -> Result<HashMap<i32, Vec<String>>, DbErr> {
+        if memo_ids.is_empty() {
+            return Ok(HashMap::new());
+        }
+
+        let rows: Vec<(i32, String)> = MemoTag::find()
+            .select_only()
+            .column(memo_tag::Column::MemoId)
+            .column(tag::Column::Name)
+            .inner_join(Tag)
+            .filter(memo_tag::Column::MemoId.is_in(memo_ids.to_vec()))
+            .order_by_asc(tag::Column::Name)
+            .into_tuple()
+            .all(self.db.as_ref())
+            .await?;
+
+        let mut names: HashMap<i32, Vec<String>> = HashMap::new();
+        for (memo_id, name) in rows {
+            names.entry(memo_id).or_default().push(name);
+        }
+        Ok(names)
+    }
+
+    pub async fn set_memo_tags(&self, memo_id: i32, tag_ids: &[i32]) -> Result<(), DbErr> {
+        let txn = self.db.begin().await?;
+
+        MemoTag::delete_many()
+            .filter(memo_tag::Column::MemoId.eq(memo_id))
+            .exec(&txn)
+            .await?;
+
+        if !tag_ids.is_empty() {
+            let models = tag_ids.iter().map(|tag_id| memo_tag::ActiveModel {
+                memo_id: Set(memo_id),
+                tag_id: Set(*tag_id),
+            });
+            MemoTag::insert_many(models)
+                .exec_without_returning(&txn)
+                .await?;
+        }
+
+        txn.commit().await
+    }
+}
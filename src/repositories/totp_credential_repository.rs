@@ -0,0 +1,120 @@
+use chrono::Utc;
+use sea_orm::{sea_query::Expr, *};
+use std::sync::Arc;
+
+use crate::entities::totp_credential::{self, Entity as TotpCredential};
+
+#[derive(Clone)]
+pub struct TotpCredentialRepository {
+    db: Arc<DatabaseConnection>,
+}
+
+impl TotpCredentialRepository {
+    pub fn new(db: Arc<DatabaseConnection>) -> Self {
+        Self { db }
+    }
+
+    pub async fn find_by_user_id(
+        &self,
+        user_id: i32,
+    ) -> Result<Option<totp_credential::Model>, DbErr> {
+        TotpCredential::find()
+            .filter(totp_credential::Column::UserId.eq(user_id))
+            .one(self.db.as_ref())
+            .await
+    }
+
+    pub async fn create(
+        &self,
+        user_id: i32,
+        secret: String,
+    ) -> Result<totp_credential::Model, DbErr> {
+        let active_model = totp_credential::ActiveModel {
+            user_id: Set(user_id),
+            secret: Set(secret),
+            enabled_at: Set(None),
+            enforce_on_oauth: Set(false),
+            last_used_step: Set(None),
+            failed_attempts: Set(0),
+            last_failed_at: Set(None),
+            created_at: Set(Utc::now().naive_utc()),
+            ..Default::default()
+        };
+
+        active_model.insert(self.db.as_ref()).await
+    }
+
+    pub async fn enable(&self, id: i32, step: i64) -> Result<UpdateResult, DbErr> {
+        TotpCredential::update_many()
+            .col_expr(
+                totp_credential::Column::EnabledAt,
+                Expr::value(Utc::now().naive_utc()),
+            )
+            .col_expr(totp_credential::Column::LastUsedStep, Expr::value(step))
+            .filter(totp_credential::Column::Id.eq(id))
+            .exec(self.db.as_ref())
+            .await
+    }
+
+    pub async fn set_enforce_on_oauth(
+        &self,
+        id: i32,
+        enforce_on_oauth: bool,
+    ) -> Result<UpdateResult, DbErr> {
+        TotpCredential::update_many()
+            .col_expr(
+                totp_credential::Column::EnforceOnOauth,
+                Expr::value(enforce_on_oauth),
+            )
+            .filter(totp_credential::Column::Id.eq(id))
+            .exec(self.db.as_ref())
+            .await
+    }
+
+    // 같은 시간 단계의 코드가 두 번 쓰이지 않도록 조건부로 갱신한다.
+    pub async fn record_success(&self, id: i32, step: i64) -> Result<bool, DbErr> {
+        let result = TotpCredential::update_many()
+            .col_expr(totp_credential::Column::LastUsedStep, Expr::value(step))
+            .col_expr(totp_credential::Column::FailedAttempts, Expr::value(0))
+            .filter(totp_credential::Column::Id.eq(id))
+            .filter(
+                Condition::any()
+                    .add(totp_credential::Column::LastUsedStep.is_null())
+                    .add(totp_credential::Column::LastUsedStep.lt(step)),
+            )
+            .exec(self.db.as_ref())
+            .await?;
+
+        Ok(result.rows_affected == 1)
+    }
+
+    pub async fn reset_failures(&self, id: i32) -> Result<UpdateResult, DbErr> {
+        TotpCredential::update_many()
+            .col_expr(totp_credential::Column::FailedAttempts, Expr::value(0))
+            .filter(totp_credential::Column::Id.eq(id))
+            .exec(self.db.as_ref())
+            .await
+    }
+
+    pub async fn record_failure(&self, id: i32) -> Result<UpdateResult, DbErr> {
+        TotpCredential::update_many()
+            .col_expr(
+                totp_credential::Column::FailedAttempts,
+                Expr::col(totp_credential::Column::FailedAttempts).add(1),
+            )
+            .col_expr(
+                totp_credential::Column::LastFailedAt,
+                Expr::value(Utc::now().naive_utc()),
+            )
+            .filter(totp_credential::Column::Id.eq(id))
+            .exec(self.db.as_ref())
+            .await
+    }
+
+    pub async fn delete_by_user_id(&self, user_id: i32) -> Result<DeleteResult, DbErr> {
+        TotpCredential::delete_many()
+            .filter(totp_credential::Column::UserId.eq(user_id))
+            .exec(self.db.as_ref())
+            .await
+    }
+}
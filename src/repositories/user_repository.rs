@@ -1,8 +1,11 @@
-use chrono::Utc;
-use sea_orm::*;
+use chrono::{NaiveDateTime, Utc};
+use sea_orm::{
+    sea_query::{extension::postgres::PgExpr, Expr},
+    *,
+};
 use std::sync::Arc;
 
-use crate::entities::user::{self, Entity as User};
+use crate::entities::user::{self, Entity as User, UserRole};
 
 #[derive(Clone)]
 pub struct UserRepository {
@@ -57,6 +60,7 @@ impl UserRepository {
         id: i32,
         username: Option<String>,
         email: Option<String>,
+        auto_tag_memos: Option<bool>,
     ) -> Result<user::Model, DbErr> {
         let user = self
             .find_by_id(id)
@@ -71,6 +75,26 @@ impl UserRepository {
         if let Some(email) = email {
             active_model.email = Set(email);
         }
+        if let Some(auto_tag_memos) = auto_tag_memos {
+            active_model.auto_tag_memos = Set(auto_tag_memos);
+        }
+        active_model.updated_at = Set(Utc::now().naive_utc());
+
+        active_model.update(self.db.as_ref()).await
+    }
+
+    pub async fn update_password(
+        &self,
+        id: i32,
+        password_hash: String,
+    ) -> Result<user::Model, DbErr> {
+        let user = self
+            .find_by_id(id)
+            .await?
+            .ok_or(DbErr::RecordNotFound("User not found".into()))?;
+
+        let mut active_model: user::ActiveModel = user.into();
+        active_model.password_hash = Set(Some(password_hash));
         active_model.updated_at = Set(Utc::now().naive_utc());
 
         active_model.update(self.db.as_ref()).await
@@ -79,4 +103,67 @@ impl UserRepository {
     pub async fn delete(&self, id: i32) -> Result<DeleteResult, DbErr> {
         User::delete_by_id(id).exec(self.db.as_ref()).await
     }
+
+    // 사용자 이름이나 이메일에 검색어가 포함된 사용자를 가입 순으로 조회한다.
+    // Postgres의 LIKE 기본 이스케이프 문자가 `\`이므로 와일드카드는 그대로 이스케이프해 넘긴다.
+    pub async fn search(
+        &self,
+        query: Option<&str>,
+        limit: u64,
+        offset: u64,
+    ) -> Result<(Vec<user::Model>, u64), DbErr> {
+        let mut select = User::find();
+        if let Some(query) = query {
+            let escaped = query
+                .replace('\\', "\\\\")
+                .replace('%', "\\%")
+                .replace('_', "\\_");
+            let pattern = format!("%{}%", escaped);
+            select = select.filter(
+                Condition::any()
+                    .add(Expr::col(user::Column::Username).ilike(pattern.clone()))
+                    .add(Expr::col(user::Column::Email).ilike(pattern)),
+            );
+        }
+
+        let total = select.clone().count(self.db.as_ref()).await?;
+        let users = select
+            .order_by_asc(user::Column::Id)
+            .limit(limit)
+            .offset(offset)
+            .all(self.db.as_ref())
+            .await?;
+
+        Ok((users, total))
+    }
+
+    pub async fn update_role(&self, id: i32, role: UserRole) -> Result<user::Model, DbErr> {
+        let user = self
+            .find_by_id(id)
+            .await?
+            .ok_or(DbErr::RecordNotFound("User not found".into()))?;
+
+        let mut active_model: user::ActiveModel = user.into();
+        active_model.role = Set(role);
+        active_model.updated_at = Set(Utc::now().naive_utc());
+
+        active_model.update(self.db.as_ref()).await
+    }
+
+    pub async fn set_suspended_at(
+        &self,
+        id: i32,
+        suspended_at: Option<NaiveDateTime>,
+    ) -> Result<user::Model, DbErr> {
+        let user = self
+            .find_by_id(id)
+            .await?
+            .ok_or(DbErr::RecordNotFound("User not found".into()))?;
+
+        let mut active_model: user::ActiveModel = user.into();
+        active_model.suspended_at = Set(suspended_at);
+        active_model.updated_at = Set(Utc::now().naive_utc());
+
+        active_model.update(self.db.as_ref()).await
+    }
 }
@@ -0,0 +1,142 @@
+use chrono::Utc;
+use sea_orm::DatabaseConnection;
+use std::sync::Arc;
+
+use crate::{
+    entities::user::{self, UserRole},
+    errors::ServiceError,
+    models::{AdminUserListQuery, AdminUserListResponse, AdminUserResponse},
+    repositories::{MemoRepository, PersonalAccessTokenRepository, UserRepository},
+    services::AuthService,
+    utils::jwt::JwtKeys,
+};
+
+const DEFAULT_PAGE_SIZE: u64 = 50;
+const MAX_PAGE_SIZE: u64 = 100;
+
+#[derive(Clone)]
+pub struct AdminService {
+    user_repo: UserRepository,
+    memo_repo: MemoRepository,
+    token_repo: PersonalAccessTokenRepository,
+    auth_service: AuthService,
+}
+
+impl AdminService {
+    pub fn new(db: Arc<DatabaseConnection>, jwt_keys: Arc<JwtKeys>) -> Self {
+        Self {
+            user_repo: UserRepository::new(db.clone()),
+            memo_repo: MemoRepository::new(db.clone()),
+            token_repo: PersonalAccessTokenRepository::new(db.clone()),
+            auth_service: AuthService::new(db, jwt_keys),
+        }
+    }
+
+    pub async fn list_users(
+        &self,
+        query: AdminUserListQuery,
+    ) -> Result<AdminUserListResponse, ServiceError> {
+        let search = query.q.as_deref().map(str::trim).filter(|q| !q.is_empty());
+        let limit = query
+            .limit
+            .unwrap_or(DEFAULT_PAGE_SIZE)
+            .clamp(1, MAX_PAGE_SIZE);
+        let offset = query.offset.unwrap_or(0);
+
+        let (users, total) = self.user_repo.search(search, limit, offset).await?;
+
+        let user_ids: Vec<i32> = users.iter().map(|u| u.id).collect();
+        let memo_counts = self.memo_repo.count_by_user_ids(&user_ids).await?;
+
+        let users = users
+            .into_iter()
+            .map(|u| {
+                let memo_count = memo_counts.get(&u.id).copied().unwrap_or(0);
+                AdminUserResponse::from_model(u, memo_count)
+            })
+            .collect();
+
+        Ok(AdminUserListResponse { users, total })
+    }
+
+    pub async fn update_role(
+        &self,
+        admin_id: i32,
+        user_id: i32,
+        role: UserRole,
+    ) -> Result<AdminUserResponse, ServiceError> {
+        // 마지막 관리자가 스스로 권한을 잃어 관리 기능이 잠기는 일을 막는다.
+        if admin_id == user_id && role != UserRole::Admin {
+            return Err(ServiceError::CannotModifyOwnAccount);
+        }
+        self.find_user(user_id).await?;
+
+        let user = self.user_repo.update_role(user_id, role).await?;
+        self.to_response(user).await
+    }
+
+    pub async fn suspend_user(
+        &self,
+        admin_id: i32,
+        user_id: i32,
+    ) -> Result<AdminUserResponse, ServiceError> {
+        if admin_id == user_id {
+            return Err(ServiceError::CannotModifyOwnAccount);
+        }
+        let user = self.find_user(user_id).await?;
+
+        let user = match user.suspended_at {
+            Some(_) => user,
+            None => {
+                self.user_repo
+                    .set_suspended_at(user_id, Some(Utc::now().naive_utc()))
+                    .await?
+            }
+        };
+        self.revoke_all_credentials(user_id).await?;
+
+        self.to_response(user).await
+    }
+
+    pub async fn reactivate_user(&self, user_id: i32) -> Result<AdminUserResponse, ServiceError> {
+        self.find_user(user_id).await?;
+
+        let user = self.user_repo.set_suspended_at(user_id, None).await?;
+        self.to_response(user).await
+    }
+
+    pub async fn force_logout(&self, user_id: i32) -> Result<u64, ServiceError> {
+        self.find_user(user_id).await?;
+        self.revoke_all_credentials(user_id).await
+    }
+
+    // 세션과 리프레시 토큰뿐 아니라 세션 없이 쓰이는 개인 액세스 토큰도 함께 폐기한다.
+    async fn revoke_all_credentials(&self, user_id: i32) -> Result<u64, ServiceError> {
+        let revoked = self.auth_service.revoke_all_sessions(user_id).await?;
+        self.token_repo.revoke_by_user_id(user_id).await?;
+
+        Ok(revoked)
+    }
+
+    async fn find_user(&self, user_id: i32) -> Result<user::Model, ServiceError> {
+        self.user_repo
+            .find_by_id(user_id)
+            .await?
+            .ok_or(ServiceError::UserNotFound)
+    }
+
+    async fn to_response(&self, user: user::Model) -> Result<AdminUserResponse, ServiceError> {
+        let memo_count = self
+            .memo_repo
+            .count_by_user_ids(&[user.id])
+            .await?
+            .get(&user.id)
+            .copied()
+            .unwrap_or(0);
+
+        Ok(AdminUserResponse::from_model(user, memo_count))
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,178 @@
+use super::*;
+use crate::{
+    db,
+    models::{ClientInfo, CreatePersonalAccessTokenRequest, Scope},
+    services::PersonalAccessTokenService,
+};
+use rand::Rng;
+use sea_orm::*;
+
+async fn setup_test_db() -> Arc<DatabaseConnection> {
+    dotenv::dotenv().ok();
+    let database_url = std::env::var("DATABASE_URL_TEST")
+        .expect("DATABASE_URL_TEST must be set. Run: just setup-test-db");
+    Arc::new(db::create_connection(&database_url).await.unwrap())
+}
+
+async fn create_user(db: &DatabaseConnection, prefix: &str, role: UserRole) -> user::Model {
+    let now = Utc::now().naive_utc();
+    let random: u32 = rand::thread_rng().gen();
+    let unique_id = format!("{}_{}", now.and_utc().timestamp_micros(), random);
+
+    let new_user = user::ActiveModel {
+        id: NotSet,
+        username: Set(format!("{}_{}", prefix, unique_id)),
+        email: Set(format!("{}_{}@example.com", prefix, unique_id)),
+        password_hash: Set(None),
+        role: Set(role),
+        created_at: Set(now),
+        updated_at: Set(now),
+        ..Default::default()
+    };
+    new_user.insert(db).await.unwrap()
+}
+
+#[tokio::test]
+async fn test_list_users_with_memo_counts() {
+    let db = setup_test_db().await;
+    let service = AdminService::new(db.clone(), Arc::new(JwtKeys::generate()));
+    let user = create_user(&db, "listed", UserRole::User).await;
+
+    let memo_repo = MemoRepository::new(db);
+    memo_repo
+        .create(user.id, "첫 메모".to_string(), None)
+        .await
+        .unwrap();
+    memo_repo
+        .create(user.id, "둘째 메모".to_string(), None)
+        .await
+        .unwrap();
+    // 휴지통의 메모는 세지 않는다.
+    let trashed = memo_repo
+        .create(user.id, "지운 메모".to_string(), None)
+        .await
+        .unwrap();
+    memo_repo.trash_many(user.id, &[trashed.id]).await.unwrap();
+
+    // 이메일 일부를 대문자로 검색해도 찾을 수 있다.
+    let result = service
+        .list_users(AdminUserListQuery {
+            q: Some(user.email.to_uppercase()),
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+
+    assert_eq!(result.total, 1);
+    assert_eq!(result.users[0].id, user.id);
+    assert_eq!(result.users[0].memo_count, 2);
+    assert_eq!(result.users[0].role, UserRole::User);
+
+    let wildcard = service
+        .list_users(AdminUserListQuery {
+            q: Some("%".to_string()),
+            limit: Some(1),
+            offset: None,
+        })
+        .await
+        .unwrap();
+    assert!(wildcard.users.is_empty());
+}
+
+#[tokio::test]
+async fn test_suspend_revokes_sessions_and_reactivate_restores_access() {
+    let db = setup_test_db().await;
+    let jwt_keys = Arc::new(JwtKeys::generate());
+    let service = AdminService::new(db.clone(), jwt_keys.clone());
+    let auth_service = AuthService::new(db.clone(), jwt_keys);
+    let admin = create_user(&db, "admin", UserRole::Admin).await;
+    let user = create_user(&db, "suspended", UserRole::User).await;
+
+    let issued = auth_service
+        .issue_tokens(user.clone(), ClientInfo::default())
+        .await
+        .unwrap();
+    let pat_service = PersonalAccessTokenService::new(db.clone());
+    let pat = pat_service
+        .create_token(
+            user.id,
+            &[Scope::MemosRead],
+            CreatePersonalAccessTokenRequest {
+                name: "sync".to_string(),
+                scopes: vec![Scope::MemosRead],
+                expires_in_days: None,
+            },
+        )
+        .await
+        .unwrap();
+
+    let suspended = service.suspend_user(admin.id, user.id).await.unwrap();
+    assert!(suspended.suspended_at.is_some());
+
+    assert!(matches!(
+        auth_service.ensure_active(user.id).await,
+        Err(ServiceError::AccountSuspended)
+    ));
+    assert!(auth_service
+        .refresh(&issued.refresh_token, ClientInfo::default())
+        .await
+        .is_err());
+    assert!(auth_service
+        .list_sessions(user.id, None)
+        .await
+        .unwrap()
+        .is_empty());
+    assert!(matches!(
+        pat_service.authenticate(&pat.secret).await,
+        Err(ServiceError::InvalidToken)
+    ));
+
+    let suspended_model = UserRepository::new(db.clone())
+        .find_by_id(user.id)
+        .await
+        .unwrap()
+        .unwrap();
+    assert!(matches!(
+        auth_service
+            .issue_tokens(suspended_model, ClientInfo::default())
+            .await,
+        Err(ServiceError::AccountSuspended)
+    ));
+
+    let reactivated = service.reactivate_user(user.id).await.unwrap();
+    assert!(reactivated.suspended_at.is_none());
+    assert_eq!(
+        auth_service.ensure_active(user.id).await.unwrap(),
+        UserRole::User
+    );
+}
+
+#[tokio::test]
+async fn test_admin_cannot_lock_themselves_out() {
+    let db = setup_test_db().await;
+    let service = AdminService::new(db.clone(), Arc::new(JwtKeys::generate()));
+    let admin = create_user(&db, "self_admin", UserRole::Admin).await;
+    let user = create_user(&db, "promoted", UserRole::User).await;
+
+    assert!(matches!(
+        service.suspend_user(admin.id, admin.id).await,
+        Err(ServiceError::CannotModifyOwnAccount)
+    ));
+    assert!(matches!(
+        service
+            .update_role(admin.id, admin.id, UserRole::User)
+            .await,
+        Err(ServiceError::CannotModifyOwnAccount)
+    ));
+
+    let promoted = service
+        .update_role(admin.id, user.id, UserRole::Admin)
+        .await
+        .unwrap();
+    assert_eq!(promoted.role, UserRole::Admin);
+
+    assert!(matches!(
+        service.force_logout(-1).await,
+        Err(ServiceError::UserNotFound)
+    ));
+}
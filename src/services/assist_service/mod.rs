@@ -5,7 +5,8 @@ use crate::{
     clients::{Embedder, TextGenerator},
     errors::ServiceError,
     models::assist_dto::{AssistRequest, AssistResponse, SimilarMemo},
-    repositories::{MemoRepository, QdrantRepo},
+    repositories::{MemoListFilter, MemoRepository, QdrantRepo},
+    services::tag_service::normalize_tag_name,
 };
 
 #[derive(Clone)]
@@ -37,16 +38,21 @@ impl AssistService {
         req: AssistRequest,
     ) -> Result<AssistResponse, ServiceError> {
         let query_vector = self.embedder.embed(&req.prompt).await?;
+        let filter = MemoListFilter {
+            tags: req.tags.iter().map(|tag| normalize_tag_name(tag)).collect(),
+            is_archived: (!req.include_archived).then_some(false),
+            ..Default::default()
+        };
 
         let similar_memo_ids = self
             .qdrant_repo
-            .search_similar(user_id, query_vector, req.limit)
+            .search_similar(user_id, query_vector, &filter, req.limit)
             .await?;
 
         let mut similar_memos = Vec::new();
         let mut context = Vec::new();
 
-        for memo_id in similar_memo_ids {
+        for (memo_id, _) in similar_memo_ids {
             if let Some(memo) = self.memo_repo.find_by_id(memo_id).await? {
                 if memo.user_id == user_id {
                     context.push(memo.content.clone());
@@ -59,10 +65,7 @@ impl AssistService {
             }
         }
 
-        let suggestion = self
-            .text_generator
-            .generate(&req.prompt, context)
-            .await?;
+        let suggestion = self.text_generator.generate(&req.prompt, context).await?;
 
         Ok(AssistResponse {
             suggestion,
@@ -28,6 +28,7 @@ async fn setup_test_db() -> (Arc<DatabaseConnection>, i32) {
         password_hash: Set(Some("test_hash".to_string())),
         created_at: Set(now),
         updated_at: Set(now),
+        ..Default::default()
     };
     let user_id = new_user.insert(db.as_ref()).await.unwrap().id;
 
@@ -45,6 +46,7 @@ async fn test_get_assistance() {
         db.clone(),
         qdrant_repo.clone(),
         embedder.clone() as Arc<dyn Embedder>,
+        text_generator.clone() as Arc<dyn TextGenerator>,
     );
 
     memo_service
@@ -52,6 +54,7 @@ async fn test_get_assistance() {
             user_id,
             CreateMemoRequest {
                 content: "Rust is a systems programming language".to_string(),
+                ..Default::default()
             },
         )
         .await
@@ -62,6 +65,7 @@ async fn test_get_assistance() {
             user_id,
             CreateMemoRequest {
                 content: "Async programming in Rust".to_string(),
+                ..Default::default()
             },
         )
         .await
@@ -77,6 +81,8 @@ async fn test_get_assistance() {
     let req = AssistRequest {
         prompt: "Tell me about Rust programming".to_string(),
         limit: 5,
+        tags: Vec::new(),
+        include_archived: false,
     };
 
     let result = assist_service.get_assistance(user_id, req).await.unwrap();
@@ -102,6 +108,8 @@ async fn test_get_assistance_no_similar_memos() {
     let req = AssistRequest {
         prompt: "Tell me about Python".to_string(),
         limit: 5,
+        tags: Vec::new(),
+        include_archived: false,
     };
 
     let result = assist_service.get_assistance(user_id, req).await.unwrap();
@@ -123,6 +131,7 @@ async fn test_get_assistance_user_isolation() {
         db.clone(),
         qdrant_repo.clone(),
         embedder.clone() as Arc<dyn Embedder>,
+        text_generator.clone() as Arc<dyn TextGenerator>,
     );
 
     memo_service
@@ -130,6 +139,7 @@ async fn test_get_assistance_user_isolation() {
             user1_id,
             CreateMemoRequest {
                 content: "User 1 memo about Rust".to_string(),
+                ..Default::default()
             },
         )
         .await
@@ -140,6 +150,7 @@ async fn test_get_assistance_user_isolation() {
             user2_id,
             CreateMemoRequest {
                 content: "User 2 memo about Rust".to_string(),
+                ..Default::default()
             },
         )
         .await
@@ -155,6 +166,8 @@ async fn test_get_assistance_user_isolation() {
     let req = AssistRequest {
         prompt: "Tell me about Rust".to_string(),
         limit: 5,
+        tags: Vec::new(),
+        include_archived: false,
     };
 
     let result = assist_service.get_assistance(user1_id, req).await.unwrap();
@@ -168,3 +181,128 @@ async fn test_get_assistance_user_isolation() {
         .iter()
         .any(|memo| memo.content.contains("User 2")));
 }
+
+#[tokio::test]
+async fn test_get_assistance_restricted_to_tags() {
+    let (db, user_id) = setup_test_db().await;
+    let qdrant_repo = Arc::new(MockQdrantRepository::new());
+    let embedder = Arc::new(MockGeminiClient::new());
+    let text_generator = Arc::new(MockGeminiClient::new());
+
+    let memo_service = MemoService::new(
+        db.clone(),
+        qdrant_repo.clone(),
+        embedder.clone() as Arc<dyn Embedder>,
+        text_generator.clone() as Arc<dyn TextGenerator>,
+    );
+
+    let tagged = memo_service
+        .create_memo(
+            user_id,
+            CreateMemoRequest {
+                content: "Ownership and borrowing".to_string(),
+                tags: vec!["Rust".to_string()],
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+    memo_service
+        .create_memo(
+            user_id,
+            CreateMemoRequest {
+                content: "Grocery list for the week".to_string(),
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+
+    let assist_service = AssistService::new(
+        db,
+        qdrant_repo as Arc<dyn QdrantRepo>,
+        embedder as Arc<dyn Embedder>,
+        text_generator as Arc<dyn TextGenerator>,
+    );
+
+    let req = AssistRequest {
+        prompt: "Explain borrowing".to_string(),
+        limit: 5,
+        tags: vec!["#rust".to_string()],
+        include_archived: false,
+    };
+
+    let result = assist_service.get_assistance(user_id, req).await.unwrap();
+
+    assert_eq!(result.similar_memos.len(), 1);
+    assert_eq!(result.similar_memos[0].id, tagged.id);
+}
+
+#[tokio::test]
+async fn test_get_assistance_includes_archived_memos_only_on_request() {
+    let (db, user_id) = setup_test_db().await;
+    let qdrant_repo = Arc::new(MockQdrantRepository::new());
+    let embedder = Arc::new(MockGeminiClient::new());
+    let text_generator = Arc::new(MockGeminiClient::new());
+
+    let memo_service = MemoService::new(
+        db.clone(),
+        qdrant_repo.clone(),
+        embedder.clone() as Arc<dyn Embedder>,
+        text_generator.clone() as Arc<dyn TextGenerator>,
+    );
+
+    let active = memo_service
+        .create_memo(
+            user_id,
+            CreateMemoRequest {
+                content: "Ownership in Rust".to_string(),
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+    let archived = memo_service
+        .create_memo(
+            user_id,
+            CreateMemoRequest {
+                content: "Lifetimes in Rust".to_string(),
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+    memo_service
+        .archive_memo(user_id, archived.id)
+        .await
+        .unwrap();
+
+    let assist_service = AssistService::new(
+        db,
+        qdrant_repo as Arc<dyn QdrantRepo>,
+        embedder as Arc<dyn Embedder>,
+        text_generator as Arc<dyn TextGenerator>,
+    );
+
+    let request = |include_archived| AssistRequest {
+        prompt: "Explain Rust".to_string(),
+        limit: 5,
+        tags: Vec::new(),
+        include_archived,
+    };
+
+    let result = assist_service
+        .get_assistance(user_id, request(false))
+        .await
+        .unwrap();
+    let ids: Vec<i32> = result.similar_memos.iter().map(|memo| memo.id).collect();
+    assert_eq!(ids, vec![active.id]);
+
+    let result = assist_service
+        .get_assistance(user_id, request(true))
+        .await
+        .unwrap();
+    let mut ids: Vec<i32> = result.similar_memos.iter().map(|memo| memo.id).collect();
+    ids.sort();
+    assert_eq!(ids, vec![active.id, archived.id]);
+}
@@ -0,0 +1,127 @@
+use sea_orm::DatabaseConnection;
+use std::sync::Arc;
+
+use crate::{
+    entities::auth_event::AuthEventType,
+    errors::ServiceError,
+    models::{
+        AdminSecurityEventListQuery, AuthEventListResponse, AuthEventResponse, ClientInfo,
+        SecurityEventListQuery,
+    },
+    repositories::{AuthEventFilter, AuthEventRepository},
+};
+
+const DEFAULT_PAGE_SIZE: u64 = 50;
+const MAX_PAGE_SIZE: u64 = 100;
+const MAX_EMAIL_LENGTH: usize = 255;
+
+#[derive(Clone)]
+pub struct AuthEventService {
+    auth_event_repo: AuthEventRepository,
+}
+
+impl AuthEventService {
+    pub fn new(db: Arc<DatabaseConnection>) -> Self {
+        Self {
+            auth_event_repo: AuthEventRepository::new(db),
+        }
+    }
+
+    pub async fn record(
+        &self,
+        user_id: Option<i32>,
+        event_type: AuthEventType,
+        detail: Option<&str>,
+        client: &ClientInfo,
+    ) -> Result<(), ServiceError> {
+        self.auth_event_repo
+            .create(
+                user_id,
+                None,
+                event_type,
+                detail.map(str::to_string),
+                client.ip_address.clone(),
+                client.user_agent.clone(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    // 존재하지 않는 계정에 대한 시도는 사용자 ID 대신 시도한 이메일을 남겨 무차별 대입을 추적할 수 있게 한다.
+    pub async fn record_attempt(
+        &self,
+        email: &str,
+        event_type: AuthEventType,
+        detail: Option<&str>,
+        client: &ClientInfo,
+    ) -> Result<(), ServiceError> {
+        self.auth_event_repo
+            .create(
+                None,
+                Some(email.chars().take(MAX_EMAIL_LENGTH).collect()),
+                event_type,
+                detail.map(str::to_string),
+                client.ip_address.clone(),
+                client.user_agent.clone(),
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn list_for_user(
+        &self,
+        user_id: i32,
+        query: SecurityEventListQuery,
+    ) -> Result<AuthEventListResponse, ServiceError> {
+        let filter = AuthEventFilter {
+            user_id: Some(user_id),
+            event_type: query.event_type,
+            ..Default::default()
+        };
+        self.search(filter, query.limit, query.offset).await
+    }
+
+    pub async fn list_all(
+        &self,
+        query: AdminSecurityEventListQuery,
+    ) -> Result<AuthEventListResponse, ServiceError> {
+        let filter = AuthEventFilter {
+            user_id: query.user_id,
+            email: query
+                .email
+                .map(|email| email.trim().to_lowercase())
+                .filter(|email| !email.is_empty()),
+            event_type: query.event_type,
+            ip_address: query
+                .ip_address
+                .map(|ip| ip.trim().to_string())
+                .filter(|ip| !ip.is_empty()),
+            since: query.since,
+            until: query.until,
+        };
+        self.search(filter, query.limit, query.offset).await
+    }
+
+    async fn search(
+        &self,
+        filter: AuthEventFilter,
+        limit: Option<u64>,
+        offset: Option<u64>,
+    ) -> Result<AuthEventListResponse, ServiceError> {
+        let limit = limit.unwrap_or(DEFAULT_PAGE_SIZE).clamp(1, MAX_PAGE_SIZE);
+        let (events, total) = self
+            .auth_event_repo
+            .search(filter, limit, offset.unwrap_or(0))
+            .await?;
+
+        Ok(AuthEventListResponse {
+            events: events.into_iter().map(AuthEventResponse::from).collect(),
+            total,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests;
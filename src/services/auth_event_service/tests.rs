@@ -0,0 +1,243 @@
+use super::*;
+use crate::{
+    db,
+    entities::{auth_event, user},
+};
+use chrono::{Duration, Utc};
+use rand::Rng;
+use sea_orm::*;
+
+async fn setup_test_db() -> (Arc<DatabaseConnection>, user::Model) {
+    dotenv::dotenv().ok();
+    let database_url = std::env::var("DATABASE_URL_TEST")
+        .expect("DATABASE_URL_TEST must be set. Run: just setup-test-db");
+    let db = Arc::new(db::create_connection(&database_url).await.unwrap());
+
+    let now = Utc::now().naive_utc();
+    let random: u32 = rand::thread_rng().gen();
+    let unique_id = format!("{}_{}", now.and_utc().timestamp_micros(), random);
+
+    let new_user = user::ActiveModel {
+        id: NotSet,
+        username: Set(format!("audit_user_{}", unique_id)),
+        email: Set(format!("audit_{}@example.com", unique_id)),
+        password_hash: Set(None),
+        created_at: Set(now),
+        updated_at: Set(now),
+        ..Default::default()
+    };
+    let user = new_user.insert(db.as_ref()).await.unwrap();
+
+    (db, user)
+}
+
+fn client(ip_address: &str) -> ClientInfo {
+    ClientInfo {
+        user_agent: Some("Mozilla/5.0".to_string()),
+        ip_address: Some(ip_address.to_string()),
+    }
+}
+
+#[tokio::test]
+async fn test_list_for_user_filters_by_event_type() {
+    let (db, user) = setup_test_db().await;
+    let service = AuthEventService::new(db);
+
+    service
+        .record(
+            Some(user.id),
+            AuthEventType::LoginFailed,
+            Some("invalid_credentials"),
+            &client("198.51.100.1"),
+        )
+        .await
+        .unwrap();
+    service
+        .record(
+            Some(user.id),
+            AuthEventType::LoginSucceeded,
+            Some("password"),
+            &client("198.51.100.1"),
+        )
+        .await
+        .unwrap();
+    service
+        .record(
+            Some(user.id),
+            AuthEventType::PasswordChanged,
+            None,
+            &client("198.51.100.1"),
+        )
+        .await
+        .unwrap();
+
+    let all = service
+        .list_for_user(user.id, SecurityEventListQuery::default())
+        .await
+        .unwrap();
+    assert_eq!(all.total, 3);
+    assert_eq!(all.events[0].event_type, AuthEventType::PasswordChanged);
+    assert!(all.events.iter().all(|e| e.user_id == Some(user.id)));
+    assert!(all
+        .events
+        .iter()
+        .all(|e| e.email.as_deref() == Some(user.email.as_str())));
+
+    let failures = service
+        .list_for_user(
+            user.id,
+            SecurityEventListQuery {
+                event_type: Some(AuthEventType::LoginFailed),
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+    assert_eq!(failures.total, 1);
+    assert_eq!(
+        failures.events[0].detail.as_deref(),
+        Some("invalid_credentials")
+    );
+    assert_eq!(
+        failures.events[0].ip_address.as_deref(),
+        Some("198.51.100.1")
+    );
+}
+
+#[tokio::test]
+async fn test_list_all_filters_by_ip_and_time_range() {
+    let (db, user) = setup_test_db().await;
+    let service = AuthEventService::new(db);
+    // 이전 실행에서 남은 기록과 겹치지 않도록 넓은 범위에서 고른다.
+    let random: u32 = rand::thread_rng().gen();
+    let ip_address = format!(
+        "10.{}.{}.{}",
+        (random >> 16) & 0xff,
+        (random >> 8) & 0xff,
+        random & 0xff
+    );
+    let before = Utc::now().naive_utc() - Duration::seconds(1);
+
+    // 존재하지 않는 계정에 대한 실패도 IP로 추적할 수 있어야 한다.
+    service
+        .record(
+            None,
+            AuthEventType::LoginFailed,
+            Some("invalid_credentials"),
+            &client(&ip_address),
+        )
+        .await
+        .unwrap();
+    service
+        .record(
+            Some(user.id),
+            AuthEventType::LoginFailed,
+            Some("invalid_credentials"),
+            &client(&ip_address),
+        )
+        .await
+        .unwrap();
+
+    let by_ip = service
+        .list_all(AdminSecurityEventListQuery {
+            ip_address: Some(ip_address.clone()),
+            since: Some(before),
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+    assert_eq!(by_ip.total, 2);
+    assert!(by_ip.events.iter().any(|e| e.user_id.is_none()));
+
+    let by_user = service
+        .list_all(AdminSecurityEventListQuery {
+            user_id: Some(user.id),
+            ip_address: Some(ip_address.clone()),
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+    assert_eq!(by_user.total, 1);
+
+    let before_range = service
+        .list_all(AdminSecurityEventListQuery {
+            ip_address: Some(ip_address),
+            until: Some(before),
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+    assert_eq!(before_range.total, 0);
+}
+
+#[tokio::test]
+async fn test_events_survive_user_deletion_and_are_append_only() {
+    let (db, user) = setup_test_db().await;
+    let service = AuthEventService::new(db.clone());
+
+    service
+        .record(
+            Some(user.id),
+            AuthEventType::LoginSucceeded,
+            Some("password"),
+            &client("198.51.100.2"),
+        )
+        .await
+        .unwrap();
+    user::Entity::delete_by_id(user.id)
+        .exec(db.as_ref())
+        .await
+        .unwrap();
+
+    // 탈퇴한 사용자의 기록도 사용자 ID와 당시 이메일로 찾을 수 있다.
+    let events = service
+        .list_all(AdminSecurityEventListQuery {
+            user_id: Some(user.id),
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+    assert_eq!(events.total, 1);
+    assert_eq!(events.events[0].email.as_deref(), Some(user.email.as_str()));
+
+    let event = auth_event::Entity::find_by_id(events.events[0].id)
+        .one(db.as_ref())
+        .await
+        .unwrap()
+        .unwrap();
+    let mut active_model: auth_event::ActiveModel = event.clone().into();
+    active_model.detail = Set(Some("tampered".to_string()));
+    assert!(active_model.update(db.as_ref()).await.is_err());
+    assert!(event.delete(db.as_ref()).await.is_err());
+    assert!(db.execute_unprepared("TRUNCATE auth_events").await.is_err());
+}
+
+#[tokio::test]
+async fn test_record_attempt_keeps_truncated_email() {
+    let (db, _) = setup_test_db().await;
+    let service = AuthEventService::new(db);
+    let random: u32 = rand::thread_rng().gen();
+    let email = format!("{}{}@example.com", random, "a".repeat(300));
+
+    service
+        .record_attempt(
+            &email,
+            AuthEventType::LoginFailed,
+            Some("invalid_credentials"),
+            &client("198.51.100.3"),
+        )
+        .await
+        .unwrap();
+
+    let truncated: String = email.chars().take(MAX_EMAIL_LENGTH).collect();
+    let events = service
+        .list_all(AdminSecurityEventListQuery {
+            email: Some(truncated.clone()),
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+    assert_eq!(events.total, 1);
+    assert_eq!(events.events[0].user_id, None);
+    assert_eq!(events.events[0].email, Some(truncated));
+}
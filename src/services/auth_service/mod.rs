@@ -0,0 +1,302 @@
+use chrono::{Duration, Utc};
+use rand::{distributions::Alphanumeric, Rng};
+use sea_orm::DatabaseConnection;
+use sha2::{Digest, Sha256};
+use std::sync::Arc;
+
+use crate::{
+    entities::{
+        auth_event::AuthEventType,
+        user::{self, UserRole},
+    },
+    errors::ServiceError,
+    models::{AuthResponse, ClientInfo, SessionResponse, UserResponse},
+    repositories::{RefreshTokenRepository, SessionRepository, UserRepository},
+    services::AuthEventService,
+    utils::jwt::JwtKeys,
+};
+
+const REFRESH_TOKEN_LENGTH: usize = 64;
+const SESSION_TOUCH_INTERVAL_SECONDS: i64 = 60;
+
+#[derive(Clone)]
+pub struct AuthService {
+    user_repo: UserRepository,
+    refresh_repo: RefreshTokenRepository,
+    session_repo: SessionRepository,
+    auth_event_service: AuthEventService,
+    jwt_keys: Arc<JwtKeys>,
+}
+
+impl AuthService {
+    pub fn new(db: Arc<DatabaseConnection>, jwt_keys: Arc<JwtKeys>) -> Self {
+        Self {
+            user_repo: UserRepository::new(db.clone()),
+            refresh_repo: RefreshTokenRepository::new(db.clone()),
+            session_repo: SessionRepository::new(db.clone()),
+            auth_event_service: AuthEventService::new(db),
+            jwt_keys,
+        }
+    }
+
+    // 세션 ID는 리프레시 토큰 계열(family) ID와 같다.
+    pub async fn issue_tokens(
+        &self,
+        user: user::Model,
+        client: ClientInfo,
+    ) -> Result<AuthResponse, ServiceError> {
+        if user.suspended_at.is_some() {
+            return Err(ServiceError::AccountSuspended);
+        }
+
+        let session_id = uuid::Uuid::new_v4().to_string();
+        self.session_repo
+            .create(
+                session_id.clone(),
+                user.id,
+                client.user_agent,
+                client.ip_address,
+            )
+            .await?;
+
+        self.issue_tokens_in_family(user, session_id).await
+    }
+
+    pub async fn refresh(
+        &self,
+        refresh_token: &str,
+        client: ClientInfo,
+    ) -> Result<AuthResponse, ServiceError> {
+        let stored = self
+            .refresh_repo
+            .find_by_hash(&hash_token(refresh_token))
+            .await?
+            .ok_or(ServiceError::InvalidToken)?;
+
+        if stored.expires_at <= Utc::now().naive_utc() {
+            return Err(ServiceError::InvalidToken);
+        }
+
+        let rotated = stored.revoked_at.is_none() && self.refresh_repo.rotate(stored.id).await?;
+        if !rotated {
+            // 갱신으로 이미 교체된 토큰이 다시 제출되면 탈취로 간주하고 같은 계열의 토큰을 모두 폐기한다.
+            // 로그아웃이나 세션 종료로 폐기된 토큰은 그냥 거부한다. 동시에 들어온 갱신이 먼저 교체했을 수 있어 다시 읽는다.
+            let reused = match stored.rotated_at {
+                Some(_) => true,
+                None => self
+                    .refresh_repo
+                    .find_by_hash(&stored.token_hash)
+                    .await?
+                    .is_some_and(|token| token.rotated_at.is_some()),
+            };
+            if reused {
+                self.refresh_repo.revoke_family(&stored.family_id).await?;
+                self.auth_event_service
+                    .record(
+                        Some(stored.user_id),
+                        AuthEventType::RefreshTokenReused,
+                        Some(&stored.family_id),
+                        &client,
+                    )
+                    .await?;
+            }
+            return Err(ServiceError::InvalidToken);
+        }
+
+        match self.session_repo.find_by_id(&stored.family_id).await? {
+            Some(session) if session.revoked_at.is_some() => {
+                self.refresh_repo.revoke_family(&stored.family_id).await?;
+                return Err(ServiceError::InvalidToken);
+            }
+            Some(session) => {
+                self.session_repo
+                    .touch(&session.id, Utc::now().naive_utc())
+                    .await?;
+            }
+            // 세션 기록 도입 전에 발급된 토큰은 세션을 새로 만들어 이어간다.
+            None => {
+                self.session_repo
+                    .create(stored.family_id.clone(), stored.user_id, None, None)
+                    .await?;
+            }
+        }
+
+        let user = self
+            .user_repo
+            .find_by_id(stored.user_id)
+            .await?
+            .ok_or(ServiceError::InvalidToken)?;
+        if user.suspended_at.is_some() {
+            self.refresh_repo.revoke_family(&stored.family_id).await?;
+            return Err(ServiceError::AccountSuspended);
+        }
+
+        self.auth_event_service
+            .record(
+                Some(user.id),
+                AuthEventType::TokenRefreshed,
+                Some(&stored.family_id),
+                &client,
+            )
+            .await?;
+
+        self.issue_tokens_in_family(user, stored.family_id).await
+    }
+
+    pub async fn logout(
+        &self,
+        refresh_token: &str,
+        client: ClientInfo,
+    ) -> Result<(), ServiceError> {
+        let stored = self
+            .refresh_repo
+            .find_by_hash(&hash_token(refresh_token))
+            .await?
+            .ok_or(ServiceError::InvalidToken)?;
+
+        self.refresh_repo.revoke_family(&stored.family_id).await?;
+        self.session_repo
+            .revoke(&stored.family_id, stored.user_id)
+            .await?;
+        self.auth_event_service
+            .record(
+                Some(stored.user_id),
+                AuthEventType::Logout,
+                Some(&stored.family_id),
+                &client,
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    // 토큰이 유효하더라도 정지된 계정이나 삭제된 계정의 요청은 받지 않는다.
+    pub async fn ensure_active(&self, user_id: i32) -> Result<UserRole, ServiceError> {
+        let user = self
+            .user_repo
+            .find_by_id(user_id)
+            .await?
+            .ok_or(ServiceError::InvalidToken)?;
+        if user.suspended_at.is_some() {
+            return Err(ServiceError::AccountSuspended);
+        }
+
+        Ok(user.role)
+    }
+
+    pub async fn validate_session(&self, session_id: &str) -> Result<(), ServiceError> {
+        let session = self
+            .session_repo
+            .find_by_id(session_id)
+            .await?
+            .filter(|s| s.revoked_at.is_none())
+            .ok_or(ServiceError::InvalidToken)?;
+
+        // 요청마다 쓰기가 일어나지 않도록 일정 간격으로만 갱신한다.
+        let now = Utc::now().naive_utc();
+        if now - session.last_seen_at >= Duration::seconds(SESSION_TOUCH_INTERVAL_SECONDS) {
+            self.session_repo.touch(&session.id, now).await?;
+        }
+
+        Ok(())
+    }
+
+    pub async fn list_sessions(
+        &self,
+        user_id: i32,
+        current_session_id: Option<&str>,
+    ) -> Result<Vec<SessionResponse>, ServiceError> {
+        let sessions = self.session_repo.find_active_by_user_id(user_id).await?;
+        Ok(sessions
+            .into_iter()
+            .map(|s| SessionResponse::from_model(s, current_session_id))
+            .collect())
+    }
+
+    pub async fn revoke_session(&self, user_id: i32, session_id: &str) -> Result<(), ServiceError> {
+        if !self.session_repo.revoke(session_id, user_id).await? {
+            return Err(ServiceError::SessionNotFound);
+        }
+        self.refresh_repo.revoke_family(session_id).await?;
+
+        Ok(())
+    }
+
+    pub async fn revoke_other_sessions(
+        &self,
+        user_id: i32,
+        current_session_id: Option<&str>,
+    ) -> Result<u64, ServiceError> {
+        let revoked = self
+            .session_repo
+            .revoke_all_except(user_id, current_session_id.unwrap_or_default())
+            .await?;
+        for session_id in &revoked {
+            self.refresh_repo.revoke_family(session_id).await?;
+        }
+
+        Ok(revoked.len() as u64)
+    }
+
+    pub async fn revoke_all_sessions(&self, user_id: i32) -> Result<u64, ServiceError> {
+        let revoked = self.revoke_other_sessions(user_id, None).await?;
+        // 세션 기록 도입 전에 발급된 리프레시 토큰도 함께 폐기한다.
+        self.refresh_repo.revoke_by_user_id(user_id).await?;
+
+        Ok(revoked)
+    }
+
+    async fn issue_tokens_in_family(
+        &self,
+        user: user::Model,
+        family_id: String,
+    ) -> Result<AuthResponse, ServiceError> {
+        let access_minutes = env_i64("JWT_ACCESS_TOKEN_MINUTES", 15);
+        let refresh_days = refresh_token_days();
+
+        let access_token = self
+            .jwt_keys
+            .generate_session_token(user.id, user.role, &family_id, access_minutes)
+            .map_err(|_| ServiceError::TokenGenerationFailed)?;
+
+        let refresh_token: String = rand::thread_rng()
+            .sample_iter(&Alphanumeric)
+            .take(REFRESH_TOKEN_LENGTH)
+            .map(char::from)
+            .collect();
+
+        self.refresh_repo
+            .create(
+                user.id,
+                family_id,
+                hash_token(&refresh_token),
+                (Utc::now() + Duration::days(refresh_days)).naive_utc(),
+            )
+            .await?;
+
+        Ok(AuthResponse {
+            user: UserResponse::from(user),
+            access_token,
+            refresh_token,
+            expires_in: access_minutes * 60,
+        })
+    }
+}
+
+pub fn hash_token(token: &str) -> String {
+    format!("{:x}", Sha256::digest(token.as_bytes()))
+}
+
+pub fn refresh_token_days() -> i64 {
+    env_i64("REFRESH_TOKEN_DAYS", 30)
+}
+
+fn env_i64(key: &str, default: i64) -> i64 {
+    std::env::var(key)
+        .ok()
+        .and_then(|v| v.parse().ok())
+        .unwrap_or(default)
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,298 @@
+use super::*;
+use crate::{
+    db,
+    entities::{auth_event, refresh_token, user},
+};
+use rand::Rng;
+use sea_orm::*;
+
+async fn setup_test_db() -> (Arc<DatabaseConnection>, user::Model) {
+    dotenv::dotenv().ok();
+    let database_url = std::env::var("DATABASE_URL_TEST")
+        .expect("DATABASE_URL_TEST must be set. Run: just setup-test-db");
+    let db = Arc::new(db::create_connection(&database_url).await.unwrap());
+
+    let now = Utc::now().naive_utc();
+    let timestamp = now.and_utc().timestamp_micros();
+    let random: u32 = rand::thread_rng().gen();
+    let unique_id = format!("{}_{}", timestamp, random);
+
+    let new_user = user::ActiveModel {
+        id: NotSet,
+        username: Set(format!("test_user_{}", unique_id)),
+        email: Set(format!("test_{}@example.com", unique_id)),
+        password_hash: Set(None),
+        created_at: Set(now),
+        updated_at: Set(now),
+        ..Default::default()
+    };
+    let user = new_user.insert(db.as_ref()).await.unwrap();
+
+    (db, user)
+}
+
+#[tokio::test]
+async fn test_refresh_rotates_tokens() {
+    let (db, user) = setup_test_db().await;
+    let service = AuthService::new(db, Arc::new(JwtKeys::generate()));
+
+    let issued = service
+        .issue_tokens(user.clone(), ClientInfo::default())
+        .await
+        .unwrap();
+    let refreshed = service
+        .refresh(&issued.refresh_token, ClientInfo::default())
+        .await
+        .unwrap();
+
+    assert_eq!(refreshed.user.id, user.id);
+    assert_ne!(refreshed.refresh_token, issued.refresh_token);
+    assert!(!refreshed.access_token.is_empty());
+
+    let refreshed_again = service
+        .refresh(&refreshed.refresh_token, ClientInfo::default())
+        .await;
+    assert!(refreshed_again.is_ok());
+}
+
+#[tokio::test]
+async fn test_refresh_token_reuse_revokes_family() {
+    let (db, user) = setup_test_db().await;
+    let service = AuthService::new(db, Arc::new(JwtKeys::generate()));
+
+    let issued = service
+        .issue_tokens(user, ClientInfo::default())
+        .await
+        .unwrap();
+    let rotated = service
+        .refresh(&issued.refresh_token, ClientInfo::default())
+        .await
+        .unwrap();
+
+    let reused = service
+        .refresh(&issued.refresh_token, ClientInfo::default())
+        .await;
+    assert!(matches!(reused, Err(ServiceError::InvalidToken)));
+
+    let after_reuse = service
+        .refresh(&rotated.refresh_token, ClientInfo::default())
+        .await;
+    assert!(matches!(after_reuse, Err(ServiceError::InvalidToken)));
+}
+
+#[tokio::test]
+async fn test_logout_revokes_only_current_session() {
+    let (db, user) = setup_test_db().await;
+    let service = AuthService::new(db, Arc::new(JwtKeys::generate()));
+
+    let first_session = service
+        .issue_tokens(user.clone(), ClientInfo::default())
+        .await
+        .unwrap();
+    let second_session = service
+        .issue_tokens(user, ClientInfo::default())
+        .await
+        .unwrap();
+
+    service
+        .logout(&first_session.refresh_token, ClientInfo::default())
+        .await
+        .unwrap();
+
+    let result = service
+        .refresh(&first_session.refresh_token, ClientInfo::default())
+        .await;
+    assert!(matches!(result, Err(ServiceError::InvalidToken)));
+    assert!(service
+        .refresh(&second_session.refresh_token, ClientInfo::default())
+        .await
+        .is_ok());
+}
+
+async fn count_reuse_events(db: &DatabaseConnection, user_id: i32) -> u64 {
+    auth_event::Entity::find()
+        .filter(auth_event::Column::UserId.eq(user_id))
+        .filter(auth_event::Column::EventType.eq(AuthEventType::RefreshTokenReused))
+        .count(db)
+        .await
+        .unwrap()
+}
+
+#[tokio::test]
+async fn test_refresh_after_logout_is_not_treated_as_reuse() {
+    let (db, user) = setup_test_db().await;
+    let service = AuthService::new(db.clone(), Arc::new(JwtKeys::generate()));
+
+    let issued = service
+        .issue_tokens(user.clone(), ClientInfo::default())
+        .await
+        .unwrap();
+    let rotated = service
+        .refresh(&issued.refresh_token, ClientInfo::default())
+        .await
+        .unwrap();
+    service
+        .logout(&rotated.refresh_token, ClientInfo::default())
+        .await
+        .unwrap();
+
+    // 로그아웃한 뒤 재시도한 요청은 탈취 기록 없이 거부된다.
+    let retried = service
+        .refresh(&rotated.refresh_token, ClientInfo::default())
+        .await;
+    assert!(matches!(retried, Err(ServiceError::InvalidToken)));
+    assert_eq!(count_reuse_events(&db, user.id).await, 0);
+
+    // 교체된 토큰을 다시 쓰는 것은 여전히 탈취로 기록한다.
+    let reused = service
+        .refresh(&issued.refresh_token, ClientInfo::default())
+        .await;
+    assert!(matches!(reused, Err(ServiceError::InvalidToken)));
+    assert_eq!(count_reuse_events(&db, user.id).await, 1);
+}
+
+#[tokio::test]
+async fn test_expired_refresh_token_is_rejected_without_rotation() {
+    let (db, user) = setup_test_db().await;
+    let service = AuthService::new(db.clone(), Arc::new(JwtKeys::generate()));
+
+    let issued = service
+        .issue_tokens(user.clone(), ClientInfo::default())
+        .await
+        .unwrap();
+    let stored = refresh_token::Entity::find()
+        .filter(refresh_token::Column::TokenHash.eq(hash_token(&issued.refresh_token)))
+        .one(db.as_ref())
+        .await
+        .unwrap()
+        .unwrap();
+    refresh_token::ActiveModel {
+        id: Set(stored.id),
+        expires_at: Set(Utc::now().naive_utc() - Duration::minutes(1)),
+        ..Default::default()
+    }
+    .update(db.as_ref())
+    .await
+    .unwrap();
+
+    let result = service
+        .refresh(&issued.refresh_token, ClientInfo::default())
+        .await;
+    assert!(matches!(result, Err(ServiceError::InvalidToken)));
+
+    let stored = refresh_token::Entity::find_by_id(stored.id)
+        .one(db.as_ref())
+        .await
+        .unwrap()
+        .unwrap();
+    assert!(stored.revoked_at.is_none());
+    assert!(stored.rotated_at.is_none());
+    assert_eq!(count_reuse_events(&db, user.id).await, 0);
+}
+
+#[tokio::test]
+async fn test_refresh_with_unknown_token() {
+    let (db, _user) = setup_test_db().await;
+    let service = AuthService::new(db, Arc::new(JwtKeys::generate()));
+
+    let result = service
+        .refresh("not-a-real-refresh-token", ClientInfo::default())
+        .await;
+    assert!(matches!(result, Err(ServiceError::InvalidToken)));
+}
+
+fn session_id_of(service: &AuthService, response: &AuthResponse) -> String {
+    service
+        .jwt_keys
+        .verify_token(&response.access_token)
+        .unwrap()
+        .sid
+        .unwrap()
+}
+
+#[tokio::test]
+async fn test_sessions_record_client_and_can_be_revoked() {
+    let (db, user) = setup_test_db().await;
+    let service = AuthService::new(db, Arc::new(JwtKeys::generate()));
+
+    let phone = service
+        .issue_tokens(
+            user.clone(),
+            ClientInfo {
+                user_agent: Some("Inklings iOS".to_string()),
+                ip_address: Some("203.0.113.7".to_string()),
+            },
+        )
+        .await
+        .unwrap();
+    let laptop = service
+        .issue_tokens(user.clone(), ClientInfo::default())
+        .await
+        .unwrap();
+    let phone_session = session_id_of(&service, &phone);
+    let laptop_session = session_id_of(&service, &laptop);
+
+    let sessions = service
+        .list_sessions(user.id, Some(&laptop_session))
+        .await
+        .unwrap();
+    assert_eq!(sessions.len(), 2);
+    let listed_phone = sessions.iter().find(|s| s.id == phone_session).unwrap();
+    assert_eq!(listed_phone.user_agent.as_deref(), Some("Inklings iOS"));
+    assert_eq!(listed_phone.ip_address.as_deref(), Some("203.0.113.7"));
+    assert!(!listed_phone.is_current);
+    assert!(sessions
+        .iter()
+        .any(|s| s.id == laptop_session && s.is_current));
+
+    service
+        .revoke_session(user.id, &phone_session)
+        .await
+        .unwrap();
+
+    let validated = service.validate_session(&phone_session).await;
+    assert!(matches!(validated, Err(ServiceError::InvalidToken)));
+    let refreshed = service
+        .refresh(&phone.refresh_token, ClientInfo::default())
+        .await;
+    assert!(matches!(refreshed, Err(ServiceError::InvalidToken)));
+    assert!(service.validate_session(&laptop_session).await.is_ok());
+
+    let again = service.revoke_session(user.id, &phone_session).await;
+    assert!(matches!(again, Err(ServiceError::SessionNotFound)));
+}
+
+#[tokio::test]
+async fn test_revoke_other_sessions_keeps_current() {
+    let (db, user) = setup_test_db().await;
+    let service = AuthService::new(db, Arc::new(JwtKeys::generate()));
+
+    let current = service
+        .issue_tokens(user.clone(), ClientInfo::default())
+        .await
+        .unwrap();
+    let other = service
+        .issue_tokens(user.clone(), ClientInfo::default())
+        .await
+        .unwrap();
+    let current_session = session_id_of(&service, &current);
+
+    let revoked = service
+        .revoke_other_sessions(user.id, Some(&current_session))
+        .await
+        .unwrap();
+    assert_eq!(revoked, 1);
+
+    assert!(service
+        .refresh(&other.refresh_token, ClientInfo::default())
+        .await
+        .is_err());
+    assert!(service
+        .refresh(&current.refresh_token, ClientInfo::default())
+        .await
+        .is_ok());
+
+    let sessions = service.list_sessions(user.id, None).await.unwrap();
+    assert_eq!(sessions.len(), 1);
+    assert_eq!(sessions[0].id, current_session);
+}
@@ -1,18 +1,104 @@
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
+use chrono::{DateTime, NaiveDateTime, TimeDelta, Utc};
 use sea_orm::DatabaseConnection;
-use std::sync::Arc;
+use similar::{ChangeTag, TextDiff};
+use std::{
+    collections::{HashMap, HashSet},
+    sync::Arc,
+    time::Duration,
+};
 
 use crate::{
-    clients::Embedder,
+    clients::{Embedder, TextGenerator},
+    entities::{memo, memo_revision},
     errors::ServiceError,
-    models::{CreateMemoRequest, MemoResponse, UpdateMemoRequest},
-    repositories::{MemoRepository, QdrantRepo},
+    models::{
+        CreateMemoRequest, DiffChunk, DiffGranularity, DiffOp, HybridSearchQuery,
+        HybridSearchResponse, HybridSearchResult, MemoListQuery, MemoListResponse, MemoResponse,
+        MemoRevisionDiffQuery, MemoRevisionDiffResponse, MemoRevisionResponse, MemoRevisionSummary,
+        MemoSearchQuery, MemoSearchResponse, MemoSearchResult, MoveMemosRequest,
+        TagSuggestionResponse, TrashedMemoResponse, UpdateMemoRequest,
+    },
+    repositories::{
+        MemoCursor, MemoListFilter, MemoPayload, MemoRepository, MemoRevisionRepository,
+        NotebookRepository, QdrantRepo, TagRepository, UserRepository,
+    },
+    services::tag_service::{normalize_tag_name, validate_tag_name, validate_tag_names},
+    utils::search,
 };
 
+const DEFAULT_PAGE_SIZE: u64 = 50;
+const DEFAULT_SEARCH_PAGE_SIZE: u64 = 20;
+const MAX_PAGE_SIZE: u64 = 100;
+// 하이브리드 검색에서 융합 전에 각 검색이 가져오는 후보 수
+const HYBRID_CANDIDATE_POOL: u64 = 50;
+// 태그 제안 시 모델에 알려 주는 기존 태그 수
+const MAX_TAG_VOCABULARY: usize = 200;
+const MAX_SUGGESTED_EXISTING_TAGS: usize = 5;
+const MAX_SUGGESTED_NEW_TAGS: usize = 3;
+const TRASH_PURGE_INTERVAL: Duration = Duration::from_secs(60 * 60);
+const PAYLOAD_BACKFILL_BATCH_SIZE: u64 = 500;
+// 글자 단위 비교가 긴 메모에서 오래 걸리면 이 시간 뒤에 덜 정밀한 결과를 돌려준다.
+const DIFF_TIMEOUT: Duration = Duration::from_millis(500);
+
+// 파싱한 검색어를 키워드 검색(tsquery + DB 조건)과 의미 기반 검색(임베딩할 문장 + 페이로드 조건)으로 나눈 것
+struct SearchPlan {
+    tsquery: Option<String>,
+    exclusion_tsquery: Option<String>,
+    filter: MemoListFilter,
+    semantic_text: Option<String>,
+    highlight_words: Vec<String>,
+}
+
+impl SearchPlan {
+    fn new(q: &str) -> Result<Self, ServiceError> {
+        let query = search::query::parse(q)?;
+        let tsquery = search::search_tsquery(&query);
+        let filter = MemoListFilter {
+            is_pinned: query.pinned,
+            created_since: query.created_since,
+            created_until: query.created_until,
+            updated_since: query.updated_since,
+            updated_until: query.updated_until,
+            tags: query
+                .tags
+                .iter()
+                .map(|tag| normalize_tag_name(tag))
+                .collect(),
+            excluded_tags: query
+                .excluded_tags
+                .iter()
+                .map(|tag| normalize_tag_name(tag))
+                .collect(),
+            notebook_ids: None,
+            is_archived: None,
+        };
+        if tsquery.is_none() && filter == MemoListFilter::default() {
+            return Err(ServiceError::Validation(
+                "Search query must contain at least one word or field".to_string(),
+            ));
+        }
+
+        Ok(Self {
+            tsquery,
+            exclusion_tsquery: search::exclusion_tsquery(&query),
+            filter,
+            semantic_text: query.semantic_text(),
+            highlight_words: search::highlight_words(&query),
+        })
+    }
+}
+
 #[derive(Clone)]
 pub struct MemoService {
     memo_repo: MemoRepository,
+    revision_repo: MemoRevisionRepository,
+    tag_repo: TagRepository,
+    notebook_repo: NotebookRepository,
+    user_repo: UserRepository,
     qdrant_repo: Arc<dyn QdrantRepo>,
     embedder: Arc<dyn Embedder>,
+    text_generator: Arc<dyn TextGenerator>,
 }
 
 impl MemoService {
@@ -20,11 +106,17 @@ impl MemoService {
         db: Arc<DatabaseConnection>,
         qdrant_repo: Arc<dyn QdrantRepo>,
         embedder: Arc<dyn Embedder>,
+        text_generator: Arc<dyn TextGenerator>,
     ) -> Self {
         Self {
-            memo_repo: MemoRepository::new(db),
+            memo_repo: MemoRepository::new(db.clone()),
+            revision_repo: MemoRevisionRepository::new(db.clone()),
+            tag_repo: TagRepository::new(db.clone()),
+            notebook_repo: NotebookRepository::new(db.clone()),
+            user_repo: UserRepository::new(db),
             qdrant_repo,
             embedder,
+            text_generator,
         }
     }
 
@@ -33,14 +125,36 @@ impl MemoService {
         user_id: i32,
         req: CreateMemoRequest,
     ) -> Result<MemoResponse, ServiceError> {
-        let memo = self.memo_repo.create(user_id, req.content.clone()).await?;
+        let mut tag_names = validate_tag_names(&req.tags)?;
+        if let Some(notebook_id) = req.notebook_id {
+            self.ensure_notebook(user_id, notebook_id).await?;
+        }
+        let memo = self
+            .memo_repo
+            .create(user_id, req.content.clone(), req.notebook_id)
+            .await?;
+
+        // 직접 붙인 태그가 없을 때만 자동 태그를 붙인다. 제안에 실패해도 메모 작성은 성공으로 둔다.
+        if tag_names.is_empty() && self.auto_tag_enabled(user_id).await? {
+            match self.tag_suggestions(user_id, &memo.content, &[]).await {
+                Ok(suggestions) => {
+                    tag_names = suggestions
+                        .existing
+                        .into_iter()
+                        .chain(suggestions.new)
+                        .collect();
+                }
+                Err(e) => tracing::warn!("Failed to auto-tag memo {}: {}", memo.id, e),
+            }
+        }
+        let tags = self.set_tags(user_id, memo.id, &tag_names).await?;
 
         let vector = self.embedder.embed(&req.content).await?;
         self.qdrant_repo
-            .upsert_memo(memo.id, user_id, vector)
+            .upsert_memo(MemoPayload::new(&memo, tags.clone()), vector)
             .await?;
 
-        Ok(MemoResponse::from(memo))
+        Ok(MemoResponse::from((memo, tags)))
     }
 
     pub async fn get_memo(&self, user_id: i32, memo_id: i32) -> Result<MemoResponse, ServiceError> {
@@ -54,12 +168,208 @@ impl MemoService {
             return Err(ServiceError::Unauthorized);
         }
 
-        Ok(MemoResponse::from(memo))
+        Ok(self.with_tags(vec![memo]).await?.remove(0))
+    }
+
+    pub async fn list_memos(
+        &self,
+        user_id: i32,
+        query: MemoListQuery,
+    ) -> Result<MemoListResponse, ServiceError> {
+        let limit = query
+            .limit
+            .unwrap_or(DEFAULT_PAGE_SIZE)
+            .clamp(1, MAX_PAGE_SIZE);
+        let after = query.cursor.as_deref().map(decode_cursor).transpose()?;
+        let notebook_ids = match query.notebook_id {
+            Some(notebook_id) => {
+                self.ensure_notebook(user_id, notebook_id).await?;
+                Some(if query.recursive {
+                    self.notebook_repo
+                        .find_subtree_ids(user_id, notebook_id)
+                        .await?
+                } else {
+                    vec![notebook_id]
+                })
+            }
+            None => None,
+        };
+        let filter = MemoListFilter {
+            is_pinned: query.pinned,
+            created_since: query.created_since,
+            created_until: query.created_until,
+            updated_since: query.updated_since,
+            updated_until: query.updated_until,
+            tags: query
+                .tags
+                .as_deref()
+                .unwrap_or_default()
+                .split(',')
+                .map(normalize_tag_name)
+                .filter(|tag| !tag.is_empty())
+                .collect(),
+            notebook_ids,
+            is_archived: Some(query.archived.unwrap_or(false)),
+            ..Default::default()
+        };
+
+        // 한 건을 더 읽어 다음 페이지가 있는지 확인한다.
+        let mut memos = self
+            .memo_repo
+            .list_by_user(user_id, filter, after, limit + 1)
+            .await?;
+        let next_cursor = if memos.len() as u64 > limit {
+            memos.truncate(limit as usize);
+            memos
+                .last()
+                .map(|memo| encode_cursor(&MemoCursor::from(memo)))
+        } else {
+            None
+        };
+
+        Ok(MemoListResponse {
+            memos: self.with_tags(memos).await?,
+            next_cursor,
+        })
+    }
+
+    // 검색을 실행하지 않고 검색어가 유효한지만 확인한다. 저장된 검색을 만들 때 쓴다.
+    pub fn validate_search_query(q: &str) -> Result<(), ServiceError> {
+        SearchPlan::new(q).map(|_| ())
+    }
+
+    pub async fn search_memos(
+        &self,
+        user_id: i32,
+        query: MemoSearchQuery,
+    ) -> Result<MemoSearchResponse, ServiceError> {
+        let plan = SearchPlan::new(&query.q)?;
+        let limit = query
+            .limit
+            .unwrap_or(DEFAULT_SEARCH_PAGE_SIZE)
+            .clamp(1, MAX_PAGE_SIZE);
+
+        let (hits, total) = self
+            .memo_repo
+            .search_keyword(
+                user_id,
+                plan.tsquery.as_deref(),
+                &plan.filter,
+                limit,
+                query.offset.unwrap_or(0),
+            )
+            .await?;
+        let ids: Vec<i32> = hits.iter().map(|(memo, _)| memo.id).collect();
+        let mut tags = self.tag_repo.find_names_by_memo_ids(&ids).await?;
+
+        Ok(MemoSearchResponse {
+            results: hits
+                .into_iter()
+                .map(|(memo, rank)| {
+                    let memo_tags = tags.remove(&memo.id).unwrap_or_default();
+                    MemoSearchResult {
+                        snippet: search::highlight(&memo.content, &plan.highlight_words),
+                        memo: MemoResponse::from((memo, memo_tags)),
+                        rank,
+                    }
+                })
+                .collect(),
+            total,
+        })
     }
 
-    pub async fn list_memos(&self, user_id: i32) -> Result<Vec<MemoResponse>, ServiceError> {
-        let memos = self.memo_repo.find_by_user_id(user_id).await?;
-        Ok(memos.into_iter().map(MemoResponse::from).collect())
+    // 키워드 검색과 의미 기반 검색을 동시에 실행해 순위를 RRF로 합친다.
+    // 제외어나 필드 조건만 있어 임베딩할 문장이 없으면 키워드 검색 결과만 사용한다.
+    pub async fn hybrid_search(
+        &self,
+        user_id: i32,
+        query: HybridSearchQuery,
+    ) -> Result<HybridSearchResponse, ServiceError> {
+        let plan = SearchPlan::new(&query.q)?;
+        let limit = query
+            .limit
+            .unwrap_or(DEFAULT_SEARCH_PAGE_SIZE)
+            .clamp(1, MAX_PAGE_SIZE);
+        let candidates = limit.max(HYBRID_CANDIDATE_POOL);
+
+        let keyword = async {
+            let (hits, _) = self
+                .memo_repo
+                .search_keyword(
+                    user_id,
+                    plan.tsquery.as_deref(),
+                    &plan.filter,
+                    candidates,
+                    0,
+                )
+                .await?;
+            Ok::<_, ServiceError>(hits)
+        };
+        let semantic = async {
+            let Some(text) = &plan.semantic_text else {
+                return Ok(Vec::new());
+            };
+            let vector = self.embedder.embed(text).await?;
+            let hits = self
+                .qdrant_repo
+                .search_similar(user_id, vector, &plan.filter, candidates)
+                .await?;
+            Ok::<_, ServiceError>(hits)
+        };
+        let (keyword_hits, semantic_hits): (Vec<_>, Vec<_>) = tokio::try_join!(keyword, semantic)?;
+
+        let keyword_ids: Vec<i32> = keyword_hits.iter().map(|(memo, _)| memo.id).collect();
+        let semantic_ids: Vec<i32> = semantic_hits.iter().map(|(id, _)| *id).collect();
+        let keyword_scores: HashMap<i32, f32> = keyword_hits
+            .iter()
+            .map(|(memo, rank)| (memo.id, *rank))
+            .collect();
+        let semantic_scores: HashMap<i32, f32> = semantic_hits.into_iter().collect();
+
+        // 의미 기반 검색에서만 찾은 메모는 본문을 따로 읽어 오면서 제외어 조건을 적용한다.
+        // 그 사이 삭제되었거나 페이로드가 최신이 아니어서 조건에 맞지 않게 된 메모도 여기서 빠진다.
+        let mut memos: HashMap<i32, memo::Model> = keyword_hits
+            .into_iter()
+            .map(|(memo, _)| (memo.id, memo))
+            .collect();
+        let missing: Vec<i32> = semantic_ids
+            .iter()
+            .copied()
+            .filter(|id| !memos.contains_key(id))
+            .collect();
+        memos.extend(
+            self.memo_repo
+                .find_matching_ids(
+                    user_id,
+                    &missing,
+                    &plan.filter,
+                    plan.exclusion_tsquery.as_deref(),
+                )
+                .await?
+                .into_iter()
+                .map(|memo| (memo.id, memo)),
+        );
+
+        let ids: Vec<i32> = memos.keys().copied().collect();
+        let mut tags = self.tag_repo.find_names_by_memo_ids(&ids).await?;
+
+        let results = search::reciprocal_rank_fusion(&[&keyword_ids, &semantic_ids])
+            .into_iter()
+            .filter_map(|(id, score)| {
+                let memo = memos.remove(&id)?;
+                let memo_tags = tags.remove(&id).unwrap_or_default();
+                Some(HybridSearchResult {
+                    snippet: search::highlight(&memo.content, &plan.highlight_words),
+                    memo: MemoResponse::from((memo, memo_tags)),
+                    score,
+                    keyword_score: keyword_scores.get(&id).copied(),
+                    semantic_score: semantic_scores.get(&id).copied(),
+                })
+            })
+            .take(limit as usize)
+            .collect();
+
+        Ok(HybridSearchResponse { results })
     }
 
     pub async fn update_memo(
@@ -78,14 +388,20 @@ impl MemoService {
             return Err(ServiceError::Unauthorized);
         }
 
+        // 태그를 보내지 않으면 기존 태그를 그대로 둔다.
+        let tag_names = req.tags.as_deref().map(validate_tag_names).transpose()?;
         let updated_memo = self.memo_repo.update(memo_id, req.content.clone()).await?;
+        let tags = match tag_names {
+            Some(names) => self.set_tags(user_id, memo_id, &names).await?,
+            None => self.load_tags(memo_id).await?,
+        };
 
         let vector = self.embedder.embed(&req.content).await?;
         self.qdrant_repo
-            .upsert_memo(memo_id, user_id, vector)
+            .upsert_memo(MemoPayload::new(&updated_memo, tags.clone()), vector)
             .await?;
 
-        Ok(MemoResponse::from(updated_memo))
+        Ok(MemoResponse::from((updated_memo, tags)))
     }
 
     pub async fn delete_memo(&self, user_id: i32, memo_id: i32) -> Result<(), ServiceError> {
@@ -99,13 +415,110 @@ impl MemoService {
             return Err(ServiceError::Unauthorized);
         }
 
-        self.memo_repo.delete(memo_id).await?;
-        self.qdrant_repo.delete_memo(memo_id).await?;
+        self.trash_memos(user_id, &[memo_id]).await
+    }
+
+    // 메모를 휴지통으로 옮긴다. 영구 삭제는 보관 기간이 지난 뒤 `purge_trash`가 한다.
+    // 노트북을 지울 때도 그 안의 메모를 한꺼번에 옮긴다. 다른 사용자의 메모는 건드리지 않는다.
+    pub async fn trash_memos(&self, user_id: i32, memo_ids: &[i32]) -> Result<(), ServiceError> {
+        if memo_ids.is_empty() {
+            return Ok(());
+        }
+
+        self.memo_repo.trash_many(user_id, memo_ids).await?;
+        self.refresh_payloads(user_id, memo_ids).await
+    }
 
-        Ok(())
+    pub async fn list_trash(&self, user_id: i32) -> Result<Vec<TrashedMemoResponse>, ServiceError> {
+        let retention = TimeDelta::days(trash_retention_days());
+        let memos = self.memo_repo.find_trashed(user_id).await?;
+        let ids: Vec<i32> = memos.iter().map(|memo| memo.id).collect();
+        let mut tags = self.tag_repo.find_names_by_memo_ids(&ids).await?;
+
+        Ok(memos
+            .into_iter()
+            .filter_map(|memo| {
+                let deleted_at = memo.deleted_at?;
+                let memo_tags = tags.remove(&memo.id).unwrap_or_default();
+                Some(TrashedMemoResponse {
+                    memo: MemoResponse::from((memo, memo_tags)),
+                    deleted_at,
+                    purge_at: deleted_at + retention,
+                })
+            })
+            .collect())
     }
 
-    pub async fn toggle_pin(&self, user_id: i32, memo_id: i32) -> Result<MemoResponse, ServiceError> {
+    pub async fn restore_memo(
+        &self,
+        user_id: i32,
+        memo_id: i32,
+    ) -> Result<MemoResponse, ServiceError> {
+        let memo = self
+            .memo_repo
+            .find_trashed_by_id(memo_id, user_id)
+            .await?
+            .ok_or(ServiceError::MemoNotFound)?;
+
+        let restored = self.memo_repo.restore(memo).await?;
+        let tags = self.load_tags(memo_id).await?;
+        self.qdrant_repo
+            .update_payload(MemoPayload::new(&restored, tags.clone()))
+            .await?;
+
+        Ok(MemoResponse::from((restored, tags)))
+    }
+
+    // `cutoff` 이전에 휴지통으로 옮긴 메모를 모든 사용자에 걸쳐 영구 삭제하고 삭제한 수를 돌려준다.
+    // 벡터는 트랜잭션 안에서 실제로 지운 행에 대해서만 지우므로, 그사이 복원된 메모의 벡터는 남는다.
+    pub async fn purge_trash(&self, cutoff: NaiveDateTime) -> Result<usize, ServiceError> {
+        let purged = self
+            .memo_repo
+            .purge_deleted_before(cutoff, |ids| async move {
+                self.qdrant_repo.delete_memos(&ids).await
+            })
+            .await?;
+        Ok(purged.len())
+    }
+
+    pub async fn move_memos(
+        &self,
+        user_id: i32,
+        req: MoveMemosRequest,
+    ) -> Result<Vec<MemoResponse>, ServiceError> {
+        let ids: Vec<i32> = req
+            .memo_ids
+            .into_iter()
+            .collect::<HashSet<_>>()
+            .into_iter()
+            .collect();
+        if ids.is_empty() {
+            return Err(ServiceError::Validation(
+                "memo_ids must not be empty".to_string(),
+            ));
+        }
+        // 하나라도 없거나 다른 사용자의 메모면 아무것도 옮기지 않는다.
+        if self.memo_repo.find_by_ids(user_id, &ids).await?.len() != ids.len() {
+            return Err(ServiceError::MemoNotFound);
+        }
+        if let Some(notebook_id) = req.notebook_id {
+            self.ensure_notebook(user_id, notebook_id).await?;
+        }
+
+        self.memo_repo
+            .move_to_notebook(user_id, &ids, req.notebook_id)
+            .await?;
+        self.refresh_payloads(user_id, &ids).await?;
+
+        let memos = self.memo_repo.find_by_ids(user_id, &ids).await?;
+        self.with_tags(memos).await
+    }
+
+    pub async fn toggle_pin(
+        &self,
+        user_id: i32,
+        memo_id: i32,
+    ) -> Result<MemoResponse, ServiceError> {
         let memo = self
             .memo_repo
             .find_by_id(memo_id)
@@ -117,8 +530,398 @@ impl MemoService {
         }
 
         let updated_memo = self.memo_repo.toggle_pin(memo_id).await?;
-        Ok(MemoResponse::from(updated_memo))
+        let tags = self.load_tags(memo_id).await?;
+        self.qdrant_repo
+            .update_payload(MemoPayload::new(&updated_memo, tags.clone()))
+            .await?;
+
+        Ok(MemoResponse::from((updated_memo, tags)))
     }
+
+    pub async fn archive_memo(
+        &self,
+        user_id: i32,
+        memo_id: i32,
+    ) -> Result<MemoResponse, ServiceError> {
+        self.set_archived(user_id, memo_id, true).await
+    }
+
+    pub async fn unarchive_memo(
+        &self,
+        user_id: i32,
+        memo_id: i32,
+    ) -> Result<MemoResponse, ServiceError> {
+        self.set_archived(user_id, memo_id, false).await
+    }
+
+    pub async fn list_revisions(
+        &self,
+        user_id: i32,
+        memo_id: i32,
+    ) -> Result<Vec<MemoRevisionSummary>, ServiceError> {
+        self.find_memo(user_id, memo_id).await?;
+
+        let revisions = self.revision_repo.find_by_memo_id(memo_id).await?;
+        Ok(revisions
+            .into_iter()
+            .map(MemoRevisionSummary::from)
+            .collect())
+    }
+
+    pub async fn get_revision(
+        &self,
+        user_id: i32,
+        memo_id: i32,
+        revision_id: i32,
+    ) -> Result<MemoRevisionResponse, ServiceError> {
+        self.find_memo(user_id, memo_id).await?;
+
+        let revision = self.find_revision(memo_id, revision_id).await?;
+        Ok(MemoRevisionResponse::from(revision))
+    }
+
+    pub async fn diff_revisions(
+        &self,
+        user_id: i32,
+        memo_id: i32,
+        query: MemoRevisionDiffQuery,
+    ) -> Result<MemoRevisionDiffResponse, ServiceError> {
+        let memo = self.find_memo(user_id, memo_id).await?;
+
+        let from = self.find_revision(memo_id, query.from).await?;
+        let to = match query.to {
+            Some(to) => self.find_revision(memo_id, to).await?.content,
+            None => memo.content,
+        };
+
+        Ok(MemoRevisionDiffResponse {
+            from: query.from,
+            to: query.to,
+            granularity: query.granularity,
+            chunks: diff_chunks(&from.content, &to, query.granularity),
+        })
+    }
+
+    // 되돌리기도 수정이므로 되돌리기 전의 내용이 새 버전으로 남는다.
+    pub async fn restore_revision(
+        &self,
+        user_id: i32,
+        memo_id: i32,
+        revision_id: i32,
+    ) -> Result<MemoResponse, ServiceError> {
+        self.find_memo(user_id, memo_id).await?;
+        let revision = self.find_revision(memo_id, revision_id).await?;
+
+        let restored = self.memo_repo.update(memo_id, revision.content).await?;
+        let tags = self.load_tags(memo_id).await?;
+
+        let vector = self.embedder.embed(&restored.content).await?;
+        self.qdrant_repo
+            .upsert_memo(MemoPayload::new(&restored, tags.clone()), vector)
+            .await?;
+
+        Ok(MemoResponse::from((restored, tags)))
+    }
+
+    pub async fn suggest_tags(
+        &self,
+        user_id: i32,
+        memo_id: i32,
+    ) -> Result<TagSuggestionResponse, ServiceError> {
+        let memo = self
+            .memo_repo
+            .find_by_id(memo_id)
+            .await?
+            .ok_or(ServiceError::MemoNotFound)?;
+
+        if memo.user_id != user_id {
+            return Err(ServiceError::Unauthorized);
+        }
+
+        let current = self.load_tags(memo_id).await?;
+        self.tag_suggestions(user_id, &memo.content, &current).await
+    }
+
+    // 모델이 돌려준 후보를 태그 이름 규칙으로 걸러 기존 태그와 새 태그로 나눈다.
+    // 규칙에 맞지 않는 후보나 메모에 이미 붙은 태그는 버린다.
+    async fn tag_suggestions(
+        &self,
+        user_id: i32,
+        content: &str,
+        current: &[String],
+    ) -> Result<TagSuggestionResponse, ServiceError> {
+        let vocabulary: Vec<String> = self
+            .tag_repo
+            .find_by_user_id(user_id)
+            .await?
+            .into_iter()
+            .take(MAX_TAG_VOCABULARY)
+            .map(|tag| tag.name)
+            .collect();
+        let candidates = self
+            .text_generator
+            .suggest_tags(content, &vocabulary)
+            .await?;
+
+        let known: HashSet<&str> = vocabulary.iter().map(String::as_str).collect();
+        let mut seen: HashSet<String> = current.iter().cloned().collect();
+        let mut suggestions = TagSuggestionResponse::default();
+        for candidate in candidates {
+            let Ok(name) = validate_tag_name(&candidate) else {
+                continue;
+            };
+            if !seen.insert(name.clone()) {
+                continue;
+            }
+            if known.contains(name.as_str()) {
+                if suggestions.existing.len() < MAX_SUGGESTED_EXISTING_TAGS {
+                    suggestions.existing.push(name);
+                }
+            } else if suggestions.new.len() < MAX_SUGGESTED_NEW_TAGS {
+                suggestions.new.push(name);
+            }
+        }
+        Ok(suggestions)
+    }
+
+    async fn auto_tag_enabled(&self, user_id: i32) -> Result<bool, ServiceError> {
+        Ok(self
+            .user_repo
+            .find_by_id(user_id)
+            .await?
+            .is_some_and(|user| user.auto_tag_memos))
+    }
+
+    // 태그 이름이 바뀌거나 태그가 삭제·병합되면, 또는 메모가 다른 노트북이나 휴지통으로 옮겨지면
+    // Qdrant 페이로드도 다시 써야 한다.
+    pub async fn refresh_payloads(
+        &self,
+        user_id: i32,
+        memo_ids: &[i32],
+    ) -> Result<(), ServiceError> {
+        let mut tags = self.tag_repo.find_names_by_memo_ids(memo_ids).await?;
+        for memo in self
+            .memo_repo
+            .find_by_ids_with_trashed(user_id, memo_ids)
+            .await?
+        {
+            let memo_tags = tags.remove(&memo.id).unwrap_or_default();
+            self.qdrant_repo
+                .update_payload(MemoPayload::new(&memo, memo_tags))
+                .await?;
+        }
+        Ok(())
+    }
+
+    // 필터용 페이로드 키(태그, 노트북, 고정, 작성·수정 시각 등)가 생기기 전에 저장된 포인트에는
+    // `user_id`와 `memo_id`만 있어 필터를 건 의미 기반 검색에서 빠진다. 모든 메모의 페이로드를 현재 값으로 다시 쓴다.
+    // 벡터가 없는 메모처럼 실패한 메모는 건너뛰고 (갱신한 수, 실패한 수)를 돌려준다.
+    pub async fn backfill_payloads(&self) -> Result<(usize, usize), ServiceError> {
+        let (mut updated, mut failed) = (0, 0);
+        let mut after_id = 0;
+        loop {
+            let memos = self
+                .memo_repo
+                .find_all_after_id(after_id, PAYLOAD_BACKFILL_BATCH_SIZE)
+                .await?;
+            let Some(last) = memos.last() else {
+                break;
+            };
+            after_id = last.id;
+
+            let ids: Vec<i32> = memos.iter().map(|memo| memo.id).collect();
+            let mut tags = self.tag_repo.find_names_by_memo_ids(&ids).await?;
+            for memo in memos {
+                let memo_tags = tags.remove(&memo.id).unwrap_or_default();
+                match self
+                    .qdrant_repo
+                    .update_payload(MemoPayload::new(&memo, memo_tags))
+                    .await
+                {
+                    Ok(()) => updated += 1,
+                    Err(e) => {
+                        tracing::warn!("Failed to backfill payload of memo {}: {}", memo.id, e);
+                        failed += 1;
+                    }
+                }
+            }
+        }
+        Ok((updated, failed))
+    }
+
+    async fn find_memo(&self, user_id: i32, memo_id: i32) -> Result<memo::Model, ServiceError> {
+        let memo = self
+            .memo_repo
+            .find_by_id(memo_id)
+            .await?
+            .ok_or(ServiceError::MemoNotFound)?;
+
+        if memo.user_id != user_id {
+            return Err(ServiceError::Unauthorized);
+        }
+
+        Ok(memo)
+    }
+
+    async fn set_archived(
+        &self,
+        user_id: i32,
+        memo_id: i32,
+        is_archived: bool,
+    ) -> Result<MemoResponse, ServiceError> {
+        self.find_memo(user_id, memo_id).await?;
+
+        let updated_memo = self.memo_repo.set_archived(memo_id, is_archived).await?;
+        let tags = self.load_tags(memo_id).await?;
+        self.qdrant_repo
+            .update_payload(MemoPayload::new(&updated_memo, tags.clone()))
+            .await?;
+
+        Ok(MemoResponse::from((updated_memo, tags)))
+    }
+
+    async fn find_revision(
+        &self,
+        memo_id: i32,
+        revision_id: i32,
+    ) -> Result<memo_revision::Model, ServiceError> {
+        self.revision_repo
+            .find_by_id(revision_id, memo_id)
+            .await?
+            .ok_or(ServiceError::MemoRevisionNotFound)
+    }
+
+    async fn ensure_notebook(&self, user_id: i32, notebook_id: i32) -> Result<(), ServiceError> {
+        self.notebook_repo
+            .find_by_id(notebook_id, user_id)
+            .await?
+            .ok_or(ServiceError::NotebookNotFound)?;
+        Ok(())
+    }
+
+    async fn set_tags(
+        &self,
+        user_id: i32,
+        memo_id: i32,
+        names: &[String],
+    ) -> Result<Vec<String>, ServiceError> {
+        let tags = self.tag_repo.find_or_create(user_id, names).await?;
+        let tag_ids: Vec<i32> = tags.iter().map(|tag| tag.id).collect();
+        self.tag_repo.set_memo_tags(memo_id, &tag_ids).await?;
+        self.load_tags(memo_id).await
+    }
+
+    async fn load_tags(&self, memo_id: i32) -> Result<Vec<String>, ServiceError> {
+        let mut tags = self.tag_repo.find_names_by_memo_ids(&[memo_id]).await?;
+        Ok(tags.remove(&memo_id).unwrap_or_default())
+    }
+
+    async fn with_tags(&self, memos: Vec<memo::Model>) -> Result<Vec<MemoResponse>, ServiceError> {
+        let ids: Vec<i32> = memos.iter().map(|memo| memo.id).collect();
+        let mut tags = self.tag_repo.find_names_by_memo_ids(&ids).await?;
+        Ok(memos
+            .into_iter()
+            .map(|memo| {
+                let memo_tags = tags.remove(&memo.id).unwrap_or_default();
+                MemoResponse::from((memo, memo_tags))
+            })
+            .collect())
+    }
+}
+
+// 휴지통의 메모가 영구 삭제되기까지의 기간
+pub fn trash_retention_days() -> i64 {
+    std::env::var("TRASH_RETENTION_DAYS")
+        .ok()
+        .and_then(|v| v.parse().ok())
+        .unwrap_or(30)
+}
+
+// 보관 기간이 지난 휴지통 메모를 주기적으로 영구 삭제한다. 실패하면 다음 주기에 다시 시도한다.
+pub fn spawn_trash_purge(memo_service: Arc<MemoService>) -> tokio::task::JoinHandle<()> {
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(TRASH_PURGE_INTERVAL);
+        loop {
+            interval.tick().await;
+            let cutoff = Utc::now().naive_utc() - TimeDelta::days(trash_retention_days());
+            match memo_service.purge_trash(cutoff).await {
+                Ok(0) => {}
+                Ok(count) => tracing::info!("Purged {} memos from trash", count),
+                Err(e) => tracing::warn!("Failed to purge trash: {}", e),
+            }
+        }
+    })
+}
+
+// 같은 종류의 변경이 이어지면 하나의 구간으로 합친다. 바뀐 부분은 삭제 구간이 삽입 구간보다 먼저 온다.
+fn diff_chunks(old: &str, new: &str, granularity: DiffGranularity) -> Vec<DiffChunk> {
+    let mut config = TextDiff::configure();
+    config.timeout(DIFF_TIMEOUT);
+    let diff = match granularity {
+        DiffGranularity::Line => config.diff_lines(old, new),
+        DiffGranularity::Char => config.diff_chars(old, new),
+    };
+
+    let mut chunks: Vec<DiffChunk> = Vec::new();
+    for change in diff.iter_all_changes() {
+        let op = match change.tag() {
+            ChangeTag::Equal => DiffOp::Equal,
+            ChangeTag::Insert => DiffOp::Insert,
+            ChangeTag::Delete => DiffOp::Delete,
+        };
+        match chunks.last_mut() {
+            Some(last) if last.op == op => last.text.push_str(change.value()),
+            _ => chunks.push(DiffChunk {
+                op,
+                text: change.value().to_string(),
+            }),
+        }
+    }
+    chunks
+}
+
+// 커서는 클라이언트가 해석하지 않는 불투명한 문자열로 취급한다.
+fn encode_cursor(cursor: &MemoCursor) -> String {
+    let raw = format!(
+        "{}:{}:{}",
+        u8::from(cursor.is_pinned),
+        cursor.updated_at.and_utc().timestamp_micros(),
+        cursor.id
+    );
+    URL_SAFE_NO_PAD.encode(raw)
+}
+
+fn decode_cursor(cursor: &str) -> Result<MemoCursor, ServiceError> {
+    let invalid = || ServiceError::Validation("Invalid cursor".to_string());
+
+    let raw = URL_SAFE_NO_PAD.decode(cursor).map_err(|_| invalid())?;
+    let raw = String::from_utf8(raw).map_err(|_| invalid())?;
+    let mut parts = raw.split(':');
+
+    let is_pinned = match parts.next() {
+        Some("1") => true,
+        Some("0") => false,
+        _ => return Err(invalid()),
+    };
+    let updated_at = parts
+        .next()
+        .and_then(|micros| micros.parse().ok())
+        .and_then(DateTime::from_timestamp_micros)
+        .ok_or_else(invalid)?
+        .naive_utc();
+    let id = parts
+        .next()
+        .and_then(|id| id.parse().ok())
+        .ok_or_else(invalid)?;
+    if parts.next().is_some() {
+        return Err(invalid());
+    }
+
+    Ok(MemoCursor {
+        is_pinned,
+        updated_at,
+        id,
+    })
 }
 
 #[cfg(test)]
@@ -1,6 +1,7 @@
 use super::*;
 use crate::{
-    db, entities::user,
+    db,
+    entities::user,
     test_utils::{MockGeminiClient, MockQdrantRepository},
 };
 use chrono::Utc;
@@ -25,6 +26,7 @@ async fn setup_test_db() -> (Arc<DatabaseConnection>, i32) {
         password_hash: Set(Some("test_hash".to_string())),
         created_at: Set(now),
         updated_at: Set(now),
+        ..Default::default()
     };
     let user_id = new_user.insert(db.as_ref()).await.unwrap().id;
 
@@ -36,10 +38,16 @@ async fn test_create_and_get_memo() {
     let (db, user_id) = setup_test_db().await;
     let qdrant_repo = Arc::new(MockQdrantRepository::new());
     let embedder = Arc::new(MockGeminiClient::new());
-    let service = MemoService::new(db, qdrant_repo, embedder as Arc<dyn Embedder>);
+    let service = MemoService::new(
+        db,
+        qdrant_repo,
+        embedder.clone() as Arc<dyn Embedder>,
+        embedder as Arc<dyn TextGenerator>,
+    );
 
     let req = CreateMemoRequest {
         content: "Test memo content".to_string(),
+        ..Default::default()
     };
 
     let created = service.create_memo(user_id, req).await.unwrap();
@@ -57,10 +65,16 @@ async fn test_get_memo_unauthorized() {
     let (db, user_id) = setup_test_db().await;
     let qdrant_repo = Arc::new(MockQdrantRepository::new());
     let embedder = Arc::new(MockGeminiClient::new());
-    let service = MemoService::new(db, qdrant_repo, embedder as Arc<dyn Embedder>);
+    let service = MemoService::new(
+        db,
+        qdrant_repo,
+        embedder.clone() as Arc<dyn Embedder>,
+        embedder as Arc<dyn TextGenerator>,
+    );
 
     let req = CreateMemoRequest {
         content: "User 1's memo".to_string(),
+        ..Default::default()
     };
 
     let created = service.create_memo(user_id, req).await.unwrap();
@@ -74,15 +88,22 @@ async fn test_update_memo() {
     let (db, user_id) = setup_test_db().await;
     let qdrant_repo = Arc::new(MockQdrantRepository::new());
     let embedder = Arc::new(MockGeminiClient::new());
-    let service = MemoService::new(db, qdrant_repo, embedder as Arc<dyn Embedder>);
+    let service = MemoService::new(
+        db,
+        qdrant_repo,
+        embedder.clone() as Arc<dyn Embedder>,
+        embedder as Arc<dyn TextGenerator>,
+    );
 
     let create_req = CreateMemoRequest {
         content: "Original content".to_string(),
+        ..Default::default()
     };
     let created = service.create_memo(user_id, create_req).await.unwrap();
 
     let update_req = UpdateMemoRequest {
         content: "Updated content".to_string(),
+        ..Default::default()
     };
     let updated = service
         .update_memo(user_id, created.id, update_req)
@@ -98,10 +119,16 @@ async fn test_toggle_pin() {
     let (db, user_id) = setup_test_db().await;
     let qdrant_repo = Arc::new(MockQdrantRepository::new());
     let embedder = Arc::new(MockGeminiClient::new());
-    let service = MemoService::new(db, qdrant_repo, embedder as Arc<dyn Embedder>);
+    let service = MemoService::new(
+        db,
+        qdrant_repo,
+        embedder.clone() as Arc<dyn Embedder>,
+        embedder as Arc<dyn TextGenerator>,
+    );
 
     let req = CreateMemoRequest {
         content: "Pin test".to_string(),
+        ..Default::default()
     };
     let created = service.create_memo(user_id, req).await.unwrap();
     assert!(!created.is_pinned);
@@ -118,13 +145,19 @@ async fn test_list_memos_ordering() {
     let (db, user_id) = setup_test_db().await;
     let qdrant_repo = Arc::new(MockQdrantRepository::new());
     let embedder = Arc::new(MockGeminiClient::new());
-    let service = MemoService::new(db, qdrant_repo, embedder as Arc<dyn Embedder>);
+    let service = MemoService::new(
+        db,
+        qdrant_repo,
+        embedder.clone() as Arc<dyn Embedder>,
+        embedder as Arc<dyn TextGenerator>,
+    );
 
     let memo1 = service
         .create_memo(
             user_id,
             CreateMemoRequest {
                 content: "First".to_string(),
+                ..Default::default()
             },
         )
         .await
@@ -135,6 +168,7 @@ async fn test_list_memos_ordering() {
             user_id,
             CreateMemoRequest {
                 content: "Second".to_string(),
+                ..Default::default()
             },
         )
         .await
@@ -142,7 +176,11 @@ async fn test_list_memos_ordering() {
 
     service.toggle_pin(user_id, memo1.id).await.unwrap();
 
-    let memos = service.list_memos(user_id).await.unwrap();
+    let memos = service
+        .list_memos(user_id, MemoListQuery::default())
+        .await
+        .unwrap()
+        .memos;
 
     assert!(memos[0].is_pinned);
     assert_eq!(memos[0].id, memo1.id);
@@ -150,15 +188,880 @@ async fn test_list_memos_ordering() {
     assert_eq!(memos[1].id, memo2.id);
 }
 
+#[tokio::test]
+async fn test_list_memos_cursor_pagination() {
+    let (db, user_id) = setup_test_db().await;
+    let qdrant_repo = Arc::new(MockQdrantRepository::new());
+    let embedder = Arc::new(MockGeminiClient::new());
+    let service = MemoService::new(
+        db,
+        qdrant_repo,
+        embedder.clone() as Arc<dyn Embedder>,
+        embedder as Arc<dyn TextGenerator>,
+    );
+
+    let mut ids = Vec::new();
+    for i in 0..5 {
+        let memo = service
+            .create_memo(
+                user_id,
+                CreateMemoRequest {
+                    content: format!("Memo {}", i),
+                    ..Default::default()
+                },
+            )
+            .await
+            .unwrap();
+        ids.push(memo.id);
+    }
+    service.toggle_pin(user_id, ids[1]).await.unwrap();
+
+    let first = service
+        .list_memos(
+            user_id,
+            MemoListQuery {
+                limit: Some(2),
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+    let first_ids: Vec<i32> = first.memos.iter().map(|m| m.id).collect();
+    assert_eq!(first_ids, vec![ids[1], ids[4]]);
+    let cursor = first.next_cursor.unwrap();
+
+    // 이미 읽은 페이지의 메모가 수정되어도 다음 페이지는 밀리거나 겹치지 않는다.
+    service
+        .update_memo(
+            user_id,
+            ids[4],
+            UpdateMemoRequest {
+                content: "Edited".to_string(),
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+
+    let second = service
+        .list_memos(
+            user_id,
+            MemoListQuery {
+                limit: Some(2),
+                cursor: Some(cursor),
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+    let second_ids: Vec<i32> = second.memos.iter().map(|m| m.id).collect();
+    assert_eq!(second_ids, vec![ids[3], ids[2]]);
+
+    let third = service
+        .list_memos(
+            user_id,
+            MemoListQuery {
+                limit: Some(2),
+                cursor: second.next_cursor,
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+    let third_ids: Vec<i32> = third.memos.iter().map(|m| m.id).collect();
+    assert_eq!(third_ids, vec![ids[0]]);
+    assert!(third.next_cursor.is_none());
+}
+
+#[tokio::test]
+async fn test_list_memos_filters() {
+    let (db, user_id) = setup_test_db().await;
+    let qdrant_repo = Arc::new(MockQdrantRepository::new());
+    let embedder = Arc::new(MockGeminiClient::new());
+    let service = MemoService::new(
+        db,
+        qdrant_repo,
+        embedder.clone() as Arc<dyn Embedder>,
+        embedder as Arc<dyn TextGenerator>,
+    );
+
+    let older = service
+        .create_memo(
+            user_id,
+            CreateMemoRequest {
+                content: "Older".to_string(),
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+    let newer = service
+        .create_memo(
+            user_id,
+            CreateMemoRequest {
+                content: "Newer".to_string(),
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+    service.toggle_pin(user_id, older.id).await.unwrap();
+
+    let pinned = service
+        .list_memos(
+            user_id,
+            MemoListQuery {
+                pinned: Some(true),
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+    assert_eq!(pinned.memos.len(), 1);
+    assert_eq!(pinned.memos[0].id, older.id);
+
+    let created_since_newer = service
+        .list_memos(
+            user_id,
+            MemoListQuery {
+                created_since: Some(newer.created_at),
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+    assert_eq!(created_since_newer.memos.len(), 1);
+    assert_eq!(created_since_newer.memos[0].id, newer.id);
+
+    let created_until_newer = service
+        .list_memos(
+            user_id,
+            MemoListQuery {
+                created_until: Some(newer.created_at),
+                updated_until: Some(Utc::now().naive_utc() + chrono::Duration::minutes(1)),
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+    assert_eq!(created_until_newer.memos.len(), 1);
+    assert_eq!(created_until_newer.memos[0].id, older.id);
+
+    let none = service
+        .list_memos(
+            user_id,
+            MemoListQuery {
+                updated_since: Some(Utc::now().naive_utc() + chrono::Duration::minutes(1)),
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+    assert!(none.memos.is_empty());
+}
+
+#[tokio::test]
+async fn test_memo_tags_are_normalized_and_replaced() {
+    let (db, user_id) = setup_test_db().await;
+    let qdrant_repo = Arc::new(MockQdrantRepository::new());
+    let embedder = Arc::new(MockGeminiClient::new());
+    let service = MemoService::new(
+        db,
+        qdrant_repo,
+        embedder.clone() as Arc<dyn Embedder>,
+        embedder as Arc<dyn TextGenerator>,
+    );
+
+    let created = service
+        .create_memo(
+            user_id,
+            CreateMemoRequest {
+                content: "태그 메모".to_string(),
+                tags: vec![
+                    " #Rust ".to_string(),
+                    "rust".to_string(),
+                    "독서".to_string(),
+                ],
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+    assert_eq!(created.tags, vec!["rust", "독서"]);
+
+    // 태그를 보내지 않으면 그대로 두고, 보내면 통째로 바꾼다.
+    let kept = service
+        .update_memo(
+            user_id,
+            created.id,
+            UpdateMemoRequest {
+                content: "내용만 수정".to_string(),
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+    assert_eq!(kept.tags, vec!["rust", "독서"]);
+
+    let replaced = service
+        .update_memo(
+            user_id,
+            created.id,
+            UpdateMemoRequest {
+                content: "태그 수정".to_string(),
+                tags: Some(vec!["글감".to_string()]),
+            },
+        )
+        .await
+        .unwrap();
+    assert_eq!(replaced.tags, vec!["글감"]);
+    assert_eq!(
+        service.get_memo(user_id, created.id).await.unwrap().tags,
+        vec!["글감"]
+    );
+
+    for tags in [
+        vec!["a,b".to_string()],
+        vec!["두 단어".to_string()],
+        vec!["#".to_string()],
+    ] {
+        let result = service
+            .create_memo(
+                user_id,
+                CreateMemoRequest {
+                    content: "잘못된 태그".to_string(),
+                    tags,
+                    ..Default::default()
+                },
+            )
+            .await;
+        assert!(matches!(result, Err(ServiceError::Validation(_))));
+    }
+}
+
+#[tokio::test]
+async fn test_list_and_search_memos_by_tag() {
+    let (db, user_id) = setup_test_db().await;
+    let qdrant_repo = Arc::new(MockQdrantRepository::new());
+    let embedder = Arc::new(MockGeminiClient::new());
+    let service = MemoService::new(
+        db,
+        qdrant_repo,
+        embedder.clone() as Arc<dyn Embedder>,
+        embedder as Arc<dyn TextGenerator>,
+    );
+
+    let create = |content: &str, tags: &[&str]| {
+        service.create_memo(
+            user_id,
+            CreateMemoRequest {
+                content: content.to_string(),
+                tags: tags.iter().map(|&tag| String::from(tag)).collect(),
+                ..Default::default()
+            },
+        )
+    };
+    let both = create("러스트 책 메모", &["rust", "독서"]).await.unwrap();
+    let rust = create("러스트 코드 메모", &["rust"]).await.unwrap();
+    let untagged = create("러스트 잡담", &[]).await.unwrap();
+
+    let listed = service
+        .list_memos(
+            user_id,
+            MemoListQuery {
+                tags: Some("Rust, 독서".to_string()),
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+    let ids: Vec<i32> = listed.memos.iter().map(|m| m.id).collect();
+    assert_eq!(ids, vec![both.id]);
+    assert_eq!(listed.memos[0].tags, vec!["rust", "독서"]);
+
+    let found = service
+        .search_memos(
+            user_id,
+            MemoSearchQuery {
+                q: "러스트 tag:rust -tag:독서".to_string(),
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+    let ids: Vec<i32> = found.results.iter().map(|r| r.memo.id).collect();
+    assert_eq!(ids, vec![rust.id]);
+
+    // 의미 기반 검색 결과에도 태그 조건이 적용된다.
+    let hybrid = service
+        .hybrid_search(
+            user_id,
+            HybridSearchQuery {
+                q: "러스트 tag:rust".to_string(),
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+    let ids: Vec<i32> = hybrid.results.iter().map(|r| r.memo.id).collect();
+    assert_eq!(ids.len(), 2);
+    assert!(!ids.contains(&untagged.id));
+}
+
+#[tokio::test]
+async fn test_suggest_tags_prefers_existing_vocabulary() {
+    let (db, user_id) = setup_test_db().await;
+    let (_, other_user_id) = setup_test_db().await;
+    let qdrant_repo = Arc::new(MockQdrantRepository::new());
+    let embedder = Arc::new(MockGeminiClient::new());
+    let service = MemoService::new(
+        db,
+        qdrant_repo,
+        embedder.clone() as Arc<dyn Embedder>,
+        embedder as Arc<dyn TextGenerator>,
+    );
+
+    service
+        .create_memo(
+            user_id,
+            CreateMemoRequest {
+                content: "기존 태그".to_string(),
+                tags: vec!["rust".to_string(), "tokio".to_string()],
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+    let memo = service
+        .create_memo(
+            user_id,
+            CreateMemoRequest {
+                content: "Rust tokio 정리 #Async #rust #a,b #".to_string(),
+                tags: vec!["tokio".to_string()],
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+
+    // 이미 붙은 태그와 규칙에 맞지 않는 후보는 빠진다.
+    let suggestions = service.suggest_tags(user_id, memo.id).await.unwrap();
+    assert_eq!(suggestions.existing, vec!["rust"]);
+    assert_eq!(suggestions.new, vec!["async"]);
+
+    assert!(matches!(
+        service.suggest_tags(other_user_id, memo.id).await,
+        Err(ServiceError::Unauthorized)
+    ));
+}
+
+#[tokio::test]
+async fn test_create_memo_applies_auto_tags_when_enabled() {
+    let (db, user_id) = setup_test_db().await;
+    let qdrant_repo = Arc::new(MockQdrantRepository::new());
+    let embedder = Arc::new(MockGeminiClient::new());
+    let service = MemoService::new(
+        db.clone(),
+        qdrant_repo,
+        embedder.clone() as Arc<dyn Embedder>,
+        embedder as Arc<dyn TextGenerator>,
+    );
+    let create = |content: &str, tags: &[&str]| {
+        service.create_memo(
+            user_id,
+            CreateMemoRequest {
+                content: content.to_string(),
+                tags: tags.iter().map(|&tag| String::from(tag)).collect(),
+                ..Default::default()
+            },
+        )
+    };
+
+    let before = create("산책 #일기", &[]).await.unwrap();
+    assert!(before.tags.is_empty());
+
+    user::ActiveModel {
+        id: Set(user_id),
+        auto_tag_memos: Set(true),
+        ..Default::default()
+    }
+    .update(db.as_ref())
+    .await
+    .unwrap();
+
+    let auto = create("오늘도 산책 #일기 #날씨", &[]).await.unwrap();
+    assert_eq!(auto.tags, vec!["날씨", "일기"]);
+
+    // 직접 붙인 태그가 있으면 제안을 덧붙이지 않는다.
+    let manual = create("비 오는 날 #날씨", &["기분"]).await.unwrap();
+    assert_eq!(manual.tags, vec!["기분"]);
+}
+
+#[tokio::test]
+async fn test_list_memos_rejects_invalid_cursor() {
+    let (db, user_id) = setup_test_db().await;
+    let qdrant_repo = Arc::new(MockQdrantRepository::new());
+    let embedder = Arc::new(MockGeminiClient::new());
+    let service = MemoService::new(
+        db,
+        qdrant_repo,
+        embedder.clone() as Arc<dyn Embedder>,
+        embedder as Arc<dyn TextGenerator>,
+    );
+
+    for cursor in ["not-a-cursor", "MTo", "", &URL_SAFE_NO_PAD.encode("2:0:1")] {
+        let result = service
+            .list_memos(
+                user_id,
+                MemoListQuery {
+                    cursor: Some(cursor.to_string()),
+                    ..Default::default()
+                },
+            )
+            .await;
+        assert!(matches!(result, Err(ServiceError::Validation(_))));
+    }
+}
+
+#[tokio::test]
+async fn test_search_memos_matches_korean_substrings() {
+    let (db, user_id) = setup_test_db().await;
+    let (_, other_user_id) = setup_test_db().await;
+    let qdrant_repo = Arc::new(MockQdrantRepository::new());
+    let embedder = Arc::new(MockGeminiClient::new());
+    let service = MemoService::new(
+        db,
+        qdrant_repo,
+        embedder.clone() as Arc<dyn Embedder>,
+        embedder as Arc<dyn TextGenerator>,
+    );
+
+    let create = |user_id: i32, content: &str| {
+        service.create_memo(
+            user_id,
+            CreateMemoRequest {
+                content: content.to_string(),
+                ..Default::default()
+            },
+        )
+    };
+    let once = create(user_id, "오늘은 러스트를 공부했다").await.unwrap();
+    let twice = create(user_id, "러스트 비동기, 러스트 매크로")
+        .await
+        .unwrap();
+    create(user_id, "러 스트레칭은 별개").await.unwrap();
+    create(other_user_id, "다른 사람의 러스트 메모")
+        .await
+        .unwrap();
+
+    let found = service
+        .search_memos(
+            user_id,
+            MemoSearchQuery {
+                q: "러스트".to_string(),
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+    let ids: Vec<i32> = found.results.iter().map(|r| r.memo.id).collect();
+    assert_eq!(ids, vec![twice.id, once.id]);
+    assert_eq!(found.total, 2);
+    assert!(found.results[0].rank > found.results[1].rank);
+    assert_eq!(
+        found.results[1].snippet,
+        "오늘은 <mark>러스트</mark>를 공부했다"
+    );
+
+    let both_words = service
+        .search_memos(
+            user_id,
+            MemoSearchQuery {
+                q: "러스트 매크로".to_string(),
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+    assert_eq!(both_words.results.len(), 1);
+    assert_eq!(both_words.results[0].memo.id, twice.id);
+}
+
+#[tokio::test]
+async fn test_search_memos_is_case_insensitive_for_ascii() {
+    let (db, user_id) = setup_test_db().await;
+    let qdrant_repo = Arc::new(MockQdrantRepository::new());
+    let embedder = Arc::new(MockGeminiClient::new());
+    let service = MemoService::new(
+        db,
+        qdrant_repo,
+        embedder.clone() as Arc<dyn Embedder>,
+        embedder as Arc<dyn TextGenerator>,
+    );
+
+    let memo = service
+        .create_memo(
+            user_id,
+            CreateMemoRequest {
+                content: "Deploy ticket INK-1042 today".to_string(),
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+
+    let found = service
+        .search_memos(
+            user_id,
+            MemoSearchQuery {
+                q: "ink-1042".to_string(),
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+    assert_eq!(found.results.len(), 1);
+    assert_eq!(found.results[0].memo.id, memo.id);
+    assert_eq!(
+        found.results[0].snippet,
+        "Deploy ticket <mark>INK</mark>-<mark>1042</mark> today"
+    );
+}
+
+// 구분자 없는 긴 본문에서 검색용 tsvector 생성이 글자 수의 제곱으로 느려지면 쓰기가 몇십 초씩 멈춘다.
+#[tokio::test]
+async fn test_long_unbroken_content_is_written_in_bounded_time() {
+    let (db, user_id) = setup_test_db().await;
+    let qdrant_repo = Arc::new(MockQdrantRepository::new());
+    let embedder = Arc::new(MockGeminiClient::new());
+    let service = MemoService::new(
+        db,
+        qdrant_repo,
+        embedder.clone() as Arc<dyn Embedder>,
+        embedder as Arc<dyn TextGenerator>,
+    );
+
+    let content = format!("맨앞단어{}", "가나다라마바사".repeat(10_000));
+    let started = std::time::Instant::now();
+    let memo = service
+        .create_memo(
+            user_id,
+            CreateMemoRequest {
+                content: content.clone(),
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+    service
+        .update_memo(
+            user_id,
+            memo.id,
+            UpdateMemoRequest {
+                content: format!("{}아자차", content),
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+    assert!(
+        started.elapsed() < std::time::Duration::from_secs(5),
+        "writing a long memo took {:?}",
+        started.elapsed()
+    );
+
+    let found = service
+        .search_memos(
+            user_id,
+            MemoSearchQuery {
+                q: "맨앞단어".to_string(),
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+    assert_eq!(found.results.len(), 1);
+    assert_eq!(found.results[0].memo.id, memo.id);
+}
+
+#[tokio::test]
+async fn test_search_memos_rejects_empty_query() {
+    let (db, user_id) = setup_test_db().await;
+    let qdrant_repo = Arc::new(MockQdrantRepository::new());
+    let embedder = Arc::new(MockGeminiClient::new());
+    let service = MemoService::new(
+        db,
+        qdrant_repo,
+        embedder.clone() as Arc<dyn Embedder>,
+        embedder as Arc<dyn TextGenerator>,
+    );
+
+    let result = service
+        .search_memos(
+            user_id,
+            MemoSearchQuery {
+                q: " ?! ".to_string(),
+                ..Default::default()
+            },
+        )
+        .await;
+    assert!(matches!(result, Err(ServiceError::Validation(_))));
+}
+
+#[tokio::test]
+async fn test_search_memos_with_query_operators() {
+    let (db, user_id) = setup_test_db().await;
+    let qdrant_repo = Arc::new(MockQdrantRepository::new());
+    let embedder = Arc::new(MockGeminiClient::new());
+    let service = MemoService::new(
+        db,
+        qdrant_repo,
+        embedder.clone() as Arc<dyn Embedder>,
+        embedder as Arc<dyn TextGenerator>,
+    );
+
+    let create = |content: &str| {
+        service.create_memo(
+            user_id,
+            CreateMemoRequest {
+                content: content.to_string(),
+                ..Default::default()
+            },
+        )
+    };
+    let matching = create("러스트 exact phrase 정리").await.unwrap();
+    let draft = create("러스트 exact phrase draft").await.unwrap();
+    let reversed = create("러스트 phrase exact").await.unwrap();
+    let unpinned = create("러스트 exact phrase 정리").await.unwrap();
+    for memo in [&matching, &draft, &reversed] {
+        service.toggle_pin(user_id, memo.id).await.unwrap();
+    }
+
+    let search = |q: String| {
+        service.search_memos(
+            user_id,
+            MemoSearchQuery {
+                q,
+                ..Default::default()
+            },
+        )
+    };
+
+    let found = search(r#"러스트 "exact phrase" -draft pinned:true"#.to_string())
+        .await
+        .unwrap();
+    assert_eq!(found.total, 1);
+    assert_eq!(found.results[0].memo.id, matching.id);
+    assert_eq!(
+        found.results[0].snippet,
+        "<mark>러스트</mark> <mark>exact</mark> <mark>phrase</mark> 정리"
+    );
+
+    // 단어 없이 필드 조건만 있으면 모든 메모가 점수 0으로 최근 수정 순으로 나온다.
+    let tomorrow = (Utc::now() + chrono::Duration::days(1)).format("%Y-%m-%d");
+    let filter_only = search(format!("pinned:false before:{}", tomorrow))
+        .await
+        .unwrap();
+    assert_eq!(filter_only.total, 1);
+    assert_eq!(filter_only.results[0].memo.id, unpinned.id);
+    assert_eq!(filter_only.results[0].rank, 0.0);
+
+    let none = search(format!("러스트 after:{}", tomorrow)).await.unwrap();
+    assert_eq!(none.total, 0);
+}
+
+#[tokio::test]
+async fn test_search_memos_reports_parse_errors() {
+    let (db, user_id) = setup_test_db().await;
+    let qdrant_repo = Arc::new(MockQdrantRepository::new());
+    let embedder = Arc::new(MockGeminiClient::new());
+    let service = MemoService::new(
+        db,
+        qdrant_repo,
+        embedder.clone() as Arc<dyn Embedder>,
+        embedder as Arc<dyn TextGenerator>,
+    );
+
+    let result = service
+        .search_memos(
+            user_id,
+            MemoSearchQuery {
+                q: "러스트 pinned:maybe".to_string(),
+                ..Default::default()
+            },
+        )
+        .await;
+    assert!(matches!(
+        result,
+        Err(ServiceError::InvalidSearchQuery(err)) if err.position == 11
+    ));
+}
+
+#[tokio::test]
+async fn test_hybrid_search_fuses_keyword_and_semantic_results() {
+    let (db, user_id) = setup_test_db().await;
+    let qdrant_repo = Arc::new(MockQdrantRepository::new());
+    let embedder = Arc::new(MockGeminiClient::new());
+    let service = MemoService::new(
+        db,
+        qdrant_repo,
+        embedder.clone() as Arc<dyn Embedder>,
+        embedder as Arc<dyn TextGenerator>,
+    );
+
+    let create = |content: &str| {
+        service.create_memo(
+            user_id,
+            CreateMemoRequest {
+                content: content.to_string(),
+                ..Default::default()
+            },
+        )
+    };
+    let both = create("INK-1042 배포 완료했음").await.unwrap();
+    // 목 임베딩은 글자 수(바이트)로 정해지므로 질의와 길이가 같은 메모가 의미상 가장 가깝다.
+    let semantic_only = create("abcdefghijklmno").await.unwrap();
+
+    let found = service
+        .hybrid_search(
+            user_id,
+            HybridSearchQuery {
+                q: "INK-1042 배포".to_string(),
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(found.results.len(), 2);
+    let first = &found.results[0];
+    assert_eq!(first.memo.id, both.id);
+    assert!(first.keyword_score.is_some());
+    assert!(first.semantic_score.is_some());
+    assert_eq!(
+        first.snippet,
+        "<mark>INK</mark>-<mark>1042</mark> <mark>배포</mark> 완료했음"
+    );
+
+    let second = &found.results[1];
+    assert_eq!(second.memo.id, semantic_only.id);
+    assert!(second.keyword_score.is_none());
+    assert!((second.semantic_score.unwrap() - 1.0).abs() < 1e-4);
+    assert!(first.score > second.score);
+}
+
+#[tokio::test]
+async fn test_hybrid_search_applies_filters_to_semantic_results() {
+    let (db, user_id) = setup_test_db().await;
+    let qdrant_repo = Arc::new(MockQdrantRepository::new());
+    let embedder = Arc::new(MockGeminiClient::new());
+    let service = MemoService::new(
+        db,
+        qdrant_repo,
+        embedder.clone() as Arc<dyn Embedder>,
+        embedder as Arc<dyn TextGenerator>,
+    );
+
+    let create = |content: &str| {
+        service.create_memo(
+            user_id,
+            CreateMemoRequest {
+                content: content.to_string(),
+                ..Default::default()
+            },
+        )
+    };
+    let keyword = create("INK-1042 배포").await.unwrap();
+    // 모두 의미 검색 문장(`INK-1042`)과 길이가 같아 의미상 가장 가깝다.
+    let semantic = create("qrstuvwx").await.unwrap();
+    let excluded = create("abcdefgh").await.unwrap();
+    let pinned = create("zyxwvuts").await.unwrap();
+    service.toggle_pin(user_id, pinned.id).await.unwrap();
+
+    let found = service
+        .hybrid_search(
+            user_id,
+            HybridSearchQuery {
+                q: "INK-1042 -abc pinned:false".to_string(),
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+    let ids: Vec<i32> = found.results.iter().map(|r| r.memo.id).collect();
+    assert_eq!(ids[0], keyword.id);
+    assert!(ids.contains(&semantic.id));
+    assert!(!ids.contains(&excluded.id));
+    assert!(!ids.contains(&pinned.id));
+
+    // 임베딩할 단어가 없으면 키워드 검색만으로 결과를 만든다.
+    let filter_only = service
+        .hybrid_search(
+            user_id,
+            HybridSearchQuery {
+                q: "pinned:true".to_string(),
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+    assert_eq!(filter_only.results.len(), 1);
+    assert_eq!(filter_only.results[0].memo.id, pinned.id);
+    assert!(filter_only.results[0].semantic_score.is_none());
+}
+
+#[tokio::test]
+async fn test_hybrid_search_rejects_empty_query() {
+    let (db, user_id) = setup_test_db().await;
+    let qdrant_repo = Arc::new(MockQdrantRepository::new());
+    let embedder = Arc::new(MockGeminiClient::new());
+    let service = MemoService::new(
+        db,
+        qdrant_repo,
+        embedder.clone() as Arc<dyn Embedder>,
+        embedder as Arc<dyn TextGenerator>,
+    );
+
+    let result = service
+        .hybrid_search(
+            user_id,
+            HybridSearchQuery {
+                q: "   ".to_string(),
+                ..Default::default()
+            },
+        )
+        .await;
+    assert!(matches!(result, Err(ServiceError::Validation(_))));
+}
+
+#[test]
+fn test_cursor_round_trip() {
+    let cursor = MemoCursor {
+        is_pinned: true,
+        updated_at: DateTime::from_timestamp_micros(1_705_314_600_123_456)
+            .unwrap()
+            .naive_utc(),
+        id: 42,
+    };
+
+    assert_eq!(decode_cursor(&encode_cursor(&cursor)).unwrap(), cursor);
+}
+
 #[tokio::test]
 async fn test_delete_memo() {
     let (db, user_id) = setup_test_db().await;
     let qdrant_repo = Arc::new(MockQdrantRepository::new());
     let embedder = Arc::new(MockGeminiClient::new());
-    let service = MemoService::new(db, qdrant_repo, embedder as Arc<dyn Embedder>);
+    let service = MemoService::new(
+        db,
+        qdrant_repo,
+        embedder.clone() as Arc<dyn Embedder>,
+        embedder as Arc<dyn TextGenerator>,
+    );
 
     let req = CreateMemoRequest {
         content: "To be deleted".to_string(),
+        ..Default::default()
     };
     let created = service.create_memo(user_id, req).await.unwrap();
 
@@ -167,3 +1070,505 @@ async fn test_delete_memo() {
     let result = service.get_memo(user_id, created.id).await;
     assert!(matches!(result, Err(ServiceError::MemoNotFound)));
 }
+
+#[tokio::test]
+async fn test_update_memo_records_revisions_and_restores() {
+    let (db, user_id) = setup_test_db().await;
+    let (_, other_user_id) = setup_test_db().await;
+    let qdrant_repo = Arc::new(MockQdrantRepository::new());
+    let embedder = Arc::new(MockGeminiClient::new());
+    let service = MemoService::new(
+        db,
+        qdrant_repo,
+        embedder.clone() as Arc<dyn Embedder>,
+        embedder as Arc<dyn TextGenerator>,
+    );
+
+    let created = service
+        .create_memo(
+            user_id,
+            CreateMemoRequest {
+                content: "첫 버전".to_string(),
+                tags: vec!["draft".to_string()],
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+    for content in ["둘째 버전", "둘째 버전", "셋째 버전"] {
+        service
+            .update_memo(
+                user_id,
+                created.id,
+                UpdateMemoRequest {
+                    content: content.to_string(),
+                    ..Default::default()
+                },
+            )
+            .await
+            .unwrap();
+    }
+
+    // 내용이 그대로인 수정은 버전을 남기지 않는다.
+    let revisions = service.list_revisions(user_id, created.id).await.unwrap();
+    assert_eq!(revisions.len(), 2);
+    assert_eq!(revisions[1].created_at, created.updated_at);
+    let first = service
+        .get_revision(user_id, created.id, revisions[1].id)
+        .await
+        .unwrap();
+    assert_eq!(first.content, "첫 버전");
+
+    let restored = service
+        .restore_revision(user_id, created.id, first.id)
+        .await
+        .unwrap();
+    assert_eq!(restored.content, "첫 버전");
+    assert_eq!(restored.tags, vec!["draft"]);
+
+    let revisions = service.list_revisions(user_id, created.id).await.unwrap();
+    assert_eq!(revisions.len(), 3);
+    let latest = service
+        .get_revision(user_id, created.id, revisions[0].id)
+        .await
+        .unwrap();
+    assert_eq!(latest.content, "셋째 버전");
+
+    assert!(matches!(
+        service.list_revisions(other_user_id, created.id).await,
+        Err(ServiceError::Unauthorized)
+    ));
+    let other = service
+        .create_memo(
+            user_id,
+            CreateMemoRequest {
+                content: "다른 메모".to_string(),
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+    assert!(matches!(
+        service.restore_revision(user_id, other.id, first.id).await,
+        Err(ServiceError::MemoRevisionNotFound)
+    ));
+}
+
+#[tokio::test]
+async fn test_diff_revisions() {
+    let (db, user_id) = setup_test_db().await;
+    let qdrant_repo = Arc::new(MockQdrantRepository::new());
+    let embedder = Arc::new(MockGeminiClient::new());
+    let service = MemoService::new(
+        db,
+        qdrant_repo,
+        embedder.clone() as Arc<dyn Embedder>,
+        embedder as Arc<dyn TextGenerator>,
+    );
+
+    let created = service
+        .create_memo(
+            user_id,
+            CreateMemoRequest {
+                content: "장보기\n우유\n빵\n".to_string(),
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+    for content in ["장보기\n두유\n빵\n", "장보기\n두유\n빵\n계란\n"] {
+        service
+            .update_memo(
+                user_id,
+                created.id,
+                UpdateMemoRequest {
+                    content: content.to_string(),
+                    ..Default::default()
+                },
+            )
+            .await
+            .unwrap();
+    }
+    let revisions = service.list_revisions(user_id, created.id).await.unwrap();
+    let (first, second) = (revisions[1].id, revisions[0].id);
+
+    let chunks = |diff: MemoRevisionDiffResponse| -> Vec<(DiffOp, String)> {
+        diff.chunks
+            .into_iter()
+            .map(|chunk| (chunk.op, chunk.text))
+            .collect()
+    };
+
+    let lines = service
+        .diff_revisions(
+            user_id,
+            created.id,
+            MemoRevisionDiffQuery {
+                from: first,
+                to: Some(second),
+                granularity: DiffGranularity::Line,
+            },
+        )
+        .await
+        .unwrap();
+    assert_eq!(
+        chunks(lines),
+        vec![
+            (DiffOp::Equal, "장보기\n".to_string()),
+            (DiffOp::Delete, "우유\n".to_string()),
+            (DiffOp::Insert, "두유\n".to_string()),
+            (DiffOp::Equal, "빵\n".to_string()),
+        ]
+    );
+
+    let chars = service
+        .diff_revisions(
+            user_id,
+            created.id,
+            MemoRevisionDiffQuery {
+                from: first,
+                to: Some(second),
+                granularity: DiffGranularity::Char,
+            },
+        )
+        .await
+        .unwrap();
+    assert_eq!(
+        chunks(chars),
+        vec![
+            (DiffOp::Equal, "장보기\n".to_string()),
+            (DiffOp::Delete, "우".to_string()),
+            (DiffOp::Insert, "두".to_string()),
+            (DiffOp::Equal, "유\n빵\n".to_string()),
+        ]
+    );
+
+    // `to`를 생략하면 현재 내용과 비교한다.
+    let current = service
+        .diff_revisions(
+            user_id,
+            created.id,
+            MemoRevisionDiffQuery {
+                from: second,
+                to: None,
+                granularity: DiffGranularity::Line,
+            },
+        )
+        .await
+        .unwrap();
+    assert_eq!(current.to, None);
+    assert_eq!(
+        chunks(current),
+        vec![
+            (DiffOp::Equal, "장보기\n두유\n빵\n".to_string()),
+            (DiffOp::Insert, "계란\n".to_string()),
+        ]
+    );
+}
+
+#[tokio::test]
+async fn test_trash_and_restore_memo() {
+    let (db, user_id) = setup_test_db().await;
+    let (_, other_user_id) = setup_test_db().await;
+    let qdrant_repo = Arc::new(MockQdrantRepository::new());
+    let embedder = Arc::new(MockGeminiClient::new());
+    let service = MemoService::new(
+        db,
+        qdrant_repo.clone(),
+        embedder.clone() as Arc<dyn Embedder>,
+        embedder as Arc<dyn TextGenerator>,
+    );
+
+    let trashed = service
+        .create_memo(
+            user_id,
+            CreateMemoRequest {
+                content: "실수로 지운 메모".to_string(),
+                tags: vec!["중요".to_string()],
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+    let kept = service
+        .create_memo(
+            user_id,
+            CreateMemoRequest {
+                content: "남는 메모".to_string(),
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+    service.delete_memo(user_id, trashed.id).await.unwrap();
+
+    // 휴지통의 메모는 조회, 목록, 의미 기반 검색에서 모두 빠진다.
+    assert!(matches!(
+        service.get_memo(user_id, trashed.id).await,
+        Err(ServiceError::MemoNotFound)
+    ));
+    let listed: Vec<i32> = service
+        .list_memos(user_id, MemoListQuery::default())
+        .await
+        .unwrap()
+        .memos
+        .iter()
+        .map(|memo| memo.id)
+        .collect();
+    assert_eq!(listed, vec![kept.id]);
+    let similar: Vec<i32> = qdrant_repo
+        .search_similar(user_id, vec![0.1; 768], &MemoListFilter::default(), 10)
+        .await
+        .unwrap()
+        .into_iter()
+        .map(|(id, _)| id)
+        .collect();
+    assert_eq!(similar, vec![kept.id]);
+
+    let trash = service.list_trash(user_id).await.unwrap();
+    assert_eq!(trash.len(), 1);
+    assert_eq!(trash[0].memo.id, trashed.id);
+    assert_eq!(trash[0].memo.tags, vec!["중요"]);
+    assert!(trash[0].purge_at > trash[0].deleted_at);
+    assert!(service.list_trash(other_user_id).await.unwrap().is_empty());
+
+    assert!(matches!(
+        service.restore_memo(other_user_id, trashed.id).await,
+        Err(ServiceError::MemoNotFound)
+    ));
+    let restored = service.restore_memo(user_id, trashed.id).await.unwrap();
+    assert_eq!(restored.content, "실수로 지운 메모");
+    assert_eq!(restored.updated_at, trashed.updated_at);
+    assert!(service.list_trash(user_id).await.unwrap().is_empty());
+    let similar = qdrant_repo
+        .search_similar(user_id, vec![0.1; 768], &MemoListFilter::default(), 10)
+        .await
+        .unwrap();
+    assert_eq!(similar.len(), 2);
+
+    assert!(matches!(
+        service.restore_memo(user_id, trashed.id).await,
+        Err(ServiceError::MemoNotFound)
+    ));
+}
+
+#[tokio::test]
+async fn test_purge_trash_removes_expired_memos() {
+    let (db, user_id) = setup_test_db().await;
+    let qdrant_repo = Arc::new(MockQdrantRepository::new());
+    let embedder = Arc::new(MockGeminiClient::new());
+    let service = MemoService::new(
+        db.clone(),
+        qdrant_repo.clone(),
+        embedder.clone() as Arc<dyn Embedder>,
+        embedder as Arc<dyn TextGenerator>,
+    );
+
+    let mut ids = Vec::new();
+    for content in ["오래전에 지운 메모", "방금 지운 메모"] {
+        let memo = service
+            .create_memo(
+                user_id,
+                CreateMemoRequest {
+                    content: content.to_string(),
+                    ..Default::default()
+                },
+            )
+            .await
+            .unwrap();
+        service.delete_memo(user_id, memo.id).await.unwrap();
+        ids.push(memo.id);
+    }
+
+    // 다른 테스트가 휴지통에 넣은 메모를 건드리지 않도록 아주 오래전에 지운 것으로 만든다.
+    let long_ago = DateTime::from_timestamp(946_684_800, 0)
+        .unwrap()
+        .naive_utc();
+    memo::ActiveModel {
+        id: Set(ids[0]),
+        deleted_at: Set(Some(long_ago)),
+        ..Default::default()
+    }
+    .update(db.as_ref())
+    .await
+    .unwrap();
+
+    // 벡터 삭제가 실패하면 DB 행도 남겨 두어 다음 실행에서 다시 시도한다.
+    qdrant_repo.fail_deletes(true);
+    assert!(service
+        .purge_trash(long_ago + TimeDelta::days(1))
+        .await
+        .is_err());
+    assert_eq!(service.list_trash(user_id).await.unwrap().len(), 2);
+    qdrant_repo.fail_deletes(false);
+
+    let purged = service
+        .purge_trash(long_ago + TimeDelta::days(1))
+        .await
+        .unwrap();
+    assert!(purged >= 1);
+
+    let trash: Vec<i32> = service
+        .list_trash(user_id)
+        .await
+        .unwrap()
+        .iter()
+        .map(|memo| memo.memo.id)
+        .collect();
+    assert_eq!(trash, vec![ids[1]]);
+    assert!(matches!(
+        service.restore_memo(user_id, ids[0]).await,
+        Err(ServiceError::MemoNotFound)
+    ));
+
+    // 영구 삭제된 메모는 벡터 저장소에서도 지워져 복원해도 다시 나타나지 않는다.
+    service.restore_memo(user_id, ids[1]).await.unwrap();
+    let similar: Vec<i32> = qdrant_repo
+        .search_similar(user_id, vec![0.1; 768], &MemoListFilter::default(), 10)
+        .await
+        .unwrap()
+        .into_iter()
+        .map(|(id, _)| id)
+        .collect();
+    assert_eq!(similar, vec![ids[1]]);
+}
+
+#[tokio::test]
+async fn test_archive_and_unarchive_memo() {
+    let (db, user_id) = setup_test_db().await;
+    let (_, other_user_id) = setup_test_db().await;
+    let embedder = Arc::new(MockGeminiClient::new());
+    let service = MemoService::new(
+        db,
+        Arc::new(MockQdrantRepository::new()),
+        embedder.clone() as Arc<dyn Embedder>,
+        embedder as Arc<dyn TextGenerator>,
+    );
+
+    let archived = service
+        .create_memo(
+            user_id,
+            CreateMemoRequest {
+                content: "다 끝난 프로젝트 메모".to_string(),
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+    let kept = service
+        .create_memo(
+            user_id,
+            CreateMemoRequest {
+                content: "진행 중인 메모".to_string(),
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+
+    assert!(matches!(
+        service.archive_memo(other_user_id, archived.id).await,
+        Err(ServiceError::Unauthorized)
+    ));
+    let result = service.archive_memo(user_id, archived.id).await.unwrap();
+    assert!(result.is_archived);
+    assert_eq!(result.updated_at, archived.updated_at);
+
+    let list = |archived| {
+        let service = &service;
+        async move {
+            service
+                .list_memos(
+                    user_id,
+                    MemoListQuery {
+                        archived,
+                        ..Default::default()
+                    },
+                )
+                .await
+                .unwrap()
+                .memos
+                .iter()
+                .map(|memo| memo.id)
+                .collect::<Vec<i32>>()
+        }
+    };
+    assert_eq!(list(None).await, vec![kept.id]);
+    assert_eq!(list(Some(false)).await, vec![kept.id]);
+    assert_eq!(list(Some(true)).await, vec![archived.id]);
+
+    // 보관된 메모도 직접 조회할 수 있다.
+    assert!(
+        service
+            .get_memo(user_id, archived.id)
+            .await
+            .unwrap()
+            .is_archived
+    );
+
+    let result = service.unarchive_memo(user_id, archived.id).await.unwrap();
+    assert!(!result.is_archived);
+    assert_eq!(list(None).await.len(), 2);
+}
+
+#[tokio::test]
+async fn test_backfill_payloads_fills_filter_keys_of_old_points() {
+    let (db, user_id) = setup_test_db().await;
+    let qdrant_repo = Arc::new(MockQdrantRepository::new());
+    let embedder = Arc::new(MockGeminiClient::new());
+    let service = MemoService::new(
+        db,
+        qdrant_repo.clone(),
+        embedder.clone() as Arc<dyn Embedder>,
+        embedder as Arc<dyn TextGenerator>,
+    );
+
+    let memo = service
+        .create_memo(
+            user_id,
+            CreateMemoRequest {
+                content: "필터 키가 없던 시절의 메모".to_string(),
+                tags: vec!["예전".to_string()],
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+    let memo = service.toggle_pin(user_id, memo.id).await.unwrap();
+
+    // 필터용 키가 생기기 전에 저장된 포인트처럼 태그와 고정 여부를 비운다.
+    let stale = memo::Model {
+        id: memo.id,
+        user_id,
+        content: memo.content.clone(),
+        is_pinned: false,
+        is_archived: false,
+        notebook_id: None,
+        created_at: memo.created_at,
+        updated_at: memo.updated_at,
+        deleted_at: None,
+    };
+    qdrant_repo
+        .upsert_memo(MemoPayload::new(&stale, Vec::new()), vec![0.1; 768])
+        .await
+        .unwrap();
+    let filter = MemoListFilter {
+        is_pinned: Some(true),
+        tags: vec![String::from("예전")],
+        ..Default::default()
+    };
+    let search = || qdrant_repo.search_similar(user_id, vec![0.1; 768], &filter, 10);
+    assert!(search().await.unwrap().is_empty());
+
+    let (updated, failed) = service.backfill_payloads().await.unwrap();
+    assert!(updated >= 1);
+    assert_eq!(failed, 0);
+
+    let ids: Vec<i32> = search()
+        .await
+        .unwrap()
+        .into_iter()
+        .map(|(id, _)| id)
+        .collect();
+    assert_eq!(ids, vec![memo.id]);
+}
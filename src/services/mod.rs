@@ -1,7 +1,23 @@
+pub mod admin_service;
 pub mod assist_service;
+pub mod auth_event_service;
+pub mod auth_service;
 pub mod memo_service;
+pub mod notebook_service;
+pub mod personal_access_token_service;
+pub mod saved_search_service;
+pub mod tag_service;
+pub mod two_factor_service;
 pub mod user_service;
 
+pub use admin_service::AdminService;
 pub use assist_service::AssistService;
+pub use auth_event_service::AuthEventService;
+pub use auth_service::AuthService;
 pub use memo_service::MemoService;
+pub use notebook_service::NotebookService;
+pub use personal_access_token_service::PersonalAccessTokenService;
+pub use saved_search_service::SavedSearchService;
+pub use tag_service::TagService;
+pub use two_factor_service::TwoFactorService;
 pub use user_service::UserService;
@@ -0,0 +1,209 @@
+use sea_orm::DatabaseConnection;
+use std::sync::Arc;
+
+use crate::{
+    entities::notebook,
+    errors::ServiceError,
+    models::{
+        CreateNotebookRequest, DeleteNotebookMode, MoveNotebookRequest, NotebookResponse,
+        RenameNotebookRequest,
+    },
+    repositories::{MemoRepository, NotebookRepository},
+    services::MemoService,
+};
+
+const MAX_NOTEBOOK_NAME_LENGTH: usize = 100;
+
+// 노트북은 `parent_id`로 중첩되며, 같은 부모 아래에서만 이름이 유일하다.
+// 메모가 든 노트북은 Qdrant 페이로드에도 복제되어 있으므로 메모가 옮겨지면 페이로드를 다시 쓴다.
+#[derive(Clone)]
+pub struct NotebookService {
+    notebook_repo: NotebookRepository,
+    memo_repo: MemoRepository,
+    memo_service: Arc<MemoService>,
+}
+
+impl NotebookService {
+    pub fn new(db: Arc<DatabaseConnection>, memo_service: Arc<MemoService>) -> Self {
+        Self {
+            notebook_repo: NotebookRepository::new(db.clone()),
+            memo_repo: MemoRepository::new(db),
+            memo_service,
+        }
+    }
+
+    // 트리는 클라이언트가 `parent_id`로 조립한다.
+    pub async fn list_notebooks(
+        &self,
+        user_id: i32,
+    ) -> Result<Vec<NotebookResponse>, ServiceError> {
+        let notebooks = self.notebook_repo.find_by_user_id(user_id).await?;
+        let counts = self.notebook_repo.count_memos(user_id).await?;
+
+        Ok(notebooks
+            .into_iter()
+            .map(|notebook| {
+                let memo_count = counts.get(&notebook.id).copied().unwrap_or(0);
+                NotebookResponse::from((notebook, memo_count))
+            })
+            .collect())
+    }
+
+    pub async fn create_notebook(
+        &self,
+        user_id: i32,
+        req: CreateNotebookRequest,
+    ) -> Result<NotebookResponse, ServiceError> {
+        let name = validate_notebook_name(&req.name)?;
+        if let Some(parent_id) = req.parent_id {
+            self.find_notebook(user_id, parent_id).await?;
+        }
+        self.ensure_name_available(user_id, req.parent_id, &name, None)
+            .await?;
+
+        let notebook = self
+            .notebook_repo
+            .create(user_id, req.parent_id, name)
+            .await?;
+        Ok(NotebookResponse::from((notebook, 0)))
+    }
+
+    pub async fn rename_notebook(
+        &self,
+        user_id: i32,
+        id: i32,
+        req: RenameNotebookRequest,
+    ) -> Result<NotebookResponse, ServiceError> {
+        let notebook = self.find_notebook(user_id, id).await?;
+        let name = validate_notebook_name(&req.name)?;
+        self.ensure_name_available(user_id, notebook.parent_id, &name, Some(id))
+            .await?;
+
+        let parent_id = notebook.parent_id;
+        let renamed = self.notebook_repo.update(notebook, name, parent_id).await?;
+        self.with_memo_count(user_id, renamed).await
+    }
+
+    pub async fn move_notebook(
+        &self,
+        user_id: i32,
+        id: i32,
+        req: MoveNotebookRequest,
+    ) -> Result<NotebookResponse, ServiceError> {
+        let notebook = self.find_notebook(user_id, id).await?;
+        if let Some(parent_id) = req.parent_id {
+            self.find_notebook(user_id, parent_id).await?;
+            // 자기 자신이나 하위 노트북 아래로 옮기면 순환이 생긴다.
+            let subtree = self.notebook_repo.find_subtree_ids(user_id, id).await?;
+            if subtree.contains(&parent_id) {
+                return Err(ServiceError::Validation(
+                    "Cannot move a notebook into itself or one of its descendants".to_string(),
+                ));
+            }
+        }
+        self.ensure_name_available(user_id, req.parent_id, &notebook.name, Some(id))
+            .await?;
+
+        let name = notebook.name.clone();
+        let moved = self
+            .notebook_repo
+            .update(notebook, name, req.parent_id)
+            .await?;
+        self.with_memo_count(user_id, moved).await
+    }
+
+    pub async fn delete_notebook(
+        &self,
+        user_id: i32,
+        id: i32,
+        mode: DeleteNotebookMode,
+    ) -> Result<(), ServiceError> {
+        let notebook = self.find_notebook(user_id, id).await?;
+
+        match mode {
+            // 노트북이 지워지면 FK(ON DELETE SET NULL)가 휴지통에 든 메모의 노트북도 비우므로,
+            // 이 메모들은 복원하면 노트북 밖(최상위)으로 돌아온다. 페이로드는 노트북을 지운 뒤에 다시 쓴다.
+            DeleteNotebookMode::Cascade => {
+                let notebook_ids = self.notebook_repo.find_subtree_ids(user_id, id).await?;
+                let memo_ids = self
+                    .memo_repo
+                    .find_ids_by_notebook_ids(user_id, &notebook_ids)
+                    .await?;
+                self.memo_repo.trash_many(user_id, &memo_ids).await?;
+                self.notebook_repo.delete(id).await?;
+                self.memo_service
+                    .refresh_payloads(user_id, &memo_ids)
+                    .await?;
+            }
+            DeleteNotebookMode::Move => {
+                // 옮겨질 메모는 페이로드를 다시 써야 하므로 미리 읽어 둔다.
+                let memo_ids = self
+                    .memo_repo
+                    .find_ids_by_notebook_ids(user_id, &[id])
+                    .await?;
+                if !self.notebook_repo.delete_into_parent(&notebook).await? {
+                    return Err(ServiceError::NotebookNameExists);
+                }
+                self.memo_service
+                    .refresh_payloads(user_id, &memo_ids)
+                    .await?;
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn find_notebook(&self, user_id: i32, id: i32) -> Result<notebook::Model, ServiceError> {
+        self.notebook_repo
+            .find_by_id(id, user_id)
+            .await?
+            .ok_or(ServiceError::NotebookNotFound)
+    }
+
+    async fn ensure_name_available(
+        &self,
+        user_id: i32,
+        parent_id: Option<i32>,
+        name: &str,
+        except_id: Option<i32>,
+    ) -> Result<(), ServiceError> {
+        match self
+            .notebook_repo
+            .find_sibling_by_name(user_id, parent_id, name)
+            .await?
+        {
+            Some(existing) if Some(existing.id) != except_id => {
+                Err(ServiceError::NotebookNameExists)
+            }
+            _ => Ok(()),
+        }
+    }
+
+    async fn with_memo_count(
+        &self,
+        user_id: i32,
+        notebook: notebook::Model,
+    ) -> Result<NotebookResponse, ServiceError> {
+        let memo_count = self
+            .notebook_repo
+            .count_memos(user_id)
+            .await?
+            .remove(&notebook.id)
+            .unwrap_or(0);
+        Ok(NotebookResponse::from((notebook, memo_count)))
+    }
+}
+
+fn validate_notebook_name(name: &str) -> Result<String, ServiceError> {
+    let name = name.trim();
+    if name.is_empty() || name.chars().count() > MAX_NOTEBOOK_NAME_LENGTH {
+        return Err(ServiceError::Validation(format!(
+            "Notebook name must be between 1 and {} characters",
+            MAX_NOTEBOOK_NAME_LENGTH
+        )));
+    }
+    Ok(name.to_string())
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,465 @@
+use super::*;
+use crate::{
+    clients::{Embedder, TextGenerator},
+    db,
+    entities::user,
+    models::{CreateMemoRequest, MemoListQuery, MoveMemosRequest},
+    repositories::{MemoListFilter, QdrantRepo},
+    test_utils::{MockGeminiClient, MockQdrantRepository},
+};
+use chrono::Utc;
+use rand::Rng;
+use sea_orm::*;
+
+async fn setup_test_db() -> (Arc<DatabaseConnection>, i32) {
+    dotenv::dotenv().ok();
+    let database_url = std::env::var("DATABASE_URL_TEST")
+        .expect("DATABASE_URL_TEST must be set. Run: just setup-test-db");
+    let db = Arc::new(db::create_connection(&database_url).await.unwrap());
+
+    let now = Utc::now().naive_utc();
+    let timestamp = now.and_utc().timestamp_micros();
+    let random: u32 = rand::thread_rng().gen();
+    let unique_id = format!("{}_{}", timestamp, random);
+
+    let new_user = user::ActiveModel {
+        id: NotSet,
+        username: Set(format!("test_user_{}", unique_id)),
+        email: Set(format!("test_{}@example.com", unique_id)),
+        password_hash: Set(None),
+        created_at: Set(now),
+        updated_at: Set(now),
+        ..Default::default()
+    };
+    let user_id = new_user.insert(db.as_ref()).await.unwrap().id;
+
+    (db, user_id)
+}
+
+fn create_services(
+    db: Arc<DatabaseConnection>,
+) -> (NotebookService, Arc<MemoService>, Arc<MockQdrantRepository>) {
+    let qdrant_repo = Arc::new(MockQdrantRepository::new());
+    let memo_service = Arc::new(MemoService::new(
+        db.clone(),
+        qdrant_repo.clone(),
+        Arc::new(MockGeminiClient::new()) as Arc<dyn Embedder>,
+        Arc::new(MockGeminiClient::new()) as Arc<dyn TextGenerator>,
+    ));
+    (
+        NotebookService::new(db, memo_service.clone()),
+        memo_service,
+        qdrant_repo,
+    )
+}
+
+async fn create_notebook(
+    service: &NotebookService,
+    user_id: i32,
+    name: &str,
+    parent_id: Option<i32>,
+) -> i32 {
+    service
+        .create_notebook(
+            user_id,
+            CreateNotebookRequest {
+                name: name.to_string(),
+                parent_id,
+            },
+        )
+        .await
+        .unwrap()
+        .id
+}
+
+async fn create_memo(memo_service: &MemoService, user_id: i32, notebook_id: Option<i32>) -> i32 {
+    memo_service
+        .create_memo(
+            user_id,
+            CreateMemoRequest {
+                content: "노트북 메모".to_string(),
+                notebook_id,
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap()
+        .id
+}
+
+async fn list_ids(
+    memo_service: &MemoService,
+    user_id: i32,
+    notebook_id: i32,
+    recursive: bool,
+) -> Vec<i32> {
+    let mut ids: Vec<i32> = memo_service
+        .list_memos(
+            user_id,
+            MemoListQuery {
+                notebook_id: Some(notebook_id),
+                recursive,
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap()
+        .memos
+        .into_iter()
+        .map(|memo| memo.id)
+        .collect();
+    ids.sort();
+    ids
+}
+
+// Qdrant 페이로드의 노트북으로 걸러 낸 메모 ID
+async fn semantic_ids(
+    qdrant_repo: &MockQdrantRepository,
+    user_id: i32,
+    notebook_ids: Vec<i32>,
+) -> Vec<i32> {
+    let filter = MemoListFilter {
+        notebook_ids: Some(notebook_ids),
+        ..Default::default()
+    };
+    let mut ids: Vec<i32> = qdrant_repo
+        .search_similar(user_id, vec![0.1; 768], &filter, 10)
+        .await
+        .unwrap()
+        .into_iter()
+        .map(|(id, _)| id)
+        .collect();
+    ids.sort();
+    ids
+}
+
+#[tokio::test]
+async fn test_create_rename_and_list_notebooks() {
+    let (db, user_id) = setup_test_db().await;
+    let (_, other_user_id) = setup_test_db().await;
+    let (service, memo_service, _) = create_services(db);
+
+    let work = create_notebook(&service, user_id, "업무", None).await;
+    let project = create_notebook(&service, user_id, "프로젝트", Some(work)).await;
+    create_memo(&memo_service, user_id, Some(project)).await;
+    create_memo(&memo_service, user_id, Some(project)).await;
+
+    // 이름은 같은 부모 아래에서만 유일하면 된다.
+    create_notebook(&service, user_id, "프로젝트", None).await;
+    assert!(matches!(
+        service
+            .create_notebook(
+                user_id,
+                CreateNotebookRequest {
+                    name: " 프로젝트 ".to_string(),
+                    parent_id: Some(work),
+                },
+            )
+            .await,
+        Err(ServiceError::NotebookNameExists)
+    ));
+    assert!(matches!(
+        service
+            .create_notebook(
+                other_user_id,
+                CreateNotebookRequest {
+                    name: "남의 노트북 아래".to_string(),
+                    parent_id: Some(work),
+                },
+            )
+            .await,
+        Err(ServiceError::NotebookNotFound)
+    ));
+
+    let renamed = service
+        .rename_notebook(
+            user_id,
+            project,
+            RenameNotebookRequest {
+                name: "사이드 프로젝트".to_string(),
+            },
+        )
+        .await
+        .unwrap();
+    assert_eq!(renamed.name, "사이드 프로젝트");
+    assert_eq!(renamed.parent_id, Some(work));
+    assert_eq!(renamed.memo_count, 2);
+
+    let notebooks = service.list_notebooks(user_id).await.unwrap();
+    let summary: Vec<(&str, Option<i32>, i64)> = notebooks
+        .iter()
+        .map(|notebook| {
+            (
+                notebook.name.as_str(),
+                notebook.parent_id,
+                notebook.memo_count,
+            )
+        })
+        .collect();
+    assert_eq!(
+        summary,
+        vec![
+            ("사이드 프로젝트", Some(work), 2),
+            ("업무", None, 0),
+            ("프로젝트", None, 0),
+        ]
+    );
+    assert!(service
+        .list_notebooks(other_user_id)
+        .await
+        .unwrap()
+        .is_empty());
+}
+
+#[tokio::test]
+async fn test_move_notebook_rejects_cycles() {
+    let (db, user_id) = setup_test_db().await;
+    let (service, _, _) = create_services(db);
+
+    let root = create_notebook(&service, user_id, "루트", None).await;
+    let child = create_notebook(&service, user_id, "자식", Some(root)).await;
+    let grandchild = create_notebook(&service, user_id, "손자", Some(child)).await;
+
+    for parent_id in [root, grandchild] {
+        assert!(matches!(
+            service
+                .move_notebook(
+                    user_id,
+                    root,
+                    MoveNotebookRequest {
+                        parent_id: Some(parent_id),
+                    },
+                )
+                .await,
+            Err(ServiceError::Validation(_))
+        ));
+    }
+
+    let moved = service
+        .move_notebook(user_id, grandchild, MoveNotebookRequest { parent_id: None })
+        .await
+        .unwrap();
+    assert_eq!(moved.parent_id, None);
+
+    create_notebook(&service, user_id, "자식", None).await;
+    assert!(matches!(
+        service
+            .move_notebook(user_id, child, MoveNotebookRequest { parent_id: None })
+            .await,
+        Err(ServiceError::NotebookNameExists)
+    ));
+}
+
+#[tokio::test]
+async fn test_list_memos_by_notebook_recursively() {
+    let (db, user_id) = setup_test_db().await;
+    let (_, other_user_id) = setup_test_db().await;
+    let (service, memo_service, qdrant_repo) = create_services(db);
+
+    let root = create_notebook(&service, user_id, "루트", None).await;
+    let child = create_notebook(&service, user_id, "자식", Some(root)).await;
+    let grandchild = create_notebook(&service, user_id, "손자", Some(child)).await;
+    let in_root = create_memo(&memo_service, user_id, Some(root)).await;
+    let in_grandchild = create_memo(&memo_service, user_id, Some(grandchild)).await;
+    let loose = create_memo(&memo_service, user_id, None).await;
+
+    assert_eq!(
+        list_ids(&memo_service, user_id, root, false).await,
+        vec![in_root]
+    );
+    assert_eq!(
+        list_ids(&memo_service, user_id, root, true).await,
+        vec![in_root, in_grandchild]
+    );
+    assert_eq!(
+        list_ids(&memo_service, user_id, child, true).await,
+        vec![in_grandchild]
+    );
+
+    // 메모를 옮기면 목록과 의미 기반 검색의 노트북 조건이 함께 바뀐다.
+    let moved = memo_service
+        .move_memos(
+            user_id,
+            MoveMemosRequest {
+                memo_ids: vec![loose, in_root],
+                notebook_id: Some(child),
+            },
+        )
+        .await
+        .unwrap();
+    assert!(moved.iter().all(|memo| memo.notebook_id == Some(child)));
+    assert_eq!(
+        list_ids(&memo_service, user_id, child, false).await,
+        vec![in_root, loose]
+    );
+    assert_eq!(
+        semantic_ids(&qdrant_repo, user_id, vec![child]).await,
+        vec![in_root, loose]
+    );
+    assert!(semantic_ids(&qdrant_repo, user_id, vec![root])
+        .await
+        .is_empty());
+
+    assert!(matches!(
+        memo_service
+            .move_memos(
+                other_user_id,
+                MoveMemosRequest {
+                    memo_ids: vec![loose],
+                    notebook_id: None,
+                },
+            )
+            .await,
+        Err(ServiceError::MemoNotFound)
+    ));
+    assert!(matches!(
+        memo_service
+            .list_memos(
+                other_user_id,
+                MemoListQuery {
+                    notebook_id: Some(root),
+                    ..Default::default()
+                },
+            )
+            .await,
+        Err(ServiceError::NotebookNotFound)
+    ));
+}
+
+#[tokio::test]
+async fn test_delete_notebook_moves_contents_to_parent() {
+    let (db, user_id) = setup_test_db().await;
+    let (service, memo_service, qdrant_repo) = create_services(db);
+
+    let root = create_notebook(&service, user_id, "루트", None).await;
+    let child = create_notebook(&service, user_id, "자식", Some(root)).await;
+    let grandchild = create_notebook(&service, user_id, "손자", Some(child)).await;
+    let in_child = create_memo(&memo_service, user_id, Some(child)).await;
+    let in_grandchild = create_memo(&memo_service, user_id, Some(grandchild)).await;
+
+    service
+        .delete_notebook(user_id, child, DeleteNotebookMode::Move)
+        .await
+        .unwrap();
+
+    let memo = memo_service.get_memo(user_id, in_child).await.unwrap();
+    assert_eq!(memo.notebook_id, Some(root));
+    assert_eq!(
+        semantic_ids(&qdrant_repo, user_id, vec![root]).await,
+        vec![in_child]
+    );
+    let notebooks = service.list_notebooks(user_id).await.unwrap();
+    let grandchild = notebooks.iter().find(|n| n.id == grandchild).unwrap();
+    assert_eq!(grandchild.parent_id, Some(root));
+    assert_eq!(
+        list_ids(&memo_service, user_id, root, true).await,
+        vec![in_child, in_grandchild]
+    );
+
+    // 최상위 노트북을 지우면 메모는 노트북 밖으로 나온다.
+    service
+        .delete_notebook(user_id, root, DeleteNotebookMode::Move)
+        .await
+        .unwrap();
+    let memo = memo_service.get_memo(user_id, in_child).await.unwrap();
+    assert_eq!(memo.notebook_id, None);
+}
+
+#[tokio::test]
+async fn test_delete_notebook_rejects_name_clash_with_parent_children() {
+    let (db, user_id) = setup_test_db().await;
+    let (service, memo_service, _) = create_services(db);
+
+    let root = create_notebook(&service, user_id, "루트", None).await;
+    let archive = create_notebook(&service, user_id, "보관", Some(root)).await;
+    let child = create_notebook(&service, user_id, "자료", Some(archive)).await;
+    let sibling = create_notebook(&service, user_id, "자료", Some(root)).await;
+    let in_archive = create_memo(&memo_service, user_id, Some(archive)).await;
+
+    assert!(matches!(
+        service
+            .delete_notebook(user_id, archive, DeleteNotebookMode::Move)
+            .await,
+        Err(ServiceError::NotebookNameExists)
+    ));
+
+    // 실패하면 아무것도 옮기거나 지우지 않는다.
+    let notebooks = service.list_notebooks(user_id).await.unwrap();
+    assert!(notebooks.iter().any(|n| n.id == archive));
+    let child = notebooks.iter().find(|n| n.id == child).unwrap();
+    assert_eq!(child.parent_id, Some(archive));
+    let memo = memo_service.get_memo(user_id, in_archive).await.unwrap();
+    assert_eq!(memo.notebook_id, Some(archive));
+
+    service
+        .rename_notebook(
+            user_id,
+            sibling,
+            RenameNotebookRequest {
+                name: "자료 (이전)".to_string(),
+            },
+        )
+        .await
+        .unwrap();
+    service
+        .delete_notebook(user_id, archive, DeleteNotebookMode::Move)
+        .await
+        .unwrap();
+    let notebooks = service.list_notebooks(user_id).await.unwrap();
+    let child = notebooks.iter().find(|n| n.id == child.id).unwrap();
+    assert_eq!(child.parent_id, Some(root));
+}
+
+#[tokio::test]
+async fn test_delete_notebook_cascade() {
+    let (db, user_id) = setup_test_db().await;
+    let (service, memo_service, qdrant_repo) = create_services(db);
+
+    let root = create_notebook(&service, user_id, "루트", None).await;
+    let child = create_notebook(&service, user_id, "자식", Some(root)).await;
+    let other = create_notebook(&service, user_id, "다른", None).await;
+    let in_root = create_memo(&memo_service, user_id, Some(root)).await;
+    let in_child = create_memo(&memo_service, user_id, Some(child)).await;
+    let kept = create_memo(&memo_service, user_id, Some(other)).await;
+    let trashed_before = create_memo(&memo_service, user_id, Some(child)).await;
+    memo_service
+        .delete_memo(user_id, trashed_before)
+        .await
+        .unwrap();
+
+    service
+        .delete_notebook(user_id, root, DeleteNotebookMode::Cascade)
+        .await
+        .unwrap();
+
+    for memo_id in [in_root, in_child] {
+        assert!(matches!(
+            memo_service.get_memo(user_id, memo_id).await,
+            Err(ServiceError::MemoNotFound)
+        ));
+    }
+    assert!(memo_service.get_memo(user_id, kept).await.is_ok());
+    let notebooks = service.list_notebooks(user_id).await.unwrap();
+    let ids: Vec<i32> = notebooks.iter().map(|notebook| notebook.id).collect();
+    assert_eq!(ids, vec![other]);
+    assert_eq!(
+        semantic_ids(&qdrant_repo, user_id, vec![root, child, other]).await,
+        vec![kept]
+    );
+
+    // 삭제된 노트북의 메모는 복원하면 노트북 밖으로 돌아오고, 페이로드도 이를 따른다.
+    for memo_id in [in_child, trashed_before] {
+        let restored = memo_service.restore_memo(user_id, memo_id).await.unwrap();
+        assert_eq!(restored.notebook_id, None);
+    }
+    assert!(semantic_ids(&qdrant_repo, user_id, vec![root, child])
+        .await
+        .is_empty());
+
+    assert!(matches!(
+        service
+            .delete_notebook(user_id, root, DeleteNotebookMode::Cascade)
+            .await,
+        Err(ServiceError::NotebookNotFound)
+    ));
+}
@@ -0,0 +1,155 @@
+use chrono::{Duration, Utc};
+use rand::{distributions::Alphanumeric, Rng};
+use sea_orm::DatabaseConnection;
+use std::sync::Arc;
+
+use crate::{
+    entities::personal_access_token,
+    errors::ServiceError,
+    models::{
+        CreatePersonalAccessTokenRequest, CreatedPersonalAccessTokenResponse,
+        PersonalAccessTokenResponse, Scope,
+    },
+    repositories::PersonalAccessTokenRepository,
+    services::auth_service::hash_token,
+};
+
+pub const TOKEN_PREFIX: &str = "ink_pat_";
+const TOKEN_SECRET_LENGTH: usize = 40;
+const DISPLAY_PREFIX_LENGTH: usize = 12;
+const MAX_EXPIRES_IN_DAYS: i64 = 365;
+const LAST_USED_TOUCH_INTERVAL_SECONDS: i64 = 60;
+
+#[derive(Clone)]
+pub struct PersonalAccessTokenService {
+    token_repo: PersonalAccessTokenRepository,
+}
+
+impl PersonalAccessTokenService {
+    pub fn new(db: Arc<DatabaseConnection>) -> Self {
+        Self {
+            token_repo: PersonalAccessTokenRepository::new(db),
+        }
+    }
+
+    pub async fn create_token(
+        &self,
+        user_id: i32,
+        granted_scopes: &[Scope],
+        req: CreatePersonalAccessTokenRequest,
+    ) -> Result<CreatedPersonalAccessTokenResponse, ServiceError> {
+        let name = req.name.trim().to_string();
+        if name.is_empty() || name.chars().count() > 100 {
+            return Err(ServiceError::Validation(
+                "Token name must be between 1 and 100 characters".to_string(),
+            ));
+        }
+
+        let mut scopes = Vec::new();
+        for scope in req.scopes {
+            if !scopes.contains(&scope) {
+                scopes.push(scope);
+            }
+        }
+        if scopes.is_empty() {
+            return Err(ServiceError::Validation(
+                "At least one scope is required".to_string(),
+            ));
+        }
+        // 발급 요청에 사용한 토큰보다 넓은 권한의 토큰은 만들 수 없다.
+        if !scopes.iter().all(|scope| granted_scopes.contains(scope)) {
+            return Err(ServiceError::InsufficientScope);
+        }
+
+        let expires_at = match req.expires_in_days {
+            Some(days) if !(1..=MAX_EXPIRES_IN_DAYS).contains(&days) => {
+                return Err(ServiceError::Validation(format!(
+                    "expires_in_days must be between 1 and {}",
+                    MAX_EXPIRES_IN_DAYS
+                )));
+            }
+            Some(days) => Some((Utc::now() + Duration::days(days)).naive_utc()),
+            None => None,
+        };
+
+        let random: String = rand::thread_rng()
+            .sample_iter(&Alphanumeric)
+            .take(TOKEN_SECRET_LENGTH)
+            .map(char::from)
+            .collect();
+        let secret = format!("{}{}", TOKEN_PREFIX, random);
+
+        let token = self
+            .token_repo
+            .create(
+                user_id,
+                name,
+                secret[..DISPLAY_PREFIX_LENGTH].to_string(),
+                hash_token(&secret),
+                Scope::join(&scopes),
+                expires_at,
+            )
+            .await?;
+
+        Ok(CreatedPersonalAccessTokenResponse {
+            token: PersonalAccessTokenResponse::from(token),
+            secret,
+        })
+    }
+
+    pub async fn list_tokens(
+        &self,
+        user_id: i32,
+    ) -> Result<Vec<PersonalAccessTokenResponse>, ServiceError> {
+        let tokens = self.token_repo.find_active_by_user_id(user_id).await?;
+        Ok(tokens
+            .into_iter()
+            .map(PersonalAccessTokenResponse::from)
+            .collect())
+    }
+
+    pub async fn revoke_token(&self, user_id: i32, token_id: i32) -> Result<(), ServiceError> {
+        if !self.token_repo.revoke(token_id, user_id).await? {
+            return Err(ServiceError::TokenNotFound);
+        }
+        Ok(())
+    }
+
+    pub async fn authenticate(
+        &self,
+        secret: &str,
+    ) -> Result<personal_access_token::Model, ServiceError> {
+        let token = self
+            .token_repo
+            .find_by_hash(&hash_token(secret))
+            .await?
+            .ok_or(ServiceError::InvalidToken)?;
+
+        if token.revoked_at.is_some() {
+            return Err(ServiceError::InvalidToken);
+        }
+        if token
+            .expires_at
+            .is_some_and(|expires_at| expires_at <= Utc::now().naive_utc())
+        {
+            return Err(ServiceError::InvalidToken);
+        }
+
+        // 세션과 마찬가지로 요청마다 쓰기가 일어나지 않도록 일정 간격으로만 갱신한다.
+        let now = Utc::now().naive_utc();
+        if token.last_used_at.is_none_or(|last_used_at| {
+            now - last_used_at >= Duration::seconds(LAST_USED_TOUCH_INTERVAL_SECONDS)
+        }) {
+            self.token_repo.touch_last_used(token.id, now).await?;
+        }
+
+        Ok(token)
+    }
+}
+
+pub fn is_personal_access_token(token: &str) -> bool {
+    token.starts_with(TOKEN_PREFIX)
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,141 @@
+use super::*;
+use crate::{db, entities::user};
+use rand::Rng;
+use sea_orm::*;
+
+async fn setup_test_db() -> (Arc<DatabaseConnection>, user::Model) {
+    dotenv::dotenv().ok();
+    let database_url = std::env::var("DATABASE_URL_TEST")
+        .expect("DATABASE_URL_TEST must be set. Run: just setup-test-db");
+    let db = Arc::new(db::create_connection(&database_url).await.unwrap());
+
+    let now = Utc::now().naive_utc();
+    let timestamp = now.and_utc().timestamp_micros();
+    let random: u32 = rand::thread_rng().gen();
+    let unique_id = format!("{}_{}", timestamp, random);
+
+    let new_user = user::ActiveModel {
+        id: NotSet,
+        username: Set(format!("test_user_{}", unique_id)),
+        email: Set(format!("test_{}@example.com", unique_id)),
+        password_hash: Set(None),
+        created_at: Set(now),
+        updated_at: Set(now),
+        ..Default::default()
+    };
+    let user = new_user.insert(db.as_ref()).await.unwrap();
+
+    (db, user)
+}
+
+fn create_request(
+    scopes: Vec<Scope>,
+    expires_in_days: Option<i64>,
+) -> CreatePersonalAccessTokenRequest {
+    CreatePersonalAccessTokenRequest {
+        name: "백업 스크립트".to_string(),
+        scopes,
+        expires_in_days,
+    }
+}
+
+#[tokio::test]
+async fn test_create_and_authenticate_token() {
+    let (db, user) = setup_test_db().await;
+    let service = PersonalAccessTokenService::new(db);
+
+    let created = service
+        .create_token(
+            user.id,
+            &Scope::ALL,
+            create_request(
+                vec![Scope::MemosRead, Scope::MemosRead, Scope::Assist],
+                Some(30),
+            ),
+        )
+        .await
+        .unwrap();
+
+    assert!(is_personal_access_token(&created.secret));
+    assert!(created.secret.starts_with(&created.token.token_prefix));
+    assert_eq!(created.token.scopes, vec![Scope::MemosRead, Scope::Assist]);
+    assert!(created.token.last_used_at.is_none());
+
+    let authenticated = service.authenticate(&created.secret).await.unwrap();
+    assert_eq!(authenticated.user_id, user.id);
+
+    let listed = service.list_tokens(user.id).await.unwrap();
+    assert_eq!(listed.len(), 1);
+    assert_eq!(listed[0].id, created.token.id);
+    assert!(listed[0].last_used_at.is_some());
+
+    // 갱신 간격 안에 다시 쓰면 마지막 사용 시각을 고치지 않는다.
+    service.authenticate(&created.secret).await.unwrap();
+    let relisted = service.list_tokens(user.id).await.unwrap();
+    assert_eq!(relisted[0].last_used_at, listed[0].last_used_at);
+
+    let unknown = service.authenticate("ink_pat_unknown").await;
+    assert!(matches!(unknown, Err(ServiceError::InvalidToken)));
+}
+
+#[tokio::test]
+async fn test_revoked_token_is_rejected() {
+    let (db, user) = setup_test_db().await;
+    let service = PersonalAccessTokenService::new(db);
+
+    let created = service
+        .create_token(
+            user.id,
+            &Scope::ALL,
+            create_request(vec![Scope::MemosWrite], None),
+        )
+        .await
+        .unwrap();
+
+    let wrong_owner = service.revoke_token(user.id + 1, created.token.id).await;
+    assert!(matches!(wrong_owner, Err(ServiceError::TokenNotFound)));
+
+    service
+        .revoke_token(user.id, created.token.id)
+        .await
+        .unwrap();
+
+    let result = service.authenticate(&created.secret).await;
+    assert!(matches!(result, Err(ServiceError::InvalidToken)));
+    assert!(service.list_tokens(user.id).await.unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn test_create_token_validation() {
+    let (db, user) = setup_test_db().await;
+    let service = PersonalAccessTokenService::new(db);
+
+    let no_scopes = service
+        .create_token(user.id, &Scope::ALL, create_request(vec![], None))
+        .await;
+    assert!(matches!(no_scopes, Err(ServiceError::Validation(_))));
+
+    let too_long = service
+        .create_token(
+            user.id,
+            &Scope::ALL,
+            create_request(vec![Scope::Account], Some(1000)),
+        )
+        .await;
+    assert!(matches!(too_long, Err(ServiceError::Validation(_))));
+}
+
+#[tokio::test]
+async fn test_create_token_cannot_escalate_scopes() {
+    let (db, user) = setup_test_db().await;
+    let service = PersonalAccessTokenService::new(db);
+
+    let result = service
+        .create_token(
+            user.id,
+            &[Scope::Account, Scope::MemosRead],
+            create_request(vec![Scope::MemosWrite], None),
+        )
+        .await;
+    assert!(matches!(result, Err(ServiceError::InsufficientScope)));
+}
@@ -0,0 +1,180 @@
+use sea_orm::DatabaseConnection;
+use std::{collections::HashSet, sync::Arc};
+
+use crate::{
+    errors::ServiceError,
+    models::{
+        CreateSavedSearchRequest, MemoSearchQuery, MemoSearchResponse, ReorderSavedSearchesRequest,
+        SavedSearchMemosQuery, SavedSearchResponse, UpdateSavedSearchRequest,
+    },
+    repositories::SavedSearchRepository,
+    services::MemoService,
+};
+
+const MAX_NAME_LENGTH: usize = 100;
+
+// 저장된 검색은 검색어만 보관하고, 결과는 조회할 때마다 메모 검색을 다시 실행해 만든다.
+#[derive(Clone)]
+pub struct SavedSearchService {
+    saved_search_repo: SavedSearchRepository,
+    memo_service: Arc<MemoService>,
+}
+
+impl SavedSearchService {
+    pub fn new(db: Arc<DatabaseConnection>, memo_service: Arc<MemoService>) -> Self {
+        Self {
+            saved_search_repo: SavedSearchRepository::new(db),
+            memo_service,
+        }
+    }
+
+    pub async fn create_saved_search(
+        &self,
+        user_id: i32,
+        req: CreateSavedSearchRequest,
+    ) -> Result<SavedSearchResponse, ServiceError> {
+        let name = validate_name(&req.name)?;
+        MemoService::validate_search_query(&req.query)?;
+        self.ensure_name_available(user_id, &name, None).await?;
+
+        let saved_search = self
+            .saved_search_repo
+            .create(user_id, name, req.query)
+            .await?;
+        Ok(SavedSearchResponse::from(saved_search))
+    }
+
+    pub async fn list_saved_searches(
+        &self,
+        user_id: i32,
+    ) -> Result<Vec<SavedSearchResponse>, ServiceError> {
+        let saved_searches = self.saved_search_repo.find_by_user_id(user_id).await?;
+        Ok(saved_searches
+            .into_iter()
+            .map(SavedSearchResponse::from)
+            .collect())
+    }
+
+    pub async fn get_saved_search(
+        &self,
+        user_id: i32,
+        id: i32,
+    ) -> Result<SavedSearchResponse, ServiceError> {
+        let saved_search = self
+            .saved_search_repo
+            .find_by_id(id, user_id)
+            .await?
+            .ok_or(ServiceError::SavedSearchNotFound)?;
+        Ok(SavedSearchResponse::from(saved_search))
+    }
+
+    pub async fn update_saved_search(
+        &self,
+        user_id: i32,
+        id: i32,
+        req: UpdateSavedSearchRequest,
+    ) -> Result<SavedSearchResponse, ServiceError> {
+        let saved_search = self
+            .saved_search_repo
+            .find_by_id(id, user_id)
+            .await?
+            .ok_or(ServiceError::SavedSearchNotFound)?;
+
+        let name = req.name.as_deref().map(validate_name).transpose()?;
+        if let Some(name) = &name {
+            self.ensure_name_available(user_id, name, Some(id)).await?;
+        }
+        if let Some(query) = &req.query {
+            MemoService::validate_search_query(query)?;
+        }
+
+        let updated = self
+            .saved_search_repo
+            .update(saved_search, name, req.query)
+            .await?;
+        Ok(SavedSearchResponse::from(updated))
+    }
+
+    pub async fn delete_saved_search(&self, user_id: i32, id: i32) -> Result<(), ServiceError> {
+        if !self.saved_search_repo.delete(id, user_id).await? {
+            return Err(ServiceError::SavedSearchNotFound);
+        }
+        Ok(())
+    }
+
+    // 일부만 보내면 빠진 항목의 위치가 겹치므로 전체 목록을 받는다.
+    pub async fn reorder_saved_searches(
+        &self,
+        user_id: i32,
+        req: ReorderSavedSearchesRequest,
+    ) -> Result<Vec<SavedSearchResponse>, ServiceError> {
+        let existing: HashSet<i32> = self
+            .saved_search_repo
+            .find_by_user_id(user_id)
+            .await?
+            .into_iter()
+            .map(|saved_search| saved_search.id)
+            .collect();
+        let requested: HashSet<i32> = req.ids.iter().copied().collect();
+        if requested.len() != req.ids.len() || requested != existing {
+            return Err(ServiceError::Validation(
+                "ids must list every saved search exactly once".to_string(),
+            ));
+        }
+
+        self.saved_search_repo.reorder(user_id, &req.ids).await?;
+        self.list_saved_searches(user_id).await
+    }
+
+    pub async fn search_memos(
+        &self,
+        user_id: i32,
+        id: i32,
+        query: SavedSearchMemosQuery,
+    ) -> Result<MemoSearchResponse, ServiceError> {
+        let saved_search = self
+            .saved_search_repo
+            .find_by_id(id, user_id)
+            .await?
+            .ok_or(ServiceError::SavedSearchNotFound)?;
+
+        self.memo_service
+            .search_memos(
+                user_id,
+                MemoSearchQuery {
+                    q: saved_search.query,
+                    limit: query.limit,
+                    offset: query.offset,
+                },
+            )
+            .await
+    }
+
+    async fn ensure_name_available(
+        &self,
+        user_id: i32,
+        name: &str,
+        current_id: Option<i32>,
+    ) -> Result<(), ServiceError> {
+        match self.saved_search_repo.find_by_name(user_id, name).await? {
+            Some(existing) if Some(existing.id) != current_id => {
+                Err(ServiceError::SavedSearchNameExists)
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+fn validate_name(name: &str) -> Result<String, ServiceError> {
+    let name = name.trim();
+    if name.is_empty() || name.chars().count() > MAX_NAME_LENGTH {
+        return Err(ServiceError::Validation(format!(
+            "Saved search name must be between 1 and {} characters",
+            MAX_NAME_LENGTH
+        )));
+    }
+    Ok(name.to_string())
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,243 @@
+use super::*;
+use crate::{
+    clients::{Embedder, TextGenerator},
+    db,
+    entities::user,
+    models::CreateMemoRequest,
+    test_utils::{MockGeminiClient, MockQdrantRepository},
+};
+use chrono::Utc;
+use rand::Rng;
+use sea_orm::*;
+
+async fn setup_test_db() -> (Arc<DatabaseConnection>, i32) {
+    dotenv::dotenv().ok();
+    let database_url = std::env::var("DATABASE_URL_TEST")
+        .expect("DATABASE_URL_TEST must be set. Run: just setup-test-db");
+    let db = Arc::new(db::create_connection(&database_url).await.unwrap());
+
+    let now = Utc::now().naive_utc();
+    let timestamp = now.and_utc().timestamp_micros();
+    let random: u32 = rand::thread_rng().gen();
+    let unique_id = format!("{}_{}", timestamp, random);
+
+    let new_user = user::ActiveModel {
+        id: NotSet,
+        username: Set(format!("test_user_{}", unique_id)),
+        email: Set(format!("test_{}@example.com", unique_id)),
+        password_hash: Set(None),
+        created_at: Set(now),
+        updated_at: Set(now),
+        ..Default::default()
+    };
+    let user_id = new_user.insert(db.as_ref()).await.unwrap().id;
+
+    (db, user_id)
+}
+
+fn create_services(db: Arc<DatabaseConnection>) -> (SavedSearchService, Arc<MemoService>) {
+    let memo_service = Arc::new(MemoService::new(
+        db.clone(),
+        Arc::new(MockQdrantRepository::new()),
+        Arc::new(MockGeminiClient::new()) as Arc<dyn Embedder>,
+        Arc::new(MockGeminiClient::new()) as Arc<dyn TextGenerator>,
+    ));
+    (
+        SavedSearchService::new(db, memo_service.clone()),
+        memo_service,
+    )
+}
+
+fn create_request(name: &str, query: &str) -> CreateSavedSearchRequest {
+    CreateSavedSearchRequest {
+        name: name.to_string(),
+        query: query.to_string(),
+    }
+}
+
+#[tokio::test]
+async fn test_saved_search_reruns_query_live() {
+    let (db, user_id) = setup_test_db().await;
+    let (service, memo_service) = create_services(db);
+
+    let saved = service
+        .create_saved_search(
+            user_id,
+            create_request("  최근 아이디어 ", "아이디어 pinned:false after:30d"),
+        )
+        .await
+        .unwrap();
+    assert_eq!(saved.name, "최근 아이디어");
+    assert_eq!(saved.position, 0);
+
+    let first = service
+        .search_memos(user_id, saved.id, SavedSearchMemosQuery::default())
+        .await
+        .unwrap();
+    assert_eq!(first.total, 0);
+
+    // 저장한 뒤에 작성한 메모도 다음 조회에 나타난다.
+    let idea = memo_service
+        .create_memo(
+            user_id,
+            CreateMemoRequest {
+                content: "새 아이디어".to_string(),
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+    let pinned = memo_service
+        .create_memo(
+            user_id,
+            CreateMemoRequest {
+                content: "고정한 아이디어".to_string(),
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+    memo_service.toggle_pin(user_id, pinned.id).await.unwrap();
+
+    let second = service
+        .search_memos(user_id, saved.id, SavedSearchMemosQuery::default())
+        .await
+        .unwrap();
+    assert_eq!(second.total, 1);
+    assert_eq!(second.results[0].memo.id, idea.id);
+}
+
+#[tokio::test]
+async fn test_create_saved_search_validation() {
+    let (db, user_id) = setup_test_db().await;
+    let (service, _) = create_services(db);
+
+    service
+        .create_saved_search(user_id, create_request("글감", "글감"))
+        .await
+        .unwrap();
+
+    assert!(matches!(
+        service
+            .create_saved_search(user_id, create_request("글감", "다른 검색"))
+            .await,
+        Err(ServiceError::SavedSearchNameExists)
+    ));
+    assert!(matches!(
+        service
+            .create_saved_search(user_id, create_request("  ", "글감"))
+            .await,
+        Err(ServiceError::Validation(_))
+    ));
+    assert!(matches!(
+        service
+            .create_saved_search(user_id, create_request("잘못된 검색", "before:어제"))
+            .await,
+        Err(ServiceError::InvalidSearchQuery(err)) if err.position == 7
+    ));
+    assert!(matches!(
+        service
+            .create_saved_search(user_id, create_request("빈 검색", "   "))
+            .await,
+        Err(ServiceError::Validation(_))
+    ));
+}
+
+#[tokio::test]
+async fn test_update_and_delete_saved_search() {
+    let (db, user_id) = setup_test_db().await;
+    let (_, other_user_id) = setup_test_db().await;
+    let (service, _) = create_services(db);
+
+    let first = service
+        .create_saved_search(user_id, create_request("첫 번째", "하나"))
+        .await
+        .unwrap();
+    service
+        .create_saved_search(user_id, create_request("두 번째", "둘"))
+        .await
+        .unwrap();
+
+    let renamed = service
+        .update_saved_search(
+            user_id,
+            first.id,
+            UpdateSavedSearchRequest {
+                name: Some("첫 번째".to_string()),
+                query: Some("하나 pinned:true".to_string()),
+            },
+        )
+        .await
+        .unwrap();
+    assert_eq!(renamed.query, "하나 pinned:true");
+
+    assert!(matches!(
+        service
+            .update_saved_search(
+                user_id,
+                first.id,
+                UpdateSavedSearchRequest {
+                    name: Some("두 번째".to_string()),
+                    ..Default::default()
+                },
+            )
+            .await,
+        Err(ServiceError::SavedSearchNameExists)
+    ));
+    assert!(matches!(
+        service.get_saved_search(other_user_id, first.id).await,
+        Err(ServiceError::SavedSearchNotFound)
+    ));
+    assert!(matches!(
+        service.delete_saved_search(other_user_id, first.id).await,
+        Err(ServiceError::SavedSearchNotFound)
+    ));
+
+    service
+        .delete_saved_search(user_id, first.id)
+        .await
+        .unwrap();
+    let remaining = service.list_saved_searches(user_id).await.unwrap();
+    assert_eq!(remaining.len(), 1);
+    assert_eq!(remaining[0].name, "두 번째");
+}
+
+#[tokio::test]
+async fn test_reorder_saved_searches() {
+    let (db, user_id) = setup_test_db().await;
+    let (service, _) = create_services(db);
+
+    let mut ids = Vec::new();
+    for name in ["가", "나", "다"] {
+        let saved = service
+            .create_saved_search(user_id, create_request(name, name))
+            .await
+            .unwrap();
+        ids.push(saved.id);
+    }
+
+    let reordered = service
+        .reorder_saved_searches(
+            user_id,
+            ReorderSavedSearchesRequest {
+                ids: vec![ids[2], ids[0], ids[1]],
+            },
+        )
+        .await
+        .unwrap();
+    let names: Vec<&str> = reordered.iter().map(|s| s.name.as_str()).collect();
+    assert_eq!(names, vec!["다", "가", "나"]);
+    assert_eq!(
+        reordered.iter().map(|s| s.position).collect::<Vec<_>>(),
+        vec![0, 1, 2]
+    );
+
+    for ids in [vec![ids[0], ids[1]], vec![ids[0], ids[0], ids[1], ids[2]]] {
+        assert!(matches!(
+            service
+                .reorder_saved_searches(user_id, ReorderSavedSearchesRequest { ids })
+                .await,
+            Err(ServiceError::Validation(_))
+        ));
+    }
+}
@@ -0,0 +1,177 @@
+use sea_orm::DatabaseConnection;
+use std::{collections::HashSet, sync::Arc};
+
+use crate::{
+    errors::ServiceError,
+    models::{CreateTagRequest, MergeTagRequest, RenameTagRequest, TagResponse},
+    repositories::TagRepository,
+    services::MemoService,
+};
+
+const MAX_TAG_LENGTH: usize = 50;
+const MAX_TAGS_PER_MEMO: usize = 20;
+
+// 태그 이름은 사용자별로 유일하며, 메모의 태그 목록은 Qdrant 페이로드에도 복제되어 있다.
+// 이름을 바꾸거나 태그를 없애면 영향을 받는 메모의 페이로드를 다시 쓴다.
+#[derive(Clone)]
+pub struct TagService {
+    tag_repo: TagRepository,
+    memo_service: Arc<MemoService>,
+}
+
+impl TagService {
+    pub fn new(db: Arc<DatabaseConnection>, memo_service: Arc<MemoService>) -> Self {
+        Self {
+            tag_repo: TagRepository::new(db),
+            memo_service,
+        }
+    }
+
+    pub async fn list_tags(&self, user_id: i32) -> Result<Vec<TagResponse>, ServiceError> {
+        let tags = self.tag_repo.find_by_user_id(user_id).await?;
+        let ids: Vec<i32> = tags.iter().map(|tag| tag.id).collect();
+        let counts = self.tag_repo.count_memos(&ids).await?;
+
+        Ok(tags
+            .into_iter()
+            .map(|tag| {
+                let memo_count = counts.get(&tag.id).copied().unwrap_or(0);
+                TagResponse::from((tag, memo_count))
+            })
+            .collect())
+    }
+
+    pub async fn create_tag(
+        &self,
+        user_id: i32,
+        req: CreateTagRequest,
+    ) -> Result<TagResponse, ServiceError> {
+        let name = validate_tag_name(&req.name)?;
+        if self.tag_repo.find_by_name(user_id, &name).await?.is_some() {
+            return Err(ServiceError::TagAlreadyExists);
+        }
+
+        let tag = self.tag_repo.create(user_id, name).await?;
+        Ok(TagResponse::from((tag, 0)))
+    }
+
+    // 이미 있는 이름으로 바꾸려면 병합을 사용해야 한다.
+    pub async fn rename_tag(
+        &self,
+        user_id: i32,
+        id: i32,
+        req: RenameTagRequest,
+    ) -> Result<TagResponse, ServiceError> {
+        let tag = self
+            .tag_repo
+            .find_by_id(id, user_id)
+            .await?
+            .ok_or(ServiceError::TagNotFound)?;
+        let name = validate_tag_name(&req.name)?;
+        if let Some(existing) = self.tag_repo.find_by_name(user_id, &name).await? {
+            if existing.id != id {
+                return Err(ServiceError::TagAlreadyExists);
+            }
+        }
+
+        let renamed = self.tag_repo.rename(tag, name).await?;
+        let memo_ids = self.tag_repo.find_memo_ids(id).await?;
+        self.memo_service
+            .refresh_payloads(user_id, &memo_ids)
+            .await?;
+
+        Ok(TagResponse::from((renamed, memo_ids.len() as i64)))
+    }
+
+    pub async fn delete_tag(&self, user_id: i32, id: i32) -> Result<(), ServiceError> {
+        // 연결이 함께 삭제되므로 영향을 받는 메모는 미리 읽어 둔다.
+        let memo_ids = self.tag_repo.find_memo_ids(id).await?;
+        if !self.tag_repo.delete(id, user_id).await? {
+            return Err(ServiceError::TagNotFound);
+        }
+
+        self.memo_service.refresh_payloads(user_id, &memo_ids).await
+    }
+
+    // 경로의 태그(`id`)를 `target_id` 태그로 합치고 합쳐진 태그를 돌려준다.
+    pub async fn merge_tag(
+        &self,
+        user_id: i32,
+        id: i32,
+        req: MergeTagRequest,
+    ) -> Result<TagResponse, ServiceError> {
+        if id == req.target_id {
+            return Err(ServiceError::Validation(
+                "Cannot merge a tag into itself".to_string(),
+            ));
+        }
+        let source = self
+            .tag_repo
+            .find_by_id(id, user_id)
+            .await?
+            .ok_or(ServiceError::TagNotFound)?;
+        let target = self
+            .tag_repo
+            .find_by_id(req.target_id, user_id)
+            .await?
+            .ok_or(ServiceError::TagNotFound)?;
+
+        let memo_ids = self.tag_repo.find_memo_ids(source.id).await?;
+        self.tag_repo.merge(source.id, target.id).await?;
+        self.memo_service
+            .refresh_payloads(user_id, &memo_ids)
+            .await?;
+
+        let memo_count = self
+            .tag_repo
+            .count_memos(&[target.id])
+            .await?
+            .remove(&target.id)
+            .unwrap_or(0);
+        Ok(TagResponse::from((target, memo_count)))
+    }
+}
+
+// 앞뒤 공백과 맨 앞의 `#`을 떼고 소문자로 바꾼다. `#Rust`와 `rust`는 같은 태그다.
+pub fn normalize_tag_name(name: &str) -> String {
+    name.trim().trim_start_matches('#').trim().to_lowercase()
+}
+
+pub fn validate_tag_name(name: &str) -> Result<String, ServiceError> {
+    let name = normalize_tag_name(name);
+    if name.is_empty() || name.chars().count() > MAX_TAG_LENGTH {
+        return Err(ServiceError::Validation(format!(
+            "Tag name must be between 1 and {} characters",
+            MAX_TAG_LENGTH
+        )));
+    }
+    // 쉼표는 목록 조회의 `tags` 구분자, 공백은 검색어의 `tag:` 값 구분자로 쓰인다.
+    if name.contains(|c: char| c == ',' || c.is_whitespace()) {
+        return Err(ServiceError::Validation(
+            "Tag name cannot contain commas or spaces".to_string(),
+        ));
+    }
+    Ok(name)
+}
+
+// 메모에 붙일 태그 목록. 정규화한 뒤 중복을 제거하고 처음 나온 순서를 유지한다.
+pub fn validate_tag_names(names: &[String]) -> Result<Vec<String>, ServiceError> {
+    let mut seen = HashSet::new();
+    let mut tags = Vec::new();
+    for name in names {
+        let name = validate_tag_name(name)?;
+        if seen.insert(name.clone()) {
+            tags.push(name);
+        }
+    }
+    if tags.len() > MAX_TAGS_PER_MEMO {
+        return Err(ServiceError::Validation(format!(
+            "A memo can have at most {} tags",
+            MAX_TAGS_PER_MEMO
+        )));
+    }
+    Ok(tags)
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,275 @@
+use super::*;
+use crate::{
+    clients::{Embedder, TextGenerator},
+    db,
+    entities::user,
+    models::{CreateMemoRequest, MemoListQuery},
+    repositories::{MemoListFilter, QdrantRepo},
+    test_utils::{MockGeminiClient, MockQdrantRepository},
+};
+use chrono::Utc;
+use rand::Rng;
+use sea_orm::*;
+
+async fn setup_test_db() -> (Arc<DatabaseConnection>, i32) {
+    dotenv::dotenv().ok();
+    let database_url = std::env::var("DATABASE_URL_TEST")
+        .expect("DATABASE_URL_TEST must be set. Run: just setup-test-db");
+    let db = Arc::new(db::create_connection(&database_url).await.unwrap());
+
+    let now = Utc::now().naive_utc();
+    let timestamp = now.and_utc().timestamp_micros();
+    let random: u32 = rand::thread_rng().gen();
+    let unique_id = format!("{}_{}", timestamp, random);
+
+    let new_user = user::ActiveModel {
+        id: NotSet,
+        username: Set(format!("test_user_{}", unique_id)),
+        email: Set(format!("test_{}@example.com", unique_id)),
+        password_hash: Set(None),
+        created_at: Set(now),
+        updated_at: Set(now),
+        ..Default::default()
+    };
+    let user_id = new_user.insert(db.as_ref()).await.unwrap().id;
+
+    (db, user_id)
+}
+
+fn create_services(
+    db: Arc<DatabaseConnection>,
+) -> (TagService, Arc<MemoService>, Arc<MockQdrantRepository>) {
+    let qdrant_repo = Arc::new(MockQdrantRepository::new());
+    let memo_service = Arc::new(MemoService::new(
+        db.clone(),
+        qdrant_repo.clone(),
+        Arc::new(MockGeminiClient::new()) as Arc<dyn Embedder>,
+        Arc::new(MockGeminiClient::new()) as Arc<dyn TextGenerator>,
+    ));
+    (
+        TagService::new(db, memo_service.clone()),
+        memo_service,
+        qdrant_repo,
+    )
+}
+
+async fn create_memo(memo_service: &MemoService, user_id: i32, tags: &[&str]) -> i32 {
+    memo_service
+        .create_memo(
+            user_id,
+            CreateMemoRequest {
+                content: format!("{} 메모", tags.join(" ")),
+                tags: tags.iter().map(|&tag| String::from(tag)).collect(),
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap()
+        .id
+}
+
+// Qdrant 페이로드의 태그로 걸러 낸 메모 ID
+async fn semantic_ids(qdrant_repo: &MockQdrantRepository, user_id: i32, tag: &str) -> Vec<i32> {
+    let filter = MemoListFilter {
+        tags: vec![tag.to_string()],
+        ..Default::default()
+    };
+    let mut ids: Vec<i32> = qdrant_repo
+        .search_similar(user_id, vec![0.1; 768], &filter, 10)
+        .await
+        .unwrap()
+        .into_iter()
+        .map(|(id, _)| id)
+        .collect();
+    ids.sort();
+    ids
+}
+
+#[tokio::test]
+async fn test_create_and_list_tags() {
+    let (db, user_id) = setup_test_db().await;
+    let (service, memo_service, _) = create_services(db);
+
+    create_memo(&memo_service, user_id, &["rust", "독서"]).await;
+    create_memo(&memo_service, user_id, &["rust"]).await;
+    let empty = service
+        .create_tag(
+            user_id,
+            CreateTagRequest {
+                name: "#Later".to_string(),
+            },
+        )
+        .await
+        .unwrap();
+    assert_eq!(empty.name, "later");
+    assert_eq!(empty.memo_count, 0);
+
+    let tags = service.list_tags(user_id).await.unwrap();
+    let summary: Vec<(&str, i64)> = tags
+        .iter()
+        .map(|tag| (tag.name.as_str(), tag.memo_count))
+        .collect();
+    assert_eq!(summary, vec![("later", 0), ("rust", 2), ("독서", 1)]);
+
+    assert!(matches!(
+        service
+            .create_tag(
+                user_id,
+                CreateTagRequest {
+                    name: "RUST".to_string(),
+                },
+            )
+            .await,
+        Err(ServiceError::TagAlreadyExists)
+    ));
+}
+
+#[tokio::test]
+async fn test_rename_tag_updates_memos_and_payloads() {
+    let (db, user_id) = setup_test_db().await;
+    let (_, other_user_id) = setup_test_db().await;
+    let (service, memo_service, qdrant_repo) = create_services(db);
+
+    let memo_id = create_memo(&memo_service, user_id, &["rust"]).await;
+    create_memo(&memo_service, user_id, &["go"]).await;
+    let tags = service.list_tags(user_id).await.unwrap();
+    let rust = tags.iter().find(|tag| tag.name == "rust").unwrap();
+
+    let renamed = service
+        .rename_tag(
+            user_id,
+            rust.id,
+            RenameTagRequest {
+                name: "Rust-Lang".to_string(),
+            },
+        )
+        .await
+        .unwrap();
+    assert_eq!(renamed.name, "rust-lang");
+    assert_eq!(renamed.memo_count, 1);
+
+    let memo = memo_service.get_memo(user_id, memo_id).await.unwrap();
+    assert_eq!(memo.tags, vec!["rust-lang"]);
+    assert_eq!(
+        semantic_ids(&qdrant_repo, user_id, "rust-lang").await,
+        vec![memo_id]
+    );
+    assert!(semantic_ids(&qdrant_repo, user_id, "rust").await.is_empty());
+
+    assert!(matches!(
+        service
+            .rename_tag(
+                user_id,
+                rust.id,
+                RenameTagRequest {
+                    name: "go".to_string(),
+                },
+            )
+            .await,
+        Err(ServiceError::TagAlreadyExists)
+    ));
+    assert!(matches!(
+        service
+            .rename_tag(
+                other_user_id,
+                rust.id,
+                RenameTagRequest {
+                    name: "stolen".to_string(),
+                },
+            )
+            .await,
+        Err(ServiceError::TagNotFound)
+    ));
+}
+
+#[tokio::test]
+async fn test_merge_tags() {
+    let (db, user_id) = setup_test_db().await;
+    let (service, memo_service, qdrant_repo) = create_services(db);
+
+    let both = create_memo(&memo_service, user_id, &["js", "javascript"]).await;
+    let source_only = create_memo(&memo_service, user_id, &["js"]).await;
+    let target_only = create_memo(&memo_service, user_id, &["javascript"]).await;
+    let tags = service.list_tags(user_id).await.unwrap();
+    let js = tags.iter().find(|tag| tag.name == "js").unwrap();
+    let javascript = tags.iter().find(|tag| tag.name == "javascript").unwrap();
+
+    assert!(matches!(
+        service
+            .merge_tag(user_id, js.id, MergeTagRequest { target_id: js.id })
+            .await,
+        Err(ServiceError::Validation(_))
+    ));
+
+    let merged = service
+        .merge_tag(
+            user_id,
+            js.id,
+            MergeTagRequest {
+                target_id: javascript.id,
+            },
+        )
+        .await
+        .unwrap();
+    assert_eq!(merged.id, javascript.id);
+    assert_eq!(merged.memo_count, 3);
+
+    let names: Vec<String> = service
+        .list_tags(user_id)
+        .await
+        .unwrap()
+        .into_iter()
+        .map(|tag| tag.name)
+        .collect();
+    assert_eq!(names, vec!["javascript"]);
+
+    let mut expected = vec![both, source_only, target_only];
+    expected.sort();
+    assert_eq!(
+        semantic_ids(&qdrant_repo, user_id, "javascript").await,
+        expected
+    );
+    assert!(semantic_ids(&qdrant_repo, user_id, "js").await.is_empty());
+}
+
+#[tokio::test]
+async fn test_delete_tag_keeps_memos() {
+    let (db, user_id) = setup_test_db().await;
+    let (_, other_user_id) = setup_test_db().await;
+    let (service, memo_service, qdrant_repo) = create_services(db);
+
+    let memo_id = create_memo(&memo_service, user_id, &["임시"]).await;
+    let tag = service.list_tags(user_id).await.unwrap().remove(0);
+
+    assert!(matches!(
+        service.delete_tag(other_user_id, tag.id).await,
+        Err(ServiceError::TagNotFound)
+    ));
+
+    service.delete_tag(user_id, tag.id).await.unwrap();
+    assert!(service.list_tags(user_id).await.unwrap().is_empty());
+    assert!(semantic_ids(&qdrant_repo, user_id, "임시").await.is_empty());
+
+    let memos = memo_service
+        .list_memos(user_id, MemoListQuery::default())
+        .await
+        .unwrap();
+    assert_eq!(memos.memos.len(), 1);
+    assert_eq!(memos.memos[0].id, memo_id);
+    assert!(memos.memos[0].tags.is_empty());
+}
+
+#[test]
+fn test_validate_tag_names() {
+    assert_eq!(
+        validate_tag_names(&[
+            "#Rust".to_string(),
+            " rust ".to_string(),
+            "독서".to_string()
+        ])
+        .unwrap(),
+        vec!["rust", "독서"]
+    );
+    assert!(validate_tag_names(&["a".repeat(51)]).is_err());
+    assert!(validate_tag_names(&(0..21).map(|i| i.to_string()).collect::<Vec<_>>()).is_err());
+}
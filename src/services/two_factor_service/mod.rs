@@ -0,0 +1,306 @@
+use chrono::{Duration, Utc};
+use rand::{distributions::Alphanumeric, Rng};
+use sea_orm::DatabaseConnection;
+use std::{collections::HashSet, sync::Arc};
+
+use crate::{
+    entities::totp_credential,
+    errors::ServiceError,
+    models::{
+        RecoveryCodesResponse, TotpEnrollmentResponse, TwoFactorChallenge, TwoFactorStatusResponse,
+        UpdateTwoFactorPolicyRequest,
+    },
+    repositories::{RecoveryCodeRepository, TotpCredentialRepository, UserRepository},
+    services::auth_service::hash_token,
+    utils::{jwt::JwtKeys, password, totp},
+};
+
+const CHALLENGE_TOKEN_MINUTES: i64 = 5;
+const RECOVERY_CODE_COUNT: usize = 10;
+const RECOVERY_CODE_LENGTH: usize = 10;
+const RECOVERY_CODE_PREFIX_LENGTH: usize = 2;
+const MAX_FAILED_ATTEMPTS: i32 = 5;
+const LOCKOUT_MINUTES: i64 = 15;
+
+#[derive(Clone)]
+pub struct TwoFactorService {
+    user_repo: UserRepository,
+    totp_repo: TotpCredentialRepository,
+    recovery_repo: RecoveryCodeRepository,
+    jwt_keys: Arc<JwtKeys>,
+}
+
+impl TwoFactorService {
+    pub fn new(db: Arc<DatabaseConnection>, jwt_keys: Arc<JwtKeys>) -> Self {
+        Self {
+            user_repo: UserRepository::new(db.clone()),
+            totp_repo: TotpCredentialRepository::new(db.clone()),
+            recovery_repo: RecoveryCodeRepository::new(db),
+            jwt_keys,
+        }
+    }
+
+    pub async fn status(&self, user_id: i32) -> Result<TwoFactorStatusResponse, ServiceError> {
+        let credential = self.enabled_credential(user_id).await?;
+        let recovery_codes_remaining = match credential {
+            Some(_) => self.recovery_repo.count_unused(user_id).await?,
+            None => 0,
+        };
+
+        Ok(TwoFactorStatusResponse {
+            enabled: credential.is_some(),
+            enforce_on_oauth: credential.is_some_and(|c| c.enforce_on_oauth),
+            recovery_codes_remaining,
+        })
+    }
+
+    pub async fn start_enrollment(
+        &self,
+        user_id: i32,
+    ) -> Result<TotpEnrollmentResponse, ServiceError> {
+        let user = self
+            .user_repo
+            .find_by_id(user_id)
+            .await?
+            .ok_or(ServiceError::UserNotFound)?;
+
+        if let Some(existing) = self.totp_repo.find_by_user_id(user_id).await? {
+            if existing.enabled_at.is_some() {
+                return Err(ServiceError::TwoFactorAlreadyEnabled);
+            }
+            // 활성화되지 않은 이전 등록은 새 비밀 키로 교체한다.
+            self.totp_repo.delete_by_user_id(user_id).await?;
+        }
+
+        let secret = totp::generate_secret();
+        let otpauth_uri = totp::otpauth_uri(&secret, &issuer(), &user.email)
+            .ok_or_else(|| ServiceError::Validation("Cannot build otpauth URI".to_string()))?;
+
+        self.totp_repo.create(user_id, secret.clone()).await?;
+
+        Ok(TotpEnrollmentResponse {
+            secret,
+            otpauth_uri,
+        })
+    }
+
+    pub async fn activate(
+        &self,
+        user_id: i32,
+        code: &str,
+    ) -> Result<RecoveryCodesResponse, ServiceError> {
+        let credential = self
+            .totp_repo
+            .find_by_user_id(user_id)
+            .await?
+            .ok_or(ServiceError::TwoFactorEnrollmentNotStarted)?;
+        if credential.enabled_at.is_some() {
+            return Err(ServiceError::TwoFactorAlreadyEnabled);
+        }
+
+        let step = totp::verify_code(&credential.secret, code, unix_now())
+            .ok_or(ServiceError::InvalidTwoFactorCode)?;
+        self.totp_repo.enable(credential.id, step).await?;
+
+        self.issue_recovery_codes(user_id).await
+    }
+
+    pub async fn disable(&self, user_id: i32, code: &str) -> Result<(), ServiceError> {
+        self.verify_second_factor(user_id, code).await?;
+
+        self.recovery_repo.delete_by_user_id(user_id).await?;
+        self.totp_repo.delete_by_user_id(user_id).await?;
+
+        Ok(())
+    }
+
+    pub async fn regenerate_recovery_codes(
+        &self,
+        user_id: i32,
+        code: &str,
+    ) -> Result<RecoveryCodesResponse, ServiceError> {
+        self.verify_second_factor(user_id, code).await?;
+        self.issue_recovery_codes(user_id).await
+    }
+
+    pub async fn update_policy(
+        &self,
+        user_id: i32,
+        req: UpdateTwoFactorPolicyRequest,
+    ) -> Result<TwoFactorStatusResponse, ServiceError> {
+        let credential = self
+            .enabled_credential(user_id)
+            .await?
+            .ok_or(ServiceError::TwoFactorNotEnabled)?;
+
+        self.totp_repo
+            .set_enforce_on_oauth(credential.id, req.enforce_on_oauth)
+            .await?;
+
+        self.status(user_id).await
+    }
+
+    // 비밀번호 로그인은 2단계 인증이 켜져 있으면 항상, OAuth 로그인은 사용자가 정책으로 선택한 경우에만 요구한다.
+    pub async fn requires_challenge(
+        &self,
+        user_id: i32,
+        is_oauth_login: bool,
+    ) -> Result<bool, ServiceError> {
+        Ok(match self.enabled_credential(user_id).await? {
+            Some(credential) => !is_oauth_login || credential.enforce_on_oauth,
+            None => false,
+        })
+    }
+
+    pub fn issue_challenge(&self, user_id: i32) -> Result<TwoFactorChallenge, ServiceError> {
+        let challenge_token = self
+            .jwt_keys
+            .generate_challenge_token(user_id, CHALLENGE_TOKEN_MINUTES)
+            .map_err(|_| ServiceError::TokenGenerationFailed)?;
+
+        Ok(TwoFactorChallenge {
+            challenge_token,
+            expires_in: CHALLENGE_TOKEN_MINUTES * 60,
+        })
+    }
+
+    pub fn verify_challenge(&self, challenge_token: &str) -> Result<i32, ServiceError> {
+        self.jwt_keys
+            .verify_challenge_token(challenge_token)
+            .ok()
+            .and_then(|claims| claims.sub.parse().ok())
+            .ok_or(ServiceError::InvalidToken)
+    }
+
+    pub async fn verify_second_factor(&self, user_id: i32, code: &str) -> Result<(), ServiceError> {
+        let credential = self
+            .enabled_credential(user_id)
+            .await?
+            .ok_or(ServiceError::TwoFactorNotEnabled)?;
+
+        let now = Utc::now().naive_utc();
+        if credential.failed_attempts >= MAX_FAILED_ATTEMPTS {
+            let locked = credential
+                .last_failed_at
+                .is_some_and(|at| now - at < Duration::minutes(LOCKOUT_MINUTES));
+            if locked {
+                return Err(ServiceError::TooManyTwoFactorAttempts);
+            }
+            self.totp_repo.reset_failures(credential.id).await?;
+        }
+
+        let verified = if is_totp_code(code) {
+            match totp::verify_code(&credential.secret, code, unix_now()) {
+                Some(step) => self.totp_repo.record_success(credential.id, step).await?,
+                None => false,
+            }
+        } else {
+            let code = normalize_recovery_code(code);
+            let candidates = self
+                .recovery_repo
+                .find_unused_candidates(user_id, recovery_code_prefix(&code))
+                .await?;
+
+            // 앞부분은 사용자마다 겹치지 않으므로 argon2 검증은 많아야 한 번만 한다.
+            let mut matched = None;
+            for candidate in candidates {
+                let is_match = match candidate.code_prefix {
+                    Some(_) => {
+                        password::verify_password_async(code.clone(), candidate.code_hash).await
+                    }
+                    // argon2 이전에 발급된 코드는 솔트 없는 SHA-256 해시로 남아 있어, 재발급하기 전까지는 그 형식도 받아 준다.
+                    None => hash_token(&code) == candidate.code_hash,
+                };
+                if is_match {
+                    matched = Some(candidate.id);
+                    break;
+                }
+            }
+            let used = match matched {
+                Some(id) => self.recovery_repo.mark_used(id).await?,
+                None => false,
+            };
+            if used {
+                self.totp_repo.reset_failures(credential.id).await?;
+            }
+            used
+        };
+
+        if !verified {
+            self.totp_repo.record_failure(credential.id).await?;
+            return Err(ServiceError::InvalidTwoFactorCode);
+        }
+
+        Ok(())
+    }
+
+    async fn enabled_credential(
+        &self,
+        user_id: i32,
+    ) -> Result<Option<totp_credential::Model>, ServiceError> {
+        Ok(self
+            .totp_repo
+            .find_by_user_id(user_id)
+            .await?
+            .filter(|c| c.enabled_at.is_some()))
+    }
+
+    async fn issue_recovery_codes(
+        &self,
+        user_id: i32,
+    ) -> Result<RecoveryCodesResponse, ServiceError> {
+        // 검증할 때 후보를 하나로 좁힐 수 있도록 앞부분이 겹치지 않게 만든다.
+        let mut prefixes = HashSet::new();
+        let mut recovery_codes = Vec::with_capacity(RECOVERY_CODE_COUNT);
+        while recovery_codes.len() < RECOVERY_CODE_COUNT {
+            let code: String = rand::thread_rng()
+                .sample_iter(&Alphanumeric)
+                .take(RECOVERY_CODE_LENGTH)
+                .map(|c| char::from(c).to_ascii_lowercase())
+                .collect();
+            if prefixes.insert(code[..RECOVERY_CODE_PREFIX_LENGTH].to_string()) {
+                recovery_codes.push(format!("{}-{}", &code[..5], &code[5..]));
+            }
+        }
+
+        // 복구 코드는 비밀번호처럼 코드마다 솔트를 둔 argon2 해시로 저장한다.
+        let mut codes = Vec::with_capacity(RECOVERY_CODE_COUNT);
+        for recovery_code in &recovery_codes {
+            let code = normalize_recovery_code(recovery_code);
+            let code_hash = password::hash_password_async(code.clone())
+                .await
+                .map_err(|_| ServiceError::PasswordHashFailed)?;
+            codes.push((recovery_code_prefix(&code).to_string(), code_hash));
+        }
+        self.recovery_repo.replace_for_user(user_id, codes).await?;
+
+        Ok(RecoveryCodesResponse { recovery_codes })
+    }
+}
+
+fn is_totp_code(code: &str) -> bool {
+    let code = code.trim();
+    code.len() == 6 && code.chars().all(|c| c.is_ascii_digit())
+}
+
+fn normalize_recovery_code(code: &str) -> String {
+    code.chars()
+        .filter(|c| c.is_ascii_alphanumeric())
+        .map(|c| c.to_ascii_lowercase())
+        .collect()
+}
+
+fn recovery_code_prefix(code: &str) -> &str {
+    code.get(..RECOVERY_CODE_PREFIX_LENGTH).unwrap_or(code)
+}
+
+fn issuer() -> String {
+    std::env::var("TOTP_ISSUER").unwrap_or_else(|_| "Lekha".to_string())
+}
+
+fn unix_now() -> u64 {
+    Utc::now().timestamp() as u64
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,238 @@
+use super::*;
+use crate::{
+    db,
+    entities::{recovery_code, user},
+};
+use rand::Rng;
+use sea_orm::*;
+
+async fn setup_test_db() -> Arc<DatabaseConnection> {
+    dotenv::dotenv().ok();
+    let database_url = std::env::var("DATABASE_URL_TEST")
+        .expect("DATABASE_URL_TEST must be set. Run: just setup-test-db");
+    Arc::new(db::create_connection(&database_url).await.unwrap())
+}
+
+async fn setup_service() -> (TwoFactorService, user::Model) {
+    let db = setup_test_db().await;
+
+    let now = Utc::now().naive_utc();
+    let timestamp = now.and_utc().timestamp_micros();
+    let random: u32 = rand::thread_rng().gen();
+    let unique_id = format!("{}_{}", timestamp, random);
+
+    let new_user = user::ActiveModel {
+        id: NotSet,
+        username: Set(format!("test_user_{}", unique_id)),
+        email: Set(format!("test_{}@example.com", unique_id)),
+        password_hash: Set(None),
+        created_at: Set(now),
+        updated_at: Set(now),
+        ..Default::default()
+    };
+    let user = new_user.insert(db.as_ref()).await.unwrap();
+
+    (
+        TwoFactorService::new(db, Arc::new(JwtKeys::generate())),
+        user,
+    )
+}
+
+async fn enable_totp(service: &TwoFactorService, user_id: i32) -> (String, Vec<String>) {
+    let enrollment = service.start_enrollment(user_id).await.unwrap();
+    let code = totp::generate_code(&enrollment.secret, unix_now()).unwrap();
+    let recovery = service.activate(user_id, &code).await.unwrap();
+    (enrollment.secret, recovery.recovery_codes)
+}
+
+#[tokio::test]
+async fn test_enrollment_requires_valid_code() {
+    let (service, user) = setup_service().await;
+
+    assert!(matches!(
+        service.activate(user.id, "123456").await,
+        Err(ServiceError::TwoFactorEnrollmentNotStarted)
+    ));
+
+    let enrollment = service.start_enrollment(user.id).await.unwrap();
+    assert!(enrollment.otpauth_uri.starts_with("otpauth://totp/"));
+    assert!(!service.requires_challenge(user.id, false).await.unwrap());
+
+    assert!(matches!(
+        service.activate(user.id, "not-a-code").await,
+        Err(ServiceError::InvalidTwoFactorCode)
+    ));
+
+    let (_, recovery_codes) = enable_totp(&service, user.id).await;
+    assert_eq!(recovery_codes.len(), RECOVERY_CODE_COUNT);
+
+    let status = service.status(user.id).await.unwrap();
+    assert!(status.enabled);
+    assert!(!status.enforce_on_oauth);
+    assert_eq!(status.recovery_codes_remaining, RECOVERY_CODE_COUNT as u64);
+
+    assert!(matches!(
+        service.start_enrollment(user.id).await,
+        Err(ServiceError::TwoFactorAlreadyEnabled)
+    ));
+}
+
+#[tokio::test]
+async fn test_totp_code_cannot_be_replayed() {
+    let (service, user) = setup_service().await;
+    let (secret, _) = enable_totp(&service, user.id).await;
+
+    // 활성화에 사용한 코드는 같은 시간 구간 안에서 다시 쓸 수 없다.
+    let used = totp::generate_code(&secret, unix_now()).unwrap();
+    assert!(matches!(
+        service.verify_second_factor(user.id, &used).await,
+        Err(ServiceError::InvalidTwoFactorCode)
+    ));
+
+    let next = totp::generate_code(&secret, unix_now() + 30).unwrap();
+    service.verify_second_factor(user.id, &next).await.unwrap();
+}
+
+#[tokio::test]
+async fn test_recovery_code_is_single_use() {
+    let (service, user) = setup_service().await;
+    let (_, recovery_codes) = enable_totp(&service, user.id).await;
+
+    let code = recovery_codes[0].to_uppercase().replace('-', "");
+    service.verify_second_factor(user.id, &code).await.unwrap();
+    assert!(matches!(
+        service
+            .verify_second_factor(user.id, &recovery_codes[0])
+            .await,
+        Err(ServiceError::InvalidTwoFactorCode)
+    ));
+
+    let status = service.status(user.id).await.unwrap();
+    assert_eq!(
+        status.recovery_codes_remaining,
+        RECOVERY_CODE_COUNT as u64 - 1
+    );
+}
+
+#[tokio::test]
+async fn test_recovery_codes_are_stored_with_salted_hashes() {
+    let (service, user) = setup_service().await;
+    let (_, recovery_codes) = enable_totp(&service, user.id).await;
+    let db = setup_test_db().await;
+
+    let stored = recovery_code::Entity::find()
+        .filter(recovery_code::Column::UserId.eq(user.id))
+        .all(db.as_ref())
+        .await
+        .unwrap();
+    assert_eq!(stored.len(), RECOVERY_CODE_COUNT);
+    for recovery_code in &stored {
+        assert!(recovery_code.code_hash.starts_with("$argon2"));
+        assert!(!recovery_codes
+            .iter()
+            .any(|code| hash_token(&normalize_recovery_code(code)) == recovery_code.code_hash));
+    }
+
+    // 앞부분이 겹치지 않아 입력한 코드마다 argon2로 검증할 후보는 하나뿐이다.
+    for code in &recovery_codes {
+        let code = normalize_recovery_code(code);
+        let candidates = service
+            .recovery_repo
+            .find_unused_candidates(user.id, recovery_code_prefix(&code))
+            .await
+            .unwrap();
+        assert_eq!(candidates.len(), 1);
+    }
+
+    // 이전 형식으로 저장된 코드도 재발급 전까지는 쓸 수 있다.
+    let legacy_code = "abcd-2345";
+    recovery_code::ActiveModel {
+        user_id: Set(user.id),
+        code_hash: Set(hash_token(&normalize_recovery_code(legacy_code))),
+        code_prefix: Set(None),
+        used_at: Set(None),
+        created_at: Set(Utc::now().naive_utc()),
+        ..Default::default()
+    }
+    .insert(db.as_ref())
+    .await
+    .unwrap();
+    service
+        .verify_second_factor(user.id, legacy_code)
+        .await
+        .unwrap();
+    assert_eq!(
+        service
+            .status(user.id)
+            .await
+            .unwrap()
+            .recovery_codes_remaining,
+        RECOVERY_CODE_COUNT as u64
+    );
+}
+
+#[tokio::test]
+async fn test_repeated_failures_lock_out_verification() {
+    let (service, user) = setup_service().await;
+    let (_, recovery_codes) = enable_totp(&service, user.id).await;
+
+    for _ in 0..MAX_FAILED_ATTEMPTS {
+        assert!(matches!(
+            service.verify_second_factor(user.id, "wrong-code").await,
+            Err(ServiceError::InvalidTwoFactorCode)
+        ));
+    }
+
+    assert!(matches!(
+        service
+            .verify_second_factor(user.id, &recovery_codes[0])
+            .await,
+        Err(ServiceError::TooManyTwoFactorAttempts)
+    ));
+}
+
+#[tokio::test]
+async fn test_oauth_enforcement_policy() {
+    let (service, user) = setup_service().await;
+
+    assert!(matches!(
+        service
+            .update_policy(
+                user.id,
+                UpdateTwoFactorPolicyRequest {
+                    enforce_on_oauth: true
+                }
+            )
+            .await,
+        Err(ServiceError::TwoFactorNotEnabled)
+    ));
+
+    enable_totp(&service, user.id).await;
+    assert!(service.requires_challenge(user.id, false).await.unwrap());
+    assert!(!service.requires_challenge(user.id, true).await.unwrap());
+
+    let status = service
+        .update_policy(
+            user.id,
+            UpdateTwoFactorPolicyRequest {
+                enforce_on_oauth: true,
+            },
+        )
+        .await
+        .unwrap();
+    assert!(status.enforce_on_oauth);
+    assert!(service.requires_challenge(user.id, true).await.unwrap());
+}
+
+#[tokio::test]
+async fn test_disable_removes_credentials() {
+    let (service, user) = setup_service().await;
+    let (_, recovery_codes) = enable_totp(&service, user.id).await;
+
+    service.disable(user.id, &recovery_codes[1]).await.unwrap();
+
+    let status = service.status(user.id).await.unwrap();
+    assert!(!status.enabled);
+    assert_eq!(status.recovery_codes_remaining, 0);
+    assert!(!service.requires_challenge(user.id, false).await.unwrap());
+}
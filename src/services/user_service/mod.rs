@@ -2,33 +2,63 @@ use sea_orm::DatabaseConnection;
 use std::sync::Arc;
 
 use crate::{
+    clients::OAuthVerifier,
+    entities::{auth_event::AuthEventType, oauth_account::OAuthProvider, user},
     errors::ServiceError,
-    models::{AuthResponse, OAuthLoginRequest, UserResponse},
-    repositories::{OAuthAccountRepository, UserRepository},
-    utils::jwt,
+    models::{
+        AuthResponse, ChangePasswordRequest, ClientInfo, LinkOAuthAccountRequest,
+        LinkedAccountResponse, LoginRequest, LoginResponse, OAuthLoginRequest, RegisterRequest,
+        TwoFactorLoginRequest, UpdateUserRequest, UserResponse,
+    },
+    repositories::{OAuthAccountRepository, QdrantRepo, UserRepository},
+    services::{AuthEventService, AuthService, TwoFactorService},
+    utils::{jwt::JwtKeys, password},
 };
 
+const MAX_USERNAME_LENGTH: usize = 50;
+
 #[derive(Clone)]
 pub struct UserService {
     user_repo: UserRepository,
     oauth_repo: OAuthAccountRepository,
+    qdrant_repo: Arc<dyn QdrantRepo>,
+    oauth_verifier: Arc<dyn OAuthVerifier>,
+    auth_service: AuthService,
+    two_factor_service: TwoFactorService,
+    auth_event_service: AuthEventService,
 }
 
 impl UserService {
-    pub fn new(db: Arc<DatabaseConnection>) -> Self {
+    pub fn new(
+        db: Arc<DatabaseConnection>,
+        qdrant_repo: Arc<dyn QdrantRepo>,
+        oauth_verifier: Arc<dyn OAuthVerifier>,
+        jwt_keys: Arc<JwtKeys>,
+    ) -> Self {
         Self {
             user_repo: UserRepository::new(db.clone()),
-            oauth_repo: OAuthAccountRepository::new(db),
+            oauth_repo: OAuthAccountRepository::new(db.clone()),
+            qdrant_repo,
+            oauth_verifier,
+            auth_service: AuthService::new(db.clone(), jwt_keys.clone()),
+            two_factor_service: TwoFactorService::new(db.clone(), jwt_keys),
+            auth_event_service: AuthEventService::new(db),
         }
     }
 
     pub async fn oauth_login(
         &self,
         req: OAuthLoginRequest,
-    ) -> Result<AuthResponse, ServiceError> {
+        client: ClientInfo,
+    ) -> Result<LoginResponse, ServiceError> {
+        let identity = self
+            .oauth_verifier
+            .verify(&req.provider, &req.credential)
+            .await?;
+
         let user = if let Some(oauth_account) = self
             .oauth_repo
-            .find_by_provider_and_id(&req.provider, &req.provider_user_id)
+            .find_by_provider_and_id(&req.provider, &identity.provider_user_id)
             .await?
         {
             self.user_repo
@@ -36,41 +66,461 @@ impl UserService {
                 .await?
                 .ok_or(ServiceError::UserNotFound)?
         } else {
-            let user = if let Some(existing_user) =
-                self.user_repo.find_by_email(&req.email).await?
-            {
-                existing_user
-            } else {
-                self.user_repo
-                    .create(req.username.clone(), req.email.clone(), None)
-                    .await?
-            };
+            // 이메일만으로 기존 계정에 연결하지 않는다. 기존 계정으로 로그인한 뒤 직접 연결해야 한다.
+            let email = normalize_email(&identity.email);
+            if let Some(existing) = self.user_repo.find_by_email(&email).await? {
+                // 같은 이메일로 다른 제공자 계정이 로그인을 시도한 기록은 계정 탈취 시도를 추적하는 데 쓰인다.
+                self.auth_event_service
+                    .record(
+                        Some(existing.id),
+                        AuthEventType::LoginFailed,
+                        Some(&format!("account_link_required:{}", req.provider)),
+                        &client,
+                    )
+                    .await?;
+                return Err(ServiceError::AccountLinkRequired);
+            }
+
+            let username = self
+                .available_username(identity.name.as_deref(), &email)
+                .await?;
+            let user = self.user_repo.create(username, email, None).await?;
 
             self.oauth_repo
-                .create(user.id, req.provider, req.provider_user_id)
+                .create(user.id, req.provider.clone(), identity.provider_user_id)
+                .await?;
+            self.auth_event_service
+                .record(
+                    Some(user.id),
+                    AuthEventType::OAuthAccountCreated,
+                    Some(&req.provider.to_string()),
+                    &client,
+                )
                 .await?;
 
             user
         };
 
-        let jwt_secret = std::env::var("JWT_SECRET")
-            .map_err(|_| ServiceError::MissingJwtSecret)?;
+        self.finish_login(user, client, Some(&req.provider)).await
+    }
+
+    pub async fn register(
+        &self,
+        req: RegisterRequest,
+        client: ClientInfo,
+    ) -> Result<AuthResponse, ServiceError> {
+        let email = normalize_email(&req.email);
+        if !is_valid_email(&email) {
+            return Err(ServiceError::Validation(
+                "Invalid email address".to_string(),
+            ));
+        }
+
+        let username = validate_username(&req.username)?;
+
+        password::validate_password(&req.password).map_err(ServiceError::Validation)?;
+
+        if self.user_repo.find_by_email(&email).await?.is_some() {
+            return Err(ServiceError::EmailAlreadyExists);
+        }
+        if self.user_repo.find_by_username(&username).await?.is_some() {
+            return Err(ServiceError::UsernameAlreadyExists);
+        }
+
+        let password_hash = password::hash_password_async(req.password)
+            .await
+            .map_err(|_| ServiceError::PasswordHashFailed)?;
+
+        let user = self
+            .user_repo
+            .create(username, email, Some(password_hash))
+            .await?;
 
-        let expiration_hours = std::env::var("JWT_EXPIRATION_HOURS")
-            .unwrap_or_else(|_| "24".to_string())
-            .parse::<i64>()
-            .unwrap_or(24);
+        self.auth_service.issue_tokens(user, client).await
+    }
+
+    pub async fn login(
+        &self,
+        req: LoginRequest,
+        client: ClientInfo,
+    ) -> Result<LoginResponse, ServiceError> {
+        let email = normalize_email(&req.email);
+        let user = self.user_repo.find_by_email(&email).await?;
 
-        let access_token = jwt::generate_token(user.id, &jwt_secret, expiration_hours)
-            .map_err(|_| ServiceError::TokenGenerationFailed)?;
+        let password_hash = user.as_ref().and_then(|u| u.password_hash.clone());
+        if !password::verify_password_or_dummy_async(req.password, password_hash).await {
+            match &user {
+                Some(user) => {
+                    self.auth_event_service
+                        .record(
+                            Some(user.id),
+                            AuthEventType::LoginFailed,
+                            Some("invalid_credentials"),
+                            &client,
+                        )
+                        .await?
+                }
+                None => {
+                    self.auth_event_service
+                        .record_attempt(
+                            &email,
+                            AuthEventType::LoginFailed,
+                            Some("invalid_credentials"),
+                            &client,
+                        )
+                        .await?
+                }
+            }
+            return Err(ServiceError::InvalidCredentials);
+        }
 
-        let expires_in = expiration_hours * 3600;
+        let user = user.ok_or(ServiceError::InvalidCredentials)?;
+        self.finish_login(user, client, None).await
+    }
+
+    pub async fn complete_two_factor_login(
+        &self,
+        req: TwoFactorLoginRequest,
+        client: ClientInfo,
+    ) -> Result<AuthResponse, ServiceError> {
+        let user_id = self
+            .two_factor_service
+            .verify_challenge(&req.challenge_token)?;
+        if let Err(e) = self
+            .two_factor_service
+            .verify_second_factor(user_id, &req.code)
+            .await
+        {
+            let reason = match e {
+                ServiceError::InvalidTwoFactorCode => Some("invalid_two_factor_code"),
+                ServiceError::TooManyTwoFactorAttempts => Some("two_factor_locked"),
+                _ => None,
+            };
+            if let Some(reason) = reason {
+                self.auth_event_service
+                    .record(
+                        Some(user_id),
+                        AuthEventType::LoginFailed,
+                        Some(reason),
+                        &client,
+                    )
+                    .await?;
+            }
+            return Err(e);
+        }
 
-        Ok(AuthResponse {
-            user: UserResponse::from(user),
-            access_token,
-            expires_in,
-        })
+        let user = self
+            .user_repo
+            .find_by_id(user_id)
+            .await?
+            .ok_or(ServiceError::InvalidToken)?;
+        let tokens = self.auth_service.issue_tokens(user, client.clone()).await?;
+        self.auth_event_service
+            .record(
+                Some(user_id),
+                AuthEventType::LoginSucceeded,
+                Some("two_factor"),
+                &client,
+            )
+            .await?;
+
+        Ok(tokens)
+    }
+
+    // OAuth 제공자가 없으면 이메일/비밀번호 로그인이다.
+    async fn finish_login(
+        &self,
+        user: user::Model,
+        client: ClientInfo,
+        oauth_provider: Option<&OAuthProvider>,
+    ) -> Result<LoginResponse, ServiceError> {
+        let method = match oauth_provider {
+            Some(provider) => format!("oauth:{}", provider),
+            None => "password".to_string(),
+        };
+
+        if user.suspended_at.is_some() {
+            self.auth_event_service
+                .record(
+                    Some(user.id),
+                    AuthEventType::LoginFailed,
+                    Some(&format!("account_suspended:{}", method)),
+                    &client,
+                )
+                .await?;
+            return Err(ServiceError::AccountSuspended);
+        }
+
+        if self
+            .two_factor_service
+            .requires_challenge(user.id, oauth_provider.is_some())
+            .await?
+        {
+            let challenge = self.two_factor_service.issue_challenge(user.id)?;
+            return Ok(LoginResponse::TwoFactorRequired(challenge));
+        }
+
+        let user_id = user.id;
+        let tokens = self.auth_service.issue_tokens(user, client.clone()).await?;
+        self.auth_event_service
+            .record(
+                Some(user_id),
+                AuthEventType::LoginSucceeded,
+                Some(&method),
+                &client,
+            )
+            .await?;
+
+        Ok(LoginResponse::Authenticated(tokens))
+    }
+
+    pub async fn change_password(
+        &self,
+        user_id: i32,
+        req: ChangePasswordRequest,
+        client: ClientInfo,
+    ) -> Result<(), ServiceError> {
+        let user = self
+            .user_repo
+            .find_by_id(user_id)
+            .await?
+            .ok_or(ServiceError::UserNotFound)?;
+
+        if let Some(current_hash) = user.password_hash {
+            let current_password = req.current_password.unwrap_or_default();
+            if !password::verify_password_async(current_password, current_hash).await {
+                return Err(ServiceError::IncorrectPassword);
+            }
+        }
+
+        password::validate_password(&req.new_password).map_err(ServiceError::Validation)?;
+
+        let password_hash = password::hash_password_async(req.new_password)
+            .await
+            .map_err(|_| ServiceError::PasswordHashFailed)?;
+        self.user_repo
+            .update_password(user_id, password_hash)
+            .await?;
+        self.auth_event_service
+            .record(Some(user_id), AuthEventType::PasswordChanged, None, &client)
+            .await?;
+
+        Ok(())
+    }
+
+    pub async fn get_profile(&self, user_id: i32) -> Result<UserResponse, ServiceError> {
+        let user = self
+            .user_repo
+            .find_by_id(user_id)
+            .await?
+            .ok_or(ServiceError::UserNotFound)?;
+
+        Ok(UserResponse::from(user))
+    }
+
+    pub async fn update_profile(
+        &self,
+        user_id: i32,
+        req: UpdateUserRequest,
+    ) -> Result<UserResponse, ServiceError> {
+        self.user_repo
+            .find_by_id(user_id)
+            .await?
+            .ok_or(ServiceError::UserNotFound)?;
+
+        let username = match req.username {
+            Some(username) => {
+                let username = validate_username(&username)?;
+                if let Some(existing) = self.user_repo.find_by_username(&username).await? {
+                    if existing.id != user_id {
+                        return Err(ServiceError::UsernameAlreadyExists);
+                    }
+                }
+                Some(username)
+            }
+            None => None,
+        };
+
+        let email = match req.email {
+            Some(email) => {
+                let email = normalize_email(&email);
+                if !is_valid_email(&email) {
+                    return Err(ServiceError::Validation(
+                        "Invalid email address".to_string(),
+                    ));
+                }
+                if let Some(existing) = self.user_repo.find_by_email(&email).await? {
+                    if existing.id != user_id {
+                        return Err(ServiceError::EmailAlreadyExists);
+                    }
+                }
+                Some(email)
+            }
+            None => None,
+        };
+
+        let user = self
+            .user_repo
+            .update(user_id, username, email, req.auto_tag_memos)
+            .await?;
+        Ok(UserResponse::from(user))
+    }
+
+    pub async fn delete_account(&self, user_id: i32) -> Result<(), ServiceError> {
+        self.user_repo
+            .find_by_id(user_id)
+            .await?
+            .ok_or(ServiceError::UserNotFound)?;
+
+        // 벡터가 남지 않도록 Qdrant를 먼저 정리한다. 메모, OAuth 계정, 리프레시 토큰은 FK CASCADE로 함께 삭제된다.
+        self.qdrant_repo.delete_user_memos(user_id).await?;
+        self.user_repo.delete(user_id).await?;
+
+        Ok(())
+    }
+
+    pub async fn list_oauth_accounts(
+        &self,
+        user_id: i32,
+    ) -> Result<Vec<LinkedAccountResponse>, ServiceError> {
+        let accounts = self.oauth_repo.find_by_user_id(user_id).await?;
+        Ok(accounts
+            .into_iter()
+            .map(LinkedAccountResponse::from)
+            .collect())
+    }
+
+    pub async fn link_oauth_account(
+        &self,
+        user_id: i32,
+        req: LinkOAuthAccountRequest,
+        client: ClientInfo,
+    ) -> Result<LinkedAccountResponse, ServiceError> {
+        let identity = self
+            .oauth_verifier
+            .verify(&req.provider, &req.credential)
+            .await?;
+
+        if let Some(existing) = self
+            .oauth_repo
+            .find_by_provider_and_id(&req.provider, &identity.provider_user_id)
+            .await?
+        {
+            if existing.user_id == user_id {
+                return Ok(LinkedAccountResponse::from(existing));
+            }
+            return Err(ServiceError::OAuthAccountAlreadyLinked);
+        }
+
+        let accounts = self.oauth_repo.find_by_user_id(user_id).await?;
+        if accounts.iter().any(|a| a.provider == req.provider) {
+            return Err(ServiceError::ProviderAlreadyLinked(String::from(
+                req.provider.clone(),
+            )));
+        }
+
+        let account = self
+            .oauth_repo
+            .create(user_id, req.provider.clone(), identity.provider_user_id)
+            .await?;
+        self.auth_event_service
+            .record(
+                Some(user_id),
+                AuthEventType::OAuthAccountLinked,
+                Some(&req.provider.to_string()),
+                &client,
+            )
+            .await?;
+
+        Ok(LinkedAccountResponse::from(account))
+    }
+
+    pub async fn unlink_oauth_account(
+        &self,
+        user_id: i32,
+        provider: OAuthProvider,
+    ) -> Result<(), ServiceError> {
+        let user = self
+            .user_repo
+            .find_by_id(user_id)
+            .await?
+            .ok_or(ServiceError::UserNotFound)?;
+
+        let accounts = self.oauth_repo.find_by_user_id(user_id).await?;
+        let account = accounts
+            .iter()
+            .find(|a| a.provider == provider)
+            .ok_or(ServiceError::OAuthAccountNotFound)?;
+
+        if user.password_hash.is_none() && accounts.len() == 1 {
+            return Err(ServiceError::LastLoginMethod);
+        }
+
+        self.oauth_repo.delete(account.id).await?;
+
+        Ok(())
+    }
+
+    async fn available_username(
+        &self,
+        name: Option<&str>,
+        email: &str,
+    ) -> Result<String, ServiceError> {
+        let base = name
+            .map(str::trim)
+            .filter(|n| !n.is_empty())
+            .unwrap_or_else(|| email.split('@').next().unwrap_or_default())
+            .to_string();
+        let base = if base.is_empty() {
+            "user".to_string()
+        } else {
+            base
+        };
+
+        // 이름이나 이메일 앞부분이 길면 글자 단위로 잘라 접미사를 붙여도 길이 제한을 넘지 않게 한다.
+        let mut candidate = truncate_chars(&base, MAX_USERNAME_LENGTH).to_string();
+        let mut suffix = 1;
+        while self.user_repo.find_by_username(&candidate).await?.is_some() {
+            suffix += 1;
+            let suffix = format!("_{}", suffix);
+            candidate = format!(
+                "{}{}",
+                truncate_chars(&base, MAX_USERNAME_LENGTH - suffix.len()),
+                suffix
+            );
+        }
+
+        Ok(candidate)
+    }
+}
+
+fn normalize_email(email: &str) -> String {
+    email.trim().to_lowercase()
+}
+
+fn truncate_chars(value: &str, max_chars: usize) -> &str {
+    match value.char_indices().nth(max_chars) {
+        Some((index, _)) => &value[..index],
+        None => value,
+    }
+}
+
+fn validate_username(username: &str) -> Result<String, ServiceError> {
+    let username = username.trim();
+    if username.is_empty() || username.chars().count() > MAX_USERNAME_LENGTH {
+        return Err(ServiceError::Validation(
+            "Username must be between 1 and 50 characters".to_string(),
+        ));
+    }
+    Ok(username.to_string())
+}
+
+fn is_valid_email(email: &str) -> bool {
+    match email.split_once('@') {
+        Some((local, domain)) => {
+            !local.is_empty() && domain.contains('.') && !domain.starts_with('.')
+        }
+        None => false,
     }
 }
 
@@ -1,5 +1,13 @@
 use super::*;
-use crate::entities::oauth_account::OAuthProvider;
+use crate::{
+    clients::OAuthClient,
+    entities::oauth_account::OAuthProvider,
+    models::AdminSecurityEventListQuery,
+    repositories::{MemoListFilter, MemoPayload, MemoRepository},
+    test_utils::{MockOAuthServer, MockQdrantRepository},
+};
+use chrono::Utc;
+use rand::Rng;
 
 async fn setup_test_db() -> Arc<DatabaseConnection> {
     dotenv::dotenv().ok();
@@ -8,70 +16,781 @@ async fn setup_test_db() -> Arc<DatabaseConnection> {
     Arc::new(crate::db::create_connection(&database_url).await.unwrap())
 }
 
+async fn setup_service() -> (UserService, MockOAuthServer) {
+    let db = setup_test_db().await;
+    let oauth_server = MockOAuthServer::start().await;
+    let oauth_client = Arc::new(OAuthClient::new(oauth_server.config()));
+    (
+        UserService::new(
+            db,
+            Arc::new(MockQdrantRepository::new()),
+            oauth_client,
+            Arc::new(JwtKeys::generate()),
+        ),
+        oauth_server,
+    )
+}
+
+fn authenticated(response: LoginResponse) -> AuthResponse {
+    match response {
+        LoginResponse::Authenticated(tokens) => tokens,
+        LoginResponse::TwoFactorRequired(_) => panic!("unexpected two-factor challenge"),
+    }
+}
+
+fn generate_unique_id() -> String {
+    let timestamp = Utc::now().timestamp_micros();
+    let random: u32 = rand::thread_rng().gen();
+    format!("{}_{}", timestamp, random)
+}
+
+fn register_request(unique_id: &str) -> RegisterRequest {
+    RegisterRequest {
+        email: format!("Writer_{}@Example.com", unique_id),
+        username: format!("writer_{}", unique_id),
+        password: "correct-horse-battery-42".to_string(),
+    }
+}
+
 #[tokio::test]
 async fn test_oauth_login_new_user() {
-    let db = setup_test_db().await;
-    let service = UserService::new(db);
+    let (service, oauth_server) = setup_service().await;
 
     let req = OAuthLoginRequest {
         provider: OAuthProvider::Google,
-        provider_user_id: "google_123".to_string(),
-        email: "newuser@example.com".to_string(),
-        username: "newuser".to_string(),
+        credential: oauth_server.google_id_token("newuser", "newuser@example.com"),
     };
 
-    let result = service.oauth_login(req).await.unwrap();
+    let result = service
+        .oauth_login(req, ClientInfo::default())
+        .await
+        .map(authenticated)
+        .unwrap();
 
     assert_eq!(result.user.username, "newuser");
     assert_eq!(result.user.email, "newuser@example.com");
-    assert!(result.access_token.len() > 0);
-    assert_eq!(result.expires_in, 86400);
+    assert!(!result.access_token.is_empty());
+    assert_eq!(result.expires_in, 900);
+}
+
+#[tokio::test]
+async fn test_oauth_login_truncates_long_provider_name() {
+    let (service, oauth_server) = setup_service().await;
+    let long_name = format!("{}{}", generate_unique_id(), "아주긴표시이름".repeat(10));
+
+    let mut usernames = Vec::new();
+    for _ in 0..2 {
+        let unique_id = generate_unique_id();
+        let req = OAuthLoginRequest {
+            provider: OAuthProvider::Google,
+            credential: oauth_server.google_id_token(
+                &format!("{}{}", long_name, unique_id),
+                &format!("long_{}@example.com", unique_id),
+            ),
+        };
+        let result = service
+            .oauth_login(req, ClientInfo::default())
+            .await
+            .map(authenticated)
+            .unwrap();
+        usernames.push(result.user.username);
+    }
+
+    let base: String = long_name.chars().take(MAX_USERNAME_LENGTH).collect();
+    assert_eq!(usernames[0], base);
+    assert_eq!(
+        usernames[1],
+        format!("{}_2", base.chars().take(48).collect::<String>())
+    );
+    assert_eq!(usernames[1].chars().count(), MAX_USERNAME_LENGTH);
 }
 
 #[tokio::test]
 async fn test_oauth_login_existing_oauth_account() {
-    let db = setup_test_db().await;
-    let service = UserService::new(db);
+    let (service, oauth_server) = setup_service().await;
 
     let req = OAuthLoginRequest {
         provider: OAuthProvider::Kakao,
-        provider_user_id: "kakao_456".to_string(),
-        email: "existing@example.com".to_string(),
-        username: "existing".to_string(),
+        credential: oauth_server.kakao_access_token("456", "existing@example.com"),
     };
 
-    let first_login = service.oauth_login(req.clone()).await.unwrap();
-    let second_login = service.oauth_login(req).await.unwrap();
+    let first_login = service
+        .oauth_login(req.clone(), ClientInfo::default())
+        .await
+        .map(authenticated)
+        .unwrap();
+    let second_login = service
+        .oauth_login(req, ClientInfo::default())
+        .await
+        .map(authenticated)
+        .unwrap();
 
     assert_eq!(first_login.user.id, second_login.user.id);
     assert_eq!(first_login.user.email, second_login.user.email);
-    assert!(first_login.access_token.len() > 0);
-    assert!(second_login.access_token.len() > 0);
+    assert!(!first_login.access_token.is_empty());
+    assert!(!second_login.access_token.is_empty());
 }
 
 #[tokio::test]
-async fn test_oauth_login_different_provider_same_email() {
-    let db = setup_test_db().await;
-    let service = UserService::new(db);
+async fn test_oauth_login_with_oidc_provider() {
+    let (service, oauth_server) = setup_service().await;
+    let unique_id = generate_unique_id();
+
+    let req = OAuthLoginRequest {
+        provider: OAuthProvider::Oidc("company".to_string()),
+        credential: oauth_server.oidc_id_token(
+            &format!("employee_{}", unique_id),
+            &format!("employee_{}@company.example", unique_id),
+        ),
+    };
+
+    let first_login = service
+        .oauth_login(req.clone(), ClientInfo::default())
+        .await
+        .map(authenticated)
+        .unwrap();
+    let second_login = service
+        .oauth_login(req, ClientInfo::default())
+        .await
+        .map(authenticated)
+        .unwrap();
+    assert_eq!(first_login.user.id, second_login.user.id);
+
+    let accounts = service
+        .list_oauth_accounts(first_login.user.id)
+        .await
+        .unwrap();
+    assert_eq!(accounts.len(), 1);
+    assert_eq!(
+        accounts[0].provider,
+        OAuthProvider::Oidc("company".to_string())
+    );
+}
+
+#[tokio::test]
+async fn test_oauth_login_does_not_merge_by_email() {
+    let (service, oauth_server) = setup_service().await;
+    let unique_id = generate_unique_id();
+    let email = format!("multiauth_{}@example.com", unique_id);
 
     let google_req = OAuthLoginRequest {
         provider: OAuthProvider::Google,
-        provider_user_id: "google_789".to_string(),
-        email: "multiauth@example.com".to_string(),
-        username: "multiauth".to_string(),
+        credential: oauth_server.google_id_token(&format!("google_{}", unique_id), &email),
+    };
+
+    let naver_req = OAuthLoginRequest {
+        provider: OAuthProvider::Naver,
+        credential: oauth_server.naver_access_token(&format!("naver_{}", unique_id), &email),
     };
 
-    let kakao_req = OAuthLoginRequest {
+    service
+        .oauth_login(google_req, ClientInfo::default())
+        .await
+        .unwrap();
+    let naver_login = service.oauth_login(naver_req, ClientInfo::default()).await;
+
+    assert!(matches!(
+        naver_login,
+        Err(ServiceError::AccountLinkRequired)
+    ));
+}
+
+#[tokio::test]
+async fn test_oauth_login_rejects_unverified_credential() {
+    let (service, _oauth_server) = setup_service().await;
+
+    let req = OAuthLoginRequest {
         provider: OAuthProvider::Kakao,
-        provider_user_id: "kakao_789".to_string(),
-        email: "multiauth@example.com".to_string(),
-        username: "multiauth".to_string(),
+        credential: "forged-access-token".to_string(),
     };
 
-    let google_login = service.oauth_login(google_req).await.unwrap();
-    let kakao_login = service.oauth_login(kakao_req).await.unwrap();
+    let result = service.oauth_login(req, ClientInfo::default()).await;
+    assert!(matches!(
+        result,
+        Err(ServiceError::InvalidOAuthCredential(_))
+    ));
+}
+
+#[tokio::test]
+async fn test_register_and_login() {
+    let (service, _oauth_server) = setup_service().await;
+    let unique_id = generate_unique_id();
+
+    let registered = service
+        .register(register_request(&unique_id), ClientInfo::default())
+        .await
+        .unwrap();
+    assert_eq!(
+        registered.user.email,
+        format!("writer_{}@example.com", unique_id)
+    );
+
+    let logged_in = service
+        .login(
+            LoginRequest {
+                email: format!("WRITER_{}@example.com", unique_id),
+                password: "correct-horse-battery-42".to_string(),
+            },
+            ClientInfo::default(),
+        )
+        .await
+        .map(authenticated)
+        .unwrap();
+    assert_eq!(logged_in.user.id, registered.user.id);
+    assert!(!logged_in.access_token.is_empty());
+}
+
+#[tokio::test]
+async fn test_register_rejects_duplicates_and_weak_passwords() {
+    let (service, _oauth_server) = setup_service().await;
+    let unique_id = generate_unique_id();
+
+    service
+        .register(register_request(&unique_id), ClientInfo::default())
+        .await
+        .unwrap();
+
+    let duplicate_email = service
+        .register(register_request(&unique_id), ClientInfo::default())
+        .await;
+    assert!(matches!(
+        duplicate_email,
+        Err(ServiceError::EmailAlreadyExists)
+    ));
+
+    let duplicate_username = service
+        .register(
+            RegisterRequest {
+                email: format!("other_{}@example.com", unique_id),
+                ..register_request(&unique_id)
+            },
+            ClientInfo::default(),
+        )
+        .await;
+    assert!(matches!(
+        duplicate_username,
+        Err(ServiceError::UsernameAlreadyExists)
+    ));
+
+    let weak_password = service
+        .register(
+            RegisterRequest {
+                password: "short".to_string(),
+                ..register_request(&generate_unique_id())
+            },
+            ClientInfo::default(),
+        )
+        .await;
+    assert!(matches!(weak_password, Err(ServiceError::Validation(_))));
+}
+
+#[tokio::test]
+async fn test_login_failures_are_indistinguishable() {
+    let (service, _oauth_server) = setup_service().await;
+    let unique_id = generate_unique_id();
+    let registered = service
+        .register(register_request(&unique_id), ClientInfo::default())
+        .await
+        .unwrap();
+
+    let wrong_password = service
+        .login(
+            LoginRequest {
+                email: registered.user.email.clone(),
+                password: "wrong-horse-battery-42".to_string(),
+            },
+            ClientInfo::default(),
+        )
+        .await;
+    let unknown_email = service
+        .login(
+            LoginRequest {
+                email: format!("nobody_{}@example.com", unique_id),
+                password: "correct-horse-battery-42".to_string(),
+            },
+            ClientInfo::default(),
+        )
+        .await;
+
+    assert!(matches!(
+        wrong_password,
+        Err(ServiceError::InvalidCredentials)
+    ));
+    assert!(matches!(
+        unknown_email,
+        Err(ServiceError::InvalidCredentials)
+    ));
+}
+
+#[tokio::test]
+async fn test_login_records_security_events() {
+    let (service, _oauth_server) = setup_service().await;
+    let events = AuthEventService::new(setup_test_db().await);
+    let unique_id = generate_unique_id();
+    let registered = service
+        .register(register_request(&unique_id), ClientInfo::default())
+        .await
+        .unwrap();
+    let client = ClientInfo {
+        user_agent: Some("audit-test".to_string()),
+        ip_address: Some("203.0.113.7".to_string()),
+    };
+
+    let failed = service
+        .login(
+            LoginRequest {
+                email: registered.user.email.clone(),
+                password: "wrong-horse-battery-42".to_string(),
+            },
+            client.clone(),
+        )
+        .await;
+    assert!(matches!(failed, Err(ServiceError::InvalidCredentials)));
+    authenticated(
+        service
+            .login(
+                LoginRequest {
+                    email: registered.user.email.clone(),
+                    password: "correct-horse-battery-42".to_string(),
+                },
+                client,
+            )
+            .await
+            .unwrap(),
+    );
+
+    let recorded = events
+        .list_for_user(registered.user.id, Default::default())
+        .await
+        .unwrap();
+    assert_eq!(recorded.total, 2);
+    assert_eq!(recorded.events[0].event_type, AuthEventType::LoginSucceeded);
+    assert_eq!(recorded.events[0].detail.as_deref(), Some("password"));
+    assert_eq!(recorded.events[1].event_type, AuthEventType::LoginFailed);
+    assert_eq!(
+        recorded.events[1].ip_address.as_deref(),
+        Some("203.0.113.7")
+    );
+    assert_eq!(recorded.events[1].user_agent.as_deref(), Some("audit-test"));
+
+    // 존재하지 않는 계정으로 시도하면 시도한 이메일이 남는다.
+    let unknown_email = format!("nobody_{}@example.com", unique_id);
+    let failed = service
+        .login(
+            LoginRequest {
+                email: unknown_email.to_uppercase(),
+                password: "wrong-horse-battery-42".to_string(),
+            },
+            ClientInfo::default(),
+        )
+        .await;
+    assert!(matches!(failed, Err(ServiceError::InvalidCredentials)));
+    let recorded = events
+        .list_all(AdminSecurityEventListQuery {
+            email: Some(unknown_email.clone()),
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+    assert_eq!(recorded.total, 1);
+    assert_eq!(recorded.events[0].user_id, None);
+    assert_eq!(recorded.events[0].email, Some(unknown_email));
+}
+
+#[tokio::test]
+async fn test_change_password() {
+    let (service, _oauth_server) = setup_service().await;
+    let unique_id = generate_unique_id();
+    let registered = service
+        .register(register_request(&unique_id), ClientInfo::default())
+        .await
+        .unwrap();
+
+    let wrong_current = service
+        .change_password(
+            registered.user.id,
+            ChangePasswordRequest {
+                current_password: Some("not-my-password-1".to_string()),
+                new_password: "brand-new-password-7".to_string(),
+            },
+            ClientInfo::default(),
+        )
+        .await;
+    assert!(matches!(
+        wrong_current,
+        Err(ServiceError::IncorrectPassword)
+    ));
+
+    service
+        .change_password(
+            registered.user.id,
+            ChangePasswordRequest {
+                current_password: Some("correct-horse-battery-42".to_string()),
+                new_password: "brand-new-password-7".to_string(),
+            },
+            ClientInfo::default(),
+        )
+        .await
+        .unwrap();
+
+    let old_login = service
+        .login(
+            LoginRequest {
+                email: registered.user.email.clone(),
+                password: "correct-horse-battery-42".to_string(),
+            },
+            ClientInfo::default(),
+        )
+        .await;
+    assert!(matches!(old_login, Err(ServiceError::InvalidCredentials)));
+
+    let new_login = service
+        .login(
+            LoginRequest {
+                email: registered.user.email,
+                password: "brand-new-password-7".to_string(),
+            },
+            ClientInfo::default(),
+        )
+        .await;
+    assert!(new_login.is_ok());
+}
+
+#[tokio::test]
+async fn test_get_and_update_profile() {
+    let (service, _oauth_server) = setup_service().await;
+    let unique_id = generate_unique_id();
+    let registered = service
+        .register(register_request(&unique_id), ClientInfo::default())
+        .await
+        .unwrap();
+    let other = service
+        .register(
+            register_request(&generate_unique_id()),
+            ClientInfo::default(),
+        )
+        .await
+        .unwrap();
+
+    let profile = service.get_profile(registered.user.id).await.unwrap();
+    assert_eq!(profile, registered.user);
+
+    let updated = service
+        .update_profile(
+            registered.user.id,
+            UpdateUserRequest {
+                username: Some(format!("renamed_{}", unique_id)),
+                email: Some(format!("  Renamed_{}@Example.com ", unique_id)),
+                auto_tag_memos: Some(true),
+            },
+        )
+        .await
+        .unwrap();
+    assert!(updated.auto_tag_memos);
+    assert_eq!(updated.username, format!("renamed_{}", unique_id));
+    assert_eq!(updated.email, format!("renamed_{}@example.com", unique_id));
+
+    let unchanged = service
+        .update_profile(
+            registered.user.id,
+            UpdateUserRequest {
+                username: Some(updated.username.clone()),
+                email: None,
+                auto_tag_memos: None,
+            },
+        )
+        .await
+        .unwrap();
+    assert_eq!(unchanged.email, updated.email);
+    assert!(unchanged.auto_tag_memos);
+
+    let email_taken = service
+        .update_profile(
+            registered.user.id,
+            UpdateUserRequest {
+                username: None,
+                email: Some(other.user.email.clone()),
+                auto_tag_memos: None,
+            },
+        )
+        .await;
+    assert!(matches!(email_taken, Err(ServiceError::EmailAlreadyExists)));
+
+    let username_taken = service
+        .update_profile(
+            registered.user.id,
+            UpdateUserRequest {
+                username: Some(other.user.username.clone()),
+                email: None,
+                auto_tag_memos: None,
+            },
+        )
+        .await;
+    assert!(matches!(
+        username_taken,
+        Err(ServiceError::UsernameAlreadyExists)
+    ));
+}
+
+#[tokio::test]
+async fn test_delete_account_cascades() {
+    let db = setup_test_db().await;
+    let qdrant_repo = Arc::new(MockQdrantRepository::new());
+    let oauth_server = MockOAuthServer::start().await;
+    let service = UserService::new(
+        db.clone(),
+        qdrant_repo.clone(),
+        Arc::new(OAuthClient::new(oauth_server.config())),
+        Arc::new(JwtKeys::generate()),
+    );
+    let memo_repo = MemoRepository::new(db);
+
+    let unique_id = generate_unique_id();
+    let login = service
+        .oauth_login(
+            OAuthLoginRequest {
+                provider: OAuthProvider::Google,
+                credential: oauth_server.google_id_token(
+                    &format!("delete_{}", unique_id),
+                    &format!("delete_{}@example.com", unique_id),
+                ),
+            },
+            ClientInfo::default(),
+        )
+        .await
+        .map(authenticated)
+        .unwrap();
+    let user_id = login.user.id;
+
+    let memo = memo_repo
+        .create(user_id, "사라질 메모".to_string(), None)
+        .await
+        .unwrap();
+    qdrant_repo
+        .upsert_memo(MemoPayload::new(&memo, Vec::new()), vec![0.1; 768])
+        .await
+        .unwrap();
+
+    service.delete_account(user_id).await.unwrap();
+
+    assert!(matches!(
+        service.get_profile(user_id).await,
+        Err(ServiceError::UserNotFound)
+    ));
+    assert!(memo_repo.find_by_id(memo.id).await.unwrap().is_none());
+    assert!(qdrant_repo
+        .search_similar(user_id, vec![0.1; 768], &MemoListFilter::default(), 10)
+        .await
+        .unwrap()
+        .is_empty());
+    assert!(service
+        .oauth_repo
+        .find_by_provider_and_id(&OAuthProvider::Google, &format!("delete_{}", unique_id))
+        .await
+        .unwrap()
+        .is_none());
+}
+
+#[tokio::test]
+async fn test_link_and_unlink_oauth_accounts() {
+    let (service, oauth_server) = setup_service().await;
+    let unique_id = generate_unique_id();
+    let google_id = format!("google_{}", unique_id);
+    let naver_id = format!("naver_{}", unique_id);
+
+    let google_login = service
+        .oauth_login(
+            OAuthLoginRequest {
+                provider: OAuthProvider::Google,
+                credential: oauth_server
+                    .google_id_token(&google_id, &format!("link_{}@example.com", unique_id)),
+            },
+            ClientInfo::default(),
+        )
+        .await
+        .map(authenticated)
+        .unwrap();
+    let user_id = google_login.user.id;
+
+    let naver_credential =
+        oauth_server.naver_access_token(&naver_id, &format!("other_{}@example.com", unique_id));
+    let linked = service
+        .link_oauth_account(
+            user_id,
+            LinkOAuthAccountRequest {
+                provider: OAuthProvider::Naver,
+                credential: naver_credential.clone(),
+            },
+            ClientInfo::default(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(linked.provider, OAuthProvider::Naver);
+
+    let naver_login = service
+        .oauth_login(
+            OAuthLoginRequest {
+                provider: OAuthProvider::Naver,
+                credential: naver_credential,
+            },
+            ClientInfo::default(),
+        )
+        .await
+        .map(authenticated)
+        .unwrap();
+    assert_eq!(naver_login.user.id, user_id);
+
+    let second_google = service
+        .link_oauth_account(
+            user_id,
+            LinkOAuthAccountRequest {
+                provider: OAuthProvider::Google,
+                credential: oauth_server.google_id_token(
+                    &format!("google2_{}", unique_id),
+                    &format!("link2_{}@example.com", unique_id),
+                ),
+            },
+            ClientInfo::default(),
+        )
+        .await;
+    assert!(matches!(
+        second_google,
+        Err(ServiceError::ProviderAlreadyLinked(_))
+    ));
+
+    let accounts = service.list_oauth_accounts(user_id).await.unwrap();
+    assert_eq!(accounts.len(), 2);
+
+    service
+        .unlink_oauth_account(user_id, OAuthProvider::Google)
+        .await
+        .unwrap();
+    let last_method = service
+        .unlink_oauth_account(user_id, OAuthProvider::Naver)
+        .await;
+    assert!(matches!(last_method, Err(ServiceError::LastLoginMethod)));
+
+    let not_linked = service
+        .unlink_oauth_account(user_id, OAuthProvider::Google)
+        .await;
+    assert!(matches!(
+        not_linked,
+        Err(ServiceError::OAuthAccountNotFound)
+    ));
+}
+
+#[tokio::test]
+async fn test_link_rejects_account_owned_by_another_user() {
+    let (service, oauth_server) = setup_service().await;
+    let unique_id = generate_unique_id();
+    let kakao_id = format!("{}", rand::thread_rng().gen::<u32>());
+    let kakao_credential =
+        oauth_server.kakao_access_token(&kakao_id, &format!("owner_{}@example.com", unique_id));
+
+    service
+        .oauth_login(
+            OAuthLoginRequest {
+                provider: OAuthProvider::Kakao,
+                credential: kakao_credential.clone(),
+            },
+            ClientInfo::default(),
+        )
+        .await
+        .unwrap();
+    let registered = service
+        .register(register_request(&unique_id), ClientInfo::default())
+        .await
+        .unwrap();
+
+    let result = service
+        .link_oauth_account(
+            registered.user.id,
+            LinkOAuthAccountRequest {
+                provider: OAuthProvider::Kakao,
+                credential: kakao_credential,
+            },
+            ClientInfo::default(),
+        )
+        .await;
+    assert!(matches!(
+        result,
+        Err(ServiceError::OAuthAccountAlreadyLinked)
+    ));
+
+    // 비밀번호가 있는 계정은 OAuth 연결이 없어도 로그인할 수 있다.
+    assert!(service
+        .list_oauth_accounts(registered.user.id)
+        .await
+        .unwrap()
+        .is_empty());
+}
+
+#[tokio::test]
+async fn test_login_with_two_factor_challenge() {
+    let db = setup_test_db().await;
+    let oauth_server = MockOAuthServer::start().await;
+    let jwt_keys = Arc::new(JwtKeys::generate());
+    let service = UserService::new(
+        db.clone(),
+        Arc::new(MockQdrantRepository::new()),
+        Arc::new(OAuthClient::new(oauth_server.config())),
+        jwt_keys.clone(),
+    );
+    let two_factor_service = TwoFactorService::new(db, jwt_keys.clone());
+
+    let unique_id = generate_unique_id();
+    let registered = service
+        .register(register_request(&unique_id), ClientInfo::default())
+        .await
+        .unwrap();
+    let user_id = registered.user.id;
+
+    let secret = two_factor_service
+        .start_enrollment(user_id)
+        .await
+        .unwrap()
+        .secret;
+    let code = crate::utils::totp::generate_code(&secret, Utc::now().timestamp() as u64).unwrap();
+    let recovery = two_factor_service.activate(user_id, &code).await.unwrap();
+
+    let login = service
+        .login(
+            LoginRequest {
+                email: registered.user.email.clone(),
+                password: "correct-horse-battery-42".to_string(),
+            },
+            ClientInfo::default(),
+        )
+        .await
+        .unwrap();
+    let challenge = match login {
+        LoginResponse::TwoFactorRequired(challenge) => challenge,
+        LoginResponse::Authenticated(_) => panic!("expected two-factor challenge"),
+    };
+
+    // 챌린지 토큰은 액세스 토큰으로 사용할 수 없다.
+    assert!(jwt_keys.verify_token(&challenge.challenge_token).is_err());
+
+    let wrong = service
+        .complete_two_factor_login(
+            TwoFactorLoginRequest {
+                challenge_token: challenge.challenge_token.clone(),
+                code: "wrong-code".to_string(),
+            },
+            ClientInfo::default(),
+        )
+        .await;
+    assert!(matches!(wrong, Err(ServiceError::InvalidTwoFactorCode)));
 
-    assert_eq!(google_login.user.id, kakao_login.user.id);
-    assert!(google_login.access_token.len() > 0);
-    assert!(kakao_login.access_token.len() > 0);
+    let completed = service
+        .complete_two_factor_login(
+            TwoFactorLoginRequest {
+                challenge_token: challenge.challenge_token,
+                code: recovery.recovery_codes[0].clone(),
+            },
+            ClientInfo::default(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(completed.user.id, user_id);
 }
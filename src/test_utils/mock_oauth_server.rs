@@ -0,0 +1,298 @@
+use axum::{
+    extract::{Path, State},
+    http::{HeaderMap, StatusCode},
+    response::IntoResponse,
+    routing::get,
+    Json, Router,
+};
+use chrono::{Duration, Utc};
+use jsonwebtoken::{encode, Algorithm, EncodingKey, Header};
+use serde_json::json;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+
+use crate::clients::{OAuthConfig, OidcProviderConfig, VerifiedIdentity};
+
+const RSA_PRIVATE_KEY: &str = include_str!("fixtures/oauth_rsa_key.pem");
+const RSA_MODULUS: &str = "mbqz80os3dxfpkZcU6VvuX0zH1QaTzu_n82WXHG8c49qN5OOCX45zcbPOV60DXy-BEG_eoWYxu4lbUakSNwZa9Bn-JrNhy1j1wTCaRdNlaXd93gZ4FuWyPukqjvIJVeW_8Z_Xn4JN7B-NotU-99HG29V84kTb-2WePSe__q1iF1nxIX6lwKhsUcqS1emP9-Pt-AbKg1TdTK32RtHBeCbvsFs8hxcXJ0BAwF97CGG7axL6WwIHK8ZqdOeipiPhaEbhNMGkHnjHrPxkDSSQSh6KPhUP_L8gD0BOQVpcg-zILbNPDusC0fUsZadEbg719IiS9uIcC1_9E8dGEwXIZpwzw";
+const RSA_EXPONENT: &str = "AQAB";
+const KEY_ID: &str = "mock-key-1";
+
+pub const MOCK_GOOGLE_CLIENT_ID: &str = "mock-google-client-id";
+pub const MOCK_GOOGLE_ISSUER: &str = "https://accounts.google.com";
+pub const MOCK_OIDC_PROVIDER: &str = "company";
+pub const MOCK_OIDC_CLIENT_ID: &str = "mock-oidc-client-id";
+pub const MOCK_KAKAO_APP_ID: &str = "100001";
+pub const MOCK_NAVER_CLIENT_ID: &str = "mock-naver-client-id";
+
+// 액세스 토큰마다 사용자 정보와 토큰을 발급받은 앱 ID를 함께 보관한다.
+type TokenMap = Arc<Mutex<HashMap<String, (VerifiedIdentity, String)>>>;
+
+#[derive(Clone, Default)]
+struct MockState {
+    base_url: String,
+    kakao_tokens: TokenMap,
+    naver_tokens: TokenMap,
+    jwks_fetches: Arc<AtomicUsize>,
+}
+
+pub struct MockOAuthServer {
+    base_url: String,
+    state: MockState,
+    encoding_key: EncodingKey,
+}
+
+impl MockOAuthServer {
+    pub async fn start() -> Self {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let state = MockState {
+            base_url: format!("http://{}", addr),
+            ..Default::default()
+        };
+
+        // OIDC 발급자는 `/oidc/:name` 아래에서 디스커버리 문서와 JWKS를 제공한다.
+        let app = Router::new()
+            .route("/google/jwks", get(jwks))
+            .route(
+                "/oidc/:name/.well-known/openid-configuration",
+                get(oidc_configuration),
+            )
+            .route("/oidc/:name/jwks", get(jwks))
+            .route("/kakao/v1/user/access_token_info", get(kakao_token_info))
+            .route("/kakao/v2/user/me", get(kakao_me))
+            .route("/naver/v1/nid/verify", get(naver_token_info))
+            .route("/naver/v1/nid/me", get(naver_me))
+            .with_state(state.clone());
+
+        tokio::spawn(async move {
+            axum::serve(listener, app).await.unwrap();
+        });
+
+        Self {
+            base_url: state.base_url.clone(),
+            state,
+            encoding_key: EncodingKey::from_rsa_pem(RSA_PRIVATE_KEY.as_bytes()).unwrap(),
+        }
+    }
+
+    pub fn config(&self) -> OAuthConfig {
+        OAuthConfig {
+            google_client_ids: vec![MOCK_GOOGLE_CLIENT_ID.to_string()],
+            google_jwks_url: format!("{}/google/jwks", self.base_url),
+            google_issuers: vec![MOCK_GOOGLE_ISSUER.to_string()],
+            kakao_app_ids: vec![MOCK_KAKAO_APP_ID.to_string()],
+            kakao_userinfo_url: format!("{}/kakao/v2/user/me", self.base_url),
+            kakao_token_info_url: format!("{}/kakao/v1/user/access_token_info", self.base_url),
+            naver_client_ids: vec![MOCK_NAVER_CLIENT_ID.to_string()],
+            naver_userinfo_url: format!("{}/naver/v1/nid/me", self.base_url),
+            naver_token_info_url: format!("{}/naver/v1/nid/verify", self.base_url),
+            oidc_providers: vec![OidcProviderConfig {
+                name: MOCK_OIDC_PROVIDER.to_string(),
+                issuer: self.oidc_issuer(),
+                client_ids: vec![MOCK_OIDC_CLIENT_ID.to_string()],
+            }],
+        }
+    }
+
+    /// Google과 OIDC 발급자를 합쳐 JWKS가 요청된 횟수
+    pub fn jwks_fetch_count(&self) -> usize {
+        self.state.jwks_fetches.load(Ordering::SeqCst)
+    }
+
+    pub fn oidc_issuer(&self) -> String {
+        format!("{}/oidc/{}", self.base_url, MOCK_OIDC_PROVIDER)
+    }
+
+    pub fn sign_google_id_token(&self, claims: serde_json::Value) -> String {
+        self.sign_id_token(claims)
+    }
+
+    pub fn sign_id_token(&self, claims: serde_json::Value) -> String {
+        self.sign_id_token_with_kid(claims, KEY_ID)
+    }
+
+    pub fn sign_id_token_with_kid(&self, claims: serde_json::Value, kid: &str) -> String {
+        let mut header = Header::new(Algorithm::RS256);
+        header.kid = Some(kid.to_string());
+        encode(&header, &claims, &self.encoding_key).unwrap()
+    }
+
+    pub fn oidc_id_token(&self, sub: &str, email: &str) -> String {
+        let now = Utc::now();
+        self.sign_id_token(json!({
+            "iss": self.oidc_issuer(),
+            "aud": MOCK_OIDC_CLIENT_ID,
+            "sub": sub,
+            "email": email,
+            "email_verified": true,
+            "preferred_username": sub,
+            "iat": now.timestamp(),
+            "exp": (now + Duration::hours(1)).timestamp(),
+        }))
+    }
+
+    pub fn google_id_token(&self, sub: &str, email: &str) -> String {
+        let now = Utc::now();
+        self.sign_google_id_token(json!({
+            "iss": MOCK_GOOGLE_ISSUER,
+            "aud": MOCK_GOOGLE_CLIENT_ID,
+            "sub": sub,
+            "email": email,
+            "email_verified": true,
+            "name": sub,
+            "iat": now.timestamp(),
+            "exp": (now + Duration::hours(1)).timestamp(),
+        }))
+    }
+
+    pub fn kakao_access_token(&self, id: &str, email: &str) -> String {
+        self.kakao_access_token_for_app(id, email, MOCK_KAKAO_APP_ID)
+    }
+
+    pub fn kakao_access_token_for_app(&self, id: &str, email: &str, app_id: &str) -> String {
+        issue_access_token(&self.state.kakao_tokens, "kakao", id, email, app_id)
+    }
+
+    pub fn naver_access_token(&self, id: &str, email: &str) -> String {
+        self.naver_access_token_for_app(id, email, MOCK_NAVER_CLIENT_ID)
+    }
+
+    pub fn naver_access_token_for_app(&self, id: &str, email: &str, client_id: &str) -> String {
+        issue_access_token(&self.state.naver_tokens, "naver", id, email, client_id)
+    }
+}
+
+fn issue_access_token(
+    tokens: &TokenMap,
+    prefix: &str,
+    id: &str,
+    email: &str,
+    app_id: &str,
+) -> String {
+    let token = format!("{}-token-{}-{}", prefix, app_id, id);
+    tokens.lock().unwrap().insert(
+        token.clone(),
+        (
+            VerifiedIdentity {
+                provider_user_id: id.to_string(),
+                email: email.to_string(),
+                name: Some(id.to_string()),
+            },
+            app_id.to_string(),
+        ),
+    );
+    token
+}
+
+fn lookup_bearer(headers: &HeaderMap, tokens: &TokenMap) -> Option<(VerifiedIdentity, String)> {
+    let token = headers
+        .get("Authorization")
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| v.strip_prefix("Bearer "))?;
+    tokens.lock().unwrap().get(token).cloned()
+}
+
+async fn jwks(State(state): State<MockState>) -> impl IntoResponse {
+    state.jwks_fetches.fetch_add(1, Ordering::SeqCst);
+    Json(json!({
+        "keys": [{
+            "kty": "RSA",
+            "use": "sig",
+            "alg": "RS256",
+            "kid": KEY_ID,
+            "n": RSA_MODULUS,
+            "e": RSA_EXPONENT,
+        }]
+    }))
+}
+
+async fn oidc_configuration(
+    State(state): State<MockState>,
+    Path(name): Path<String>,
+) -> impl IntoResponse {
+    let issuer = format!("{}/oidc/{}", state.base_url, name);
+    Json(json!({
+        "issuer": issuer,
+        "jwks_uri": format!("{}/jwks", issuer),
+        "id_token_signing_alg_values_supported": ["RS256"],
+        "response_types_supported": ["code"],
+        "subject_types_supported": ["public"],
+    }))
+}
+
+async fn kakao_token_info(State(state): State<MockState>, headers: HeaderMap) -> impl IntoResponse {
+    match lookup_bearer(&headers, &state.kakao_tokens) {
+        Some((identity, app_id)) => (
+            StatusCode::OK,
+            Json(json!({
+                "id": identity.provider_user_id.parse::<i64>().unwrap_or_default(),
+                "expires_in": 3600,
+                "app_id": app_id.parse::<i64>().unwrap_or_default(),
+            })),
+        ),
+        None => (
+            StatusCode::UNAUTHORIZED,
+            Json(json!({ "code": -401, "msg": "this access token does not exist" })),
+        ),
+    }
+}
+
+async fn kakao_me(State(state): State<MockState>, headers: HeaderMap) -> impl IntoResponse {
+    match lookup_bearer(&headers, &state.kakao_tokens) {
+        Some((identity, _)) => (
+            StatusCode::OK,
+            Json(json!({
+                "id": identity.provider_user_id.parse::<i64>().unwrap_or_default(),
+                "kakao_account": {
+                    "email": identity.email,
+                    "is_email_valid": true,
+                    "is_email_verified": true,
+                    "profile": { "nickname": identity.name },
+                }
+            })),
+        ),
+        None => (
+            StatusCode::UNAUTHORIZED,
+            Json(json!({ "code": -401, "msg": "this access token does not exist" })),
+        ),
+    }
+}
+
+async fn naver_token_info(State(state): State<MockState>, headers: HeaderMap) -> impl IntoResponse {
+    match lookup_bearer(&headers, &state.naver_tokens) {
+        Some((_, client_id)) => Json(json!({
+            "resultcode": "00",
+            "message": "success",
+            "response": {
+                "token": "",
+                "expire_date": (Utc::now() + Duration::hours(1)).to_rfc3339(),
+                "allowed_profile": "id,email,nickname",
+                "client_id": client_id,
+            }
+        })),
+        None => Json(json!({
+            "resultcode": "024",
+            "message": "Authentication failed",
+        })),
+    }
+}
+
+async fn naver_me(State(state): State<MockState>, headers: HeaderMap) -> impl IntoResponse {
+    match lookup_bearer(&headers, &state.naver_tokens) {
+        Some((identity, _)) => Json(json!({
+            "resultcode": "00",
+            "message": "success",
+            "response": {
+                "id": identity.provider_user_id,
+                "email": identity.email,
+                "nickname": identity.name,
+            }
+        })),
+        None => Json(json!({
+            "resultcode": "024",
+            "message": "Authentication failed",
+        })),
+    }
+}
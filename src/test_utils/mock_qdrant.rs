@@ -1,54 +1,137 @@
-use crate::repositories::QdrantRepo;
+use crate::repositories::{MemoListFilter, MemoPayload, QdrantRepo};
 use async_trait::async_trait;
+use chrono::NaiveDateTime;
 use sea_orm::DbErr;
 use std::collections::HashMap;
-use std::sync::{Arc, Mutex};
+use std::sync::{
+    atomic::{AtomicBool, Ordering},
+    Arc, Mutex,
+};
+
+type StoredPoints = HashMap<i32, (MemoPayload, Vec<f32>)>;
 
 pub struct MockQdrantRepository {
-    memos: Arc<Mutex<HashMap<i32, (i32, Vec<f32>)>>>,
+    memos: Arc<Mutex<StoredPoints>>,
+    fail_deletes: AtomicBool,
 }
 
 impl MockQdrantRepository {
     pub fn new() -> Self {
         Self {
             memos: Arc::new(Mutex::new(HashMap::new())),
+            fail_deletes: AtomicBool::new(false),
         }
     }
+
+    // 벡터 저장소 장애를 흉내 내 삭제 요청이 실패하게 한다.
+    pub fn fail_deletes(&self, fail: bool) {
+        self.fail_deletes.store(fail, Ordering::SeqCst);
+    }
+}
+
+impl Default for MockQdrantRepository {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 #[async_trait]
 impl QdrantRepo for MockQdrantRepository {
-    async fn upsert_memo(
-        &self,
-        memo_id: i32,
-        user_id: i32,
-        vector: Vec<f32>,
-    ) -> Result<(), DbErr> {
+    async fn upsert_memo(&self, payload: MemoPayload, vector: Vec<f32>) -> Result<(), DbErr> {
         self.memos
             .lock()
             .unwrap()
-            .insert(memo_id, (user_id, vector));
+            .insert(payload.memo_id, (payload, vector));
+        Ok(())
+    }
+
+    async fn update_payload(&self, payload: MemoPayload) -> Result<(), DbErr> {
+        if let Some(point) = self.memos.lock().unwrap().get_mut(&payload.memo_id) {
+            point.0 = payload;
+        }
         Ok(())
     }
 
     async fn search_similar(
         &self,
         user_id: i32,
-        _query_vector: Vec<f32>,
+        query_vector: Vec<f32>,
+        filter: &MemoListFilter,
         limit: u64,
-    ) -> Result<Vec<i32>, DbErr> {
+    ) -> Result<Vec<(i32, f32)>, DbErr> {
         let memos = self.memos.lock().unwrap();
-        let memo_ids: Vec<i32> = memos
+        let mut scored: Vec<(i32, f32)> = memos
             .iter()
-            .filter(|(_, (uid, _))| *uid == user_id)
-            .map(|(memo_id, _)| *memo_id)
-            .take(limit as usize)
+            .filter(|(_, (payload, _))| payload.user_id == user_id && matches(payload, filter))
+            .map(|(memo_id, (_, vector))| (*memo_id, cosine_similarity(&query_vector, vector)))
             .collect();
-        Ok(memo_ids)
+        scored.sort_by(|a, b| b.1.total_cmp(&a.1).then(b.0.cmp(&a.0)));
+        scored.truncate(limit as usize);
+        Ok(scored)
     }
 
-    async fn delete_memo(&self, memo_id: i32) -> Result<(), DbErr> {
-        self.memos.lock().unwrap().remove(&memo_id);
+    async fn delete_memos(&self, memo_ids: &[i32]) -> Result<(), DbErr> {
+        if self.fail_deletes.load(Ordering::SeqCst) {
+            return Err(DbErr::Custom("Failed to delete memo".to_string()));
+        }
+        let mut memos = self.memos.lock().unwrap();
+        for memo_id in memo_ids {
+            memos.remove(memo_id);
+        }
         Ok(())
     }
+
+    async fn delete_user_memos(&self, user_id: i32) -> Result<(), DbErr> {
+        self.memos
+            .lock()
+            .unwrap()
+            .retain(|_, (payload, _)| payload.user_id != user_id);
+        Ok(())
+    }
+}
+
+fn matches(payload: &MemoPayload, filter: &MemoListFilter) -> bool {
+    let in_range =
+        |time: NaiveDateTime, since: Option<NaiveDateTime>, until: Option<NaiveDateTime>| {
+            since.is_none_or(|since| time >= since) && until.is_none_or(|until| time < until)
+        };
+
+    !payload.deleted
+        && filter
+            .is_pinned
+            .is_none_or(|is_pinned| payload.is_pinned == is_pinned)
+        && filter
+            .is_archived
+            .is_none_or(|is_archived| payload.is_archived == is_archived)
+        && in_range(
+            payload.created_at,
+            filter.created_since,
+            filter.created_until,
+        )
+        && in_range(
+            payload.updated_at,
+            filter.updated_since,
+            filter.updated_until,
+        )
+        && filter.tags.iter().all(|tag| payload.tags.contains(tag))
+        && !filter
+            .excluded_tags
+            .iter()
+            .any(|tag| payload.tags.contains(tag))
+        && filter.notebook_ids.as_ref().is_none_or(|ids| {
+            payload
+                .notebook_id
+                .is_some_and(|notebook_id| ids.contains(&notebook_id))
+        })
+}
+
+fn cosine_similarity(a: &[f32], b: &[f32]) -> f32 {
+    let dot: f32 = a.iter().zip(b).map(|(x, y)| x * y).sum();
+    let norm = |v: &[f32]| v.iter().map(|x| x * x).sum::<f32>().sqrt();
+    let denominator = norm(a) * norm(b);
+    if denominator == 0.0 {
+        0.0
+    } else {
+        dot / denominator
+    }
 }
@@ -1,5 +1,7 @@
 pub mod mock_gemini;
+pub mod mock_oauth_server;
 pub mod mock_qdrant;
 
 pub use mock_gemini::MockGeminiClient;
+pub use mock_oauth_server::MockOAuthServer;
 pub use mock_qdrant::MockQdrantRepository;
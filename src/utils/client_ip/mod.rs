@@ -0,0 +1,33 @@
+use axum::http::HeaderMap;
+use std::net::IpAddr;
+
+// `X-Forwarded-For`는 클라이언트가 마음대로 채울 수 있으므로 앞에 둔 프록시 수(`trusted_proxies`)만큼만 믿는다.
+// 프록시는 각자 받은 연결의 주소를 오른쪽에 덧붙이므로, 오른쪽에서 `trusted_proxies`번째 주소가
+// 우리 프록시가 직접 본 클라이언트 주소다. 그보다 왼쪽은 클라이언트가 보낸 값이다.
+// 프록시가 없으면(0) 헤더를 무시하고 TCP 연결의 상대 주소를 쓴다.
+pub fn resolve(
+    headers: &HeaderMap,
+    peer: Option<IpAddr>,
+    trusted_proxies: usize,
+) -> Option<String> {
+    if trusted_proxies == 0 {
+        return peer.map(|ip| ip.to_string());
+    }
+
+    let hops: Vec<&str> = headers
+        .get_all("X-Forwarded-For")
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .flat_map(|value| value.split(','))
+        .map(str::trim)
+        .collect();
+
+    // 항목이 프록시 수보다 적으면 모두 우리 프록시가 덧붙인 것이므로 가장 왼쪽이 클라이언트다.
+    hops.get(hops.len().saturating_sub(trusted_proxies))
+        .and_then(|hop| hop.parse::<IpAddr>().ok())
+        .or(peer)
+        .map(|ip| ip.to_string())
+}
+
+#[cfg(test)]
+mod tests;
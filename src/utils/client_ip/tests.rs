@@ -0,0 +1,69 @@
+use super::*;
+use axum::http::HeaderValue;
+
+fn peer() -> Option<IpAddr> {
+    Some("10.0.0.9".parse().unwrap())
+}
+
+fn forwarded(value: &'static str) -> HeaderMap {
+    let mut headers = HeaderMap::new();
+    headers.insert("X-Forwarded-For", HeaderValue::from_static(value));
+    headers
+}
+
+#[test]
+fn test_resolve_ignores_forwarded_header_without_trusted_proxies() {
+    let headers = forwarded("198.51.100.20");
+
+    assert_eq!(resolve(&headers, peer(), 0), Some("10.0.0.9".to_string()));
+    assert_eq!(resolve(&headers, None, 0), None);
+}
+
+#[test]
+fn test_resolve_takes_rightmost_untrusted_hop() {
+    // 클라이언트가 위조한 첫 주소는 건너뛰고, 프록시 두 대가 덧붙인 주소 중 클라이언트 쪽을 고른다.
+    let headers = forwarded("1.2.3.4, 198.51.100.20, 10.0.0.1");
+
+    assert_eq!(
+        resolve(&headers, peer(), 2),
+        Some("198.51.100.20".to_string())
+    );
+    assert_eq!(resolve(&headers, peer(), 1), Some("10.0.0.1".to_string()));
+}
+
+#[test]
+fn test_resolve_reads_multiple_headers_in_order() {
+    let mut headers = HeaderMap::new();
+    headers.append("X-Forwarded-For", HeaderValue::from_static("1.2.3.4"));
+    headers.append(
+        "X-Forwarded-For",
+        HeaderValue::from_static("2001:db8::1, 10.0.0.1"),
+    );
+
+    assert_eq!(
+        resolve(&headers, peer(), 2),
+        Some("2001:db8::1".to_string())
+    );
+}
+
+#[test]
+fn test_resolve_uses_leftmost_hop_when_chain_is_short() {
+    let headers = forwarded("198.51.100.20");
+
+    assert_eq!(
+        resolve(&headers, peer(), 2),
+        Some("198.51.100.20".to_string())
+    );
+}
+
+#[test]
+fn test_resolve_falls_back_to_peer_on_missing_or_invalid_hop() {
+    assert_eq!(
+        resolve(&HeaderMap::new(), peer(), 1),
+        Some("10.0.0.9".to_string())
+    );
+    assert_eq!(
+        resolve(&forwarded("1.2.3.4, not-an-ip"), peer(), 1),
+        Some("10.0.0.9".to_string())
+    );
+}
@@ -0,0 +1,46 @@
+use axum::http::{header, HeaderMap};
+
+// `Cookie` 헤더가 여러 개로 나뉘어 와도 모두 살펴본다.
+pub fn get(headers: &HeaderMap, name: &str) -> Option<String> {
+    headers
+        .get_all(header::COOKIE)
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .flat_map(|value| value.split(';'))
+        .filter_map(|pair| pair.trim().split_once('='))
+        .find(|(key, _)| *key == name)
+        .map(|(_, value)| value.trim_matches('"').to_string())
+        .filter(|value| !value.is_empty())
+}
+
+pub struct SetCookie<'a> {
+    pub name: &'a str,
+    pub value: &'a str,
+    pub path: &'a str,
+    pub max_age: i64,
+    pub http_only: bool,
+    pub secure: bool,
+}
+
+impl SetCookie<'_> {
+    // 인증 쿠키는 다른 사이트에서 시작된 요청에 실리지 않도록 항상 SameSite=Strict로 보낸다.
+    pub fn to_header_value(&self) -> String {
+        let mut cookie = format!(
+            "{}={}; Path={}; Max-Age={}; SameSite=Strict",
+            self.name,
+            self.value,
+            self.path,
+            self.max_age.max(0)
+        );
+        if self.http_only {
+            cookie.push_str("; HttpOnly");
+        }
+        if self.secure {
+            cookie.push_str("; Secure");
+        }
+        cookie
+    }
+}
+
+#[cfg(test)]
+mod tests;
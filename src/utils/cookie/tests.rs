@@ -0,0 +1,59 @@
+use super::*;
+use axum::http::HeaderValue;
+
+#[test]
+fn test_get_finds_cookie_among_others() {
+    let mut headers = HeaderMap::new();
+    headers.insert(
+        header::COOKIE,
+        HeaderValue::from_static("theme=dark; lekha_access=abc.def; lekha_csrf=xyz"),
+    );
+
+    assert_eq!(get(&headers, "lekha_access"), Some("abc.def".to_string()));
+    assert_eq!(get(&headers, "lekha_csrf"), Some("xyz".to_string()));
+    assert_eq!(get(&headers, "lekha"), None);
+}
+
+#[test]
+fn test_get_reads_multiple_cookie_headers_and_skips_empty_values() {
+    let mut headers = HeaderMap::new();
+    headers.append(header::COOKIE, HeaderValue::from_static("lekha_csrf="));
+    headers.append(
+        header::COOKIE,
+        HeaderValue::from_static("lekha_refresh=\"r1\""),
+    );
+
+    assert_eq!(get(&headers, "lekha_csrf"), None);
+    assert_eq!(get(&headers, "lekha_refresh"), Some("r1".to_string()));
+}
+
+#[test]
+fn test_set_cookie_header_value() {
+    let cookie = SetCookie {
+        name: "lekha_access",
+        value: "token",
+        path: "/api",
+        max_age: 900,
+        http_only: true,
+        secure: true,
+    };
+
+    assert_eq!(
+        cookie.to_header_value(),
+        "lekha_access=token; Path=/api; Max-Age=900; SameSite=Strict; HttpOnly; Secure"
+    );
+
+    let cleared = SetCookie {
+        name: "lekha_csrf",
+        value: "",
+        path: "/",
+        max_age: -1,
+        http_only: false,
+        secure: false,
+    };
+
+    assert_eq!(
+        cleared.to_header_value(),
+        "lekha_csrf=; Path=/; Max-Age=0; SameSite=Strict"
+    );
+}
@@ -1,44 +1,305 @@
+use base64::{engine::general_purpose::URL_SAFE_NO_PAD, Engine};
 use chrono::{Duration, Utc};
-use jsonwebtoken::{decode, encode, DecodingKey, EncodingKey, Header, Validation};
+use ed25519_dalek::{
+    pkcs8::{DecodePrivateKey, DecodePublicKey, EncodePrivateKey},
+    SigningKey, VerifyingKey,
+};
+use jsonwebtoken::{
+    decode, decode_header, encode,
+    jwk::{
+        AlgorithmParameters, CommonParameters, EllipticCurve, Jwk, JwkSet, KeyAlgorithm,
+        OctetKeyPairParameters, OctetKeyPairType, PublicKeyUse,
+    },
+    Algorithm, DecodingKey, EncodingKey, Header, Validation,
+};
 use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::HashMap;
+use thiserror::Error;
+
+use crate::{entities::user::UserRole, models::Scope};
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct Claims {
     pub sub: String,
     pub exp: i64,
     pub iat: i64,
+    #[serde(default)]
+    pub scope: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sid: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub role: Option<UserRole>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub purpose: Option<String>,
+}
+
+const TWO_FACTOR_CHALLENGE_PURPOSE: &str = "2fa_challenge";
+
+impl Claims {
+    pub fn scopes(&self) -> Vec<Scope> {
+        Scope::parse_list(&self.scope)
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum JwtKeyError {
+    #[error("Invalid signing key: {0}")]
+    InvalidSigningKey(String),
+
+    #[error("Invalid verification key {0}: {1}")]
+    InvalidVerificationKey(String, String),
+
+    #[error("JWT_SIGNING_KEY or JWT_SIGNING_KEY_FILE must be set (set JWT_ALLOW_EPHEMERAL_KEY=true to use a throwaway key in development)")]
+    MissingSigningKey,
 }
 
-pub fn generate_token(
-    user_id: i32,
-    secret: &str,
-    expiration_hours: i64,
-) -> Result<String, jsonwebtoken::errors::Error> {
-    let now = Utc::now();
-    let expires_at = now + Duration::hours(expiration_hours);
-
-    let claims = Claims {
-        sub: user_id.to_string(),
-        exp: expires_at.timestamp(),
-        iat: now.timestamp(),
-    };
-
-    encode(
-        &Header::default(),
-        &claims,
-        &EncodingKey::from_secret(secret.as_ref()),
-    )
+pub struct JwtKeys {
+    signing_kid: String,
+    encoding_key: EncodingKey,
+    decoding_keys: HashMap<String, DecodingKey>,
+    jwks: JwkSet,
 }
 
-pub fn verify_token(token: &str, secret: &str) -> Result<Claims, jsonwebtoken::errors::Error> {
-    let validation = Validation::default();
-    let token_data = decode::<Claims>(
-        token,
-        &DecodingKey::from_secret(secret.as_ref()),
-        &validation,
-    )?;
+impl JwtKeys {
+    pub fn new(signing_key: SigningKey) -> Result<Self, JwtKeyError> {
+        let der = signing_key
+            .to_pkcs8_der()
+            .map_err(|e| JwtKeyError::InvalidSigningKey(e.to_string()))?;
+        let jwk = public_jwk(&signing_key.verifying_key());
+        let signing_kid = jwk.common.key_id.clone().unwrap_or_default();
+
+        let mut keys = Self {
+            signing_kid,
+            encoding_key: EncodingKey::from_ed_der(der.as_bytes()),
+            decoding_keys: HashMap::new(),
+            jwks: JwkSet { keys: Vec::new() },
+        };
+        keys.insert_verification_jwk(jwk);
+
+        Ok(keys)
+    }
+
+    pub fn generate() -> Self {
+        let signing_key = SigningKey::generate(&mut rand::rngs::OsRng);
+        Self::new(signing_key).expect("Generated Ed25519 key must be encodable")
+    }
+
+    pub fn from_pem(signing_key_pem: &str) -> Result<Self, JwtKeyError> {
+        let signing_key = SigningKey::from_pkcs8_pem(signing_key_pem)
+            .map_err(|e| JwtKeyError::InvalidSigningKey(e.to_string()))?;
+        Self::new(signing_key)
+    }
+
+    pub fn from_env() -> Result<Self, JwtKeyError> {
+        let signing_key_pem = match std::env::var("JWT_SIGNING_KEY_FILE") {
+            Ok(path) => Some(std::fs::read_to_string(&path).map_err(|e| {
+                JwtKeyError::InvalidSigningKey(format!("failed to read {}: {}", path, e))
+            })?),
+            Err(_) => std::env::var("JWT_SIGNING_KEY").ok(),
+        };
+
+        let mut keys = match signing_key_pem {
+            Some(pem) => Self::from_pem(&pem)?,
+            None if allow_ephemeral_key() => {
+                tracing::warn!(
+                    "JWT_SIGNING_KEY is not set; using an ephemeral key. Issued tokens will not survive a restart."
+                );
+                Self::generate()
+            }
+            None => return Err(JwtKeyError::MissingSigningKey),
+        };
+
+        let verification_key_files =
+            std::env::var("JWT_VERIFICATION_KEY_FILES").unwrap_or_default();
+        for path in verification_key_files
+            .split(',')
+            .map(str::trim)
+            .filter(|p| !p.is_empty())
+        {
+            let pem = std::fs::read_to_string(path).map_err(|e| {
+                JwtKeyError::InvalidVerificationKey(path.to_string(), e.to_string())
+            })?;
+            keys.add_verification_key_pem(&pem).map_err(|e| {
+                JwtKeyError::InvalidVerificationKey(path.to_string(), e.to_string())
+            })?;
+        }
+
+        Ok(keys)
+    }
+
+    pub fn add_verification_key_pem(
+        &mut self,
+        public_key_pem: &str,
+    ) -> Result<String, JwtKeyError> {
+        let verifying_key = VerifyingKey::from_public_key_pem(public_key_pem)
+            .map_err(|e| JwtKeyError::InvalidVerificationKey("PEM".to_string(), e.to_string()))?;
+        Ok(self.add_verification_key(&verifying_key))
+    }
+
+    pub fn add_verification_key(&mut self, verifying_key: &VerifyingKey) -> String {
+        let jwk = public_jwk(verifying_key);
+        let kid = jwk.common.key_id.clone().unwrap_or_default();
+        self.insert_verification_jwk(jwk);
+        kid
+    }
+
+    fn insert_verification_jwk(&mut self, jwk: Jwk) {
+        let kid = jwk.common.key_id.clone().unwrap_or_default();
+        if self.decoding_keys.contains_key(&kid) {
+            return;
+        }
+        let decoding_key =
+            DecodingKey::from_jwk(&jwk).expect("Ed25519 JWK must produce a decoding key");
+        self.decoding_keys.insert(kid, decoding_key);
+        self.jwks.keys.push(jwk);
+    }
+
+    pub fn signing_kid(&self) -> &str {
+        &self.signing_kid
+    }
+
+    pub fn jwks(&self) -> &JwkSet {
+        &self.jwks
+    }
+
+    #[cfg(test)]
+    pub fn generate_token(
+        &self,
+        user_id: i32,
+        expiration_minutes: i64,
+    ) -> Result<String, jsonwebtoken::errors::Error> {
+        self.generate_scoped_token(user_id, &Scope::ALL, expiration_minutes)
+    }
+
+    #[cfg(test)]
+    pub fn generate_scoped_token(
+        &self,
+        user_id: i32,
+        scopes: &[Scope],
+        expiration_minutes: i64,
+    ) -> Result<String, jsonwebtoken::errors::Error> {
+        self.encode(&self.build_claims(user_id, scopes, None, expiration_minutes))
+    }
+
+    pub fn generate_session_token(
+        &self,
+        user_id: i32,
+        role: UserRole,
+        session_id: &str,
+        expiration_minutes: i64,
+    ) -> Result<String, jsonwebtoken::errors::Error> {
+        let mut claims = self.build_claims(
+            user_id,
+            &Scope::ALL,
+            Some(session_id.to_string()),
+            expiration_minutes,
+        );
+        claims.role = Some(role);
+        self.encode(&claims)
+    }
+
+    // 2단계 인증 대기 중임을 나타내는 토큰으로, 스코프가 없고 액세스 토큰으로는 쓸 수 없다.
+    pub fn generate_challenge_token(
+        &self,
+        user_id: i32,
+        expiration_minutes: i64,
+    ) -> Result<String, jsonwebtoken::errors::Error> {
+        let mut claims = self.build_claims(user_id, &[], None, expiration_minutes);
+        claims.purpose = Some(TWO_FACTOR_CHALLENGE_PURPOSE.to_string());
+        self.encode(&claims)
+    }
+
+    fn build_claims(
+        &self,
+        user_id: i32,
+        scopes: &[Scope],
+        session_id: Option<String>,
+        expiration_minutes: i64,
+    ) -> Claims {
+        let now = Utc::now();
+        let expires_at = now + Duration::minutes(expiration_minutes);
+
+        Claims {
+            sub: user_id.to_string(),
+            exp: expires_at.timestamp(),
+            iat: now.timestamp(),
+            scope: Scope::join(scopes),
+            sid: session_id,
+            role: None,
+            purpose: None,
+        }
+    }
+
+    fn encode(&self, claims: &Claims) -> Result<String, jsonwebtoken::errors::Error> {
+        let mut header = Header::new(Algorithm::EdDSA);
+        header.kid = Some(self.signing_kid.clone());
+
+        encode(&header, claims, &self.encoding_key)
+    }
+
+    pub fn verify_token(&self, token: &str) -> Result<Claims, jsonwebtoken::errors::Error> {
+        let claims = self.decode_claims(token)?;
+        if claims.purpose.is_some() {
+            return Err(jsonwebtoken::errors::ErrorKind::InvalidToken.into());
+        }
+        Ok(claims)
+    }
+
+    pub fn verify_challenge_token(
+        &self,
+        token: &str,
+    ) -> Result<Claims, jsonwebtoken::errors::Error> {
+        let claims = self.decode_claims(token)?;
+        if claims.purpose.as_deref() != Some(TWO_FACTOR_CHALLENGE_PURPOSE) {
+            return Err(jsonwebtoken::errors::ErrorKind::InvalidToken.into());
+        }
+        Ok(claims)
+    }
+
+    fn decode_claims(&self, token: &str) -> Result<Claims, jsonwebtoken::errors::Error> {
+        let header = decode_header(token)?;
+        let decoding_key = header
+            .kid
+            .as_deref()
+            .and_then(|kid| self.decoding_keys.get(kid))
+            .ok_or(jsonwebtoken::errors::ErrorKind::InvalidKeyFormat)?;
+
+        let validation = Validation::new(Algorithm::EdDSA);
+        let token_data = decode::<Claims>(token, decoding_key, &validation)?;
+
+        Ok(token_data.claims)
+    }
+}
+
+// 임시 키로 발급한 토큰은 재시작하면 모두 무효가 되므로 디버그 빌드나 명시적으로 허용한 경우에만 쓴다.
+fn allow_ephemeral_key() -> bool {
+    cfg!(debug_assertions)
+        || std::env::var("JWT_ALLOW_EPHEMERAL_KEY")
+            .map(|v| v == "true")
+            .unwrap_or(false)
+}
+
+fn public_jwk(verifying_key: &VerifyingKey) -> Jwk {
+    let x = URL_SAFE_NO_PAD.encode(verifying_key.to_bytes());
+
+    // RFC 7638 JWK thumbprint를 kid로 사용해 키 파일만으로 kid가 결정되도록 한다.
+    let thumbprint_input = format!(r#"{{"crv":"Ed25519","kty":"OKP","x":"{}"}}"#, x);
+    let kid = URL_SAFE_NO_PAD.encode(Sha256::digest(thumbprint_input.as_bytes()));
 
-    Ok(token_data.claims)
+    Jwk {
+        common: CommonParameters {
+            public_key_use: Some(PublicKeyUse::Signature),
+            key_algorithm: Some(KeyAlgorithm::EdDSA),
+            key_id: Some(kid),
+            ..Default::default()
+        },
+        algorithm: AlgorithmParameters::OctetKeyPair(OctetKeyPairParameters {
+            key_type: OctetKeyPairType::OctetKeyPair,
+            curve: EllipticCurve::Ed25519,
+            x,
+        }),
+    }
 }
 
 #[cfg(test)]
@@ -2,24 +2,115 @@ use super::*;
 
 #[test]
 fn test_generate_and_verify_token() {
-    let secret = "test_secret_key_min_32_chars_long";
+    let keys = JwtKeys::generate();
     let user_id = 123;
-    let expiration_hours = 24;
+    let expiration_minutes = 15;
 
-    let token = generate_token(user_id, secret, expiration_hours).unwrap();
-    let claims = verify_token(&token, secret).unwrap();
+    let token = keys.generate_token(user_id, expiration_minutes).unwrap();
+    let claims = keys.verify_token(&token).unwrap();
 
     assert_eq!(claims.sub, "123");
     assert!(claims.exp > claims.iat);
+    assert_eq!(claims.scopes(), Scope::ALL.to_vec());
+
+    let header = decode_header(&token).unwrap();
+    assert_eq!(header.alg, Algorithm::EdDSA);
+    assert_eq!(header.kid.as_deref(), Some(keys.signing_kid()));
 }
 
 #[test]
-fn test_invalid_secret_fails() {
-    let secret = "test_secret_key_min_32_chars_long";
-    let wrong_secret = "wrong_secret_key_min_32_chars_long";
+fn test_invalid_key_fails() {
+    let keys = JwtKeys::generate();
+    let other_keys = JwtKeys::generate();
 
-    let token = generate_token(1, secret, 24).unwrap();
-    let result = verify_token(&token, wrong_secret);
+    let token = keys.generate_token(1, 15).unwrap();
+    let result = other_keys.verify_token(&token);
 
     assert!(result.is_err());
 }
+
+#[test]
+fn test_rotated_key_still_verifies() {
+    let old_signing_key = SigningKey::generate(&mut rand::rngs::OsRng);
+    let old_keys = JwtKeys::new(old_signing_key.clone()).unwrap();
+    let token_from_old_key = old_keys.generate_token(7, 15).unwrap();
+
+    let mut new_keys = JwtKeys::generate();
+    let old_kid = new_keys.add_verification_key(&old_signing_key.verifying_key());
+
+    assert_eq!(old_kid, old_keys.signing_kid());
+    assert_ne!(new_keys.signing_kid(), old_keys.signing_kid());
+    assert_eq!(new_keys.jwks().keys.len(), 2);
+    assert_eq!(new_keys.verify_token(&token_from_old_key).unwrap().sub, "7");
+}
+
+#[test]
+fn test_hs256_token_rejected() {
+    let keys = JwtKeys::generate();
+    let claims = Claims {
+        sub: "1".to_string(),
+        exp: (Utc::now() + Duration::minutes(15)).timestamp(),
+        iat: Utc::now().timestamp(),
+        scope: Scope::join(&Scope::ALL),
+        sid: None,
+        role: None,
+        purpose: None,
+    };
+    let mut header = Header::new(Algorithm::HS256);
+    header.kid = Some(keys.signing_kid().to_string());
+    let token = encode(
+        &header,
+        &claims,
+        &EncodingKey::from_secret(b"shared-secret"),
+    )
+    .unwrap();
+
+    assert!(keys.verify_token(&token).is_err());
+}
+
+#[test]
+fn test_from_pem() {
+    let signing_key = SigningKey::generate(&mut rand::rngs::OsRng);
+    let pem = signing_key
+        .to_pkcs8_pem(Default::default())
+        .unwrap()
+        .to_string();
+
+    let keys = JwtKeys::from_pem(&pem).unwrap();
+    let token = keys.generate_token(42, 15).unwrap();
+
+    assert_eq!(keys.verify_token(&token).unwrap().sub, "42");
+    assert!(JwtKeys::from_pem("not a pem").is_err());
+}
+
+#[test]
+fn test_scoped_token() {
+    let keys = JwtKeys::generate();
+    let token = keys
+        .generate_scoped_token(5, &[Scope::MemosRead], 15)
+        .unwrap();
+
+    let claims = keys.verify_token(&token).unwrap();
+    assert_eq!(claims.scope, "memos:read");
+    assert_eq!(claims.scopes(), vec![Scope::MemosRead]);
+    assert!(claims.sid.is_none());
+
+    let session_token = keys
+        .generate_session_token(5, UserRole::Admin, "session-1", 15)
+        .unwrap();
+    let claims = keys.verify_token(&session_token).unwrap();
+    assert_eq!(claims.sid.as_deref(), Some("session-1"));
+    assert_eq!(claims.role, Some(UserRole::Admin));
+}
+
+#[test]
+fn test_challenge_token_is_not_an_access_token() {
+    let keys = JwtKeys::generate();
+
+    let challenge = keys.generate_challenge_token(9, 5).unwrap();
+    assert!(keys.verify_token(&challenge).is_err());
+    assert_eq!(keys.verify_challenge_token(&challenge).unwrap().sub, "9");
+
+    let access = keys.generate_token(9, 15).unwrap();
+    assert!(keys.verify_challenge_token(&access).is_err());
+}
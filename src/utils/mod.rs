@@ -1 +1,11 @@
+pub mod client_ip;
+pub mod cookie;
 pub mod jwt;
+pub mod password;
+pub mod search;
+pub mod totp;
+
+// 비밀 값 비교에 걸리는 시간으로 일치한 길이가 드러나지 않도록 끝까지 비교한다.
+pub fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).fold(0u8, |acc, (x, y)| acc | (x ^ y)) == 0
+}
@@ -0,0 +1,86 @@
+use argon2::{
+    password_hash::{rand_core::OsRng, PasswordHash, PasswordHasher, PasswordVerifier, SaltString},
+    Argon2,
+};
+use std::sync::OnceLock;
+
+pub const MIN_PASSWORD_LENGTH: usize = 10;
+pub const MAX_PASSWORD_LENGTH: usize = 128;
+
+static DUMMY_HASH: OnceLock<String> = OnceLock::new();
+
+pub fn hash_password(password: &str) -> Result<String, argon2::password_hash::Error> {
+    let salt = SaltString::generate(&mut OsRng);
+    let hash = Argon2::default().hash_password(password.as_bytes(), &salt)?;
+    Ok(hash.to_string())
+}
+
+pub fn verify_password(password: &str, hash: &str) -> bool {
+    match PasswordHash::new(hash) {
+        Ok(parsed) => Argon2::default()
+            .verify_password(password.as_bytes(), &parsed)
+            .is_ok(),
+        Err(_) => false,
+    }
+}
+
+// 존재하지 않는 계정도 실제 검증과 같은 비용을 들여 응답 시간으로 계정 존재 여부가 드러나지 않게 한다.
+pub fn verify_password_or_dummy(password: &str, hash: Option<&str>) -> bool {
+    match hash {
+        Some(hash) if PasswordHash::new(hash).is_ok() => verify_password(password, hash),
+        _ => {
+            let dummy = DUMMY_HASH.get_or_init(|| {
+                hash_password("inklings-dummy-password").expect("Failed to hash dummy password")
+            });
+            let _ = verify_password(password, dummy);
+            false
+        }
+    }
+}
+
+// argon2는 한 번에 수십 ms씩 CPU를 쓰므로 비동기 작업자를 막지 않도록 블로킹 스레드에서 실행한다.
+pub async fn hash_password_async(password: String) -> Result<String, argon2::password_hash::Error> {
+    tokio::task::spawn_blocking(move || hash_password(&password))
+        .await
+        .unwrap_or(Err(argon2::password_hash::Error::Crypto))
+}
+
+pub async fn verify_password_async(password: String, hash: String) -> bool {
+    tokio::task::spawn_blocking(move || verify_password(&password, &hash))
+        .await
+        .unwrap_or(false)
+}
+
+pub async fn verify_password_or_dummy_async(password: String, hash: Option<String>) -> bool {
+    tokio::task::spawn_blocking(move || verify_password_or_dummy(&password, hash.as_deref()))
+        .await
+        .unwrap_or(false)
+}
+
+pub fn validate_password(password: &str) -> Result<(), String> {
+    let length = password.chars().count();
+
+    if length < MIN_PASSWORD_LENGTH {
+        return Err(format!(
+            "Password must be at least {} characters long",
+            MIN_PASSWORD_LENGTH
+        ));
+    }
+    if length > MAX_PASSWORD_LENGTH {
+        return Err(format!(
+            "Password must be at most {} characters long",
+            MAX_PASSWORD_LENGTH
+        ));
+    }
+    if !password.chars().any(char::is_alphabetic) {
+        return Err("Password must contain at least one letter".to_string());
+    }
+    if !password.chars().any(|c| !c.is_alphabetic()) {
+        return Err("Password must contain at least one digit or symbol".to_string());
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests;
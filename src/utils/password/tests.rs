@@ -0,0 +1,42 @@
+use super::*;
+
+#[test]
+fn test_hash_and_verify_password() {
+    let hash = hash_password("correct horse 42").unwrap();
+
+    assert!(hash.starts_with("$argon2id$"));
+    assert!(verify_password("correct horse 42", &hash));
+    assert!(!verify_password("wrong horse 42", &hash));
+}
+
+#[test]
+fn test_verify_rejects_malformed_hash() {
+    assert!(!verify_password("whatever", "not-a-phc-string"));
+    assert!(!verify_password_or_dummy(
+        "whatever",
+        Some("not-a-phc-string")
+    ));
+    assert!(!verify_password_or_dummy("whatever", None));
+}
+
+#[test]
+fn test_validate_password_policy() {
+    assert!(validate_password("short1").is_err());
+    assert!(validate_password("onlyletterspassword").is_err());
+    assert!(validate_password("1234567890").is_err());
+    assert!(validate_password(&"a1".repeat(65)).is_err());
+    assert!(validate_password("글쓰기는즐거워2024").is_ok());
+    assert!(validate_password("correct horse battery").is_ok());
+}
+
+#[tokio::test]
+async fn test_async_helpers_match_sync_helpers() {
+    let hash = hash_password_async("correct horse 42".to_string())
+        .await
+        .unwrap();
+
+    assert!(verify_password_async("correct horse 42".to_string(), hash.clone()).await);
+    assert!(!verify_password_async("wrong horse 42".to_string(), hash.clone()).await);
+    assert!(verify_password_or_dummy_async("correct horse 42".to_string(), Some(hash)).await);
+    assert!(!verify_password_or_dummy_async("correct horse 42".to_string(), None).await);
+}
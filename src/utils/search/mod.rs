@@ -0,0 +1,212 @@
+use std::collections::HashMap;
+
+pub mod query;
+
+use query::SearchQuery;
+
+// 메모 본문의 tsvector는 DB 함수 `memo_search_vector`가 만든다. 검색어도 같은 규칙
+// (ASCII 대문자만 소문자로, ASCII 공백/구두점으로 단어 구분, 긴 단어는 64자씩 자름, 단어마다 2글자 n-gram)으로
+// 나눠야 일치한다.
+const MAX_WORD_CHARS: usize = 64;
+const SNIPPET_CHARS: usize = 160;
+const SNIPPET_LEAD_CHARS: usize = 40;
+// RRF 상수. 원 논문의 기본값으로, 상위 몇 건의 순위 차이가 점수를 지나치게 좌우하지 않게 한다.
+const RRF_K: f32 = 60.0;
+
+fn is_separator(c: char) -> bool {
+    c.is_ascii_punctuation() || matches!(c, ' ' | '\t' | '\n' | '\x0b' | '\x0c' | '\r')
+}
+
+pub fn query_words(query: &str) -> Vec<String> {
+    query
+        .to_ascii_lowercase()
+        .split(is_separator)
+        .filter(|word| !word.is_empty())
+        .flat_map(|word| {
+            let chars: Vec<char> = word.chars().collect();
+            chars
+                .chunks(MAX_WORD_CHARS)
+                .map(|chunk| chunk.iter().collect::<String>())
+                .collect::<Vec<_>>()
+        })
+        .collect()
+}
+
+// 한 글자 단어는 그 글자로 시작하는 n-gram을, 그보다 긴 단어는 n-gram이 연달아 나오는 위치를 찾는다.
+// 결과적으로 단어가 본문 단어의 일부로 포함되기만 하면 일치한다.
+fn word_tsquery(word: &str) -> String {
+    let chars: Vec<char> = word.chars().collect();
+    if chars.len() == 1 {
+        return format!("'{}':*", word);
+    }
+
+    chars
+        .windows(2)
+        .map(|gram| format!("'{}{}'", gram[0], gram[1]))
+        .collect::<Vec<_>>()
+        .join(" <-> ")
+}
+
+pub fn to_tsquery(words: &[String]) -> Option<String> {
+    if words.is_empty() {
+        return None;
+    }
+
+    Some(
+        words
+            .iter()
+            .map(|word| format!("({})", word_tsquery(word)))
+            .collect::<Vec<_>>()
+            .join(" & "),
+    )
+}
+
+// 구문 안의 단어는 본문에서 바로 이어져야 한다. DB 함수가 단어 사이에 위치 하나를 비워 두므로 거리는 2다.
+fn phrase_tsquery(phrase: &str) -> Option<String> {
+    let words = query_words(phrase);
+    if words.is_empty() {
+        return None;
+    }
+
+    Some(
+        words
+            .iter()
+            .map(|word| format!("({})", word_tsquery(word)))
+            .collect::<Vec<_>>()
+            .join(" <2> "),
+    )
+}
+
+// 단어와 구문은 모두 포함해야 하고, 제외어는 하나라도 포함되면 안 된다.
+// 구두점만 있는 등 검색할 단어가 하나도 없으면 `None`
+pub fn search_tsquery(query: &SearchQuery) -> Option<String> {
+    let words: Vec<String> = query
+        .terms
+        .iter()
+        .flat_map(|term| query_words(term))
+        .collect();
+    let parts: Vec<String> = to_tsquery(&words)
+        .into_iter()
+        .chain(
+            query
+                .phrases
+                .iter()
+                .filter_map(|phrase| phrase_tsquery(phrase)),
+        )
+        .chain(exclusion_tsquery(query))
+        .collect();
+
+    (!parts.is_empty()).then(|| parts.join(" & "))
+}
+
+// 제외어 조건만 뽑은 것. 키워드 검색을 거치지 않은 의미 기반 검색 결과를 거를 때 쓴다.
+pub fn exclusion_tsquery(query: &SearchQuery) -> Option<String> {
+    let parts: Vec<String> = query
+        .excluded
+        .iter()
+        .filter_map(|excluded| phrase_tsquery(excluded))
+        .map(|tsquery| format!("!({})", tsquery))
+        .collect();
+
+    (!parts.is_empty()).then(|| parts.join(" & "))
+}
+
+// 스니펫에서 강조할 단어. 제외어는 결과 본문에 없으므로 넣지 않는다.
+pub fn highlight_words(query: &SearchQuery) -> Vec<String> {
+    query
+        .terms
+        .iter()
+        .chain(&query.phrases)
+        .flat_map(|text| query_words(text))
+        .collect()
+}
+
+// 첫 일치 지점 주변을 잘라 일치 부분을 `<mark>`로 감싼다. 나머지 본문은 HTML 이스케이프한다.
+pub fn highlight(content: &str, words: &[String]) -> String {
+    let lower = content.to_ascii_lowercase();
+    let mut ranges: Vec<(usize, usize)> = words
+        .iter()
+        .flat_map(|word| {
+            lower
+                .match_indices(word.as_str())
+                .map(|(start, matched)| (start, start + matched.len()))
+        })
+        .collect();
+    ranges.sort_unstable();
+
+    let mut merged: Vec<(usize, usize)> = Vec::with_capacity(ranges.len());
+    for (start, end) in ranges {
+        match merged.last_mut() {
+            Some(last) if start <= last.1 => last.1 = last.1.max(end),
+            _ => merged.push((start, end)),
+        }
+    }
+
+    let offsets: Vec<usize> = content
+        .char_indices()
+        .map(|(offset, _)| offset)
+        .chain(std::iter::once(content.len()))
+        .collect();
+    let char_count = offsets.len() - 1;
+    let first_match = merged
+        .first()
+        .map(|(start, _)| offsets.partition_point(|&offset| offset < *start))
+        .unwrap_or(0);
+    let window_start = first_match
+        .saturating_sub(SNIPPET_LEAD_CHARS)
+        .min(char_count.saturating_sub(SNIPPET_CHARS));
+    let window_end = (window_start + SNIPPET_CHARS).min(char_count);
+    let (byte_start, byte_end) = (offsets[window_start], offsets[window_end]);
+
+    let mut snippet = String::new();
+    if window_start > 0 {
+        snippet.push('…');
+    }
+    let mut cursor = byte_start;
+    for (start, end) in merged {
+        let (start, end) = (start.max(byte_start), end.min(byte_end));
+        if start >= end {
+            continue;
+        }
+        push_escaped(&mut snippet, &content[cursor..start]);
+        snippet.push_str("<mark>");
+        push_escaped(&mut snippet, &content[start..end]);
+        snippet.push_str("</mark>");
+        cursor = end;
+    }
+    push_escaped(&mut snippet, &content[cursor..byte_end]);
+    if window_end < char_count {
+        snippet.push('…');
+    }
+    snippet
+}
+
+// 각 목록은 관련도 순으로 정렬된 ID 목록이다. 점수는 목록마다 1 / (k + 순위)를 더한 값이다.
+pub fn reciprocal_rank_fusion(rankings: &[&[i32]]) -> Vec<(i32, f32)> {
+    let mut totals: HashMap<i32, f32> = HashMap::new();
+    for ranking in rankings {
+        for (index, id) in ranking.iter().enumerate() {
+            *totals.entry(*id).or_default() += 1.0 / (RRF_K + index as f32 + 1.0);
+        }
+    }
+
+    let mut scores: Vec<(i32, f32)> = totals.into_iter().collect();
+    scores.sort_by(|a, b| b.1.total_cmp(&a.1).then(b.0.cmp(&a.0)));
+    scores
+}
+
+fn push_escaped(out: &mut String, text: &str) {
+    for c in text.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\'' => out.push_str("&#39;"),
+            _ => out.push(c),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests;
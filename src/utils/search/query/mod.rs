@@ -0,0 +1,244 @@
+use chrono::{Duration, NaiveDate, NaiveDateTime, NaiveTime, Utc};
+use thiserror::Error;
+
+// 검색어 문법:
+//   단어          본문에 포함되어야 하는 단어
+//   "구문"        단어들이 이 순서로 붙어 있어야 함
+//   -단어, -"구문" 포함되면 제외
+//   tag:이름, -tag:이름
+//   pinned:true|false
+//   after:날짜, before:날짜                 작성 시각 (after는 포함, before는 제외)
+//   updated_after:날짜, updated_before:날짜 수정 시각
+// 날짜는 `2024-06-01` 또는 `2024-06-01T09:30:00` 형식이다. `30d`, `2w`, `12h`처럼 쓰면 검색하는
+// 시각으로부터 그만큼 이전을 뜻하므로, 저장한 검색을 다시 실행해도 "최근 30일"이 유지된다.
+// 알 수 없는 `이름:값`은 URL이나 시각처럼 본문에 흔한 문자열이므로 일반 단어로 취급한다.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SearchQuery {
+    pub terms: Vec<String>,
+    pub phrases: Vec<String>,
+    pub excluded: Vec<String>,
+    pub tags: Vec<String>,
+    pub excluded_tags: Vec<String>,
+    pub pinned: Option<bool>,
+    pub created_since: Option<NaiveDateTime>,
+    pub created_until: Option<NaiveDateTime>,
+    pub updated_since: Option<NaiveDateTime>,
+    pub updated_until: Option<NaiveDateTime>,
+}
+
+impl SearchQuery {
+    pub fn has_text(&self) -> bool {
+        !self.terms.is_empty() || !self.phrases.is_empty() || !self.excluded.is_empty()
+    }
+
+    // 의미 기반 검색에는 제외어와 필드 연산자를 뺀 긍정 단어만 넘긴다.
+    pub fn semantic_text(&self) -> Option<String> {
+        let text = self
+            .terms
+            .iter()
+            .chain(&self.phrases)
+            .map(String::as_str)
+            .collect::<Vec<_>>()
+            .join(" ");
+        (!text.is_empty()).then_some(text)
+    }
+}
+
+/// `position`은 입력 문자열에서 문제가 된 위치(0부터 센 문자 단위)
+#[derive(Debug, Clone, PartialEq, Error)]
+#[error("{message} at position {position}")]
+pub struct QueryParseError {
+    pub position: usize,
+    pub message: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Field {
+    Tag,
+    Pinned,
+    After,
+    Before,
+    UpdatedAfter,
+    UpdatedBefore,
+}
+
+impl Field {
+    fn parse(name: &str) -> Option<Self> {
+        match name.to_ascii_lowercase().as_str() {
+            "tag" => Some(Self::Tag),
+            "pinned" => Some(Self::Pinned),
+            "after" => Some(Self::After),
+            "before" => Some(Self::Before),
+            "updated_after" => Some(Self::UpdatedAfter),
+            "updated_before" => Some(Self::UpdatedBefore),
+            _ => None,
+        }
+    }
+}
+
+struct Parser {
+    chars: Vec<char>,
+    pos: usize,
+}
+
+pub fn parse(input: &str) -> Result<SearchQuery, QueryParseError> {
+    parse_at(input, Utc::now().naive_utc())
+}
+
+// 상대 날짜의 기준 시각 `now`를 받는다.
+pub fn parse_at(input: &str, now: NaiveDateTime) -> Result<SearchQuery, QueryParseError> {
+    let mut parser = Parser {
+        chars: input.chars().collect(),
+        pos: 0,
+    };
+    let mut query = SearchQuery::default();
+
+    while let Some(start) = parser.skip_whitespace() {
+        let negated = parser.peek() == Some('-');
+        if negated {
+            parser.pos += 1;
+            if parser.peek().is_none_or(char::is_whitespace) {
+                return Err(error(start, "Expected a word or phrase after `-`"));
+            }
+        }
+
+        if parser.peek() == Some('"') {
+            let phrase = parser.quoted()?;
+            if negated {
+                query.excluded.push(phrase);
+            } else {
+                query.phrases.push(phrase);
+            }
+            continue;
+        }
+
+        let token_start = parser.pos;
+        let token = parser.bare();
+        let field = token
+            .split_once(':')
+            .and_then(|(name, _)| Field::parse(name).map(|field| (field, name.chars().count())));
+
+        let Some((field, name_len)) = field else {
+            if negated {
+                query.excluded.push(token);
+            } else {
+                query.terms.push(token);
+            }
+            continue;
+        };
+
+        // 값이 따옴표로 시작하면 공백을 포함할 수 있으므로 값 위치부터 다시 읽는다.
+        let value_start = token_start + name_len + 1;
+        parser.pos = value_start;
+        let value = if parser.peek() == Some('"') {
+            parser.quoted()?
+        } else {
+            parser.bare()
+        };
+        if value.is_empty() {
+            return Err(error(value_start, "Missing value for field"));
+        }
+
+        match field {
+            Field::Tag if negated => query.excluded_tags.push(value),
+            Field::Tag => query.tags.push(value),
+            _ if negated => return Err(error(start, "Only `tag:` can be negated")),
+            Field::Pinned => {
+                query.pinned = Some(match value.to_ascii_lowercase().as_str() {
+                    "true" | "yes" => true,
+                    "false" | "no" => false,
+                    _ => return Err(error(value_start, "Expected `true` or `false`")),
+                });
+            }
+            Field::After => query.created_since = Some(parse_datetime(&value, value_start, now)?),
+            Field::Before => query.created_until = Some(parse_datetime(&value, value_start, now)?),
+            Field::UpdatedAfter => {
+                query.updated_since = Some(parse_datetime(&value, value_start, now)?)
+            }
+            Field::UpdatedBefore => {
+                query.updated_until = Some(parse_datetime(&value, value_start, now)?)
+            }
+        }
+    }
+
+    Ok(query)
+}
+
+impl Parser {
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.pos).copied()
+    }
+
+    fn skip_whitespace(&mut self) -> Option<usize> {
+        while self.peek().is_some_and(char::is_whitespace) {
+            self.pos += 1;
+        }
+        self.peek().map(|_| self.pos)
+    }
+
+    fn bare(&mut self) -> String {
+        let start = self.pos;
+        while self.peek().is_some_and(|c| !c.is_whitespace()) {
+            self.pos += 1;
+        }
+        self.chars[start..self.pos].iter().collect()
+    }
+
+    fn quoted(&mut self) -> Result<String, QueryParseError> {
+        let open = self.pos;
+        self.pos += 1;
+        let start = self.pos;
+        while self.peek().is_some_and(|c| c != '"') {
+            self.pos += 1;
+        }
+        if self.peek().is_none() {
+            return Err(error(open, "Unterminated quote"));
+        }
+
+        let text: String = self.chars[start..self.pos].iter().collect();
+        self.pos += 1;
+        let text = text.trim().to_string();
+        if text.is_empty() {
+            return Err(error(open, "Empty phrase"));
+        }
+        Ok(text)
+    }
+}
+
+fn parse_datetime(
+    value: &str,
+    position: usize,
+    now: NaiveDateTime,
+) -> Result<NaiveDateTime, QueryParseError> {
+    if let Some(ago) = parse_relative(value) {
+        return now
+            .checked_sub_signed(ago)
+            .ok_or_else(|| error(position, "Relative date is too far in the past"));
+    }
+
+    NaiveDate::parse_from_str(value, "%Y-%m-%d")
+        .map(|date| date.and_time(NaiveTime::MIN))
+        .or_else(|_| NaiveDateTime::parse_from_str(value, "%Y-%m-%dT%H:%M:%S"))
+        .map_err(|_| error(position, "Expected a date like 2024-06-01 or 30d"))
+}
+
+fn parse_relative(value: &str) -> Option<Duration> {
+    let unit = value.chars().last()?;
+    let amount: i64 = value[..value.len() - unit.len_utf8()].parse().ok()?;
+    match unit.to_ascii_lowercase() {
+        'h' => Duration::try_hours(amount),
+        'd' => Duration::try_days(amount),
+        'w' => Duration::try_weeks(amount),
+        _ => None,
+    }
+}
+
+fn error(position: usize, message: &str) -> QueryParseError {
+    QueryParseError {
+        position,
+        message: message.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests;
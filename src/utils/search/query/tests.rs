@@ -0,0 +1,110 @@
+use super::*;
+
+fn date(value: &str) -> NaiveDateTime {
+    NaiveDate::parse_from_str(value, "%Y-%m-%d")
+        .unwrap()
+        .and_time(NaiveTime::MIN)
+}
+
+#[test]
+fn test_parse_full_query() {
+    let query =
+        parse(r#"tag:rust pinned:true before:2024-06-01 "exact phrase" -draft 비동기"#).unwrap();
+
+    assert_eq!(
+        query,
+        SearchQuery {
+            terms: vec!["비동기".to_string()],
+            phrases: vec!["exact phrase".to_string()],
+            excluded: vec!["draft".to_string()],
+            tags: vec!["rust".to_string()],
+            pinned: Some(true),
+            created_until: Some(date("2024-06-01")),
+            ..Default::default()
+        }
+    );
+    assert_eq!(query.semantic_text().unwrap(), "비동기 exact phrase");
+}
+
+#[test]
+fn test_parse_quoted_field_values_and_negated_tags() {
+    let query = parse(
+        r#"-tag:"old ideas" tag:글감 after:2024-01-01 updated_before:2024-02-01T12:30:00 -"초안 메모""#,
+    )
+    .unwrap();
+
+    assert_eq!(query.tags, vec!["글감"]);
+    assert_eq!(query.excluded_tags, vec!["old ideas"]);
+    assert_eq!(query.excluded, vec!["초안 메모"]);
+    assert_eq!(query.created_since, Some(date("2024-01-01")));
+    assert_eq!(
+        query.updated_until,
+        Some(date("2024-02-01") + chrono::Duration::minutes(12 * 60 + 30))
+    );
+    assert!(query.semantic_text().is_none());
+}
+
+#[test]
+fn test_unknown_fields_are_plain_terms() {
+    let query = parse("https://example.com 10:30").unwrap();
+
+    assert_eq!(query.terms, vec!["https://example.com", "10:30"]);
+}
+
+#[test]
+fn test_parse_errors_report_position() {
+    let cases = [
+        (r#"rust "unterminated"#, 5, "Unterminated quote"),
+        ("pinned:maybe", 7, "Expected `true` or `false`"),
+        (
+            "memo before:2024-13-01",
+            12,
+            "Expected a date like 2024-06-01 or 30d",
+        ),
+        ("tag:", 4, "Missing value for field"),
+        ("rust -pinned:true", 5, "Only `tag:` can be negated"),
+        ("rust - draft", 5, "Expected a word or phrase after `-`"),
+        (r#"비동기 """#, 4, "Empty phrase"),
+    ];
+
+    for (input, position, message) in cases {
+        assert_eq!(
+            parse(input).unwrap_err(),
+            QueryParseError {
+                position,
+                message: message.to_string(),
+            },
+            "{}",
+            input
+        );
+    }
+}
+
+#[test]
+fn test_empty_query_has_no_text() {
+    let query = parse("   ").unwrap();
+
+    assert_eq!(query, SearchQuery::default());
+    assert!(!query.has_text());
+}
+
+#[test]
+fn test_relative_dates_are_resolved_against_now() {
+    let now = date("2024-06-30") + chrono::Duration::hours(12);
+    let query = parse_at("after:30d before:2w updated_after:12H", now).unwrap();
+
+    assert_eq!(
+        query.created_since,
+        Some(date("2024-05-31") + chrono::Duration::hours(12))
+    );
+    assert_eq!(
+        query.created_until,
+        Some(date("2024-06-16") + chrono::Duration::hours(12))
+    );
+    assert_eq!(query.updated_since, Some(date("2024-06-30")));
+
+    assert_eq!(
+        parse_at("after:30y", now).unwrap_err().message,
+        "Expected a date like 2024-06-01 or 30d"
+    );
+}
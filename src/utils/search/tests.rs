@@ -0,0 +1,103 @@
+use super::*;
+
+#[test]
+fn test_query_words_split_on_ascii_separators() {
+    assert_eq!(
+        query_words("  Rust, 비동기-프로그래밍!  "),
+        vec!["rust", "비동기", "프로그래밍"]
+    );
+    assert!(query_words(" ,.!? ").is_empty());
+}
+
+#[test]
+fn test_query_words_chunk_long_words_like_the_search_vector() {
+    let words = query_words(&"가".repeat(MAX_WORD_CHARS * 2 + 1));
+
+    assert_eq!(
+        words,
+        vec![
+            "가".repeat(MAX_WORD_CHARS),
+            "가".repeat(MAX_WORD_CHARS),
+            "가".to_string()
+        ]
+    );
+}
+
+#[test]
+fn test_to_tsquery_builds_bigram_phrases() {
+    let words = query_words("러스트 밥 go");
+
+    assert_eq!(
+        to_tsquery(&words).unwrap(),
+        "('러스' <-> '스트') & ('밥':*) & ('go')"
+    );
+    assert!(to_tsquery(&[]).is_none());
+}
+
+#[test]
+fn test_search_tsquery_combines_terms_phrases_and_exclusions() {
+    let search = query::parse(r#"Rust "a bc" -"old draft" -x"#).unwrap();
+
+    assert_eq!(
+        search_tsquery(&search).unwrap(),
+        "('ru' <-> 'us' <-> 'st') & ('a':*) <2> ('bc') \
+         & !(('ol' <-> 'ld') <2> ('dr' <-> 'ra' <-> 'af' <-> 'ft')) & !(('x':*))"
+    );
+    assert_eq!(highlight_words(&search), vec!["rust", "a", "bc"]);
+    assert!(search_tsquery(&query::parse("pinned:true !!!").unwrap()).is_none());
+}
+
+#[test]
+fn test_highlight_marks_matches_and_escapes_html() {
+    let words = query_words("메모");
+
+    assert_eq!(
+        highlight("<b>오늘</b> 메모 & 또 메모", &words),
+        "&lt;b&gt;오늘&lt;/b&gt; <mark>메모</mark> &amp; 또 <mark>메모</mark>"
+    );
+}
+
+#[test]
+fn test_highlight_is_case_insensitive_and_merges_overlaps() {
+    let words = query_words("Rust rustacean");
+
+    assert_eq!(
+        highlight("I am a RUSTacean", &words),
+        "I am a <mark>RUSTacean</mark>"
+    );
+}
+
+#[test]
+fn test_highlight_trims_long_content_around_first_match() {
+    let content = format!("{}키워드{}", "가".repeat(300), "나".repeat(300));
+    let snippet = highlight(&content, &query_words("키워드"));
+
+    assert!(snippet.starts_with('…'));
+    assert!(snippet.ends_with('…'));
+    assert!(snippet.contains("<mark>키워드</mark>"));
+    assert_eq!(
+        snippet.chars().count(),
+        SNIPPET_CHARS + "<mark></mark>".len() + 2
+    );
+}
+
+#[test]
+fn test_highlight_without_match_returns_leading_text() {
+    let content = "다".repeat(200);
+    let snippet = highlight(&content, &query_words("없음"));
+
+    assert_eq!(snippet, format!("{}…", "다".repeat(SNIPPET_CHARS)));
+}
+
+#[test]
+fn test_reciprocal_rank_fusion_rewards_items_found_by_both() {
+    let keyword = [1, 2, 3];
+    let semantic = [4, 3, 1];
+
+    let fused = reciprocal_rank_fusion(&[&keyword, &semantic]);
+    let ids: Vec<i32> = fused.iter().map(|(id, _)| *id).collect();
+
+    assert_eq!(ids, vec![1, 3, 4, 2]);
+    assert!((fused[0].1 - (1.0 / 61.0 + 1.0 / 63.0)).abs() < f32::EPSILON);
+    assert!(reciprocal_rank_fusion(&[&[], &[]]).is_empty());
+}
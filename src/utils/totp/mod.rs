@@ -0,0 +1,57 @@
+use totp_rs::{Algorithm, Secret, TOTP};
+
+use super::constant_time_eq;
+
+const DIGITS: usize = 6;
+const STEP_SECONDS: u64 = 30;
+// 기기 시계 오차를 고려해 앞뒤 한 단계까지 허용한다.
+const ALLOWED_SKEW_STEPS: u64 = 1;
+
+pub fn generate_secret() -> String {
+    Secret::generate_secret().to_encoded().to_string()
+}
+
+fn build(secret: &str, issuer: &str, account_name: &str) -> Option<TOTP> {
+    let bytes = Secret::Encoded(secret.to_string()).to_bytes().ok()?;
+    TOTP::new(
+        Algorithm::SHA1,
+        DIGITS,
+        ALLOWED_SKEW_STEPS as u8,
+        STEP_SECONDS,
+        bytes,
+        Some(issuer.to_string()),
+        account_name.to_string(),
+    )
+    .ok()
+}
+
+pub fn otpauth_uri(secret: &str, issuer: &str, account_name: &str) -> Option<String> {
+    build(secret, issuer, &account_name.replace(':', "")).map(|totp| totp.get_url())
+}
+
+pub fn generate_code(secret: &str, unix_time: u64) -> Option<String> {
+    build(secret, "", "").map(|totp| totp.generate(unix_time))
+}
+
+// 코드가 맞으면 일치한 시간 단계를 돌려준다. 재사용 방지를 위해 호출자가 단계를 기록해야 한다.
+pub fn verify_code(secret: &str, code: &str, unix_time: u64) -> Option<i64> {
+    let code = code.trim();
+    if code.len() != DIGITS || !code.chars().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+
+    let totp = build(secret, "", "")?;
+    let current_step = unix_time / STEP_SECONDS;
+
+    (current_step.saturating_sub(ALLOWED_SKEW_STEPS)..=current_step + ALLOWED_SKEW_STEPS)
+        .find(|step| {
+            constant_time_eq(
+                totp.generate(step * STEP_SECONDS).as_bytes(),
+                code.as_bytes(),
+            )
+        })
+        .map(|step| step as i64)
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,30 @@
+use super::*;
+
+#[test]
+fn test_generated_code_verifies_within_skew() {
+    let secret = generate_secret();
+    let now = 1_700_000_000;
+    let code = generate_code(&secret, now).unwrap();
+
+    assert_eq!(verify_code(&secret, &code, now), Some((now / 30) as i64));
+    assert!(verify_code(&secret, &code, now + 30).is_some());
+    assert!(verify_code(&secret, &code, now + 90).is_none());
+}
+
+#[test]
+fn test_malformed_code_rejected() {
+    let secret = generate_secret();
+
+    assert!(verify_code(&secret, "12345", 1_700_000_000).is_none());
+    assert!(verify_code(&secret, "abcdef", 1_700_000_000).is_none());
+}
+
+#[test]
+fn test_otpauth_uri() {
+    let secret = generate_secret();
+    let uri = otpauth_uri(&secret, "Lekha", "writer@example.com").unwrap();
+
+    assert!(uri.starts_with("otpauth://totp/Lekha:writer%40example.com?"));
+    assert!(uri.contains(&format!("secret={}", secret)));
+    assert!(uri.contains("issuer=Lekha"));
+}
@@ -0,0 +1,851 @@
+use axum::{
+    body::Body,
+    http::{self, Request, StatusCode},
+    Router,
+};
+use http_body_util::BodyExt;
+use inklings_server::{
+    clients::{Embedder, OAuthClient, OAuthVerifier, TextGenerator},
+    db, handlers,
+    test_utils::{MockGeminiClient, MockOAuthServer, MockQdrantRepository},
+    utils::jwt::JwtKeys,
+};
+use rand::Rng;
+use serde_json::{json, Value};
+use std::sync::Arc;
+use tower::util::ServiceExt;
+
+async fn setup() -> (Router, Arc<JwtKeys>) {
+    dotenv::dotenv().ok();
+
+    let database_url = std::env::var("DATABASE_URL_TEST")
+        .expect("DATABASE_URL_TEST must be set. Run: just setup-test-db");
+
+    let db = Arc::new(db::create_connection(&database_url).await.unwrap());
+
+    let qdrant_repo = Arc::new(MockQdrantRepository::new());
+    let gemini_client = Arc::new(MockGeminiClient::new());
+    let oauth_server = MockOAuthServer::start().await;
+    let oauth_client = Arc::new(OAuthClient::new(oauth_server.config()));
+    let jwt_keys = Arc::new(JwtKeys::generate());
+
+    let app = handlers::create_router(
+        db,
+        qdrant_repo,
+        gemini_client.clone() as Arc<dyn Embedder>,
+        gemini_client as Arc<dyn TextGenerator>,
+        oauth_client as Arc<dyn OAuthVerifier>,
+        jwt_keys.clone(),
+    );
+    (app, jwt_keys)
+}
+
+async fn send_json(
+    app: &Router,
+    method: http::Method,
+    uri: &str,
+    body: Value,
+) -> (StatusCode, Value) {
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .method(method)
+                .uri(uri)
+                .header(http::header::CONTENT_TYPE, "application/json")
+                .body(Body::from(body.to_string()))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    let status = response.status();
+    let body = response.into_body().collect().await.unwrap().to_bytes();
+    let value = serde_json::from_slice(&body).unwrap_or(Value::Null);
+    (status, value)
+}
+
+async fn create_personal_access_token(app: &Router, access_token: &str) -> String {
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .method(http::Method::POST)
+                .uri("/api/users/me/tokens")
+                .header(http::header::CONTENT_TYPE, "application/json")
+                .header(
+                    http::header::AUTHORIZATION,
+                    format!("Bearer {}", access_token),
+                )
+                .body(Body::from(
+                    json!({ "name": "script", "scopes": ["memos:read"] }).to_string(),
+                ))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::CREATED);
+    let body = response.into_body().collect().await.unwrap().to_bytes();
+    let created: Value = serde_json::from_slice(&body).unwrap();
+    created["secret"].as_str().unwrap().to_string()
+}
+
+#[tokio::test]
+async fn test_jwks_endpoint_publishes_signing_key() {
+    let (app, jwt_keys) = setup().await;
+
+    let response = app
+        .oneshot(
+            Request::builder()
+                .uri("/.well-known/jwks.json")
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let body = response.into_body().collect().await.unwrap().to_bytes();
+    let jwks: Value = serde_json::from_slice(&body).unwrap();
+    let key = &jwks["keys"][0];
+
+    assert_eq!(key["kty"], "OKP");
+    assert_eq!(key["crv"], "Ed25519");
+    assert_eq!(key["kid"], jwt_keys.signing_kid());
+    assert!(key.get("d").is_none());
+}
+
+#[tokio::test]
+async fn test_register_refresh_and_logout_api() {
+    let (app, _) = setup().await;
+    let random: u32 = rand::thread_rng().gen();
+
+    let (status, registered) = send_json(
+        &app,
+        http::Method::POST,
+        "/api/users/register",
+        json!({
+            "email": format!("api_{}@example.com", random),
+            "username": format!("api_{}", random),
+            "password": "correct-horse-battery-42",
+        }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED);
+
+    let (status, refreshed) = send_json(
+        &app,
+        http::Method::POST,
+        "/api/auth/refresh",
+        json!({ "refresh_token": registered["refresh_token"] }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_ne!(refreshed["refresh_token"], registered["refresh_token"]);
+
+    let (status, _) = send_json(
+        &app,
+        http::Method::POST,
+        "/api/auth/logout",
+        json!({ "refresh_token": refreshed["refresh_token"] }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::NO_CONTENT);
+
+    let (status, _) = send_json(
+        &app,
+        http::Method::POST,
+        "/api/auth/refresh",
+        json!({ "refresh_token": refreshed["refresh_token"] }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::UNAUTHORIZED);
+}
+
+#[tokio::test]
+async fn test_personal_access_token_authenticates_requests() {
+    let (app, _) = setup().await;
+    let random: u32 = rand::thread_rng().gen();
+
+    let (_, registered) = send_json(
+        &app,
+        http::Method::POST,
+        "/api/users/register",
+        json!({
+            "email": format!("pat_{}@example.com", random),
+            "username": format!("pat_{}", random),
+            "password": "correct-horse-battery-42",
+        }),
+    )
+    .await;
+    let access_token = registered["access_token"].as_str().unwrap();
+
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .method(http::Method::POST)
+                .uri("/api/users/me/tokens")
+                .header(http::header::CONTENT_TYPE, "application/json")
+                .header(
+                    http::header::AUTHORIZATION,
+                    format!("Bearer {}", access_token),
+                )
+                .body(Body::from(
+                    json!({ "name": "sync", "scopes": ["memos:read"] }).to_string(),
+                ))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::CREATED);
+    let body = response.into_body().collect().await.unwrap().to_bytes();
+    let created: Value = serde_json::from_slice(&body).unwrap();
+    let secret = created["secret"].as_str().unwrap().to_string();
+    assert!(secret.starts_with("ink_pat_"));
+
+    let get = |uri: &str, token: &str| {
+        Request::builder()
+            .uri(uri)
+            .header(http::header::AUTHORIZATION, format!("Bearer {}", token))
+            .body(Body::empty())
+            .unwrap()
+    };
+
+    let response = app
+        .clone()
+        .oneshot(get("/api/memos", &secret))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let response = app
+        .clone()
+        .oneshot(get("/api/memos", &format!("{}x", secret)))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+
+    // memos:read 토큰으로는 계정 정보나 쓰기 API에 접근할 수 없다.
+    let response = app
+        .clone()
+        .oneshot(get("/api/users/me", &secret))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+
+    let response = app
+        .oneshot(
+            Request::builder()
+                .method(http::Method::POST)
+                .uri("/api/memos")
+                .header(http::header::CONTENT_TYPE, "application/json")
+                .header(http::header::AUTHORIZATION, format!("Bearer {}", secret))
+                .body(Body::from(json!({ "content": "읽기 전용" }).to_string()))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+}
+
+#[tokio::test]
+async fn test_personal_access_token_cannot_change_login_methods() {
+    let (app, _) = setup().await;
+    let random: u32 = rand::thread_rng().gen();
+
+    let (_, registered) = send_json(
+        &app,
+        http::Method::POST,
+        "/api/users/register",
+        json!({
+            "email": format!("pat_account_{}@example.com", random),
+            "username": format!("pat_account_{}", random),
+            "password": "correct-horse-battery-42",
+        }),
+    )
+    .await;
+
+    let request = |method: http::Method, uri: &str, token: &str, body: Value| {
+        Request::builder()
+            .method(method)
+            .uri(uri)
+            .header(http::header::CONTENT_TYPE, "application/json")
+            .header(http::header::AUTHORIZATION, format!("Bearer {}", token))
+            .body(Body::from(body.to_string()))
+            .unwrap()
+    };
+
+    let response = app
+        .clone()
+        .oneshot(request(
+            http::Method::POST,
+            "/api/users/me/tokens",
+            registered["access_token"].as_str().unwrap(),
+            json!({ "name": "account", "scopes": ["account"] }),
+        ))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::CREATED);
+    let body = response.into_body().collect().await.unwrap().to_bytes();
+    let created: Value = serde_json::from_slice(&body).unwrap();
+    let secret = created["secret"].as_str().unwrap();
+
+    // `account` 스코프가 있어도 개인 액세스 토큰으로는 로그인 수단이나 계정 자체를 바꿀 수 없다.
+    let session_only = [
+        (
+            http::Method::POST,
+            "/api/users/me/tokens",
+            json!({ "name": "escalated", "scopes": ["account"] }),
+        ),
+        (
+            http::Method::PUT,
+            "/api/users/me/password",
+            json!({
+                "current_password": "correct-horse-battery-42",
+                "new_password": "another-horse-battery-43",
+            }),
+        ),
+        (
+            http::Method::POST,
+            "/api/users/me/oauth-accounts",
+            json!({ "provider": "google", "credential": "attacker-id-token" }),
+        ),
+        (
+            http::Method::DELETE,
+            "/api/users/me/oauth-accounts/google",
+            Value::Null,
+        ),
+        (
+            http::Method::PATCH,
+            "/api/users/me/2fa",
+            json!({ "enforce_on_oauth": false }),
+        ),
+        (http::Method::POST, "/api/users/me/2fa/totp", Value::Null),
+        (
+            http::Method::POST,
+            "/api/users/me/2fa/totp/activate",
+            json!({ "code": "123456" }),
+        ),
+        (
+            http::Method::POST,
+            "/api/users/me/2fa/totp/disable",
+            json!({ "code": "123456" }),
+        ),
+        (
+            http::Method::POST,
+            "/api/users/me/2fa/recovery-codes",
+            json!({ "code": "123456" }),
+        ),
+        (http::Method::DELETE, "/api/users/me", Value::Null),
+    ];
+    for (method, uri, body) in session_only {
+        let response = app
+            .clone()
+            .oneshot(request(method.clone(), uri, secret, body))
+            .await
+            .unwrap();
+        assert_eq!(
+            response.status(),
+            StatusCode::FORBIDDEN,
+            "{} {} must require a login session",
+            method,
+            uri
+        );
+    }
+
+    let response = app
+        .oneshot(request(
+            http::Method::GET,
+            "/api/users/me/tokens",
+            secret,
+            Value::Null,
+        ))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+}
+
+#[tokio::test]
+async fn test_revoked_session_rejects_access_token() {
+    let (app, _) = setup().await;
+    let random: u32 = rand::thread_rng().gen();
+    let credentials = json!({
+        "email": format!("session_{}@example.com", random),
+        "username": format!("session_{}", random),
+        "password": "correct-horse-battery-42",
+    });
+
+    let (_, first) = send_json(
+        &app,
+        http::Method::POST,
+        "/api/users/register",
+        credentials.clone(),
+    )
+    .await;
+    let (status, second) = send_json(
+        &app,
+        http::Method::POST,
+        "/api/users/login",
+        json!({ "email": credentials["email"], "password": credentials["password"] }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+
+    let request = |method: http::Method, uri: &str, token: &Value| {
+        Request::builder()
+            .method(method)
+            .uri(uri)
+            .header(
+                http::header::AUTHORIZATION,
+                format!("Bearer {}", token.as_str().unwrap()),
+            )
+            .header(http::header::USER_AGENT, "auth-api-test")
+            .header("X-Forwarded-For", "198.51.100.20, 10.0.0.1")
+            .body(Body::empty())
+            .unwrap()
+    };
+
+    let response = app
+        .clone()
+        .oneshot(request(
+            http::Method::GET,
+            "/api/users/me/sessions",
+            &second["access_token"],
+        ))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = response.into_body().collect().await.unwrap().to_bytes();
+    let sessions: Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(sessions.as_array().unwrap().len(), 2);
+
+    let response = app
+        .clone()
+        .oneshot(request(
+            http::Method::DELETE,
+            "/api/users/me/sessions",
+            &second["access_token"],
+        ))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::NO_CONTENT);
+
+    let response = app
+        .clone()
+        .oneshot(request(
+            http::Method::GET,
+            "/api/users/me",
+            &first["access_token"],
+        ))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+
+    let response = app
+        .oneshot(request(
+            http::Method::GET,
+            "/api/users/me",
+            &second["access_token"],
+        ))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+}
+
+#[tokio::test]
+async fn test_session_ignores_forged_forwarded_for_header() {
+    let (app, _) = setup().await;
+    let random: u32 = rand::thread_rng().gen();
+    let credentials = json!({
+        "email": format!("forwarded_{}@example.com", random),
+        "username": format!("forwarded_{}", random),
+        "password": "correct-horse-battery-42",
+    });
+    send_json(
+        &app,
+        http::Method::POST,
+        "/api/users/register",
+        credentials.clone(),
+    )
+    .await;
+
+    // 앞단 프록시를 지정하지 않았으므로 클라이언트가 보낸 X-Forwarded-For는 기록되지 않는다.
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .method(http::Method::POST)
+                .uri("/api/users/login")
+                .header(http::header::CONTENT_TYPE, "application/json")
+                .header("X-Forwarded-For", "203.0.113.66")
+                .body(Body::from(
+                    json!({ "email": credentials["email"], "password": credentials["password"] })
+                        .to_string(),
+                ))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = response.into_body().collect().await.unwrap().to_bytes();
+    let login: Value = serde_json::from_slice(&body).unwrap();
+
+    let response = app
+        .oneshot(
+            Request::builder()
+                .uri("/api/users/me/sessions")
+                .header(
+                    http::header::AUTHORIZATION,
+                    format!("Bearer {}", login["access_token"].as_str().unwrap()),
+                )
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = response.into_body().collect().await.unwrap().to_bytes();
+    let sessions: Value = serde_json::from_slice(&body).unwrap();
+    assert!(sessions
+        .as_array()
+        .unwrap()
+        .iter()
+        .all(|session| session["ip_address"].is_null()));
+}
+
+#[tokio::test]
+async fn test_admin_api_requires_admin_and_suspension_rejects_tokens() {
+    let (app, _) = setup().await;
+    let random: u32 = rand::thread_rng().gen();
+
+    let register = |name: String| {
+        json!({
+            "email": format!("{}@example.com", name),
+            "username": name,
+            "password": "correct-horse-battery-42",
+        })
+    };
+    let (_, admin) = send_json(
+        &app,
+        http::Method::POST,
+        "/api/users/register",
+        register(format!("admin_{}", random)),
+    )
+    .await;
+    let (_, member) = send_json(
+        &app,
+        http::Method::POST,
+        "/api/users/register",
+        register(format!("member_{}", random)),
+    )
+    .await;
+    assert_eq!(member["user"]["role"], "user");
+
+    let request = |method: http::Method, uri: String, token: &Value| {
+        Request::builder()
+            .method(method)
+            .uri(uri)
+            .header(
+                http::header::AUTHORIZATION,
+                format!("Bearer {}", token.as_str().unwrap()),
+            )
+            .body(Body::empty())
+            .unwrap()
+    };
+
+    let response = app
+        .clone()
+        .oneshot(request(
+            http::Method::GET,
+            "/api/admin/users".to_string(),
+            &admin["access_token"],
+        ))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+
+    // 첫 관리자는 DB에서 직접 지정한다. 역할은 요청마다 DB에서 읽으므로 기존 토큰에도 바로 반영된다.
+    let database_url = std::env::var("DATABASE_URL_TEST").unwrap();
+    let db = db::create_connection(&database_url).await.unwrap();
+    inklings_server::repositories::UserRepository::new(Arc::new(db))
+        .update_role(
+            admin["user"]["id"].as_i64().unwrap() as i32,
+            inklings_server::entities::user::UserRole::Admin,
+        )
+        .await
+        .unwrap();
+
+    let response = app
+        .clone()
+        .oneshot(request(
+            http::Method::GET,
+            format!("/api/admin/users?q=member_{}", random),
+            &admin["access_token"],
+        ))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = response.into_body().collect().await.unwrap().to_bytes();
+    let listed: Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(listed["total"], 1);
+    assert_eq!(listed["users"][0]["memo_count"], 0);
+
+    // 세션이 없는 개인 액세스 토큰도 강제 로그아웃과 정지 후에는 거부되는지 확인한다.
+    let pat = create_personal_access_token(&app, member["access_token"].as_str().unwrap()).await;
+
+    let member_id = member["user"]["id"].as_i64().unwrap();
+    let response = app
+        .clone()
+        .oneshot(request(
+            http::Method::POST,
+            format!("/api/admin/users/{}/logout", member_id),
+            &admin["access_token"],
+        ))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::NO_CONTENT);
+
+    for token in [&member["access_token"], &json!(pat)] {
+        let response = app
+            .clone()
+            .oneshot(request(http::Method::GET, "/api/memos".to_string(), token))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    }
+
+    let (status, member) = send_json(
+        &app,
+        http::Method::POST,
+        "/api/users/login",
+        json!({
+            "email": format!("member_{}@example.com", random),
+            "password": "correct-horse-battery-42",
+        }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    let pat = create_personal_access_token(&app, member["access_token"].as_str().unwrap()).await;
+
+    let response = app
+        .clone()
+        .oneshot(request(
+            http::Method::POST,
+            format!("/api/admin/users/{}/suspend", member_id),
+            &admin["access_token"],
+        ))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let response = app
+        .clone()
+        .oneshot(request(
+            http::Method::GET,
+            "/api/memos".to_string(),
+            &member["access_token"],
+        ))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+
+    let response = app
+        .clone()
+        .oneshot(request(
+            http::Method::GET,
+            "/api/memos".to_string(),
+            &json!(pat),
+        ))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+
+    let (status, _) = send_json(
+        &app,
+        http::Method::POST,
+        "/api/users/login",
+        json!({
+            "email": format!("member_{}@example.com", random),
+            "password": "correct-horse-battery-42",
+        }),
+    )
+    .await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+}
+
+#[tokio::test]
+async fn test_cookie_auth_mode_requires_csrf_for_state_changes() {
+    let (app, _) = setup().await;
+    let random: u32 = rand::thread_rng().gen();
+
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .method(http::Method::POST)
+                .uri("/api/users/register")
+                .header(http::header::CONTENT_TYPE, "application/json")
+                .header("X-Auth-Mode", "cookie")
+                .body(Body::from(
+                    json!({
+                        "email": format!("cookie_{}@example.com", random),
+                        "username": format!("cookie_{}", random),
+                        "password": "correct-horse-battery-42",
+                    })
+                    .to_string(),
+                ))
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::CREATED);
+
+    let set_cookies = |response: &axum::response::Response| {
+        response
+            .headers()
+            .get_all(http::header::SET_COOKIE)
+            .iter()
+            .map(|value| value.to_str().unwrap().to_string())
+            .collect::<Vec<_>>()
+    };
+    let cookie_value = |cookies: &[String], name: &str| {
+        cookies
+            .iter()
+            .find_map(|cookie| cookie.strip_prefix(&format!("{}=", name)))
+            .and_then(|rest| rest.split(';').next())
+            .unwrap()
+            .to_string()
+    };
+
+    let cookies = set_cookies(&response);
+    let access_cookie = cookies
+        .iter()
+        .find(|cookie| cookie.starts_with("lekha_access="))
+        .unwrap();
+    assert!(access_cookie.contains("HttpOnly"));
+    assert!(access_cookie.contains("Secure"));
+    assert!(access_cookie.contains("SameSite=Strict"));
+    let csrf_cookie = cookies
+        .iter()
+        .find(|cookie| cookie.starts_with("lekha_csrf="))
+        .unwrap();
+    assert!(!csrf_cookie.contains("HttpOnly"));
+
+    let body = response.into_body().collect().await.unwrap().to_bytes();
+    let registered: Value = serde_json::from_slice(&body).unwrap();
+    assert!(registered.get("access_token").is_none());
+    assert!(registered.get("refresh_token").is_none());
+    let csrf = registered["csrf_token"].as_str().unwrap().to_string();
+    assert_eq!(cookie_value(&cookies, "lekha_csrf"), csrf);
+
+    let cookie_header = format!(
+        "lekha_access={}; lekha_refresh={}; lekha_csrf={}",
+        cookie_value(&cookies, "lekha_access"),
+        cookie_value(&cookies, "lekha_refresh"),
+        csrf
+    );
+    let request = |method: http::Method, uri: &str, csrf: Option<&str>| {
+        let mut builder = Request::builder()
+            .method(method)
+            .uri(uri)
+            .header(http::header::COOKIE, &cookie_header)
+            .header(http::header::CONTENT_TYPE, "application/json")
+            .header("X-Auth-Mode", "cookie");
+        if let Some(csrf) = csrf {
+            builder = builder.header("X-CSRF-Token", csrf);
+        }
+        builder
+            .body(Body::from(json!({ "content": "쿠키 메모" }).to_string()))
+            .unwrap()
+    };
+
+    let response = app
+        .clone()
+        .oneshot(request(http::Method::GET, "/api/users/me", None))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let response = app
+        .clone()
+        .oneshot(request(http::Method::POST, "/api/memos", None))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+
+    let response = app
+        .clone()
+        .oneshot(request(http::Method::POST, "/api/memos", Some("forged")))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+
+    let response = app
+        .clone()
+        .oneshot(request(http::Method::POST, "/api/memos", Some(&csrf)))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::CREATED);
+
+    let response = app
+        .clone()
+        .oneshot(request(http::Method::POST, "/api/auth/refresh", None))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+
+    let response = app
+        .clone()
+        .oneshot(request(
+            http::Method::POST,
+            "/api/auth/refresh",
+            Some(&csrf),
+        ))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let refreshed = set_cookies(&response);
+    assert_ne!(
+        cookie_value(&refreshed, "lekha_refresh"),
+        cookie_value(&cookies, "lekha_refresh")
+    );
+
+    // 로그아웃은 새로 받은 리프레시 쿠키로 세션을 끝내고 인증 쿠키를 모두 지운다.
+    let logout = |refresh: &str| {
+        Request::builder()
+            .method(http::Method::POST)
+            .uri("/api/auth/logout")
+            .header(http::header::CONTENT_TYPE, "application/json")
+            .header(
+                http::header::COOKIE,
+                format!(
+                    "lekha_refresh={}; lekha_csrf={}",
+                    refresh,
+                    cookie_value(&refreshed, "lekha_csrf")
+                ),
+            )
+            .header("X-Auth-Mode", "cookie")
+            .header("X-CSRF-Token", cookie_value(&refreshed, "lekha_csrf"))
+            .body(Body::empty())
+            .unwrap()
+    };
+
+    let response = app
+        .clone()
+        .oneshot(logout(&cookie_value(&refreshed, "lekha_refresh")))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::NO_CONTENT);
+    assert!(set_cookies(&response)
+        .iter()
+        .all(|cookie| cookie.contains("Max-Age=0")));
+
+    // 알 수 없는 리프레시 토큰으로 로그아웃이 실패해도 쿠키는 지운다.
+    let response = app.oneshot(logout("unknown-refresh-token")).await.unwrap();
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+    let cleared = set_cookies(&response);
+    assert_eq!(cleared.len(), 3);
+    assert!(cleared.iter().all(|cookie| cookie.contains("Max-Age=0")));
+}
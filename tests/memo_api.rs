@@ -5,18 +5,34 @@ use axum::{
 };
 use http_body_util::BodyExt;
 use inklings_server::{
-    clients::{Embedder, TextGenerator},
+    clients::{Embedder, OAuthClient, OAuthVerifier, TextGenerator},
     db,
     entities::user,
     handlers,
-    models::memo_dto::{CreateMemoRequest, MemoResponse},
+    models::{
+        memo_dto::{
+            CreateMemoRequest, MemoListResponse, MemoResponse, MemoSearchResponse,
+            TrashedMemoResponse,
+        },
+        memo_revision_dto::{MemoRevisionDiffResponse, MemoRevisionSummary},
+        notebook_dto::NotebookResponse,
+        saved_search_dto::{CreateSavedSearchRequest, SavedSearchResponse},
+        session_dto::ClientInfo,
+        tag_dto::TagResponse,
+    },
     services,
-    test_utils::{MockGeminiClient, MockQdrantRepository},
+    test_utils::{MockGeminiClient, MockOAuthServer, MockQdrantRepository},
+    utils::jwt::JwtKeys,
 };
 use sea_orm::{ActiveModelTrait, DatabaseConnection, EntityTrait, Set};
-use std::sync::Arc;
+use std::sync::{Arc, OnceLock};
 use tower::util::ServiceExt;
 
+fn test_jwt_keys() -> Arc<JwtKeys> {
+    static KEYS: OnceLock<Arc<JwtKeys>> = OnceLock::new();
+    KEYS.get_or_init(|| Arc::new(JwtKeys::generate())).clone()
+}
+
 async fn setup() -> (Router, Arc<DatabaseConnection>) {
     dotenv::dotenv().ok();
 
@@ -27,12 +43,16 @@ async fn setup() -> (Router, Arc<DatabaseConnection>) {
 
     let qdrant_repo = Arc::new(MockQdrantRepository::new());
     let gemini_client = Arc::new(MockGeminiClient::new());
+    let oauth_server = MockOAuthServer::start().await;
+    let oauth_client = Arc::new(OAuthClient::new(oauth_server.config()));
 
     let app = handlers::create_router(
         db.clone(),
         qdrant_repo,
         gemini_client.clone() as Arc<dyn Embedder>,
         gemini_client as Arc<dyn TextGenerator>,
+        oauth_client as Arc<dyn OAuthVerifier>,
+        test_jwt_keys(),
     );
     (app, db)
 }
@@ -50,9 +70,12 @@ async fn create_test_user(db: &DatabaseConnection, id: i32, username: &str) -> u
     user.insert(db).await.unwrap()
 }
 
-fn generate_test_token(user_id: i32) -> String {
-    let jwt_secret = std::env::var("JWT_SECRET").unwrap_or_else(|_| "test_secret_key_min_32_chars_long".to_string());
-    inklings_server::utils::jwt::generate_token(user_id, &jwt_secret, 24).unwrap()
+async fn generate_test_token(db: &Arc<DatabaseConnection>, user: &user::Model) -> String {
+    services::AuthService::new(db.clone(), test_jwt_keys())
+        .issue_tokens(user.clone(), ClientInfo::default())
+        .await
+        .unwrap()
+        .access_token
 }
 
 #[tokio::test]
@@ -62,9 +85,10 @@ async fn test_create_memo_api() {
 
     let req_body = CreateMemoRequest {
         content: "Test memo from integration test".to_string(),
+        ..Default::default()
     };
 
-    let token = generate_test_token(user.id);
+    let token = generate_test_token(&db, &user).await;
 
     let response = app
         .oneshot(
@@ -99,7 +123,8 @@ async fn test_list_memos_api() {
     let memo_service = Arc::new(services::memo_service::MemoService::new(
         db.clone(),
         qdrant_repo,
-        embedder as Arc<dyn Embedder>,
+        embedder.clone() as Arc<dyn Embedder>,
+        embedder as Arc<dyn TextGenerator>,
     ));
 
     memo_service
@@ -107,6 +132,7 @@ async fn test_list_memos_api() {
             user1.id,
             CreateMemoRequest {
                 content: "user1 memo 1".to_string(),
+                ..Default::default()
             },
         )
         .await
@@ -116,6 +142,7 @@ async fn test_list_memos_api() {
             user1.id,
             CreateMemoRequest {
                 content: "user1 memo 2".to_string(),
+                ..Default::default()
             },
         )
         .await
@@ -125,14 +152,16 @@ async fn test_list_memos_api() {
             user2.id,
             CreateMemoRequest {
                 content: "user2 memo".to_string(),
+                ..Default::default()
             },
         )
         .await
         .unwrap();
 
-    let token = generate_test_token(user1.id);
+    let token = generate_test_token(&db, &user1).await;
 
     let response = app
+        .clone()
         .oneshot(
             Request::builder()
                 .method(http::Method::GET)
@@ -147,10 +176,46 @@ async fn test_list_memos_api() {
     assert_eq!(response.status(), StatusCode::OK);
 
     let body = response.into_body().collect().await.unwrap().to_bytes();
-    let memos: Vec<MemoResponse> = serde_json::from_slice(&body).unwrap();
+    let page: MemoListResponse = serde_json::from_slice(&body).unwrap();
+
+    assert_eq!(page.memos.len(), 2);
+    assert!(page.memos.iter().all(|m| m.user_id == user1.id));
+    assert!(page.next_cursor.is_none());
+
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .method(http::Method::GET)
+                .uri("/api/memos?limit=1")
+                .header(http::header::AUTHORIZATION, format!("Bearer {}", token))
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    let body = response.into_body().collect().await.unwrap().to_bytes();
+    let first: MemoListResponse = serde_json::from_slice(&body).unwrap();
+    assert_eq!(first.memos[0].content, "user1 memo 2");
 
-    assert_eq!(memos.len(), 2);
-    assert!(memos.iter().all(|m| m.user_id == user1.id));
+    let response = app
+        .oneshot(
+            Request::builder()
+                .method(http::Method::GET)
+                .uri(format!(
+                    "/api/memos?limit=1&cursor={}",
+                    first.next_cursor.unwrap()
+                ))
+                .header(http::header::AUTHORIZATION, format!("Bearer {}", token))
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    let body = response.into_body().collect().await.unwrap().to_bytes();
+    let second: MemoListResponse = serde_json::from_slice(&body).unwrap();
+    assert_eq!(second.memos[0].content, "user1 memo 1");
+    assert!(second.next_cursor.is_none());
 }
 
 #[tokio::test]
@@ -164,7 +229,8 @@ async fn test_get_memo_unauthorized_api() {
     let memo_service = Arc::new(services::memo_service::MemoService::new(
         db.clone(),
         qdrant_repo,
-        embedder as Arc<dyn Embedder>,
+        embedder.clone() as Arc<dyn Embedder>,
+        embedder as Arc<dyn TextGenerator>,
     ));
 
     let memo1 = memo_service
@@ -172,12 +238,13 @@ async fn test_get_memo_unauthorized_api() {
             user1.id,
             CreateMemoRequest {
                 content: "user1's secret memo".to_string(),
+                ..Default::default()
             },
         )
         .await
         .unwrap();
 
-    let token = generate_test_token(user2.id);
+    let token = generate_test_token(&db, &user2).await;
 
     let response = app
         .oneshot(
@@ -193,3 +260,590 @@ async fn test_get_memo_unauthorized_api() {
 
     assert_eq!(response.status(), StatusCode::FORBIDDEN);
 }
+
+#[tokio::test]
+async fn test_search_memos_api() {
+    let (app, db) = setup().await;
+    let user = create_test_user(&db, 50, "user50").await;
+
+    let qdrant_repo = Arc::new(MockQdrantRepository::new());
+    let embedder = Arc::new(MockGeminiClient::new());
+    let memo_service = Arc::new(services::memo_service::MemoService::new(
+        db.clone(),
+        qdrant_repo,
+        embedder.clone() as Arc<dyn Embedder>,
+        embedder as Arc<dyn TextGenerator>,
+    ));
+
+    let memo = memo_service
+        .create_memo(
+            user.id,
+            CreateMemoRequest {
+                content: "tokio 런타임 정리".to_string(),
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+
+    let token = generate_test_token(&db, &user).await;
+    let search = |uri: &str| {
+        Request::builder()
+            .method(http::Method::GET)
+            .uri(uri)
+            .header(http::header::AUTHORIZATION, format!("Bearer {}", token))
+            .body(Body::empty())
+            .unwrap()
+    };
+
+    let response = app
+        .clone()
+        .oneshot(search("/api/memos/search?q=Tokio"))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let body = response.into_body().collect().await.unwrap().to_bytes();
+    let found: MemoSearchResponse = serde_json::from_slice(&body).unwrap();
+    assert_eq!(found.total, 1);
+    assert_eq!(found.results[0].memo.id, memo.id);
+    assert_eq!(found.results[0].snippet, "<mark>tokio</mark> 런타임 정리");
+
+    let response = app
+        .clone()
+        .oneshot(search("/api/memos/search?q=%20"))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+    // `tokio pinned:maybe`
+    let response = app
+        .oneshot(search("/api/memos/search?q=tokio%20pinned%3Amaybe"))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+    let body = response.into_body().collect().await.unwrap().to_bytes();
+    let error: serde_json::Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(error["error"], "Expected `true` or `false`");
+    assert_eq!(error["position"], 13);
+}
+
+#[tokio::test]
+async fn test_saved_search_api() {
+    let (app, db) = setup().await;
+    let user = create_test_user(&db, 60, "user60").await;
+    let token = generate_test_token(&db, &user).await;
+
+    let post = |uri: &str, body: String| {
+        Request::builder()
+            .method(http::Method::POST)
+            .uri(uri)
+            .header(http::header::CONTENT_TYPE, "application/json")
+            .header(http::header::AUTHORIZATION, format!("Bearer {}", token))
+            .body(Body::from(body))
+            .unwrap()
+    };
+
+    let response = app
+        .clone()
+        .oneshot(post(
+            "/api/saved-searches",
+            serde_json::to_string(&CreateSavedSearchRequest {
+                name: "최근 초안".to_string(),
+                query: "초안 after:7d".to_string(),
+            })
+            .unwrap(),
+        ))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::CREATED);
+    let body = response.into_body().collect().await.unwrap().to_bytes();
+    let saved: SavedSearchResponse = serde_json::from_slice(&body).unwrap();
+
+    let response = app
+        .clone()
+        .oneshot(post(
+            "/api/memos",
+            serde_json::to_string(&CreateMemoRequest {
+                content: "에세이 초안".to_string(),
+                ..Default::default()
+            })
+            .unwrap(),
+        ))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::CREATED);
+    let body = response.into_body().collect().await.unwrap().to_bytes();
+    let memo: MemoResponse = serde_json::from_slice(&body).unwrap();
+
+    let response = app
+        .clone()
+        .oneshot(
+            Request::builder()
+                .method(http::Method::GET)
+                .uri(format!("/api/saved-searches/{}/memos", saved.id))
+                .header(http::header::AUTHORIZATION, format!("Bearer {}", token))
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = response.into_body().collect().await.unwrap().to_bytes();
+    let found: MemoSearchResponse = serde_json::from_slice(&body).unwrap();
+    assert_eq!(found.total, 1);
+    assert_eq!(found.results[0].memo.id, memo.id);
+
+    let response = app
+        .oneshot(post(
+            "/api/saved-searches",
+            r#"{"name": "깨진 검색", "query": "\"초안"}"#.to_string(),
+        ))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    let body = response.into_body().collect().await.unwrap().to_bytes();
+    let error: serde_json::Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(error["error"], "Unterminated quote");
+    assert_eq!(error["position"], 0);
+}
+
+#[tokio::test]
+async fn test_tag_api() {
+    let (app, db) = setup().await;
+    let user = create_test_user(&db, 70, "user70").await;
+    let token = generate_test_token(&db, &user).await;
+
+    let request = |method: http::Method, uri: &str, body: Option<String>| {
+        Request::builder()
+            .method(method)
+            .uri(uri)
+            .header(http::header::CONTENT_TYPE, "application/json")
+            .header(http::header::AUTHORIZATION, format!("Bearer {}", token))
+            .body(body.map(Body::from).unwrap_or_else(Body::empty))
+            .unwrap()
+    };
+
+    let mut memo_ids = Vec::new();
+    for (content, tags) in [("타입 시스템", vec!["TS"]), ("비동기", vec!["js"])] {
+        let response = app
+            .clone()
+            .oneshot(request(
+                http::Method::POST,
+                "/api/memos",
+                Some(
+                    serde_json::to_string(&CreateMemoRequest {
+                        content: content.to_string(),
+                        tags: tags.into_iter().map(String::from).collect(),
+                        ..Default::default()
+                    })
+                    .unwrap(),
+                ),
+            ))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::CREATED);
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        let memo: MemoResponse = serde_json::from_slice(&body).unwrap();
+        memo_ids.push(memo.id);
+    }
+
+    let response = app
+        .clone()
+        .oneshot(request(http::Method::GET, "/api/tags", None))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = response.into_body().collect().await.unwrap().to_bytes();
+    let tags: Vec<TagResponse> = serde_json::from_slice(&body).unwrap();
+    let names: Vec<&str> = tags.iter().map(|tag| tag.name.as_str()).collect();
+    assert_eq!(names, vec!["js", "ts"]);
+
+    let response = app
+        .clone()
+        .oneshot(request(
+            http::Method::POST,
+            &format!("/api/tags/{}/merge", tags[1].id),
+            Some(format!(r#"{{"target_id": {}}}"#, tags[0].id)),
+        ))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = response.into_body().collect().await.unwrap().to_bytes();
+    let merged: TagResponse = serde_json::from_slice(&body).unwrap();
+    assert_eq!(merged.memo_count, 2);
+
+    let response = app
+        .clone()
+        .oneshot(request(http::Method::GET, "/api/memos?tags=js", None))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = response.into_body().collect().await.unwrap().to_bytes();
+    let page: MemoListResponse = serde_json::from_slice(&body).unwrap();
+    let mut ids: Vec<i32> = page.memos.iter().map(|m| m.id).collect();
+    ids.sort();
+    assert_eq!(ids, memo_ids);
+
+    let response = app
+        .oneshot(request(
+            http::Method::PATCH,
+            &format!("/api/tags/{}", tags[1].id),
+            Some(r#"{"name": "typescript"}"#.to_string()),
+        ))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn test_notebook_api() {
+    let (app, db) = setup().await;
+    let user = create_test_user(&db, 80, "user80").await;
+    let token = generate_test_token(&db, &user).await;
+
+    let request = |method: http::Method, uri: &str, body: Option<String>| {
+        Request::builder()
+            .method(method)
+            .uri(uri)
+            .header(http::header::CONTENT_TYPE, "application/json")
+            .header(http::header::AUTHORIZATION, format!("Bearer {}", token))
+            .body(body.map(Body::from).unwrap_or_else(Body::empty))
+            .unwrap()
+    };
+
+    let mut parent_id = None;
+    let mut notebook_ids = Vec::new();
+    for name in ["업무", "회의록"] {
+        let response = app
+            .clone()
+            .oneshot(request(
+                http::Method::POST,
+                "/api/notebooks",
+                Some(serde_json::json!({ "name": name, "parent_id": parent_id }).to_string()),
+            ))
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::CREATED);
+        let body = response.into_body().collect().await.unwrap().to_bytes();
+        let notebook: NotebookResponse = serde_json::from_slice(&body).unwrap();
+        parent_id = Some(notebook.id);
+        notebook_ids.push(notebook.id);
+    }
+    let (work, meetings) = (notebook_ids[0], notebook_ids[1]);
+
+    let response = app
+        .clone()
+        .oneshot(request(
+            http::Method::POST,
+            "/api/memos",
+            Some(
+                serde_json::to_string(&CreateMemoRequest {
+                    content: "주간 회의".to_string(),
+                    notebook_id: Some(meetings),
+                    ..Default::default()
+                })
+                .unwrap(),
+            ),
+        ))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::CREATED);
+    let body = response.into_body().collect().await.unwrap().to_bytes();
+    let memo: MemoResponse = serde_json::from_slice(&body).unwrap();
+    assert_eq!(memo.notebook_id, Some(meetings));
+
+    let response = app
+        .clone()
+        .oneshot(request(
+            http::Method::GET,
+            &format!("/api/memos?notebook_id={}&recursive=true", work),
+            None,
+        ))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = response.into_body().collect().await.unwrap().to_bytes();
+    let page: MemoListResponse = serde_json::from_slice(&body).unwrap();
+    let ids: Vec<i32> = page.memos.iter().map(|m| m.id).collect();
+    assert_eq!(ids, vec![memo.id]);
+
+    let response = app
+        .clone()
+        .oneshot(request(
+            http::Method::POST,
+            &format!("/api/notebooks/{}/move", work),
+            Some(format!(r#"{{"parent_id": {}}}"#, meetings)),
+        ))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+    let response = app
+        .clone()
+        .oneshot(request(
+            http::Method::DELETE,
+            &format!("/api/notebooks/{}?mode=cascade", work),
+            None,
+        ))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::NO_CONTENT);
+
+    let response = app
+        .oneshot(request(
+            http::Method::GET,
+            &format!("/api/memos/{}", memo.id),
+            None,
+        ))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn test_memo_revision_api() {
+    let (app, db) = setup().await;
+    let user = create_test_user(&db, 90, "user90").await;
+    let token = generate_test_token(&db, &user).await;
+
+    let request = |method: http::Method, uri: &str, body: Option<String>| {
+        Request::builder()
+            .method(method)
+            .uri(uri)
+            .header(http::header::CONTENT_TYPE, "application/json")
+            .header(http::header::AUTHORIZATION, format!("Bearer {}", token))
+            .body(body.map(Body::from).unwrap_or_else(Body::empty))
+            .unwrap()
+    };
+
+    let response = app
+        .clone()
+        .oneshot(request(
+            http::Method::POST,
+            "/api/memos",
+            Some(r#"{"content": "초안"}"#.to_string()),
+        ))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::CREATED);
+    let body = response.into_body().collect().await.unwrap().to_bytes();
+    let memo: MemoResponse = serde_json::from_slice(&body).unwrap();
+
+    let response = app
+        .clone()
+        .oneshot(request(
+            http::Method::PUT,
+            &format!("/api/memos/{}", memo.id),
+            Some(r#"{"content": "수정본"}"#.to_string()),
+        ))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let response = app
+        .clone()
+        .oneshot(request(
+            http::Method::GET,
+            &format!("/api/memos/{}/revisions", memo.id),
+            None,
+        ))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = response.into_body().collect().await.unwrap().to_bytes();
+    let revisions: Vec<MemoRevisionSummary> = serde_json::from_slice(&body).unwrap();
+    assert_eq!(revisions.len(), 1);
+    let revision_id = revisions[0].id;
+
+    let response = app
+        .clone()
+        .oneshot(request(
+            http::Method::GET,
+            &format!(
+                "/api/memos/{}/revisions/diff?from={}&granularity=char",
+                memo.id, revision_id
+            ),
+            None,
+        ))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = response.into_body().collect().await.unwrap().to_bytes();
+    let diff: MemoRevisionDiffResponse = serde_json::from_slice(&body).unwrap();
+    assert!(!diff.chunks.is_empty());
+
+    let response = app
+        .clone()
+        .oneshot(request(
+            http::Method::POST,
+            &format!("/api/memos/{}/revisions/{}/restore", memo.id, revision_id),
+            None,
+        ))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = response.into_body().collect().await.unwrap().to_bytes();
+    let restored: MemoResponse = serde_json::from_slice(&body).unwrap();
+    assert_eq!(restored.content, "초안");
+
+    let response = app
+        .oneshot(request(
+            http::Method::GET,
+            &format!("/api/memos/{}/revisions/{}", memo.id, revision_id + 1000),
+            None,
+        ))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn test_trash_api() {
+    let (app, db) = setup().await;
+    let user = create_test_user(&db, 100, "user100").await;
+    let token = generate_test_token(&db, &user).await;
+
+    let request = |method: http::Method, uri: &str, body: Option<String>| {
+        Request::builder()
+            .method(method)
+            .uri(uri)
+            .header(http::header::CONTENT_TYPE, "application/json")
+            .header(http::header::AUTHORIZATION, format!("Bearer {}", token))
+            .body(body.map(Body::from).unwrap_or_else(Body::empty))
+            .unwrap()
+    };
+
+    let response = app
+        .clone()
+        .oneshot(request(
+            http::Method::POST,
+            "/api/memos",
+            Some(r#"{"content": "지울 메모"}"#.to_string()),
+        ))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::CREATED);
+    let body = response.into_body().collect().await.unwrap().to_bytes();
+    let memo: MemoResponse = serde_json::from_slice(&body).unwrap();
+
+    let response = app
+        .clone()
+        .oneshot(request(
+            http::Method::DELETE,
+            &format!("/api/memos/{}", memo.id),
+            None,
+        ))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::NO_CONTENT);
+
+    let response = app
+        .clone()
+        .oneshot(request(http::Method::GET, "/api/memos/trash", None))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = response.into_body().collect().await.unwrap().to_bytes();
+    let trash: Vec<TrashedMemoResponse> = serde_json::from_slice(&body).unwrap();
+    assert_eq!(trash.len(), 1);
+    assert_eq!(trash[0].memo.id, memo.id);
+
+    let response = app
+        .clone()
+        .oneshot(request(
+            http::Method::POST,
+            &format!("/api/memos/{}/restore", memo.id),
+            None,
+        ))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let response = app
+        .oneshot(request(
+            http::Method::GET,
+            &format!("/api/memos/{}", memo.id),
+            None,
+        ))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+}
+
+#[tokio::test]
+async fn test_archive_api() {
+    let (app, db) = setup().await;
+    let user = create_test_user(&db, 110, "user110").await;
+    let token = generate_test_token(&db, &user).await;
+
+    let request = |method: http::Method, uri: &str, body: Option<String>| {
+        Request::builder()
+            .method(method)
+            .uri(uri)
+            .header(http::header::CONTENT_TYPE, "application/json")
+            .header(http::header::AUTHORIZATION, format!("Bearer {}", token))
+            .body(body.map(Body::from).unwrap_or_else(Body::empty))
+            .unwrap()
+    };
+
+    let response = app
+        .clone()
+        .oneshot(request(
+            http::Method::POST,
+            "/api/memos",
+            Some(r#"{"content": "보관할 메모"}"#.to_string()),
+        ))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::CREATED);
+    let body = response.into_body().collect().await.unwrap().to_bytes();
+    let memo: MemoResponse = serde_json::from_slice(&body).unwrap();
+
+    let response = app
+        .clone()
+        .oneshot(request(
+            http::Method::PATCH,
+            &format!("/api/memos/{}/archive", memo.id),
+            None,
+        ))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = response.into_body().collect().await.unwrap().to_bytes();
+    let archived: MemoResponse = serde_json::from_slice(&body).unwrap();
+    assert!(archived.is_archived);
+
+    let response = app
+        .clone()
+        .oneshot(request(http::Method::GET, "/api/memos", None))
+        .await
+        .unwrap();
+    let body = response.into_body().collect().await.unwrap().to_bytes();
+    let page: MemoListResponse = serde_json::from_slice(&body).unwrap();
+    assert!(page.memos.is_empty());
+
+    let response = app
+        .clone()
+        .oneshot(request(http::Method::GET, "/api/memos?archived=true", None))
+        .await
+        .unwrap();
+    let body = response.into_body().collect().await.unwrap().to_bytes();
+    let page: MemoListResponse = serde_json::from_slice(&body).unwrap();
+    assert_eq!(page.memos.len(), 1);
+    assert_eq!(page.memos[0].id, memo.id);
+
+    let response = app
+        .oneshot(request(
+            http::Method::PATCH,
+            &format!("/api/memos/{}/unarchive", memo.id),
+            None,
+        ))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = response.into_body().collect().await.unwrap().to_bytes();
+    let unarchived: MemoResponse = serde_json::from_slice(&body).unwrap();
+    assert!(!unarchived.is_archived);
+}